@@ -1,7 +1,8 @@
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use colored::Colorize;
-use protest::{FailureSnapshot, RegressionConfig, RegressionGenerator};
+use protest::persistence::VERIFY_ENV_VAR;
+use protest::{FailureSnapshot, RegressionConfig, RegressionGenerator, VerifyRecord};
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -54,10 +55,40 @@ enum Commands {
         #[arg(short, long, default_value = "tests/regressions")]
         output: PathBuf,
 
+        /// Path of the property function the tests call (`{test}` expands to the test name)
+        ///
+        /// Required whenever there are failures to generate tests for.
+        #[arg(short, long)]
+        property: Option<String>,
+
+        /// Expression building the test's generator (`{test}` expands to the test name)
+        ///
+        /// Seed-based replay tests need the generator the original test used.
+        /// Without it, only `--value-based` tests are generated.
+        #[arg(short, long)]
+        generator: Option<String>,
+
+        /// Also generate tests that call the property on the recorded minimal input
+        #[arg(long)]
+        value_based: bool,
+
         /// Skip confirmation prompt
         #[arg(short = 'y', long)]
         yes: bool,
     },
+    /// Check whether saved failures still reproduce
+    Verify {
+        /// Name of the test (omit to verify all tests)
+        test_name: Option<String>,
+
+        /// Command that runs the property tests
+        #[arg(short, long, default_value = "cargo test")]
+        command: String,
+
+        /// Delete saved failures that no longer reproduce
+        #[arg(long)]
+        prune: bool,
+    },
 }
 
 fn main() -> Result<()> {
@@ -77,8 +108,28 @@ fn main() -> Result<()> {
         Commands::Generate {
             test_name,
             output,
+            property,
+            generator,
+            value_based,
             yes,
-        } => generate_regressions(&snapshot, test_name, output, yes),
+        } => {
+            let mut config = RegressionConfig::new(&output).value_based(value_based);
+            if let Some(property) = property {
+                config = config.with_property(property);
+            }
+            match generator {
+                Some(generator) => config = config.with_generator(generator),
+                None if value_based => config = config.seed_based(false),
+                // Generation reports that seed-based tests need a generator
+                None => {}
+            }
+            generate_regressions(&snapshot, test_name, config, yes)
+        }
+        Commands::Verify {
+            test_name,
+            command,
+            prune,
+        } => verify_failures(&snapshot, test_name, &command, prune),
     }
 }
 
@@ -413,11 +464,11 @@ fn show_stats(snapshot: &FailureSnapshot) -> Result<()> {
 fn generate_regressions(
     snapshot: &FailureSnapshot,
     test_name: Option<String>,
-    output_dir: PathBuf,
+    config: RegressionConfig,
     yes: bool,
 ) -> Result<()> {
-    let config = RegressionConfig::new(&output_dir);
-    let generator = RegressionGenerator::new(config);
+    let output_dir = config.output_dir.clone();
+    let generator = RegressionGenerator::new(config.clone());
 
     match test_name {
         Some(test) => {
@@ -434,6 +485,7 @@ fn generate_regressions(
                 return Ok(());
             }
 
+            require_property(&config)?;
             if !yes {
                 print!(
                     "Generate regression tests for {} failure(s) in '{}'? [y/N] ",
@@ -459,7 +511,7 @@ fn generate_regressions(
             println!();
             println!("{}", "Next steps:".bold());
             println!("  1. Review the generated file: {}", file_path.display());
-            println!("  2. Implement the TODO sections with your actual test logic");
+            println!("  2. Add it to your tests directory so cargo builds it");
             println!(
                 "  3. Run: cargo test --test {}",
                 file_path.file_stem().unwrap().to_str().unwrap()
@@ -484,6 +536,7 @@ fn generate_regressions(
                 }
             }
 
+            require_property(&config)?;
             if !yes {
                 print!(
                     "Generate regression tests for {} failure(s) across {} test(s)? [y/N] ",
@@ -514,7 +567,7 @@ fn generate_regressions(
                 "  1. Review the generated files in: {}",
                 output_dir.display()
             );
-            println!("  2. Implement the TODO sections with your actual test logic");
+            println!("  2. Add them to your tests directory so cargo builds them");
             println!("  3. Run: cargo test --test '*_regressions'");
         }
     }
@@ -522,6 +575,164 @@ fn generate_regressions(
     Ok(())
 }
 
+/// Fail before prompting when there are tests to generate but no property for them to call
+fn require_property(config: &RegressionConfig) -> Result<()> {
+    if config.property.is_none() {
+        anyhow::bail!(
+            "--property is required to generate regression tests; pass the path of the \
+             property function they call, e.g. --property 'my_crate::properties::{{test}}'"
+        );
+    }
+    Ok(())
+}
+
+fn verify_failures(
+    snapshot: &FailureSnapshot,
+    test_name: Option<String>,
+    command: &str,
+    prune: bool,
+) -> Result<()> {
+    let tests = match test_name {
+        Some(test) => vec![test],
+        None => snapshot
+            .list_tests_with_failures()
+            .context("Failed to list tests")?,
+    };
+
+    let mut tests_with_failures = Vec::new();
+    for test in tests {
+        let failures = snapshot
+            .load_failures(&test)
+            .context(format!("Failed to load failures for '{}'", test))?;
+        if !failures.is_empty() {
+            tests_with_failures.push((test, failures));
+        }
+    }
+
+    if tests_with_failures.is_empty() {
+        println!("{}", "No saved failures found.".green());
+        return Ok(());
+    }
+
+    // Property tests in verify mode append one record per saved failure here
+    let report = std::env::temp_dir().join(format!("protest-verify-{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&report);
+
+    if command.trim().is_empty() {
+        anyhow::bail!("Empty test command");
+    }
+    println!("{}", format!("Running: {}", command).bright_black());
+    let status = shell_command(command)
+        .env(VERIFY_ENV_VAR, &report)
+        .status()
+        .context(format!("Failed to run '{}'", command))?;
+    if !status.success() {
+        println!(
+            "{}",
+            format!("Warning: '{}' exited with {}", command, status).yellow()
+        );
+    }
+
+    let records = VerifyRecord::load_all(&report).context("Failed to read verify report")?;
+    let _ = std::fs::remove_file(&report);
+    println!();
+
+    let mut still_failing = 0;
+    let mut fixed = Vec::new();
+    let mut unverified = 0;
+
+    for (test, failures) in &tests_with_failures {
+        println!("  {} {}", "●".bright_cyan(), test.cyan().bold());
+        for failure in failures {
+            let record = records
+                .iter()
+                .rev()
+                .find(|record| &record.test_name == test && record.seed == failure.seed);
+            match record {
+                Some(VerifyRecord {
+                    error_message: Some(error),
+                    ..
+                }) => {
+                    still_failing += 1;
+                    println!(
+                        "      {} seed={} still failing: {}",
+                        "✗".red(),
+                        failure.seed,
+                        error.red()
+                    );
+                }
+                Some(record) if record.confirms_fixed(failure) => {
+                    fixed.push((test, failure.seed));
+                    println!("      {} seed={} fixed", "✓".green(), failure.seed);
+                }
+                Some(_) => {
+                    unverified += 1;
+                    println!(
+                        "      {} seed={} not verified: the seed regenerated a different input",
+                        "?".yellow(),
+                        failure.seed
+                    );
+                }
+                None => {
+                    unverified += 1;
+                    println!("      {} seed={} not verified", "?".yellow(), failure.seed);
+                }
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{}: {} still failing, {} fixed, {} not verified",
+        "Summary".bold(),
+        still_failing,
+        fixed.len(),
+        unverified
+    );
+
+    if unverified > 0 {
+        println!(
+            "{}",
+            "Tip: Failures are only verified by property tests that set .test_name(..) and \
+             .persistence_config(..) for this failures directory"
+                .bright_black()
+        );
+    }
+
+    if prune {
+        let mut deleted = 0;
+        for (test, seed) in &fixed {
+            if snapshot.delete_failure(test, *seed).is_ok() {
+                deleted += 1;
+            }
+        }
+        println!(
+            "{}",
+            format!("✓ Pruned {} fixed failure(s)", deleted).green()
+        );
+    } else if !fixed.is_empty() {
+        println!(
+            "{}",
+            "Tip: Use --prune to delete fixed failures".bright_black()
+        );
+    }
+
+    Ok(())
+}
+
+/// Build a command that runs `command` through the platform shell, so quoting
+/// works the way it does when typed at a prompt
+fn shell_command(command: &str) -> std::process::Command {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    let mut cmd = std::process::Command::new(shell);
+    cmd.arg(flag).arg(command);
+    cmd
+}
+
 fn confirm() -> Result<bool> {
     use std::io::{self, BufRead};
 
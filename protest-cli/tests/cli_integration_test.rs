@@ -233,6 +233,10 @@ fn test_generate_command_single_test() {
     cmd.arg("--dir")
         .arg(temp_dir.path())
         .arg("generate")
+        .arg("--property")
+        .arg("crate::properties::{test}")
+        .arg("--generator")
+        .arg("crate::generators::{test}()")
         .arg("gen_test")
         .arg("-y")
         .arg("--output")
@@ -254,6 +258,8 @@ fn test_generate_command_single_test() {
     assert!(content.contains("regression_gen_test_seed_12345"));
     assert!(content.contains("regression_gen_test_seed_67890"));
     assert!(content.contains("Test error"));
+    assert!(content.contains("crate::properties::gen_test"));
+    assert!(content.contains("crate::generators::gen_test(),"));
 }
 
 #[test]
@@ -268,6 +274,10 @@ fn test_generate_command_all_tests() {
     cmd.arg("--dir")
         .arg(temp_dir.path())
         .arg("generate")
+        .arg("--property")
+        .arg("crate::properties::{test}")
+        .arg("--generator")
+        .arg("crate::generators::{test}()")
         .arg("-y")
         .arg("--output")
         .arg(&output_dir);
@@ -281,11 +291,79 @@ fn test_generate_command_all_tests() {
     assert!(output_dir.join("test_b_regressions.rs").exists());
 }
 
+#[test]
+fn test_generate_command_requires_generator_for_seed_replay() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_dir = temp_dir.path().join("regressions");
+
+    create_test_failure(temp_dir.path(), "gen_test", 12345, "Test error");
+
+    let mut cmd = Command::from_std(process::Command::new(cargo_bin!("protest")));
+    cmd.arg("--dir")
+        .arg(temp_dir.path())
+        .arg("generate")
+        .arg("--property")
+        .arg("crate::properties::{test}")
+        .arg("gen_test")
+        .arg("-y")
+        .arg("--output")
+        .arg(&output_dir);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("No generator configured"));
+    assert!(!output_dir.join("gen_test_regressions.rs").exists());
+
+    // Value-based tests alone need no generator
+    let mut cmd = Command::from_std(process::Command::new(cargo_bin!("protest")));
+    cmd.arg("--dir")
+        .arg(temp_dir.path())
+        .arg("generate")
+        .arg("--property")
+        .arg("crate::properties::{test}")
+        .arg("gen_test")
+        .arg("--value-based")
+        .arg("-y")
+        .arg("--output")
+        .arg(&output_dir);
+
+    cmd.assert().success();
+    let content = fs::read_to_string(output_dir.join("gen_test_regressions.rs")).unwrap();
+    assert!(!content.contains(".seed("));
+}
+
+#[test]
+fn test_generate_command_requires_property_for_failures() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_dir = temp_dir.path().join("regressions");
+
+    create_test_failure(temp_dir.path(), "gen_test", 12345, "Test error");
+
+    for test_name in [Some("gen_test"), None] {
+        let mut cmd = Command::from_std(process::Command::new(cargo_bin!("protest")));
+        cmd.arg("--dir").arg(temp_dir.path()).arg("generate");
+        if let Some(test_name) = test_name {
+            cmd.arg(test_name);
+        }
+        cmd.arg("--generator")
+            .arg("crate::generators::{test}()")
+            .arg("-y")
+            .arg("--output")
+            .arg(&output_dir);
+
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("--property is required"));
+    }
+    assert!(!output_dir.exists());
+}
+
 #[test]
 fn test_generate_command_no_failures() {
     let temp_dir = TempDir::new().unwrap();
     let output_dir = temp_dir.path().join("regressions");
 
+    // Without failures there is nothing to generate, so no property is needed
     let mut cmd = Command::from_std(process::Command::new(cargo_bin!("protest")));
     cmd.arg("--dir")
         .arg(temp_dir.path())
@@ -299,6 +377,73 @@ fn test_generate_command_no_failures() {
         .stdout(predicate::str::contains("No saved failures found"));
 }
 
+#[cfg(unix)]
+#[test]
+fn test_verify_command_with_prune() {
+    let temp_dir = TempDir::new().unwrap();
+
+    create_test_failure(temp_dir.path(), "verify_test", 111, "Fixed error");
+    create_test_failure(temp_dir.path(), "verify_test", 222, "Open error");
+    create_test_failure(temp_dir.path(), "verify_test", 333, "Unrun error");
+    create_test_failure(temp_dir.path(), "verify_test", 444, "Drifted error");
+
+    // Stand-in for a test suite running in verify mode, in a directory whose
+    // name needs quoting
+    let script_dir = temp_dir.path().join("verify scripts");
+    fs::create_dir_all(&script_dir).unwrap();
+    let script = script_dir.join("verify.sh");
+    fs::write(
+        &script,
+        r#"echo '{"test_name":"verify_test","seed":111,"error_message":null,"input":"test_input_111"}' >> "$PROTEST_VERIFY"
+echo '{"test_name":"verify_test","seed":222,"error_message":"still broken","input":"test_input_222"}' >> "$PROTEST_VERIFY"
+echo '{"test_name":"verify_test","seed":444,"error_message":null,"input":"test_input_other"}' >> "$PROTEST_VERIFY"
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::from_std(process::Command::new(cargo_bin!("protest")));
+    cmd.arg("--dir")
+        .arg(temp_dir.path())
+        .arg("verify")
+        .arg("verify_test")
+        .arg("--command")
+        .arg(format!("sh '{}'", script.display()))
+        .arg("--prune");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("seed=111 fixed"))
+        .stdout(predicate::str::contains(
+            "seed=222 still failing: still broken",
+        ))
+        .stdout(predicate::str::contains("seed=333 not verified"))
+        .stdout(predicate::str::contains(
+            "seed=444 not verified: the seed regenerated a different input",
+        ))
+        .stdout(predicate::str::contains(
+            "1 still failing, 1 fixed, 2 not verified",
+        ))
+        .stdout(predicate::str::contains("Pruned 1 fixed failure(s)"));
+
+    let test_dir = temp_dir.path().join("verify_test");
+    assert!(!test_dir.join("failure_seed_111.json").exists());
+    assert!(test_dir.join("failure_seed_222.json").exists());
+    assert!(test_dir.join("failure_seed_333.json").exists());
+    assert!(test_dir.join("failure_seed_444.json").exists());
+}
+
+#[test]
+fn test_verify_command_no_failures() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let mut cmd = Command::from_std(process::Command::new(cargo_bin!("protest")));
+    cmd.arg("--dir").arg(temp_dir.path()).arg("verify");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No saved failures found"));
+}
+
 #[test]
 fn test_help_command() {
     let mut cmd = Command::from_std(process::Command::new(cargo_bin!("protest")));
@@ -48,7 +48,7 @@ fn generate_generator_impl(input: &DeriveInput) -> Result<TokenStream> {
         }
     };
 
-    let shrink_body = generate_shrink_body(&input.data)?;
+    let shrink_body = generate_shrink_body(name, &input.data)?;
 
     // Generate phantom data fields for generics
     let (phantom_fields, phantom_data_init) = if generics.params.is_empty() {
@@ -115,6 +115,17 @@ fn generate_generator_impl(input: &DeriveInput) -> Result<TokenStream> {
             }
         }
 
+        // Implement AutoGen so #[property_test] can infer a generator for the type
+        impl #bounded_impl_generics protest::ergonomic::AutoGen for #name #ty_generics
+        #bounded_where_clause
+        {
+            type Generator = protest::ergonomic::ArbitraryGenerator<Self>;
+
+            fn auto_generator() -> Self::Generator {
+                protest::ergonomic::ArbitraryGenerator::new()
+            }
+        }
+
         // Implement Arbitrary trait for the original type
         impl #bounded_impl_generics protest::Arbitrary for #name #ty_generics
         #bounded_where_clause
@@ -202,20 +213,14 @@ fn generate_unnamed_fields_body(name: &syn::Ident, fields: &FieldsUnnamed) -> Re
     })
 }
 
-/// Generate the body for enum generation
-fn generate_enum_body(name: &syn::Ident, variants: &[&Variant]) -> Result<TokenStream> {
-    if variants.is_empty() {
-        return Err(Error::new_spanned(
-            name,
-            "Cannot derive Generator for empty enum",
-        ));
-    }
-
-    let variant_count = variants.len();
-    let variant_arms = variants
+/// Expressions constructing each variant with generated fields, in declaration order
+fn generate_variant_constructors(
+    name: &syn::Ident,
+    variants: &[&Variant],
+) -> Result<Vec<TokenStream>> {
+    variants
         .iter()
-        .enumerate()
-        .map(|(index, variant)| {
+        .map(|variant| {
             let variant_name = &variant.ident;
             let variant_body = match &variant.fields {
                 Fields::Named(fields_named) => {
@@ -264,24 +269,174 @@ fn generate_enum_body(name: &syn::Ident, variants: &[&Variant]) -> Result<TokenS
                 Fields::Unit => Ok::<TokenStream, Error>(quote! { #name::#variant_name }),
             }?;
 
-            Ok(quote! {
-                #index => #variant_body
-            })
+            Ok(variant_body)
         })
+        .collect()
+}
+
+/// Generate the body for enum generation
+///
+/// Once `config.max_depth` is down to 1, only variants that do not refer to the
+/// enum itself are picked, so recursive enums (through `Box`, `Rc`, `Arc`, ...)
+/// stay bounded. Enums without such leaf variants always pick from all of them.
+fn generate_enum_body(name: &syn::Ident, variants: &[&Variant]) -> Result<TokenStream> {
+    if variants.is_empty() {
+        return Err(Error::new_spanned(
+            name,
+            "Cannot derive Generator for empty enum",
+        ));
+    }
+
+    let variant_arms = generate_variant_constructors(name, variants)?;
+
+    let weights = variants
+        .iter()
+        .map(|variant| parse_variant_weight(variant))
         .collect::<Result<Vec<_>>>()?;
 
-    Ok(quote! {
+    let all_arms: Vec<_> = variant_arms.iter().zip(weights.iter().copied()).collect();
+    let select_any = select_variant(&all_arms).ok_or_else(|| {
+        Error::new_spanned(
+            name,
+            "At least one enum variant must have a non-zero weight",
+        )
+    })?;
+
+    let leaf_arms: Vec<_> = all_arms
+        .iter()
+        .zip(variants)
+        .filter(|(_, variant)| !variant_is_recursive(variant, name))
+        .map(|(arm, _)| *arm)
+        .collect();
+    if leaf_arms.len() == all_arms.len() {
+        return Ok(select_any);
+    }
+
+    match select_variant(&leaf_arms) {
+        Some(select_leaf) => Ok(quote! {
+            if config.max_depth <= 1 {
+                #select_leaf
+            } else {
+                #select_any
+            }
+        }),
+        None => Ok(select_any),
+    }
+}
+
+/// Pick one of the given variant constructors at random
+///
+/// Without any #[generator(weight = N)] attribute the pick is uniform. Otherwise
+/// unweighted variants default to 1 and zero-weight variants are never picked.
+/// Returns `None` when no variant can be picked.
+fn select_variant(arms: &[(&TokenStream, Option<u64>)]) -> Option<TokenStream> {
+    if arms.is_empty() {
+        return None;
+    }
+
+    if arms.iter().all(|(_, weight)| weight.is_none()) {
+        let variant_count = arms.len();
+        let indexed_arms = arms
+            .iter()
+            .enumerate()
+            .map(|(index, (body, _))| quote! { #index => #body });
+
+        return Some(quote! {
+            {
+                use rand::Rng;
+                let variant_index = rng.gen_range(0..#variant_count);
+                match variant_index {
+                    #(#indexed_arms,)*
+                    _ => unreachable!("Invalid variant index")
+                }
+            }
+        });
+    }
+
+    let mut cumulative = 0u64;
+    let mut weighted_arms = Vec::new();
+    for (body, weight) in arms {
+        let weight = weight.unwrap_or(1);
+        if weight == 0 {
+            continue;
+        }
+        cumulative += weight;
+        weighted_arms.push(quote! { roll if roll < #cumulative => #body });
+    }
+
+    if cumulative == 0 {
+        return None;
+    }
+
+    Some(quote! {
         {
             use rand::Rng;
-            let variant_index = rng.gen_range(0..#variant_count);
-            match variant_index {
-                #(#variant_arms,)*
-                _ => unreachable!("Invalid variant index")
+            let roll: u64 = rng.gen_range(0..#cumulative);
+            match roll {
+                #(#weighted_arms,)*
+                _ => unreachable!("Invalid variant weight roll")
             }
         }
     })
 }
 
+/// Whether any field of the variant mentions the enum itself (or `Self`)
+fn variant_is_recursive(variant: &Variant, name: &syn::Ident) -> bool {
+    fn mentions(tokens: TokenStream, name: &syn::Ident) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => ident == *name || ident == "Self",
+            proc_macro2::TokenTree::Group(group) => mentions(group.stream(), name),
+            _ => false,
+        })
+    }
+
+    variant.fields.iter().any(|field| {
+        let ty = &field.ty;
+        mentions(quote! { #ty }, name)
+    })
+}
+
+/// Parse a variant-level #[generator(weight = N)] attribute
+fn parse_variant_weight(variant: &Variant) -> Result<Option<u64>> {
+    let mut weight = None;
+
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("generator") {
+            continue;
+        }
+
+        let parser = syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated;
+        let parsed = attr.parse_args_with(parser)?;
+
+        for meta in parsed {
+            match meta {
+                Meta::NameValue(MetaNameValue { path, value, .. }) if path.is_ident("weight") => {
+                    if let syn::Expr::Lit(syn::ExprLit {
+                        lit: Lit::Int(lit_int),
+                        ..
+                    }) = &value
+                    {
+                        weight = Some(u64::from(lit_int.base10_parse::<u32>()?));
+                    } else {
+                        return Err(Error::new_spanned(
+                            value,
+                            "Weight attribute must be a non-negative integer literal",
+                        ));
+                    }
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "Unsupported variant generator attribute, expected `weight = N`",
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(weight)
+}
+
 /// Parse field-level attributes for customization
 fn parse_field_attributes(field: &Field) -> Result<TokenStream> {
     let field_type = &field.ty;
@@ -293,11 +448,16 @@ fn parse_field_attributes(field: &Field) -> Result<TokenStream> {
         }
     }
 
-    // Default generation using Arbitrary trait
+    // Default generation: this thread's default registry, then the Arbitrary trait
     Ok(quote! {
         {
-            let strategy = <#field_type as protest::Arbitrary>::arbitrary();
-            protest::Strategy::generate(&strategy, rng, config)
+            match protest::GeneratorRegistry::generate_default::<#field_type>(rng, config) {
+                Some(value) => value,
+                None => {
+                    let strategy = <#field_type as protest::Arbitrary>::arbitrary();
+                    protest::Strategy::generate(&strategy, rng, config)
+                }
+            }
         }
     })
 }
@@ -321,8 +481,17 @@ fn parse_generator_attribute(attr: &Attribute, field_type: &Type) -> Result<Toke
                             return parse_length_attribute(&value, field_type);
                         } else if path.is_ident("custom") {
                             return parse_custom_attribute(&value, field_type);
+                        } else if path.is_ident("value") {
+                            return parse_value_attribute(&value);
+                        } else if path.is_ident("constant") {
+                            return parse_constant_attribute(&value, field_type);
                         }
                     }
+                    Meta::Path(path) if path.is_ident("default") || path.is_ident("skip") => {
+                        return Ok(quote! {
+                            <#field_type as ::core::default::Default>::default()
+                        });
+                    }
                     _ => {
                         return Err(Error::new_spanned(
                             meta,
@@ -422,6 +591,55 @@ fn parse_custom_attribute(value: &syn::Expr, _field_type: &Type) -> Result<Token
     ))
 }
 
+/// Parse value attribute pinning a field to an expression (e.g., value = "Vec::new()")
+fn parse_value_attribute(value: &syn::Expr) -> Result<TokenStream> {
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: Lit::Str(lit_str),
+        ..
+    }) = value
+    {
+        let expr: syn::Expr = lit_str.parse()?;
+
+        return Ok(quote! {
+            {
+                #expr
+            }
+        });
+    }
+
+    Err(Error::new_spanned(
+        value,
+        "Value attribute must be a string literal containing an expression",
+    ))
+}
+
+/// Parse constant attribute holding a field at a value via `ConstantGenerator`
+/// (e.g., constant = "2")
+///
+/// Unlike `value`, the field is also left alone by shrinking (see
+/// [`field_shrink_strategy`]).
+fn parse_constant_attribute(value: &syn::Expr, field_type: &Type) -> Result<TokenStream> {
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: Lit::Str(lit_str),
+        ..
+    }) = value
+    {
+        let expr: syn::Expr = lit_str.parse()?;
+
+        return Ok(quote! {
+            {
+                let generator = protest::ConstantGenerator::<#field_type>::new(#expr);
+                protest::Generator::generate(&generator, rng, config)
+            }
+        });
+    }
+
+    Err(Error::new_spanned(
+        value,
+        "Constant attribute must be a string literal containing an expression",
+    ))
+}
+
 /// Parse a range string like "1..100" into (start, end) as token streams
 fn parse_range_string(range_str: &str) -> Option<(TokenStream, TokenStream)> {
     if let Some(pos) = range_str.find("..") {
@@ -440,22 +658,11 @@ fn parse_range_string(range_str: &str) -> Option<(TokenStream, TokenStream)> {
 }
 
 /// Generate shrinking implementation
-fn generate_shrink_body(data: &Data) -> Result<TokenStream> {
+fn generate_shrink_body(name: &syn::Ident, data: &Data) -> Result<TokenStream> {
     match data {
-        Data::Struct(_) => {
-            // For now, basic shrinking implementation
-            Ok(quote! {
-                // Basic shrinking - return empty iterator for now
-                // TODO: Implement proper shrinking strategies
-                Box::new(std::iter::empty())
-            })
-        }
-        Data::Enum(_) => {
-            Ok(quote! {
-                // Basic enum shrinking - return empty iterator for now
-                // TODO: Implement enum shrinking strategies
-                Box::new(std::iter::empty())
-            })
+        Data::Struct(data_struct) => generate_struct_shrink_body(name, &data_struct.fields),
+        Data::Enum(data_enum) => {
+            generate_enum_shrink_body(name, &data_enum.variants.iter().collect::<Vec<_>>())
         }
         Data::Union(_) => Err(Error::new(
             Span::call_site(),
@@ -464,6 +671,182 @@ fn generate_shrink_body(data: &Data) -> Result<TokenStream> {
     }
 }
 
+/// Shrink a struct one field at a time, in declaration order
+///
+/// Each candidate replaces a single field with one of its shrinks and clones the
+/// others. Fields marked `constant`, `custom`, `default` or `skip` are never
+/// shrunk; `range` and `length` fields shrink within the same strategy they are
+/// generated from.
+fn generate_struct_shrink_body(name: &syn::Ident, fields: &Fields) -> Result<TokenStream> {
+    let members: Vec<syn::Member> = match fields {
+        Fields::Named(fields_named) => fields_named
+            .named
+            .iter()
+            .map(|field| syn::Member::Named(field.ident.clone().unwrap()))
+            .collect(),
+        Fields::Unnamed(fields_unnamed) => (0..fields_unnamed.unnamed.len())
+            .map(|index| syn::Member::Unnamed(index.into()))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let mut field_shrinks = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let Some(strategy) = field_shrink_strategy(field)? else {
+            continue;
+        };
+
+        let member = &members[index];
+        let values = members.iter().map(|other| {
+            if other == member {
+                quote! { shrunk }
+            } else {
+                quote! { ::core::clone::Clone::clone(&value.#other) }
+            }
+        });
+        let candidate = match fields {
+            Fields::Named(_) => quote! { #name { #(#members: #values,)* } },
+            _ => quote! { #name(#(#values,)*) },
+        };
+
+        field_shrinks.push(quote! {
+            {
+                let strategy = #strategy;
+                for shrunk in protest::Strategy::shrink(&strategy, &value.#member) {
+                    candidates.push(#candidate);
+                }
+            }
+        });
+    }
+
+    if field_shrinks.is_empty() {
+        return Ok(quote! { Box::new(std::iter::empty()) });
+    }
+
+    Ok(quote! {
+        let mut candidates: Vec<Self::Value> = Vec::new();
+        #(#field_shrinks)*
+        Box::new(candidates.into_iter())
+    })
+}
+
+/// The strategy a field is shrunk with, or `None` if the field is held fixed
+fn field_shrink_strategy(field: &Field) -> Result<Option<TokenStream>> {
+    let field_type = &field.ty;
+    let arbitrary = quote! { <#field_type as protest::Arbitrary>::arbitrary() };
+
+    let Some(attr) = field
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("generator"))
+    else {
+        return Ok(Some(arbitrary));
+    };
+
+    let Meta::List(MetaList { tokens, .. }) = &attr.meta else {
+        return Ok(Some(arbitrary));
+    };
+    let parser = syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated;
+    let Some(meta) = parser.parse2(tokens.clone())?.into_iter().next() else {
+        return Ok(Some(arbitrary));
+    };
+
+    match meta {
+        Meta::NameValue(MetaNameValue { path, value, .. })
+            if path.is_ident("range") || path.is_ident("length") =>
+        {
+            let bounds = match &value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(lit_str),
+                    ..
+                }) => parse_range_string(&lit_str.value()),
+                _ => None,
+            };
+            Ok(bounds.map(|(start, end)| {
+                quote! { <#field_type as protest::Arbitrary>::arbitrary_with((#start, #end)) }
+            }))
+        }
+        Meta::NameValue(MetaNameValue { path, .. }) if path.is_ident("value") => {
+            Ok(Some(arbitrary))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// How structurally complex a variant is: unit, then tuple, then struct variants
+fn variant_complexity(variant: &Variant) -> u8 {
+    match variant.fields {
+        Fields::Unit => 0,
+        Fields::Unnamed(_) => 1,
+        Fields::Named(_) => 2,
+    }
+}
+
+/// Shrink an enum value to each simpler variant, simplest first
+///
+/// Variants are ordered by [`variant_complexity`], then by declaration order. A
+/// value shrinks to every generable variant before its own in that order. Fields
+/// of the proposed variants are generated from a fixed seed, so shrinking is
+/// deterministic.
+fn generate_enum_shrink_body(name: &syn::Ident, variants: &[&Variant]) -> Result<TokenStream> {
+    let constructors = generate_variant_constructors(name, variants)?;
+    let weights = variants
+        .iter()
+        .map(|variant| parse_variant_weight(variant))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut order: Vec<usize> = (0..variants.len()).collect();
+    order.sort_by_key(|&index| (variant_complexity(variants[index]), index));
+
+    // Zero-weight variants are never generated, so never proposed either
+    let candidates: Vec<_> = order
+        .iter()
+        .enumerate()
+        .filter(|(_, index)| weights[**index] != Some(0))
+        .map(|(rank, index)| {
+            let constructor = &constructors[*index];
+            quote! {
+                if #rank < rank {
+                    candidates.push(#constructor);
+                }
+            }
+        })
+        .collect();
+
+    if candidates.is_empty() {
+        return Ok(quote! { Box::new(std::iter::empty()) });
+    }
+
+    let rank_arms = order.iter().enumerate().map(|(rank, index)| {
+        let variant = variants[*index];
+        let variant_name = &variant.ident;
+        let pattern = match variant.fields {
+            Fields::Unit => quote! { #name::#variant_name },
+            Fields::Unnamed(_) => quote! { #name::#variant_name(..) },
+            Fields::Named(_) => quote! { #name::#variant_name { .. } },
+        };
+        quote! { #pattern => #rank }
+    });
+
+    Ok(quote! {
+        let rank: usize = match value {
+            #(#rank_arms,)*
+        };
+
+        // Only used when a simpler variant has fields to generate
+        #[allow(unused_mut, unused_variables)]
+        let mut seeded_rng = protest::create_seeded_rng(0);
+        #[allow(unused_variables)]
+        let rng = &mut seeded_rng;
+        #[allow(unused_variables)]
+        let config = &protest::GeneratorConfig::default();
+
+        let mut candidates: Vec<Self::Value> = Vec::new();
+        #(#candidates)*
+        Box::new(candidates.into_iter())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -517,4 +900,157 @@ mod tests {
 
         assert_eq!(result.to_string(), expected.to_string());
     }
+
+    #[test]
+    fn test_default_and_value_attributes() {
+        let field: Field = parse_quote! {
+            #[generator(default)]
+            cache: Vec<u8>
+        };
+        let result = parse_field_attributes(&field).unwrap();
+        let expected = quote! { <Vec<u8> as ::core::default::Default>::default() };
+        assert_eq!(result.to_string(), expected.to_string());
+
+        let field: Field = parse_quote! {
+            #[generator(value = "42")]
+            answer: u32
+        };
+        let result = parse_field_attributes(&field).unwrap();
+        assert_eq!(result.to_string(), quote! { { 42 } }.to_string());
+
+        let field: Field = parse_quote! {
+            #[generator(value = 42)]
+            answer: u32
+        };
+        assert!(parse_field_attributes(&field).is_err());
+    }
+
+    #[test]
+    fn test_constant_attribute() {
+        let field: Field = parse_quote! {
+            #[generator(constant = "VERSION")]
+            version: u16
+        };
+        let result = parse_field_attributes(&field).unwrap();
+        let expected = quote! {
+            {
+                let generator = protest::ConstantGenerator::<u16>::new(VERSION);
+                protest::Generator::generate(&generator, rng, config)
+            }
+        };
+        assert_eq!(result.to_string(), expected.to_string());
+
+        let field: Field = parse_quote! {
+            #[generator(constant = 2)]
+            version: u16
+        };
+        assert!(parse_field_attributes(&field).is_err());
+    }
+
+    #[test]
+    fn test_field_shrink_strategy() {
+        let shrunk = |field: Field| {
+            field_shrink_strategy(&field)
+                .unwrap()
+                .map(|tokens| tokens.to_string())
+        };
+
+        let expected = quote! { <u32 as protest::Arbitrary>::arbitrary() }.to_string();
+        assert_eq!(shrunk(parse_quote! { id: u32 }), Some(expected.clone()));
+        assert_eq!(
+            shrunk(parse_quote! { #[generator(value = "42")] id: u32 }),
+            Some(expected)
+        );
+        assert_eq!(
+            shrunk(parse_quote! { #[generator(range = "1..10")] id: u32 }),
+            Some(quote! { <u32 as protest::Arbitrary>::arbitrary_with((1, 10)) }.to_string())
+        );
+
+        assert_eq!(
+            shrunk(parse_quote! { #[generator(constant = "2")] id: u32 }),
+            None
+        );
+        assert_eq!(shrunk(parse_quote! { #[generator(default)] id: u32 }), None);
+        assert_eq!(shrunk(parse_quote! { #[generator(skip)] id: u32 }), None);
+        assert_eq!(
+            shrunk(parse_quote! { #[generator(custom = "one")] id: u32 }),
+            None
+        );
+
+        // Structs with nothing to shrink propose no candidates
+        let name: syn::Ident = parse_quote! { Pinned };
+        let fields: FieldsNamed = parse_quote! { { #[generator(constant = "2")] version: u16 } };
+        let body = generate_struct_shrink_body(&name, &Fields::Named(fields)).unwrap();
+        assert_eq!(
+            body.to_string(),
+            quote! { Box::new(std::iter::empty()) }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_variant_complexity_order() {
+        let variants: Vec<Variant> = vec![
+            parse_quote! { Complex { a: u8, b: u8 } },
+            parse_quote! { Wrapped(u8) },
+            parse_quote! { Simple },
+        ];
+        let complexities: Vec<_> = variants.iter().map(variant_complexity).collect();
+        assert_eq!(complexities, vec![2, 1, 0]);
+
+        // Variants are ranked simplest first, regardless of declaration order
+        let name: syn::Ident = parse_quote! { Node };
+        let body = generate_enum_shrink_body(&name, &variants.iter().collect::<Vec<_>>())
+            .unwrap()
+            .to_string();
+        assert!(body.contains("Node :: Simple => 0usize"));
+        assert!(body.contains("Node :: Wrapped (..) => 1usize"));
+        assert!(body.contains("Node :: Complex { .. } => 2usize"));
+    }
+
+    #[test]
+    fn test_parse_variant_weight() {
+        let variant: Variant = parse_quote! {
+            #[generator(weight = 10)]
+            Active
+        };
+        assert_eq!(parse_variant_weight(&variant).unwrap(), Some(10));
+
+        let variant: Variant = parse_quote! { Idle };
+        assert_eq!(parse_variant_weight(&variant).unwrap(), None);
+
+        let variant: Variant = parse_quote! {
+            #[generator(weight = "high")]
+            Error
+        };
+        assert!(parse_variant_weight(&variant).is_err());
+    }
+
+    #[test]
+    fn test_variant_is_recursive() {
+        let name: syn::Ident = parse_quote! { Tree };
+        let leaf: Variant = parse_quote! { Leaf(Box<u8>) };
+        let node: Variant = parse_quote! { Node(Box<Tree>, Box<Tree>) };
+        let forest: Variant = parse_quote! { Forest { trees: Vec<Self> } };
+
+        assert!(!variant_is_recursive(&leaf, &name));
+        assert!(variant_is_recursive(&node, &name));
+        assert!(variant_is_recursive(&forest, &name));
+
+        // Only enums with both kinds of variants check the depth
+        let body = generate_enum_body(&name, &[&leaf, &node])
+            .unwrap()
+            .to_string();
+        assert!(body.contains("config . max_depth <= 1"));
+        let body = generate_enum_body(&name, &[&leaf]).unwrap().to_string();
+        assert!(!body.contains("max_depth"));
+    }
+
+    #[test]
+    fn test_enum_body_rejects_all_zero_weights() {
+        let name: syn::Ident = parse_quote! { Status };
+        let first: Variant = parse_quote! { #[generator(weight = 0)] Active };
+        let second: Variant = parse_quote! { #[generator(weight = 0)] Error };
+
+        assert!(generate_enum_body(&name, &[&first, &second]).is_err());
+    }
 }
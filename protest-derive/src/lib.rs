@@ -41,6 +41,12 @@ mod property_test;
 ///     name: String,
 ///     #[generator(custom = "always_true")]
 ///     active: bool,
+///     #[generator(value = "String::from(\"v1\")")]
+///     schema: String,
+///     #[generator(default)]
+///     cache: Vec<u8>,
+///     #[generator(constant = "2")]
+///     version: u16,
 /// }
 ///
 /// fn always_true() -> bool {
@@ -53,6 +59,79 @@ mod property_test;
 /// - `range = "min..max"`: For numeric types, specifies the range of generated values
 /// - `length = "min..max"`: For collections and strings, specifies the length range
 /// - `custom = "function_name"`: Uses a custom function to generate the field value
+/// - `value = "expr"`: Generates the field as the given expression; shrinking
+///   may still move it like any other field
+/// - `default` (or `skip`): Fills the field with `Default::default()` and never
+///   shrinks it
+/// - `constant = "expr"`: Holds the field at the given expression with a
+///   `ConstantGenerator`, so it stays fixed while other fields shrink
+///
+/// On enum variants, `weight = N` makes a variant `N` times as likely as a
+/// variant of weight 1. Variants without a weight count as 1, and a weight of 0
+/// excludes the variant from generation:
+///
+/// ```rust
+/// use protest::Generator;
+///
+/// #[derive(Generator)]
+/// enum Status {
+///     #[generator(weight = 8)]
+///     Active,
+///     Idle,
+///     #[generator(weight = 0)]
+///     Unreachable,
+/// }
+/// ```
+///
+/// # Struct Shrinking
+///
+/// Struct values shrink one field at a time, in declaration order: each
+/// candidate replaces a single field with one of its shrinks and clones the
+/// rest, so field types must implement `Clone`. Fields marked `constant`,
+/// `custom`, `default` or `skip` are never shrunk.
+///
+/// ```rust
+/// use protest::{Generator, Strategy};
+///
+/// #[derive(Debug, Clone, PartialEq, Generator)]
+/// struct Request {
+///     size: u32,
+///     #[generator(constant = "2")]
+///     version: u16,
+/// }
+///
+/// let generator = RequestGenerator::default();
+/// let value = Request { size: 100, version: 2 };
+/// let shrinks: Vec<_> = generator.shrink(&value).collect();
+/// assert!(!shrinks.is_empty());
+/// assert!(shrinks.iter().all(|shrunk| shrunk.version == 2 && shrunk.size < 100));
+/// ```
+///
+/// # Enum Shrinking
+///
+/// Enum values shrink towards structurally simpler variants: unit variants
+/// first, then tuple variants, then struct variants, and earlier-declared
+/// variants before later ones of the same kind. A value proposes every simpler
+/// variant in that order, so the minimal counterexample is the simplest variant
+/// that still fails. Fields of the proposed variants are generated from a fixed
+/// seed, and zero-weight variants are never proposed.
+///
+/// ```rust
+/// use protest::{Generator, Strategy};
+///
+/// #[derive(Debug, Clone, PartialEq, Generator)]
+/// enum Expr {
+///     Add { left: i32, right: i32 },
+///     Lit(i32),
+///     Zero,
+/// }
+///
+/// let generator = ExprGenerator::default();
+/// let value = Expr::Add { left: 1, right: 2 };
+/// let shrinks: Vec<_> = generator.shrink(&value).collect();
+/// assert_eq!(shrinks[0], Expr::Zero);
+/// assert!(matches!(shrinks[1], Expr::Lit(_)));
+/// ```
 ///
 /// # Generic Types
 ///
@@ -119,9 +198,12 @@ pub fn derive_generator(input: TokenStream) -> TokenStream {
 /// # Supported Attributes
 ///
 /// - `iterations = N`: Number of test iterations (default: 100)
-/// - `seed = N`: Random seed for reproducible tests
+/// - `seed = N`: Random seed for reproducible tests. A failing test reports the seed it
+///   ran with, together with the `#[property_test(seed = N)]` attribute to replay it
 /// - `max_shrink_iterations = N`: Maximum shrinking attempts (default: 1000)
 /// - `shrink_timeout_secs = N`: Shrinking timeout in seconds (default: 10)
+/// - `max_rejections = N`: Inputs `protest::assume` may discard before the test
+///   fails (default: 1000)
 ///
 /// # Async Support
 ///
@@ -141,6 +223,18 @@ pub fn derive_generator(input: TokenStream) -> TokenStream {
 ///
 /// The macro automatically infers generators for function parameters based on their types.
 /// For custom types, ensure they implement the `Generator` trait or derive it.
+///
+/// A `#[strategy(expr)]` attribute on a parameter overrides inference with any
+/// expression that evaluates to a `Generator` for the parameter's type:
+///
+/// ```rust
+/// use protest::{property_test, range};
+///
+/// #[property_test]
+/// fn test_bounded(#[strategy(range(1, 100))] x: i32, name: String) {
+///     assert!((1..=100).contains(&x));
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn property_test(args: TokenStream, input: TokenStream) -> TokenStream {
     property_test::property_test_impl(args, input)
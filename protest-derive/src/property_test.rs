@@ -17,6 +17,7 @@ struct PropertyTestConfig {
     seed: Option<u64>,
     max_shrink_iterations: Option<usize>,
     shrink_timeout_secs: Option<u64>,
+    max_rejections: Option<usize>,
 }
 
 impl Parse for PropertyTestConfig {
@@ -94,10 +95,24 @@ impl Parse for PropertyTestConfig {
                                 ));
                             }
                         }
+                        "max_rejections" => {
+                            if let syn::Expr::Lit(syn::ExprLit {
+                                lit: Lit::Int(lit_int),
+                                ..
+                            }) = value
+                            {
+                                config.max_rejections = Some(lit_int.base10_parse()?);
+                            } else {
+                                return Err(syn::Error::new_spanned(
+                                    value,
+                                    "Expected integer literal",
+                                ));
+                            }
+                        }
                         _ => {
                             return Err(syn::Error::new_spanned(
                                 name,
-                                "Unknown configuration option. Supported: iterations, seed, max_shrink_iterations, shrink_timeout_secs",
+                                "Unknown configuration option. Supported: iterations, seed, max_shrink_iterations, shrink_timeout_secs, max_rejections",
                             ));
                         }
                     }
@@ -119,16 +134,30 @@ impl Parse for PropertyTestConfig {
 struct ParameterInfo {
     name: Ident,
     ty: Type,
+    /// Explicit generator from a `#[strategy(expr)]` parameter attribute
+    strategy: Option<syn::Expr>,
 }
 
 impl ParameterInfo {
     fn from_fn_arg(arg: &FnArg) -> Result<Self> {
         match arg {
-            FnArg::Typed(PatType { pat, ty, .. }) => {
+            FnArg::Typed(PatType { attrs, pat, ty, .. }) => {
                 if let Pat::Ident(pat_ident) = pat.as_ref() {
+                    let mut strategy = None;
+                    for attr in attrs.iter().filter(|attr| is_strategy_attr(attr)) {
+                        if strategy.is_some() {
+                            return Err(syn::Error::new_spanned(
+                                attr,
+                                "Only one #[strategy(...)] attribute is allowed per parameter",
+                            ));
+                        }
+                        strategy = Some(attr.parse_args::<syn::Expr>()?);
+                    }
+
                     Ok(ParameterInfo {
                         name: pat_ident.ident.clone(),
                         ty: (**ty).clone(),
+                        strategy,
                     })
                 } else {
                     Err(syn::Error::new_spanned(
@@ -145,6 +174,11 @@ impl ParameterInfo {
     }
 }
 
+/// Check whether an attribute is a `#[strategy(...)]` parameter attribute
+fn is_strategy_attr(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident("strategy")
+}
+
 /// Generate a generator expression for a given type
 ///
 /// Prefers AutoGen (ergonomic API) for common types
@@ -155,6 +189,16 @@ fn generate_generator_for_type(ty: &Type) -> TokenStream2 {
     }
 }
 
+/// Generate the generator expression for a parameter
+///
+/// An explicit `#[strategy(expr)]` overrides inference from the parameter type
+fn generate_generator_for_param(param: &ParameterInfo) -> TokenStream2 {
+    match &param.strategy {
+        Some(expr) => quote! { #expr },
+        None => generate_generator_for_type(&param.ty),
+    }
+}
+
 /// Generate test configuration from macro attributes
 fn generate_test_config(config: &PropertyTestConfig) -> TokenStream2 {
     let mut config_fields = Vec::new();
@@ -177,6 +221,10 @@ fn generate_test_config(config: &PropertyTestConfig) -> TokenStream2 {
         });
     }
 
+    if let Some(max_rejections) = config.max_rejections {
+        config_fields.push(quote! { max_rejections: #max_rejections });
+    }
+
     if config_fields.is_empty() {
         quote! { ::protest::TestConfig::default() }
     } else {
@@ -189,6 +237,43 @@ fn generate_test_config(config: &PropertyTestConfig) -> TokenStream2 {
     }
 }
 
+/// Generate the panic raised when a property test fails
+///
+/// The executor always records the seed it ran with, so the message includes a
+/// ready-to-paste attribute that reproduces the failure.
+fn generate_failure_panic() -> TokenStream2 {
+    quote! {
+        match failure.config.seed {
+            Some(seed) => panic!(
+                "Property test failed: {}\nseed = {}\nTo reproduce, run with #[property_test(seed = {})]",
+                failure, seed, seed
+            ),
+            None => panic!("Property test failed: {}", failure),
+        }
+    }
+}
+
+/// Generate code awaiting `body` that turns a panic into a `PropertyError`
+///
+/// The async counterpart of the `catch_unwind` around sync test bodies, so a
+/// panicking async test still reports its seed.
+fn generate_async_catch(body: TokenStream2) -> TokenStream2 {
+    quote! {
+        {
+            let mut body = ::std::pin::pin!(#body);
+            ::std::future::poll_fn(|cx| {
+                match ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+                    ::std::future::Future::poll(body.as_mut(), cx)
+                })) {
+                    Ok(poll) => poll.map(|_| Ok(())),
+                    Err(payload) => ::std::task::Poll::Ready(Err(PropertyError::from_panic(payload))),
+                }
+            })
+            .await
+        }
+    }
+}
+
 /// Check if a function is async
 fn is_async_fn(item_fn: &ItemFn) -> bool {
     item_fn.sig.asyncness.is_some()
@@ -235,6 +320,7 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
     let test_name = &item_fn.sig.ident;
     let test_config = generate_test_config(&config);
     let is_async = is_async_fn(&item_fn);
+    let failure_panic = generate_failure_panic();
 
     // Create the original function with a different name
     let original_fn_name = Ident::new(&format!("__{}_original", test_name), Span::call_site());
@@ -246,14 +332,22 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
         .attrs
         .retain(|attr| !attr.path().is_ident("test") && !attr.path().is_ident("tokio::test"));
 
+    // Strip #[strategy(...)] from parameters; it is only meaningful to this macro
+    for input in original_fn.sig.inputs.iter_mut() {
+        if let FnArg::Typed(pat_type) = input {
+            pat_type.attrs.retain(|attr| !is_strategy_attr(attr));
+        }
+    }
+
     let generated_test = if params.len() == 1 {
         // Single parameter case - simpler implementation
         let param = &params[0];
         let param_name = &param.name;
         let param_type = &param.ty;
-        let generator = generate_generator_for_type(&param.ty);
+        let generator = generate_generator_for_param(param);
 
         if is_async {
+            let test_body = generate_async_catch(quote! { #original_fn_name(#param_name) });
             quote! {
                 #[::tokio::test]
                 async fn #test_name() {
@@ -263,8 +357,7 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
                     impl AsyncProperty<#param_type> for TestProperty {
                         type Output = ();
                         async fn test(&self, #param_name: #param_type) -> Result<Self::Output, PropertyError> {
-                            #original_fn_name(#param_name).await;
-                            Ok(())
+                            #test_body
                         }
                     }
 
@@ -274,9 +367,7 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
 
                     match check_async_with_config(generator, property, config).await {
                         Ok(_) => {},
-                        Err(failure) => {
-                            panic!("Property test failed: {}", failure);
-                        }
+                        Err(failure) => #failure_panic,
                     }
                 }
             }
@@ -290,8 +381,10 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
                     impl Property<#param_type> for TestProperty {
                         type Output = ();
                         fn test(&self, #param_name: #param_type) -> Result<Self::Output, PropertyError> {
-                            #original_fn_name(#param_name);
-                            Ok(())
+                            ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+                                #original_fn_name(#param_name);
+                            }))
+                            .map_err(PropertyError::from_panic)
                         }
                     }
 
@@ -301,9 +394,7 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
 
                     match check_with_config(generator, property, config) {
                         Ok(_) => {},
-                        Err(failure) => {
-                            panic!("Property test failed: {}", failure);
-                        }
+                        Err(failure) => #failure_panic,
                     }
                 }
             }
@@ -312,10 +403,7 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
         // Multiple parameters case - use tuple generator
         let param_names: Vec<_> = params.iter().map(|p| &p.name).collect();
         let param_types: Vec<_> = params.iter().map(|p| &p.ty).collect();
-        let generators: Vec<_> = params
-            .iter()
-            .map(|p| generate_generator_for_type(&p.ty))
-            .collect();
+        let generators: Vec<_> = params.iter().map(generate_generator_for_param).collect();
 
         // Create tuple type and generator
         let tuple_type = if param_types.len() == 2 {
@@ -338,6 +426,7 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
         };
 
         if is_async {
+            let test_body = generate_async_catch(quote! { #original_fn_name(#(#param_names),*) });
             quote! {
                 #[::tokio::test]
                 async fn #test_name() {
@@ -348,8 +437,7 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
                         type Output = ();
                         async fn test(&self, input: #tuple_type) -> Result<Self::Output, PropertyError> {
                             let #param_destructure = input;
-                            #original_fn_name(#(#param_names),*).await;
-                            Ok(())
+                            #test_body
                         }
                     }
 
@@ -359,9 +447,7 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
 
                     match check_async_with_config(generator, property, config).await {
                         Ok(_) => {},
-                        Err(failure) => {
-                            panic!("Property test failed: {}", failure);
-                        }
+                        Err(failure) => #failure_panic,
                     }
                 }
             }
@@ -376,8 +462,10 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
                         type Output = ();
                         fn test(&self, input: #tuple_type) -> Result<Self::Output, PropertyError> {
                             let #param_destructure = input;
-                            #original_fn_name(#(#param_names),*);
-                            Ok(())
+                            ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+                                #original_fn_name(#(#param_names),*);
+                            }))
+                            .map_err(PropertyError::from_panic)
                         }
                     }
 
@@ -387,9 +475,7 @@ pub fn property_test_impl(args: TokenStream, input: TokenStream) -> TokenStream
 
                     match check_with_config(generator, property, config) {
                         Ok(_) => {},
-                        Err(failure) => {
-                            panic!("Property test failed: {}", failure);
-                        }
+                        Err(failure) => #failure_panic,
                     }
                 }
             }
@@ -586,9 +672,26 @@ mod tests {
         let param_info = ParameterInfo::from_fn_arg(&fn_arg).unwrap();
 
         assert_eq!(param_info.name.to_string(), "x");
+        assert!(param_info.strategy.is_none());
         // Type comparison is complex, so we just check it parses
     }
 
+    #[test]
+    fn test_parameter_strategy_attribute() {
+        let fn_arg: FnArg = parse_quote! { #[strategy(range(1, 100))] x: i32 };
+        let param_info = ParameterInfo::from_fn_arg(&fn_arg).unwrap();
+
+        let generator = generate_generator_for_param(&param_info);
+        assert_eq!(generator.to_string(), quote! { range(1, 100) }.to_string());
+
+        let fn_arg: FnArg = parse_quote! {
+            #[strategy(range(1, 10))]
+            #[strategy(range(1, 20))]
+            x: i32
+        };
+        assert!(ParameterInfo::from_fn_arg(&fn_arg).is_err());
+    }
+
     #[test]
     fn test_is_async_fn_detection() {
         let sync_fn: ItemFn = parse_quote! {
@@ -622,12 +725,14 @@ mod tests {
             seed: Some(42),
             max_shrink_iterations: None,
             shrink_timeout_secs: Some(30),
+            max_rejections: Some(50),
         };
         let generated = generate_test_config(&config);
 
         // Check that the generated config contains the expected fields
         let generated_str = generated.to_string();
         assert!(generated_str.contains("iterations : 1000"));
+        assert!(generated_str.contains("max_rejections : 50"));
         assert!(generated_str.contains("seed : Some (42"));
         assert!(
             generated_str.contains("shrink_timeout")
@@ -635,4 +740,21 @@ mod tests {
                 && generated_str.contains("from_secs (30")
         );
     }
+
+    #[test]
+    fn test_failure_panic_includes_seed_hint() {
+        let generated = generate_failure_panic().to_string();
+
+        assert!(generated.contains("failure . config . seed"));
+        assert!(generated.contains("#[property_test(seed = {})]"));
+    }
+
+    #[test]
+    fn test_async_catch_maps_panics_to_property_errors() {
+        let generated = generate_async_catch(quote! { body(x) }).to_string();
+
+        assert!(generated.contains("catch_unwind"));
+        assert!(generated.contains("PropertyError :: from_panic (payload)"));
+        assert!(generated.contains(". await"));
+    }
 }
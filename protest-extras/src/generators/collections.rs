@@ -4,14 +4,16 @@
 //! - Non-empty vectors (guaranteed length >= 1)
 //! - Sorted collections
 //! - Unique element collections
-//! - Bounded size maps
+//! - Bounded size maps (HashMap or BTreeMap)
 //!
 //! All generators use std library only.
 
 use protest::{Generator, GeneratorConfig};
 use rand::Rng;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
+use std::sync::Arc;
 
 // ============================================================================
 // NonEmpty Vec Generator
@@ -91,35 +93,98 @@ where
 // Sorted Vec Generator
 // ============================================================================
 
+/// Comparator used by [`SortedVecGenerator`]
+type Comparator<T> = Arc<dyn Fn(&T, &T) -> Ordering + Send + Sync>;
+
 /// Generator for sorted vectors
 ///
-/// Generates vectors that are already sorted in ascending order
-#[derive(Debug, Clone)]
+/// Generates vectors that are sorted in ascending order, or by a custom
+/// comparator set with [`SortedVecGenerator::by`]. Adjacent elements always
+/// satisfy `compare(a, b) != Ordering::Greater`, including for shrunk values.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig, IntGenerator};
+/// use protest_extras::generators::collections::SortedVecGenerator;
+/// use rand::thread_rng;
+///
+/// let generator = SortedVecGenerator::new(IntGenerator::new(0, 100), 0, 20).descending();
+/// let vec = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert!(vec.windows(2).all(|w| w[0] >= w[1]));
+/// ```
+#[derive(Clone)]
 pub struct SortedVecGenerator<T, G> {
     element_generator: G,
     min_len: usize,
     max_len: usize,
-    _phantom: std::marker::PhantomData<T>,
+    comparator: Comparator<T>,
 }
 
 impl<T, G> SortedVecGenerator<T, G>
 where
+    T: Ord + 'static,
     G: Generator<T>,
 {
-    /// Create a new sorted vec generator
+    /// Create a new sorted vec generator using the natural ordering
     pub fn new(element_generator: G, min_len: usize, max_len: usize) -> Self {
+        Self::with_comparator(element_generator, min_len, max_len, T::cmp)
+    }
+}
+
+impl<T, G> SortedVecGenerator<T, G>
+where
+    T: 'static,
+    G: Generator<T>,
+{
+    /// Create a sorted vec generator for types without a natural ordering
+    pub fn with_comparator<F>(
+        element_generator: G,
+        min_len: usize,
+        max_len: usize,
+        compare: F,
+    ) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    {
         Self {
             element_generator,
             min_len,
             max_len,
-            _phantom: std::marker::PhantomData,
+            comparator: Arc::new(compare),
         }
     }
+
+    /// Sort by a custom comparator instead of the current ordering
+    pub fn by<F>(mut self, compare: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    {
+        self.comparator = Arc::new(compare);
+        self
+    }
+
+    /// Reverse the current ordering
+    pub fn descending(mut self) -> Self {
+        let compare = self.comparator;
+        self.comparator = Arc::new(move |a, b| compare(b, a));
+        self
+    }
+}
+
+impl<T, G: std::fmt::Debug> std::fmt::Debug for SortedVecGenerator<T, G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SortedVecGenerator")
+            .field("element_generator", &self.element_generator)
+            .field("min_len", &self.min_len)
+            .field("max_len", &self.max_len)
+            .finish_non_exhaustive()
+    }
 }
 
 impl<T, G> Generator<Vec<T>> for SortedVecGenerator<T, G>
 where
-    T: Clone + Ord + 'static,
+    T: Clone + 'static,
     G: Generator<T> + Clone + 'static,
 {
     fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> Vec<T> {
@@ -127,7 +192,7 @@ where
         let mut vec: Vec<T> = (0..len)
             .map(|_| self.element_generator.generate(rng, config))
             .collect();
-        vec.sort();
+        vec.sort_by(|a, b| (self.comparator)(a, b));
         vec
     }
 
@@ -158,6 +223,16 @@ where
             }
         }
 
+        // Try shrinking individual elements, re-sorting afterwards
+        for i in 0..value.len().min(3) {
+            for element in self.element_generator.shrink(&value[i]).take(2) {
+                let mut shrunk = value.clone();
+                shrunk[i] = element;
+                shrunk.sort_by(|a, b| (self.comparator)(a, b));
+                shrinks.push(shrunk);
+            }
+        }
+
         Box::new(shrinks.into_iter())
     }
 }
@@ -166,7 +241,33 @@ where
 // Unique Vec Generator
 // ============================================================================
 
+/// Draws allowed while collecting `target` distinct values
+///
+/// Collecting `target` distinct values from a uniform domain of exactly that
+/// size is the coupon collector's problem: it takes `target * H(target)` draws
+/// on average, and the chance of needing more than `target * (ln(target) + c)`
+/// is at most `e^-c`. The budget uses `c = 20` and doubles the result to
+/// leave room for generators that favour edge values, so a domain that is
+/// large enough practically never runs out, while one that is too small still
+/// fails on every run.
+fn unique_draw_budget(target: usize) -> usize {
+    let per_value = (target.max(1) as f64).ln() + 21.0;
+    (2.0 * target as f64 * per_value).ceil() as usize
+}
+
 /// Generator for vectors with unique elements (no duplicates)
+///
+/// Elements are drawn until the target length is reached, giving up after
+/// a draw budget sized by the coupon collector's bound (shared with
+/// `BoundedMapGenerator`), so a domain with exactly `min_len` values still
+/// fills reliably. If the element generator cannot produce enough distinct
+/// values, the result is capped at however many were found, as long as that
+/// still meets `min_len`.
+///
+/// # Panics
+///
+/// Generation panics if fewer than `min_len` distinct elements could be
+/// produced, e.g. asking for 10 unique values from `IntGenerator::new(0, 3)`.
 #[derive(Debug, Clone)]
 pub struct UniqueVecGenerator<T, G> {
     element_generator: G,
@@ -202,7 +303,7 @@ where
 
         // Try to generate unique elements
         let mut attempts = 0;
-        let max_attempts = target_len * 10;
+        let max_attempts = unique_draw_budget(target_len);
 
         while vec.len() < target_len && attempts < max_attempts {
             let elem = self.element_generator.generate(rng, config);
@@ -212,6 +313,16 @@ where
             attempts += 1;
         }
 
+        if vec.len() < self.min_len {
+            panic!(
+                "UniqueVecGenerator found only {} unique elements after {} attempts, but min_len is {}; \
+                 the element generator's domain is likely too small",
+                vec.len(),
+                attempts,
+                self.min_len
+            );
+        }
+
         vec
     }
 
@@ -249,13 +360,86 @@ where
 // Bounded Map Generator
 // ============================================================================
 
+/// Map types that [`BoundedMapGenerator`] can fill
+trait BoundedMap<K, V> {
+    fn len(&self) -> usize;
+    fn contains_key(&self, key: &K) -> bool;
+    fn contains_value(&self, value: &V, eq: fn(&V, &V) -> bool) -> bool;
+    fn insert(&mut self, key: K, value: V);
+}
+
+impl<K: Eq + Hash, V> BoundedMap<K, V> for HashMap<K, V> {
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        HashMap::contains_key(self, key)
+    }
+
+    fn contains_value(&self, value: &V, eq: fn(&V, &V) -> bool) -> bool {
+        self.values().any(|v| eq(v, value))
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+}
+
+impl<K: Ord, V> BoundedMap<K, V> for BTreeMap<K, V> {
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        BTreeMap::contains_key(self, key)
+    }
+
+    fn contains_value(&self, value: &V, eq: fn(&V, &V) -> bool) -> bool {
+        self.values().any(|v| eq(v, value))
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        BTreeMap::insert(self, key, value);
+    }
+}
+
 /// Generator for HashMap with size bounds
+///
+/// `min_size` and `max_size` refer to the number of distinct keys in the final
+/// map. Duplicate keys are redrawn rather than overwriting earlier entries, up
+/// to a draw budget sized by the coupon collector's bound, so a key domain of
+/// exactly `min_size` values still fills reliably. If the key generator cannot
+/// produce enough distinct keys the map is capped at however many were found,
+/// as long as that still meets `min_size`.
+///
+/// Use [`BoundedMapGenerator::sorted`] to produce a `BTreeMap` instead, and
+/// [`BoundedMapGenerator::with_unique_values`] to also reject duplicate values.
+///
+/// # Panics
+///
+/// Generation panics if fewer than `min_size` entries could be produced.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig, IntGenerator};
+/// use protest_extras::generators::collections::BoundedMapGenerator;
+/// use rand::thread_rng;
+///
+/// let generator = BoundedMapGenerator::new(IntGenerator::new(0, 100), IntGenerator::new(0, 100), 3, 5)
+///     .with_unique_values(true)
+///     .sorted();
+/// let map = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert!(map.len() >= 3 && map.len() <= 5);
+/// ```
 #[derive(Debug, Clone)]
 pub struct BoundedMapGenerator<K, V, KG, VG> {
     key_generator: KG,
     value_generator: VG,
     min_size: usize,
     max_size: usize,
+    value_eq: Option<fn(&V, &V) -> bool>,
     _phantom: std::marker::PhantomData<(K, V)>,
 }
 
@@ -271,34 +455,76 @@ where
             value_generator,
             min_size,
             max_size,
+            value_eq: None,
             _phantom: std::marker::PhantomData,
         }
     }
-}
 
-impl<K, V, KG, VG> Generator<HashMap<K, V>> for BoundedMapGenerator<K, V, KG, VG>
-where
-    K: Clone + Eq + Hash + 'static,
-    V: Clone + 'static,
-    KG: Generator<K> + Clone + 'static,
-    VG: Generator<V> + Clone + 'static,
-{
-    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> HashMap<K, V> {
-        use rand::Rng;
+    /// Reject entries whose value is already present in the map
+    ///
+    /// Useful for testing bidirectional maps, where values must be unique too.
+    pub fn with_unique_values(mut self, unique: bool) -> Self
+    where
+        V: PartialEq,
+    {
+        self.value_eq = if unique { Some(V::eq) } else { None };
+        self
+    }
+
+    /// Generate `BTreeMap`s with the same size semantics instead of `HashMap`s
+    pub fn sorted(self) -> SortedMapGenerator<K, V, KG, VG> {
+        SortedMapGenerator { inner: self }
+    }
+
+    fn fill<M: BoundedMap<K, V>>(
+        &self,
+        map: &mut M,
+        rng: &mut dyn rand::RngCore,
+        config: &GeneratorConfig,
+    ) {
         let target_size = rng.r#gen_range(self.min_size..=self.max_size);
-        let mut map = HashMap::new();
 
-        // Try to generate unique keys
+        // Try to generate distinct keys (and values, if requested)
         let mut attempts = 0;
-        let max_attempts = target_size * 10;
+        let max_attempts = unique_draw_budget(target_size);
 
         while map.len() < target_size && attempts < max_attempts {
+            attempts += 1;
             let key = self.key_generator.generate(rng, config);
+            if map.contains_key(&key) {
+                continue;
+            }
             let value = self.value_generator.generate(rng, config);
+            if let Some(eq) = self.value_eq
+                && map.contains_value(&value, eq)
+            {
+                continue;
+            }
             map.insert(key, value);
-            attempts += 1;
         }
 
+        if map.len() < self.min_size {
+            panic!(
+                "BoundedMapGenerator found only {} distinct entries after {} attempts, but min_size is {}; \
+                 the key or value generator's domain is likely too small",
+                map.len(),
+                attempts,
+                self.min_size
+            );
+        }
+    }
+}
+
+impl<K, V, KG, VG> Generator<HashMap<K, V>> for BoundedMapGenerator<K, V, KG, VG>
+where
+    K: Clone + Eq + Hash + 'static,
+    V: Clone + 'static,
+    KG: Generator<K> + Clone + 'static,
+    VG: Generator<V> + Clone + 'static,
+{
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> HashMap<K, V> {
+        let mut map = HashMap::new();
+        self.fill(&mut map, rng, config);
         map
     }
 
@@ -326,6 +552,50 @@ where
     }
 }
 
+/// Generator for BTreeMap with size bounds
+///
+/// Created through [`BoundedMapGenerator::sorted`]; shares its size and
+/// uniqueness semantics. Iteration order is the key order.
+#[derive(Debug, Clone)]
+pub struct SortedMapGenerator<K, V, KG, VG> {
+    inner: BoundedMapGenerator<K, V, KG, VG>,
+}
+
+impl<K, V, KG, VG> Generator<BTreeMap<K, V>> for SortedMapGenerator<K, V, KG, VG>
+where
+    K: Clone + Ord + 'static,
+    V: Clone + 'static,
+    KG: Generator<K> + Clone + 'static,
+    VG: Generator<V> + Clone + 'static,
+{
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> BTreeMap<K, V> {
+        let mut map = BTreeMap::new();
+        self.inner.fill(&mut map, rng, config);
+        map
+    }
+
+    fn shrink(&self, value: &BTreeMap<K, V>) -> Box<dyn Iterator<Item = BTreeMap<K, V>>> {
+        let mut shrinks = Vec::new();
+        let min_size = self.inner.min_size;
+
+        // Try empty if allowed
+        if !value.is_empty() && min_size == 0 {
+            shrinks.push(BTreeMap::new());
+        }
+
+        // Try removing entries, smallest keys first
+        if value.len() > min_size {
+            for key in value.keys().take(3) {
+                let mut shrunk = value.clone();
+                shrunk.remove(key);
+                shrinks.push(shrunk);
+            }
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -378,6 +648,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sorted_vec_descending() {
+        let generator = SortedVecGenerator::new(IntGenerator::new(1, 100), 3, 10).descending();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..10 {
+            let vec = generator.generate(&mut rng, &config);
+            for window in vec.windows(2) {
+                assert!(
+                    window[0] >= window[1],
+                    "Vec should be descending: {:?}",
+                    vec
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sorted_vec_by_key() {
+        #[derive(Debug, Clone)]
+        struct Task {
+            priority: i32,
+        }
+
+        #[derive(Clone)]
+        struct TaskGenerator;
+
+        impl Generator<Task> for TaskGenerator {
+            fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> Task {
+                Task {
+                    priority: rng.r#gen_range(0..10),
+                }
+            }
+
+            fn shrink(&self, _value: &Task) -> Box<dyn Iterator<Item = Task>> {
+                Box::new(std::iter::empty())
+            }
+        }
+
+        let generator =
+            SortedVecGenerator::with_comparator(TaskGenerator, 1, 10, |a: &Task, b: &Task| {
+                a.priority.cmp(&b.priority)
+            })
+            .descending();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..10 {
+            let vec = generator.generate(&mut rng, &config);
+            for window in vec.windows(2) {
+                assert!(window[0].priority >= window[1].priority);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sorted_vec_shrink_preserves_order() {
+        let generator =
+            SortedVecGenerator::new(IntGenerator::new(0, 100), 0, 10).by(|a: &i32, b| b.cmp(a));
+        let value = vec![90, 50, 40, 10];
+
+        let shrinks: Vec<Vec<i32>> = generator.shrink(&value).collect();
+        assert!(
+            shrinks
+                .iter()
+                .any(|s| s.len() == value.len() && *s != value)
+        );
+        for shrunk in shrinks {
+            for window in shrunk.windows(2) {
+                assert!(window[0] >= window[1], "Shrink lost ordering: {:?}", shrunk);
+            }
+        }
+    }
+
     #[test]
     fn test_unique_vec_generator() {
         let generator = UniqueVecGenerator::new(IntGenerator::new(1, 50), 3, 10);
@@ -396,6 +741,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unique_vec_caps_to_domain() {
+        // Only 4 distinct values exist, so longer targets are capped
+        let generator = UniqueVecGenerator::new(IntGenerator::new(0, 3), 2, 10);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let vec = generator.generate(&mut rng, &config);
+            assert!(vec.len() >= 2 && vec.len() <= 4, "{:?}", vec);
+        }
+    }
+
+    #[test]
+    fn test_unique_vec_fills_an_exactly_sized_domain() {
+        // 100 distinct values from a domain of 100 needs ~520 draws on
+        // average, far more than a flat 10 draws per element allows for
+        let generator = UniqueVecGenerator::new(IntGenerator::new(0, 99), 100, 100);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..200 {
+            let mut vec = generator.generate(&mut rng, &config);
+            vec.sort_unstable();
+            assert_eq!(vec, (0..100).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "UniqueVecGenerator found only")]
+    fn test_unique_vec_impossible_min_len() {
+        let generator = UniqueVecGenerator::new(IntGenerator::new(0, 3), 10, 10);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        generator.generate(&mut rng, &config);
+    }
+
     #[test]
     fn test_bounded_map_generator() {
         let generator =
@@ -413,4 +796,62 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_bounded_map_exact_size() {
+        let generator =
+            BoundedMapGenerator::new(IntGenerator::new(0, 20), IntGenerator::new(0, 5), 6, 6);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        // Colliding keys are redrawn, so the size is always exactly 6
+        for _ in 0..50 {
+            assert_eq!(generator.generate(&mut rng, &config).len(), 6);
+        }
+    }
+
+    #[test]
+    fn test_bounded_map_unique_values() {
+        let generator =
+            BoundedMapGenerator::new(IntGenerator::new(0, 100), IntGenerator::new(0, 20), 5, 10)
+                .with_unique_values(true);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let map = generator.generate(&mut rng, &config);
+            let values: std::collections::HashSet<_> = map.values().collect();
+            assert_eq!(values.len(), map.len());
+        }
+    }
+
+    #[test]
+    fn test_bounded_map_sorted() {
+        let generator =
+            BoundedMapGenerator::new(IntGenerator::new(0, 100), IntGenerator::new(0, 100), 2, 8)
+                .sorted();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..10 {
+            let map: BTreeMap<i32, i32> = generator.generate(&mut rng, &config);
+            assert!(map.len() >= 2 && map.len() <= 8);
+        }
+
+        let value: BTreeMap<i32, i32> = (0..5).map(|i| (i, i)).collect();
+        for shrunk in generator.shrink(&value) {
+            assert!(shrunk.len() >= 2 && shrunk.len() < 5);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "BoundedMapGenerator found only")]
+    fn test_bounded_map_impossible_min_size() {
+        let generator =
+            BoundedMapGenerator::new(IntGenerator::new(0, 2), IntGenerator::new(0, 100), 5, 5);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let _: HashMap<i32, i32> = generator.generate(&mut rng, &config);
+    }
 }
@@ -4,8 +4,11 @@
 //! - Unix timestamps (i64)
 //! - Duration values
 //! - SystemTime ranges
+//! - Cron expressions
+//! - RFC3339 datetime strings (requires the `chrono` feature)
 //!
-//! All generators use std library only (no chrono dependency).
+//! All generators use std library only, except [`Rfc3339Generator`] which is
+//! only available with the `chrono` feature.
 
 use protest::{Generator, GeneratorConfig};
 use rand::Rng;
@@ -30,12 +33,16 @@ impl TimestampGenerator {
         Self { min, max }
     }
 
+    /// Create a timestamp generator for the window between two times (inclusive)
+    ///
+    /// Times before the epoch give negative timestamps.
+    pub fn between(start: SystemTime, end: SystemTime) -> Self {
+        Self::new(epoch_secs(start), epoch_secs(end))
+    }
+
     /// Create a timestamp generator for recent dates (last 10 years to now)
     pub fn recent() -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs() as i64;
+        let now = epoch_secs(SystemTime::now());
         let ten_years_ago = now - (10 * 365 * 24 * 60 * 60);
         Self {
             min: ten_years_ago,
@@ -45,15 +52,23 @@ impl TimestampGenerator {
 
     /// Create a timestamp generator for future dates (now to 10 years from now)
     pub fn future() -> Self {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs() as i64;
-        let ten_years_later = now + (10 * 365 * 24 * 60 * 60);
-        Self {
-            min: now,
-            max: ten_years_later,
-        }
+        Self::future_within(Duration::from_secs(10 * 365 * 24 * 60 * 60))
+    }
+
+    /// Create a timestamp generator for dates from now until `within` from now
+    ///
+    /// Useful for tokens or leases that have not expired yet.
+    pub fn future_within(within: Duration) -> Self {
+        let now = SystemTime::now();
+        Self::between(now, now + within)
+    }
+}
+
+/// Whole seconds since the epoch, negative for earlier times
+fn epoch_secs(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_secs() as i64,
+        Err(err) => -(err.duration().as_secs() as i64),
     }
 }
 
@@ -65,8 +80,8 @@ impl Generator<i64> for TimestampGenerator {
     fn shrink(&self, value: &i64) -> Box<dyn Iterator<Item = i64>> {
         let mut shrinks = Vec::new();
 
-        // Try epoch (0)
-        if *value > 0 && self.min <= 0 {
+        // Try epoch (0) when the window contains it
+        if *value != 0 && self.min <= 0 && self.max >= 0 {
             shrinks.push(0);
         }
 
@@ -92,80 +107,106 @@ impl Generator<i64> for TimestampGenerator {
 // ============================================================================
 
 /// Generator for Duration values
+///
+/// Durations are drawn uniformly in nanoseconds between the bounds, so a single
+/// generator can span anything from milliseconds to days.
 #[derive(Debug, Clone)]
 pub struct DurationGenerator {
-    min_secs: u64,
-    max_secs: u64,
+    min: Duration,
+    max: Duration,
 }
 
 impl DurationGenerator {
     /// Create a new duration generator with bounds in seconds
     pub fn new(min_secs: u64, max_secs: u64) -> Self {
-        Self { min_secs, max_secs }
+        Self::between(Duration::from_secs(min_secs), Duration::from_secs(max_secs))
+    }
+
+    /// Create a duration generator between two durations (inclusive)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protest::{Generator, GeneratorConfig};
+    /// use protest_extras::generators::datetime::DurationGenerator;
+    /// use rand::thread_rng;
+    /// use std::time::Duration;
+    ///
+    /// let generator = DurationGenerator::between(
+    ///     Duration::from_millis(5),
+    ///     Duration::from_secs(3 * 24 * 60 * 60),
+    /// );
+    /// let dur = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+    /// assert!(dur >= Duration::from_millis(5));
+    /// ```
+    pub fn between(min: Duration, max: Duration) -> Self {
+        Self { min, max }
+    }
+
+    /// Create a duration generator with bounds in milliseconds
+    pub fn from_millis_range(min_millis: u64, max_millis: u64) -> Self {
+        Self::between(
+            Duration::from_millis(min_millis),
+            Duration::from_millis(max_millis),
+        )
     }
 
     /// Create a duration generator for milliseconds (0-1000ms)
     pub fn milliseconds() -> Self {
-        Self {
-            min_secs: 0,
-            max_secs: 1,
-        }
+        Self::from_millis_range(0, 1000)
     }
 
     /// Create a duration generator for seconds (0-60s)
     pub fn seconds() -> Self {
-        Self {
-            min_secs: 0,
-            max_secs: 60,
-        }
+        Self::new(0, 60)
     }
 
     /// Create a duration generator for minutes (0-60 minutes)
     pub fn minutes() -> Self {
-        Self {
-            min_secs: 0,
-            max_secs: 60 * 60,
-        }
+        Self::new(0, 60 * 60)
     }
 
     /// Create a duration generator for hours (0-24 hours)
     pub fn hours() -> Self {
-        Self {
-            min_secs: 0,
-            max_secs: 24 * 60 * 60,
-        }
+        Self::new(0, 24 * 60 * 60)
+    }
+
+    fn from_nanos(nanos: u128) -> Duration {
+        Duration::new(
+            (nanos / 1_000_000_000) as u64,
+            (nanos % 1_000_000_000) as u32,
+        )
     }
 }
 
 impl Generator<Duration> for DurationGenerator {
     fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> Duration {
-        let secs = rng.r#gen_range(self.min_secs..=self.max_secs);
-        let nanos = rng.r#gen_range(0..1_000_000_000);
-        Duration::new(secs, nanos)
+        let nanos = rng.r#gen_range(self.min.as_nanos()..=self.max.as_nanos());
+        Self::from_nanos(nanos)
     }
 
     fn shrink(&self, value: &Duration) -> Box<dyn Iterator<Item = Duration>> {
         let mut shrinks = Vec::new();
 
-        // Try zero
-        if value.as_secs() > 0 || value.subsec_nanos() > 0 {
-            shrinks.push(Duration::ZERO);
-        }
-
-        // Try min duration
-        let min_dur = Duration::from_secs(self.min_secs);
-        if *value > min_dur {
-            shrinks.push(min_dur);
+        // Try the lower bound (zero when it is in range)
+        if *value > self.min {
+            shrinks.push(self.min);
         }
 
-        // Try half the duration
-        if value.as_secs() > 0 {
-            shrinks.push(Duration::from_secs(value.as_secs() / 2));
+        // Try halfway toward the lower bound
+        let half = Self::from_nanos((value.as_nanos() + self.min.as_nanos()) / 2);
+        if half > self.min && half < *value {
+            shrinks.push(half);
         }
 
-        // Try removing subsec nanos
-        if value.subsec_nanos() > 0 {
-            shrinks.push(Duration::from_secs(value.as_secs()));
+        // Try removing subsec precision
+        for truncated in [
+            Duration::from_millis(value.as_millis() as u64),
+            Duration::from_secs(value.as_secs()),
+        ] {
+            if truncated >= self.min && truncated < *value && !shrinks.contains(&truncated) {
+                shrinks.push(truncated);
+            }
         }
 
         Box::new(shrinks.into_iter())
@@ -195,6 +236,11 @@ impl SystemTimeGenerator {
     }
 
     /// Create a generator for times around now (± 1 year)
+    ///
+    /// If the system clock is within a year of the epoch (or set before it),
+    /// some generated times are earlier than `UNIX_EPOCH`, and
+    /// `duration_since(UNIX_EPOCH)` returns an error for them. Use
+    /// [`SystemTimeGenerator::after_epoch`] when callers assume otherwise.
     pub fn around_now() -> Self {
         let now = SystemTime::now();
         let one_year = 365 * 24 * 60 * 60;
@@ -215,6 +261,25 @@ impl SystemTimeGenerator {
             max_offset_secs,
         }
     }
+
+    /// Create a generator for times around now (± 1 year), never before `UNIX_EPOCH`
+    ///
+    /// Like [`SystemTimeGenerator::around_now`], but the window is clamped so
+    /// `duration_since(UNIX_EPOCH)` always succeeds on generated times.
+    pub fn after_epoch() -> Self {
+        Self::around_now().clamped_to_epoch()
+    }
+
+    /// Move a pre-epoch start up to the epoch, keeping the window's end
+    fn clamped_to_epoch(self) -> Self {
+        match UNIX_EPOCH.duration_since(self.start) {
+            Ok(before_epoch) => Self {
+                start: UNIX_EPOCH,
+                max_offset_secs: self.max_offset_secs.saturating_sub(before_epoch.as_secs()),
+            },
+            Err(_) => self,
+        }
+    }
 }
 
 impl Generator<SystemTime> for SystemTimeGenerator {
@@ -243,6 +308,404 @@ impl Generator<SystemTime> for SystemTimeGenerator {
     }
 }
 
+// ============================================================================
+// Cron Generator
+// ============================================================================
+
+/// Valid numeric domain of each cron field, in order
+const CRON_SECOND: (u32, u32) = (0, 59);
+const CRON_FIELDS: [(u32, u32); 5] = [
+    (0, 59), // minute
+    (0, 23), // hour
+    (1, 31), // day of month
+    (1, 12), // month
+    (0, 6),  // day of week
+];
+
+/// Syntactic form of a single cron field
+#[derive(Debug, Clone, Copy)]
+enum CronFieldForm {
+    Value,
+    Wildcard,
+    Range,
+    Step,
+    List,
+}
+
+/// Generator for cron expressions
+///
+/// Generates five-field cron strings (`min hour dom month dow`), optionally
+/// prefixed with a seconds field. Each field is a single value, `*`, a range
+/// (`1-5`), a step (`*/15`) or a list (`1,3,5`), depending on which forms are
+/// enabled. All numbers stay within the field's valid domain.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig};
+/// use protest_extras::generators::datetime::CronGenerator;
+/// use rand::thread_rng;
+///
+/// let generator = CronGenerator::new().with_lists(false).with_seconds(true);
+/// let cron = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert_eq!(cron.split(' ').count(), 6);
+/// assert!(!cron.contains(','));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CronGenerator {
+    wildcards: bool,
+    ranges: bool,
+    steps: bool,
+    lists: bool,
+    seconds: bool,
+}
+
+impl CronGenerator {
+    /// Create a cron generator using every field form
+    pub fn new() -> Self {
+        Self {
+            wildcards: true,
+            ranges: true,
+            steps: true,
+            lists: true,
+            seconds: false,
+        }
+    }
+
+    /// Enable or disable `*` fields
+    pub fn with_wildcards(mut self, enabled: bool) -> Self {
+        self.wildcards = enabled;
+        self
+    }
+
+    /// Enable or disable range fields such as `1-5`
+    pub fn with_ranges(mut self, enabled: bool) -> Self {
+        self.ranges = enabled;
+        self
+    }
+
+    /// Enable or disable step fields such as `*/15`
+    pub fn with_steps(mut self, enabled: bool) -> Self {
+        self.steps = enabled;
+        self
+    }
+
+    /// Enable or disable list fields such as `1,3,5`
+    pub fn with_lists(mut self, enabled: bool) -> Self {
+        self.lists = enabled;
+        self
+    }
+
+    /// Add a leading seconds field, producing six-field expressions
+    pub fn with_seconds(mut self, enabled: bool) -> Self {
+        self.seconds = enabled;
+        self
+    }
+
+    fn domains(&self) -> Vec<(u32, u32)> {
+        let mut domains = Vec::with_capacity(6);
+        if self.seconds {
+            domains.push(CRON_SECOND);
+        }
+        domains.extend_from_slice(&CRON_FIELDS);
+        domains
+    }
+
+    fn generate_field(&self, rng: &mut dyn rand::RngCore, (min, max): (u32, u32)) -> String {
+        // A single value is always available, other forms only when enabled
+        let mut forms = vec![CronFieldForm::Value];
+        if self.wildcards {
+            forms.push(CronFieldForm::Wildcard);
+        }
+        if self.ranges {
+            forms.push(CronFieldForm::Range);
+        }
+        if self.steps {
+            forms.push(CronFieldForm::Step);
+        }
+        if self.lists {
+            forms.push(CronFieldForm::List);
+        }
+
+        match forms[rng.r#gen_range(0..forms.len())] {
+            CronFieldForm::Value => rng.r#gen_range(min..=max).to_string(),
+            CronFieldForm::Wildcard => "*".to_string(),
+            CronFieldForm::Range => {
+                let start = rng.r#gen_range(min..=max);
+                let end = rng.r#gen_range(start..=max);
+                format!("{}-{}", start, end)
+            }
+            CronFieldForm::Step => format!("*/{}", rng.r#gen_range(1..=max - min)),
+            CronFieldForm::List => {
+                let count = rng.r#gen_range(2..=4);
+                let mut values: Vec<u32> = (0..count).map(|_| rng.r#gen_range(min..=max)).collect();
+                values.sort_unstable();
+                values.dedup();
+                values
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }
+        }
+    }
+
+    fn simplify_field(field: &str) -> Option<String> {
+        if let Some((first, _)) = field.split_once(',') {
+            Some(first.to_string())
+        } else if let Some((start, _)) = field.split_once('-') {
+            Some(start.to_string())
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for CronGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Generator<String> for CronGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> String {
+        self.domains()
+            .into_iter()
+            .map(|domain| self.generate_field(rng, domain))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
+        let mut shrinks = Vec::new();
+        let fields: Vec<&str> = value.split(' ').collect();
+
+        // The simplest form of each field is `*`, or the field's smallest value
+        // when wildcards are disabled
+        let domains = self.domains();
+        let simplest: Vec<String> = (0..fields.len())
+            .map(|i| match domains.get(i) {
+                Some((min, _)) if !self.wildcards => min.to_string(),
+                _ => "*".to_string(),
+            })
+            .collect();
+
+        // Try every field in its simplest form
+        let all_simplest = simplest.join(" ");
+        if *value != all_simplest {
+            shrinks.push(all_simplest);
+        }
+
+        for (i, field) in fields.iter().enumerate() {
+            // Try simplifying a single field
+            if *field != simplest[i] {
+                let mut shrunk = fields.clone();
+                shrunk[i] = &simplest[i];
+                shrinks.push(shrunk.join(" "));
+            }
+
+            // Try reducing lists and ranges to their first value
+            if let Some(simpler) = Self::simplify_field(field) {
+                let mut shrunk: Vec<String> = fields.iter().map(|f| f.to_string()).collect();
+                shrunk[i] = simpler;
+                shrinks.push(shrunk.join(" "));
+            }
+        }
+
+        // Never propose a form the generator would not produce
+        if !self.wildcards {
+            shrinks.retain(|shrunk| !shrunk.split(' ').any(|field| field == "*"));
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
+// ============================================================================
+// RFC3339 Generator
+// ============================================================================
+
+/// Offsets worth exercising: UTC, half/quarter-hour zones and the extremes
+#[cfg(feature = "chrono")]
+const RFC3339_OFFSETS: [i32; 10] = [
+    0,
+    5 * 3600 + 30 * 60,
+    5 * 3600 + 45 * 60,
+    -(3 * 3600 + 30 * 60),
+    -(9 * 3600 + 30 * 60),
+    12 * 3600 + 45 * 60,
+    14 * 3600,
+    -12 * 3600,
+    23 * 3600 + 59 * 60,
+    -(23 * 3600 + 59 * 60),
+];
+
+/// Generator for RFC3339 datetime strings
+///
+/// Formats a random instant within a year range, e.g. `2024-06-30T23:59:59.123+05:30`.
+/// Fractional seconds and non-UTC offsets can be switched on independently. About
+/// one in ten values lands within a few seconds of a leap-second boundary
+/// (midnight after June 30 or December 31). Shrinks toward `1970-01-01T00:00:00Z`.
+///
+/// Requires the `chrono` feature.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig};
+/// use protest_extras::generators::datetime::Rfc3339Generator;
+/// use rand::thread_rng;
+///
+/// let generator = Rfc3339Generator::new(2000, 2030)
+///     .with_fractional_seconds(true)
+///     .with_offsets(true);
+/// let value = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert!(chrono::DateTime::parse_from_rfc3339(&value).is_ok());
+/// ```
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone)]
+pub struct Rfc3339Generator {
+    min_year: i32,
+    max_year: i32,
+    fractional_seconds: bool,
+    offsets: bool,
+}
+
+#[cfg(feature = "chrono")]
+impl Rfc3339Generator {
+    /// Create a generator for instants between the start of `min_year` and the end of `max_year`
+    ///
+    /// Years are clamped to 0000–9999, the range RFC3339 can represent.
+    pub fn new(min_year: i32, max_year: i32) -> Self {
+        Self {
+            min_year: min_year.clamp(0, 9999),
+            max_year: max_year.clamp(0, 9999),
+            fractional_seconds: false,
+            offsets: false,
+        }
+    }
+
+    /// Include fractional seconds (milli, micro or nanosecond precision)
+    pub fn with_fractional_seconds(mut self, enabled: bool) -> Self {
+        self.fractional_seconds = enabled;
+        self
+    }
+
+    /// Use a variety of timezone offsets instead of always `Z`
+    pub fn with_offsets(mut self, enabled: bool) -> Self {
+        self.offsets = enabled;
+        self
+    }
+
+    fn year_start(year: i32) -> i64 {
+        chrono::NaiveDate::from_ymd_opt(year, 1, 1)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|dt| dt.and_utc().timestamp())
+            .unwrap_or(0)
+    }
+
+    fn generate_timestamp(&self, rng: &mut dyn rand::RngCore) -> i64 {
+        if rng.r#gen_bool(0.1) {
+            // Land next to a potential leap second: midnight after June 30 or December 31
+            let year = rng.r#gen_range(self.min_year..=self.max_year);
+            let midnight = if rng.r#gen_bool(0.5) {
+                chrono::NaiveDate::from_ymd_opt(year, 7, 1)
+            } else {
+                chrono::NaiveDate::from_ymd_opt(year, 12, 31).and_then(|d| d.succ_opt())
+            };
+            if let Some(midnight) = midnight.and_then(|d| d.and_hms_opt(0, 0, 0)) {
+                return midnight.and_utc().timestamp() + rng.r#gen_range(-3..=2);
+            }
+        }
+
+        let start = Self::year_start(self.min_year);
+        let end = Self::year_start(self.max_year + 1) - 1;
+        rng.r#gen_range(start..=end.max(start))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Default for Rfc3339Generator {
+    fn default() -> Self {
+        Self::new(1970, 2100)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Generator<String> for Rfc3339Generator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> String {
+        use chrono::{DateTime, FixedOffset, SecondsFormat};
+
+        let nanos = if self.fractional_seconds {
+            rng.r#gen_range(0..1_000_000_000)
+        } else {
+            0
+        };
+        let utc = DateTime::from_timestamp(self.generate_timestamp(rng), nanos)
+            .unwrap_or(DateTime::UNIX_EPOCH);
+
+        let precision = if self.fractional_seconds {
+            [
+                SecondsFormat::Millis,
+                SecondsFormat::Micros,
+                SecondsFormat::Nanos,
+            ][rng.r#gen_range(0..3)]
+        } else {
+            SecondsFormat::Secs
+        };
+
+        if !self.offsets || rng.r#gen_bool(0.3) {
+            return utc.to_rfc3339_opts(precision, true);
+        }
+
+        let seconds = if rng.r#gen_bool(0.7) {
+            RFC3339_OFFSETS[rng.r#gen_range(0..RFC3339_OFFSETS.len())]
+        } else {
+            rng.r#gen_range(-23..=23) * 3600
+        };
+        let offset = FixedOffset::east_opt(seconds).expect("offsets are within a day");
+        utc.with_timezone(&offset).to_rfc3339_opts(precision, false)
+    }
+
+    fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
+        use chrono::{DateTime, SecondsFormat, Timelike, Utc};
+
+        let mut shrinks = Vec::new();
+
+        // Try the epoch, if it is in range
+        let epoch = "1970-01-01T00:00:00Z".to_string();
+        if *value != epoch && self.min_year <= 1970 && 1970 <= self.max_year {
+            shrinks.push(epoch);
+        }
+
+        if let Ok(parsed) = DateTime::parse_from_rfc3339(value) {
+            let utc = parsed.with_timezone(&Utc);
+            let mut candidates = vec![
+                // Same instant, without offset or fractional seconds
+                utc.with_nanosecond(0)
+                    .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Secs, true)),
+                // Start of the day
+                utc.date_naive()
+                    .and_hms_opt(0, 0, 0)
+                    .map(|dt| dt.and_utc().to_rfc3339_opts(SecondsFormat::Secs, true)),
+            ];
+            // Start of the lower bound year
+            candidates.push(
+                DateTime::from_timestamp(Self::year_start(self.min_year), 0)
+                    .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            );
+
+            for candidate in candidates.into_iter().flatten() {
+                if candidate != *value && !shrinks.contains(&candidate) {
+                    shrinks.push(candidate);
+                }
+            }
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,6 +723,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_timestamp_between_and_future_within() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let start = UNIX_EPOCH - Duration::from_secs(100);
+        let generator = TimestampGenerator::between(start, UNIX_EPOCH + Duration::from_secs(100));
+        for _ in 0..10 {
+            let ts = generator.generate(&mut rng, &config);
+            assert!((-100..=100).contains(&ts));
+        }
+        assert_eq!(generator.shrink(&-50).next(), Some(0));
+
+        let now = epoch_secs(SystemTime::now());
+        let generator = TimestampGenerator::future_within(Duration::from_secs(3600));
+        for _ in 0..10 {
+            let ts = generator.generate(&mut rng, &config);
+            assert!(ts >= now && ts <= now + 3601);
+        }
+
+        // Shrinks head to the window's lower bound, not the epoch
+        let shrinks: Vec<_> = generator.shrink(&(now + 3000)).collect();
+        assert!(shrinks.iter().all(|&ts| ts >= now));
+        assert!(shrinks.contains(&generator.min));
+    }
+
     #[test]
     fn test_timestamp_recent() {
         let generator = TimestampGenerator::recent();
@@ -304,6 +793,46 @@ mod tests {
         assert!(dur.as_secs() <= 3600);
     }
 
+    #[test]
+    fn test_duration_between() {
+        let min = Duration::from_millis(250);
+        let max = Duration::from_secs(2 * 24 * 60 * 60);
+        let generator = DurationGenerator::between(min, max);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..100 {
+            let dur = generator.generate(&mut rng, &config);
+            assert!(dur >= min && dur <= max);
+        }
+
+        let generator = DurationGenerator::from_millis_range(10, 20);
+        for _ in 0..100 {
+            let dur = generator.generate(&mut rng, &config);
+            assert!((10..=20).contains(&dur.as_millis()));
+        }
+    }
+
+    #[test]
+    fn test_duration_shrinks_toward_lower_bound() {
+        let generator = DurationGenerator::from_millis_range(100, 10_000);
+        let value = Duration::new(5, 123_456_789);
+
+        let shrinks: Vec<_> = generator.shrink(&value).collect();
+        assert_eq!(shrinks[0], Duration::from_millis(100));
+        assert!(shrinks.contains(&Duration::from_millis(5123)));
+        assert!(shrinks.contains(&Duration::from_secs(5)));
+        assert!(
+            shrinks
+                .iter()
+                .all(|d| *d >= Duration::from_millis(100) && *d < value)
+        );
+
+        let generator = DurationGenerator::seconds();
+        let shrinks: Vec<_> = generator.shrink(&Duration::from_secs(30)).collect();
+        assert_eq!(shrinks[0], Duration::ZERO);
+    }
+
     #[test]
     fn test_system_time_generator() {
         let start = UNIX_EPOCH;
@@ -340,4 +869,186 @@ mod tests {
             }
         }
     }
+
+    fn cron_numbers(field: &str) -> Vec<u32> {
+        field
+            .split([',', '-', '/'])
+            .filter(|part| *part != "*")
+            .map(|part| part.parse().expect("cron field should be numeric"))
+            .collect()
+    }
+
+    #[test]
+    fn test_system_time_after_epoch() {
+        let generator = SystemTimeGenerator::after_epoch();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..10 {
+            let time = generator.generate(&mut rng, &config);
+            assert!(time.duration_since(UNIX_EPOCH).is_ok());
+            for shrunk in generator.shrink(&time) {
+                assert!(shrunk >= UNIX_EPOCH);
+            }
+        }
+
+        // A window straddling the epoch keeps only its post-epoch part
+        let generator =
+            SystemTimeGenerator::new(UNIX_EPOCH - Duration::from_secs(100), 300).clamped_to_epoch();
+        assert_eq!(generator.start, UNIX_EPOCH);
+        assert_eq!(generator.max_offset_secs, 200);
+    }
+
+    #[test]
+    fn test_cron_generator_field_domains() {
+        let generator = CronGenerator::new();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..200 {
+            let cron = generator.generate(&mut rng, &config);
+            let fields: Vec<&str> = cron.split(' ').collect();
+            assert_eq!(fields.len(), 5, "unexpected field count in {}", cron);
+
+            for (field, (min, max)) in fields.iter().zip(CRON_FIELDS) {
+                if let Some(step) = field.strip_prefix("*/") {
+                    let step: u32 = step.parse().unwrap();
+                    assert!(step >= 1 && step <= max, "bad step in {}", cron);
+                    continue;
+                }
+                for n in cron_numbers(field) {
+                    assert!((min..=max).contains(&n), "{} out of range in {}", n, cron);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cron_generator_with_seconds() {
+        let generator = CronGenerator::new().with_seconds(true);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..50 {
+            let cron = generator.generate(&mut rng, &config);
+            let fields: Vec<&str> = cron.split(' ').collect();
+            assert_eq!(fields.len(), 6);
+            assert!(cron_numbers(fields[0]).iter().all(|n| *n <= CRON_SECOND.1));
+        }
+    }
+
+    #[test]
+    fn test_cron_generator_disabled_forms() {
+        let generator = CronGenerator::new()
+            .with_wildcards(false)
+            .with_ranges(false)
+            .with_steps(false)
+            .with_lists(false);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..50 {
+            let cron = generator.generate(&mut rng, &config);
+            assert!(
+                cron.split(' ')
+                    .all(|f| f.chars().all(|c| c.is_ascii_digit()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_cron_generator_shrinks_toward_wildcards() {
+        let generator = CronGenerator::new();
+        let shrinks: Vec<String> = generator.shrink(&"1,3,5 2-4 */2 * 0".to_string()).collect();
+
+        assert_eq!(shrinks[0], "* * * * *");
+        assert!(shrinks.contains(&"1 2-4 */2 * 0".to_string()));
+        assert!(shrinks.contains(&"1,3,5 2 */2 * 0".to_string()));
+        assert_eq!(generator.shrink(&"* * * * *".to_string()).count(), 0);
+    }
+
+    #[test]
+    fn test_cron_generator_shrinks_without_wildcards() {
+        let generator = CronGenerator::new().with_wildcards(false);
+        let shrinks: Vec<String> = generator.shrink(&"1,3,5 2-4 9 10 6".to_string()).collect();
+
+        assert!(!shrinks.is_empty());
+        assert!(shrinks.iter().all(|s| !s.split(' ').any(|f| f == "*")));
+        assert_eq!(shrinks[0], "0 0 1 1 0");
+        assert!(shrinks.contains(&"1 2-4 9 10 6".to_string()));
+        assert!(shrinks.contains(&"1,3,5 2-4 9 1 6".to_string()));
+        assert_eq!(generator.shrink(&"0 0 1 1 0".to_string()).count(), 0);
+
+        // Shrinking generated expressions never introduces a wildcard
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+        for _ in 0..50 {
+            let cron = generator.generate(&mut rng, &config);
+            assert!(
+                generator
+                    .shrink(&cron)
+                    .all(|s| !s.contains('*') || cron.contains('*'))
+            );
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_rfc3339_generator_parses() {
+        use chrono::Datelike;
+
+        let generator = Rfc3339Generator::new(1990, 2010)
+            .with_fractional_seconds(true)
+            .with_offsets(true);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let mut saw_offset = false;
+        let mut saw_leap_adjacent = false;
+        for _ in 0..500 {
+            let value = generator.generate(&mut rng, &config);
+            let parsed = chrono::DateTime::parse_from_rfc3339(&value)
+                .unwrap_or_else(|e| panic!("{} failed to parse: {}", value, e));
+            let year = parsed.with_timezone(&chrono::Utc).year();
+            assert!((1989..=2011).contains(&year), "{}", value);
+
+            saw_offset |= !value.ends_with('Z');
+            saw_leap_adjacent |= value.contains("T23:59:5") || value.contains("T00:00:0");
+        }
+        assert!(saw_offset);
+        assert!(saw_leap_adjacent);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_rfc3339_generator_defaults_to_utc_seconds() {
+        let generator = Rfc3339Generator::new(2020, 2020);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..50 {
+            let value = generator.generate(&mut rng, &config);
+            assert!(value.ends_with('Z'));
+            assert_eq!(value.len(), "2020-01-01T00:00:00Z".len());
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_rfc3339_generator_shrinks_toward_epoch() {
+        let generator = Rfc3339Generator::default();
+        let shrinks: Vec<String> = generator
+            .shrink(&"2024-06-30T23:59:59.123+05:30".to_string())
+            .collect();
+
+        assert_eq!(shrinks[0], "1970-01-01T00:00:00Z");
+        assert!(shrinks.contains(&"2024-06-30T18:29:59Z".to_string()));
+        assert!(shrinks.contains(&"2024-06-30T00:00:00Z".to_string()));
+        assert_eq!(
+            generator
+                .shrink(&"1970-01-01T00:00:00Z".to_string())
+                .count(),
+            0
+        );
+    }
 }
@@ -5,12 +5,14 @@
 //! - Base64 encoded strings
 //! - File system paths
 //! - UUIDs (v4)
+//! - Geographic coordinates (latitude/longitude)
+//! - Colors (hex strings and RGBA tuples)
 //!
 //! All generators use std library only (no external dependencies).
 
-use protest::{Generator, GeneratorConfig};
+use protest::{Generator, GeneratorConfig, GeneratorError};
 use rand::Rng;
-use std::path::{MAIN_SEPARATOR, PathBuf};
+use std::path::{Component, MAIN_SEPARATOR, Path, PathBuf};
 
 // ============================================================================
 // Hex Generator
@@ -18,12 +20,14 @@ use std::path::{MAIN_SEPARATOR, PathBuf};
 
 /// Generator for hexadecimal strings
 ///
-/// Generates strings containing only hex characters (0-9, a-f)
+/// Generates strings containing only hex characters (0-9, a-f), optionally
+/// prefixed with `0x`
 #[derive(Debug, Clone)]
 pub struct HexGenerator {
     min_len: usize,
     max_len: usize,
     uppercase: bool,
+    prefix: bool,
 }
 
 impl HexGenerator {
@@ -33,18 +37,31 @@ impl HexGenerator {
             min_len,
             max_len,
             uppercase: false,
+            prefix: false,
         }
     }
 
     /// Create a hex generator with uppercase letters (A-F instead of a-f)
     pub fn uppercase(min_len: usize, max_len: usize) -> Self {
         Self {
-            min_len,
-            max_len,
             uppercase: true,
+            ..Self::new(min_len, max_len)
         }
     }
 
+    /// Create a hex generator with exactly `len` digits, e.g. 64 for a SHA-256
+    pub fn fixed(len: usize) -> Self {
+        Self::new(len, len)
+    }
+
+    /// Prefix generated strings with `0x`
+    ///
+    /// The length bounds count digits only, not the prefix.
+    pub fn with_0x_prefix(mut self, prefix: bool) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
     fn generate_hex_char(&self, rng: &mut dyn rand::RngCore) -> char {
         let hex_chars = if self.uppercase {
             b"0123456789ABCDEF"
@@ -53,15 +70,9 @@ impl HexGenerator {
         };
         hex_chars[rng.r#gen_range(0..hex_chars.len())] as char
     }
-}
 
-impl Generator<String> for HexGenerator {
-    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> String {
-        let len = rng.r#gen_range(self.min_len..=self.max_len);
-        (0..len).map(|_| self.generate_hex_char(rng)).collect()
-    }
-
-    fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
+    /// Shrink candidates for the digits, without any prefix
+    fn shrink_digits(&self, value: &str) -> Vec<String> {
         let mut shrinks = Vec::new();
 
         // Try empty string
@@ -74,6 +85,13 @@ impl Generator<String> for HexGenerator {
             shrinks.push("0".repeat(value.len()));
         }
 
+        // Try zeroing single digits, keeping the length
+        for (i, _) in value.char_indices().filter(|&(_, c)| c != '0').take(3) {
+            let mut shrunk = value.to_string();
+            shrunk.replace_range(i..i + 1, "0");
+            shrinks.push(shrunk);
+        }
+
         // Try min length
         if value.len() > self.min_len {
             shrinks.push(value.chars().take(self.min_len).collect());
@@ -91,6 +109,33 @@ impl Generator<String> for HexGenerator {
             }
         }
 
+        shrinks
+    }
+}
+
+impl Generator<String> for HexGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> String {
+        let len = rng.r#gen_range(self.min_len..=self.max_len);
+        let mut hex = String::with_capacity(len + 2);
+        if self.prefix {
+            hex.push_str("0x");
+        }
+        hex.extend((0..len).map(|_| self.generate_hex_char(rng)));
+        hex
+    }
+
+    fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
+        let (prefix, digits) = match value.strip_prefix("0x") {
+            Some(digits) if self.prefix => ("0x", digits),
+            _ => ("", value.as_str()),
+        };
+
+        let shrinks: Vec<String> = self
+            .shrink_digits(digits)
+            .into_iter()
+            .map(|shrunk| format!("{}{}", prefix, shrunk))
+            .collect();
+
         Box::new(shrinks.into_iter())
     }
 }
@@ -99,13 +144,21 @@ impl Generator<String> for HexGenerator {
 // Base64 Generator
 // ============================================================================
 
+const STANDARD_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
 /// Generator for Base64 encoded strings
 ///
-/// Generates valid Base64 strings (standard encoding with padding)
+/// Generates valid Base64 strings (standard encoding with padding by default,
+/// or the URL-safe alphabet via [`Base64Generator::url_safe`])
 #[derive(Debug, Clone)]
 pub struct Base64Generator {
     min_bytes: usize,
     max_bytes: usize,
+    url_safe: bool,
+    padding: bool,
 }
 
 impl Base64Generator {
@@ -117,12 +170,42 @@ impl Base64Generator {
         Self {
             min_bytes,
             max_bytes,
+            url_safe: false,
+            padding: true,
+        }
+    }
+
+    /// Create a generator using the URL-safe alphabet (`-` and `_` instead of `+` and `/`)
+    pub fn url_safe(min_bytes: usize, max_bytes: usize) -> Self {
+        Self {
+            url_safe: true,
+            ..Self::new(min_bytes, max_bytes)
         }
     }
 
-    fn encode_base64(bytes: &[u8]) -> String {
-        const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    /// Omit the trailing `=` padding, as in JWTs
+    pub fn no_padding(mut self) -> Self {
+        self.padding = false;
+        self
+    }
 
+    /// Encode with this generator's alphabet and padding
+    fn encode(&self, bytes: &[u8]) -> String {
+        let alphabet = if self.url_safe {
+            URL_SAFE_ALPHABET
+        } else {
+            STANDARD_ALPHABET
+        };
+        let encoded = Self::encode_with_alphabet(bytes, alphabet);
+
+        if self.padding {
+            encoded
+        } else {
+            encoded.trim_end_matches('=').to_string()
+        }
+    }
+
+    fn encode_with_alphabet(bytes: &[u8], alphabet: &[u8; 64]) -> String {
         let mut result = String::new();
         let mut i = 0;
 
@@ -132,10 +215,10 @@ impl Base64Generator {
             let b2 = bytes[i + 1];
             let b3 = bytes[i + 2];
 
-            result.push(CHARS[(b1 >> 2) as usize] as char);
-            result.push(CHARS[(((b1 & 0x03) << 4) | (b2 >> 4)) as usize] as char);
-            result.push(CHARS[(((b2 & 0x0f) << 2) | (b3 >> 6)) as usize] as char);
-            result.push(CHARS[(b3 & 0x3f) as usize] as char);
+            result.push(alphabet[(b1 >> 2) as usize] as char);
+            result.push(alphabet[(((b1 & 0x03) << 4) | (b2 >> 4)) as usize] as char);
+            result.push(alphabet[(((b2 & 0x0f) << 2) | (b3 >> 6)) as usize] as char);
+            result.push(alphabet[(b3 & 0x3f) as usize] as char);
 
             i += 3;
         }
@@ -143,15 +226,15 @@ impl Base64Generator {
         // Handle remaining bytes with padding
         if i < bytes.len() {
             let b1 = bytes[i];
-            result.push(CHARS[(b1 >> 2) as usize] as char);
+            result.push(alphabet[(b1 >> 2) as usize] as char);
 
             if i + 1 < bytes.len() {
                 let b2 = bytes[i + 1];
-                result.push(CHARS[(((b1 & 0x03) << 4) | (b2 >> 4)) as usize] as char);
-                result.push(CHARS[((b2 & 0x0f) << 2) as usize] as char);
+                result.push(alphabet[(((b1 & 0x03) << 4) | (b2 >> 4)) as usize] as char);
+                result.push(alphabet[((b2 & 0x0f) << 2) as usize] as char);
                 result.push('=');
             } else {
-                result.push(CHARS[((b1 & 0x03) << 4) as usize] as char);
+                result.push(alphabet[((b1 & 0x03) << 4) as usize] as char);
                 result.push_str("==");
             }
         }
@@ -164,7 +247,7 @@ impl Generator<String> for Base64Generator {
     fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> String {
         let num_bytes = rng.r#gen_range(self.min_bytes..=self.max_bytes);
         let bytes: Vec<u8> = (0..num_bytes).map(|_| rng.r#gen()).collect();
-        Self::encode_base64(&bytes)
+        self.encode(&bytes)
     }
 
     fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
@@ -178,15 +261,15 @@ impl Generator<String> for Base64Generator {
         // Try min bytes
         if !value.is_empty() && self.min_bytes > 0 {
             let bytes: Vec<u8> = (0..self.min_bytes).map(|_| 0u8).collect();
-            shrinks.push(Self::encode_base64(&bytes));
+            shrinks.push(self.encode(&bytes));
         }
 
         // Try all zeros
-        // Estimate byte count from base64 length
-        let approx_bytes = (value.len() * 3) / 4;
+        // Estimate byte count from base64 length, ignoring any padding
+        let approx_bytes = (value.trim_end_matches('=').len() * 3) / 4;
         if approx_bytes >= self.min_bytes {
             let bytes = vec![0u8; approx_bytes];
-            let encoded = Self::encode_base64(&bytes);
+            let encoded = self.encode(&bytes);
             if encoded != *value {
                 shrinks.push(encoded);
             }
@@ -208,6 +291,8 @@ pub struct PathGenerator {
     min_depth: usize,
     max_depth: usize,
     absolute: bool,
+    extensions: Vec<String>,
+    hidden_probability: f64,
 }
 
 impl PathGenerator {
@@ -219,6 +304,8 @@ impl PathGenerator {
             min_depth,
             max_depth,
             absolute: false,
+            extensions: Vec::new(),
+            hidden_probability: 0.0,
         }
     }
 
@@ -228,9 +315,28 @@ impl PathGenerator {
             min_depth,
             max_depth,
             absolute: true,
+            extensions: Vec::new(),
+            hidden_probability: 0.0,
         }
     }
 
+    /// Append one of the given extensions to the final component
+    ///
+    /// Extensions are given without the leading dot and may contain dots
+    /// themselves, e.g. `&["rs", "tar.gz"]`.
+    pub fn with_extension(mut self, extensions: &[&str]) -> Self {
+        self.extensions = extensions.iter().map(|ext| ext.to_string()).collect();
+        self
+    }
+
+    /// Prefix each component with `.` with the given probability
+    ///
+    /// The probability is clamped to [0.0, 1.0].
+    pub fn with_hidden(mut self, probability: f64) -> Self {
+        self.hidden_probability = probability.clamp(0.0, 1.0);
+        self
+    }
+
     fn generate_component(&self, rng: &mut dyn rand::RngCore) -> String {
         let len = rng.r#gen_range(1..=12);
         let mut component = String::with_capacity(len);
@@ -247,6 +353,41 @@ impl PathGenerator {
 
         component
     }
+
+    /// The path without its configured extension, if it ends with one
+    fn strip_extension(&self, value: &Path) -> Option<PathBuf> {
+        let name = value.file_name()?.to_str()?;
+
+        // Longest first, so "tar.gz" is dropped whole rather than just "gz"
+        let mut extensions: Vec<_> = self.extensions.iter().collect();
+        extensions.sort_by_key(|ext| std::cmp::Reverse(ext.len()));
+
+        extensions.into_iter().find_map(|ext| {
+            let stem = name.strip_suffix(ext.as_str())?.strip_suffix('.')?;
+            (!stem.is_empty() && stem != ".").then(|| value.with_file_name(stem))
+        })
+    }
+
+    /// The path with the leading `.` removed from every hidden component
+    fn unhide(value: &Path) -> Option<PathBuf> {
+        let mut path = PathBuf::new();
+        let mut changed = false;
+
+        for component in value.components() {
+            match component {
+                Component::Normal(name) => match name.to_str() {
+                    Some(name) if name.len() > 1 && name.starts_with('.') => {
+                        path.push(&name[1..]);
+                        changed = true;
+                    }
+                    _ => path.push(name),
+                },
+                other => path.push(other),
+            }
+        }
+
+        changed.then_some(path)
+    }
 }
 
 impl Generator<PathBuf> for PathGenerator {
@@ -272,7 +413,19 @@ impl Generator<PathBuf> for PathGenerator {
         }
 
         for _ in 0..depth {
-            path.push(self.generate_component(rng));
+            let mut component = self.generate_component(rng);
+            if self.hidden_probability > 0.0 && rng.r#gen_bool(self.hidden_probability) {
+                component.insert(0, '.');
+            }
+            path.push(component);
+        }
+
+        if !self.extensions.is_empty() {
+            let extension = &self.extensions[rng.r#gen_range(0..self.extensions.len())];
+            let mut name = path.file_name().unwrap_or_default().to_os_string();
+            name.push(".");
+            name.push(extension);
+            path.set_file_name(name);
         }
 
         path
@@ -281,6 +434,14 @@ impl Generator<PathBuf> for PathGenerator {
     fn shrink(&self, value: &PathBuf) -> Box<dyn Iterator<Item = PathBuf>> {
         let mut shrinks = Vec::new();
 
+        // Drop the extension, then un-hide, before touching the depth
+        if let Some(path) = self.strip_extension(value) {
+            shrinks.push(path);
+        }
+        if let Some(path) = Self::unhide(value) {
+            shrinks.push(path);
+        }
+
         let components: Vec<_> = value.components().collect();
         let depth = components.len();
 
@@ -386,6 +547,302 @@ impl Generator<String> for UuidV4Generator {
     }
 }
 
+// ============================================================================
+// Coordinate Generator
+// ============================================================================
+
+/// Generator for latitude/longitude pairs
+///
+/// Generates `(latitude, longitude)` tuples with latitude in [-90, 90] and
+/// longitude in [-180, 180], or within a custom bounding box. Shrinking moves
+/// both components toward `(0.0, 0.0)` when the origin lies inside the bounds,
+/// and toward the minimum corner of the bounding box otherwise.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig};
+/// use protest_extras::generators::domain::CoordinateGenerator;
+/// use rand::thread_rng;
+///
+/// let generator = CoordinateGenerator::within_bbox(51.28, -0.51, 51.69, 0.33)
+///     .unwrap()
+///     .with_precision(6);
+/// let (lat, lon) = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert!((51.28..=51.69).contains(&lat));
+/// assert!((-0.51..=0.33).contains(&lon));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CoordinateGenerator {
+    min_lat: f64,
+    min_lon: f64,
+    max_lat: f64,
+    max_lon: f64,
+    precision: Option<u32>,
+}
+
+impl CoordinateGenerator {
+    /// Create a generator covering the whole globe
+    pub fn new() -> Self {
+        Self {
+            min_lat: -90.0,
+            min_lon: -180.0,
+            max_lat: 90.0,
+            max_lon: 180.0,
+            precision: None,
+        }
+    }
+
+    /// Create a generator for coordinates inside a bounding box
+    ///
+    /// The bounds are clamped to the valid latitude/longitude ranges. Fails if
+    /// a bound is NaN or a minimum is greater than its maximum.
+    pub fn within_bbox(
+        min_lat: f64,
+        min_lon: f64,
+        max_lat: f64,
+        max_lon: f64,
+    ) -> Result<Self, GeneratorError> {
+        if [min_lat, min_lon, max_lat, max_lon]
+            .iter()
+            .any(|b| b.is_nan())
+        {
+            return Err(GeneratorError::new("bounding box bounds must not be NaN"));
+        }
+        if min_lat > max_lat {
+            return Err(GeneratorError::new(format!(
+                "bounding box min_lat {} is greater than max_lat {}",
+                min_lat, max_lat
+            )));
+        }
+        if min_lon > max_lon {
+            return Err(GeneratorError::new(format!(
+                "bounding box min_lon {} is greater than max_lon {}",
+                min_lon, max_lon
+            )));
+        }
+
+        Ok(Self {
+            min_lat: min_lat.clamp(-90.0, 90.0),
+            min_lon: min_lon.clamp(-180.0, 180.0),
+            max_lat: max_lat.clamp(-90.0, 90.0),
+            max_lon: max_lon.clamp(-180.0, 180.0),
+            precision: None,
+        })
+    }
+
+    /// Round generated values to the given number of decimal places
+    ///
+    /// Six decimal places (roughly 0.1m) survives most serialization round-trips.
+    pub fn with_precision(mut self, decimals: u32) -> Self {
+        self.precision = Some(decimals);
+        self
+    }
+
+    fn contains(&self, (lat, lon): (f64, f64)) -> bool {
+        (self.min_lat..=self.max_lat).contains(&lat) && (self.min_lon..=self.max_lon).contains(&lon)
+    }
+
+    /// Round to the configured precision, staying inside the bounds
+    fn round(&self, value: f64, min: f64, max: f64) -> f64 {
+        match self.precision {
+            Some(decimals) => {
+                let factor = 10f64.powi(decimals as i32);
+                let rounded = (value * factor).round() / factor;
+                if rounded < min {
+                    (min * factor).ceil() / factor
+                } else if rounded > max {
+                    (max * factor).floor() / factor
+                } else {
+                    rounded
+                }
+            }
+            None => value,
+        }
+    }
+
+    fn normalize(&self, (lat, lon): (f64, f64)) -> (f64, f64) {
+        (
+            self.round(lat, self.min_lat, self.max_lat),
+            self.round(lon, self.min_lon, self.max_lon),
+        )
+    }
+}
+
+impl Default for CoordinateGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Generator<(f64, f64)> for CoordinateGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> (f64, f64) {
+        let lat = rng.r#gen_range(self.min_lat..=self.max_lat);
+        let lon = rng.r#gen_range(self.min_lon..=self.max_lon);
+        self.normalize((lat, lon))
+    }
+
+    fn shrink(&self, value: &(f64, f64)) -> Box<dyn Iterator<Item = (f64, f64)>> {
+        let (lat, lon) = *value;
+        let target = if self.contains((0.0, 0.0)) {
+            (0.0, 0.0)
+        } else {
+            (self.min_lat, self.min_lon)
+        };
+
+        let candidates = [
+            target,
+            (target.0, lon),
+            (lat, target.1),
+            ((lat + target.0) / 2.0, (lon + target.1) / 2.0),
+            (lat.trunc(), lon.trunc()),
+        ];
+
+        let mut shrinks: Vec<(f64, f64)> = Vec::new();
+        for candidate in candidates {
+            let candidate = self.normalize(candidate);
+            if candidate != *value && self.contains(candidate) && !shrinks.contains(&candidate) {
+                shrinks.push(candidate);
+            }
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
+// ============================================================================
+// Color Generators
+// ============================================================================
+
+/// Generator for colors
+///
+/// [`ColorGenerator::hex`] produces `#rrggbb` strings (and `#rrggbbaa` when
+/// alpha is enabled), while [`ColorGenerator::rgba`] produces `(r, g, b, a)`
+/// tuples via [`RgbaGenerator`]. Both shrink toward black.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig};
+/// use protest_extras::generators::domain::ColorGenerator;
+/// use rand::thread_rng;
+///
+/// let mut rng = thread_rng();
+/// let config = GeneratorConfig::default();
+///
+/// let hex = ColorGenerator::hex().with_alpha(false).generate(&mut rng, &config);
+/// assert_eq!(hex.len(), 7);
+///
+/// let (_r, _g, _b, _a) = ColorGenerator::rgba().generate(&mut rng, &config);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColorGenerator {
+    alpha: bool,
+}
+
+impl ColorGenerator {
+    /// Create a generator for `#rrggbb` / `#rrggbbaa` hex color strings
+    pub fn hex() -> Self {
+        Self { alpha: true }
+    }
+
+    /// Create a generator for `(r, g, b, a)` tuples
+    pub fn rgba() -> RgbaGenerator {
+        RgbaGenerator::new()
+    }
+
+    /// Allow or forbid an alpha channel (`#rrggbbaa`) in generated strings
+    ///
+    /// With alpha disabled, only opaque `#rrggbb` colors are produced.
+    pub fn with_alpha(mut self, alpha: bool) -> Self {
+        self.alpha = alpha;
+        self
+    }
+}
+
+impl Generator<String> for ColorGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> String {
+        let [r, g, b]: [u8; 3] = rng.r#gen();
+        if self.alpha && rng.r#gen_bool(0.5) {
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, rng.r#gen::<u8>())
+        } else {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        }
+    }
+
+    fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
+        let mut shrinks = Vec::new();
+
+        // Try black
+        if value != "#000000" {
+            shrinks.push("#000000".to_string());
+        }
+
+        // Try dropping the alpha channel
+        if value.len() == 9 {
+            shrinks.push(value[..7].to_string());
+        }
+
+        // Try zeroing each channel
+        for i in (1..value.len()).step_by(2) {
+            if &value[i..i + 2] != "00" {
+                let mut shrunk = value.clone();
+                shrunk.replace_range(i..i + 2, "00");
+                if !shrinks.contains(&shrunk) {
+                    shrinks.push(shrunk);
+                }
+            }
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
+/// Generator for `(r, g, b, a)` color tuples
+///
+/// Created through [`ColorGenerator::rgba`]. Shrinks toward `(0, 0, 0, 0)`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RgbaGenerator;
+
+impl RgbaGenerator {
+    /// Create a new RGBA generator
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Generator<(u8, u8, u8, u8)> for RgbaGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> (u8, u8, u8, u8) {
+        rng.r#gen()
+    }
+
+    fn shrink(&self, value: &(u8, u8, u8, u8)) -> Box<dyn Iterator<Item = (u8, u8, u8, u8)>> {
+        let mut shrinks = Vec::new();
+        let channels = [value.0, value.1, value.2, value.3];
+
+        // Try all zeros
+        if channels != [0; 4] {
+            shrinks.push((0, 0, 0, 0));
+        }
+
+        // Try zeroing, then halving, each channel
+        for i in 0..4 {
+            if channels[i] > 0 {
+                for replacement in [0, channels[i] / 2] {
+                    let mut shrunk = channels;
+                    shrunk[i] = replacement;
+                    let shrunk = (shrunk[0], shrunk[1], shrunk[2], shrunk[3]);
+                    if !shrinks.contains(&shrunk) {
+                        shrinks.push(shrunk);
+                    }
+                }
+            }
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -423,6 +880,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hex_generator_fixed_with_prefix() {
+        let generator = HexGenerator::fixed(64).with_0x_prefix(true);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..10 {
+            let hex = generator.generate(&mut rng, &config);
+            assert_eq!(hex.len(), 66);
+            assert!(hex.starts_with("0x"));
+            assert!(hex[2..].chars().all(|c| c.is_ascii_hexdigit()));
+        }
+
+        let value = format!("0x{}", "f".repeat(64));
+        let shrinks: Vec<_> = generator.shrink(&value).collect();
+        assert!(!shrinks.is_empty());
+        assert!(shrinks.contains(&format!("0x{}", "0".repeat(64))));
+        for shrunk in shrinks {
+            assert_eq!(shrunk.len(), 66);
+            assert!(shrunk.starts_with("0x"));
+        }
+    }
+
     #[test]
     fn test_base64_generator() {
         let generator = Base64Generator::new(6, 32);
@@ -452,14 +932,40 @@ mod tests {
     #[test]
     fn test_base64_encode() {
         // Test known Base64 encodings
-        assert_eq!(Base64Generator::encode_base64(b"hello"), "aGVsbG8=");
+        let generator = Base64Generator::new(0, 16);
+        assert_eq!(generator.encode(b"hello"), "aGVsbG8=");
+        assert_eq!(generator.encode(b"hello world"), "aGVsbG8gd29ybGQ=");
+        assert_eq!(generator.encode(b"a"), "YQ==");
+        assert_eq!(generator.encode(b"ab"), "YWI=");
+        assert_eq!(generator.encode(b"abc"), "YWJj");
+    }
+
+    #[test]
+    fn test_base64_url_safe_no_padding() {
+        let generator = Base64Generator::url_safe(1, 32).no_padding();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let b64 = generator.generate(&mut rng, &config);
+            for c in b64.chars() {
+                assert!(
+                    c.is_ascii_alphanumeric() || c == '-' || c == '_',
+                    "Invalid URL-safe Base64 char: {}",
+                    c
+                );
+            }
+        }
+
+        assert_eq!(generator.encode(&[0xfb, 0xff]), "-_8");
         assert_eq!(
-            Base64Generator::encode_base64(b"hello world"),
-            "aGVsbG8gd29ybGQ="
+            Base64Generator::url_safe(0, 4).encode(&[0xfb, 0xff]),
+            "-_8="
         );
-        assert_eq!(Base64Generator::encode_base64(b"a"), "YQ==");
-        assert_eq!(Base64Generator::encode_base64(b"ab"), "YWI=");
-        assert_eq!(Base64Generator::encode_base64(b"abc"), "YWJj");
+
+        // Shrinks stay in the chosen variant
+        let shrinks: Vec<_> = generator.shrink(&"-_8".to_string()).collect();
+        assert!(shrinks.contains(&"AAA".to_string()));
     }
 
     #[test]
@@ -494,6 +1000,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_path_generator_extensions_and_hidden() {
+        let generator = PathGenerator::new(1, 3)
+            .with_extension(&["rs", "tar.gz"])
+            .with_hidden(1.0);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let path = generator.generate(&mut rng, &config);
+            let name = path.file_name().unwrap().to_str().unwrap();
+            assert!(name.ends_with(".rs") || name.ends_with(".tar.gz"));
+            for component in path.iter() {
+                assert!(component.to_str().unwrap().starts_with('.'));
+            }
+        }
+
+        // The extension goes first, then the hidden prefixes, then components
+        let shrinks: Vec<_> = generator
+            .shrink(&PathBuf::from(".src/.main.tar.gz"))
+            .collect();
+        assert_eq!(shrinks[0], PathBuf::from(".src/.main"));
+        assert_eq!(shrinks[1], PathBuf::from("src/main.tar.gz"));
+        assert!(
+            shrinks[2..]
+                .iter()
+                .all(|path| path.components().count() == 1)
+        );
+    }
+
     #[test]
     fn test_uuid_v4_generator() {
         let generator = UuidV4Generator::new();
@@ -543,4 +1079,118 @@ mod tests {
             assert!(uuids.insert(uuid), "Generated duplicate UUID");
         }
     }
+
+    #[test]
+    fn test_coordinate_generator_bounds() {
+        let generator = CoordinateGenerator::new();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..100 {
+            let (lat, lon) = generator.generate(&mut rng, &config);
+            assert!((-90.0..=90.0).contains(&lat));
+            assert!((-180.0..=180.0).contains(&lon));
+        }
+    }
+
+    #[test]
+    fn test_coordinate_generator_bbox() {
+        let generator = CoordinateGenerator::within_bbox(10.0, 20.0, 11.0, 21.0).unwrap();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..100 {
+            let (lat, lon) = generator.generate(&mut rng, &config);
+            assert!((10.0..=11.0).contains(&lat));
+            assert!((20.0..=21.0).contains(&lon));
+        }
+
+        // Origin is outside the box, so shrinking heads to the minimum corner
+        let shrinks: Vec<_> = generator.shrink(&(10.5, 20.5)).collect();
+        assert_eq!(shrinks[0], (10.0, 20.0));
+    }
+
+    #[test]
+    fn test_coordinate_generator_rejects_inverted_bbox() {
+        let error = CoordinateGenerator::within_bbox(11.0, 20.0, 10.0, 21.0).unwrap_err();
+        assert!(error.message().contains("min_lat"));
+        let error = CoordinateGenerator::within_bbox(10.0, 21.0, 11.0, 20.0).unwrap_err();
+        assert!(error.message().contains("min_lon"));
+        assert!(CoordinateGenerator::within_bbox(f64::NAN, 20.0, 11.0, 21.0).is_err());
+
+        // A single point is a valid box
+        let generator = CoordinateGenerator::within_bbox(10.0, 20.0, 10.0, 20.0).unwrap();
+        let mut rng = thread_rng();
+        assert_eq!(
+            generator.generate(&mut rng, &GeneratorConfig::default()),
+            (10.0, 20.0)
+        );
+    }
+
+    #[test]
+    fn test_coordinate_generator_precision_round_trip() {
+        let generator = CoordinateGenerator::new().with_precision(6);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..100 {
+            let (lat, lon) = generator.generate(&mut rng, &config);
+            let parsed: f64 = format!("{:.6}", lat).parse().unwrap();
+            assert_eq!(parsed, lat);
+            let parsed: f64 = format!("{:.6}", lon).parse().unwrap();
+            assert_eq!(parsed, lon);
+        }
+    }
+
+    #[test]
+    fn test_coordinate_generator_shrinks_toward_origin() {
+        let generator = CoordinateGenerator::new();
+        let shrinks: Vec<_> = generator.shrink(&(45.5, -120.25)).collect();
+
+        assert_eq!(shrinks[0], (0.0, 0.0));
+        assert!(shrinks.contains(&(0.0, -120.25)));
+        assert!(shrinks.contains(&(45.5, 0.0)));
+        assert_eq!(generator.shrink(&(0.0, 0.0)).count(), 0);
+    }
+
+    #[test]
+    fn test_color_generator_hex() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let generator = ColorGenerator::hex();
+        for _ in 0..50 {
+            let color = generator.generate(&mut rng, &config);
+            assert!(color.starts_with('#'));
+            assert!(color.len() == 7 || color.len() == 9);
+            assert!(color[1..].chars().all(|c| c.is_ascii_hexdigit()));
+        }
+
+        let generator = ColorGenerator::hex().with_alpha(false);
+        for _ in 0..50 {
+            assert_eq!(generator.generate(&mut rng, &config).len(), 7);
+        }
+    }
+
+    #[test]
+    fn test_color_generator_hex_shrinks_toward_black() {
+        let generator = ColorGenerator::hex();
+        let shrinks: Vec<String> = generator.shrink(&"#ff8000cc".to_string()).collect();
+
+        assert_eq!(shrinks[0], "#000000");
+        assert!(shrinks.contains(&"#ff8000".to_string()));
+        assert!(shrinks.contains(&"#008000cc".to_string()));
+        assert_eq!(generator.shrink(&"#000000".to_string()).count(), 0);
+    }
+
+    #[test]
+    fn test_color_generator_rgba_shrinks_toward_zero() {
+        let generator = ColorGenerator::rgba();
+        let shrinks: Vec<_> = generator.shrink(&(200, 0, 10, 255)).collect();
+
+        assert_eq!(shrinks[0], (0, 0, 0, 0));
+        assert!(shrinks.contains(&(100, 0, 10, 255)));
+        assert!(shrinks.contains(&(200, 0, 10, 0)));
+        assert_eq!(generator.shrink(&(0, 0, 0, 0)).count(), 0);
+    }
 }
@@ -1,7 +1,7 @@
 //! Network-related generators
 //!
 //! This module provides generators for:
-//! - IPv4 and IPv6 addresses
+//! - IPv4 and IPv6 addresses, as strings or as `std::net` types
 //! - Email addresses
 //! - URLs
 //!
@@ -11,11 +11,17 @@ use protest::{Generator, GeneratorConfig};
 use rand::Rng;
 use std::net::{Ipv4Addr, Ipv6Addr};
 
+// Typed `IpAddr`/`SocketAddr` generators live in `protest` so that `IpAddr` and
+// `SocketAddr` can implement `Arbitrary`; they are re-exported here.
+pub use protest::{IpAddrGenerator, SocketAddrGenerator};
+
 // ============================================================================
 // IP Address Generator
 // ============================================================================
 
-/// Generator for IP addresses (IPv4 or IPv6)
+/// Generator for IP address strings (IPv4 or IPv6)
+///
+/// Use [`IpAddrGenerator`] to get `std::net::IpAddr` values directly.
 #[derive(Debug, Clone)]
 pub enum IpAddressGenerator {
     /// Generate IPv4 addresses
@@ -130,29 +136,46 @@ impl EmailGenerator {
         }
     }
 
+    /// Mix in deliberately malformed addresses for negative testing
+    ///
+    /// Returns a [`LabeledEmailGenerator`] that produces `(address, is_valid)`
+    /// pairs, with roughly `ratio` of them malformed.
+    pub fn with_invalid_ratio(self, ratio: f64) -> LabeledEmailGenerator {
+        LabeledEmailGenerator::new(self, ratio)
+    }
+
     fn generate_local_part(&self, rng: &mut dyn rand::RngCore) -> String {
         let len = rng.r#gen_range(self.min_local_len..=self.max_local_len);
         let valid_chars = b"abcdefghijklmnopqrstuvwxyz0123456789._-";
-
-        (0..len)
-            .map(|i| {
-                if i == 0 {
-                    // First char must be alphanumeric
-                    let alphanumeric = b"abcdefghijklmnopqrstuvwxyz0123456789";
-                    alphanumeric[rng.r#gen_range(0..alphanumeric.len())] as char
-                } else {
-                    valid_chars[rng.r#gen_range(0..valid_chars.len())] as char
-                }
-            })
-            .collect()
+        let alphanumeric = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+        let mut local = String::with_capacity(len);
+        for i in 0..len {
+            // Dots may only separate atoms: never first, last, or doubled
+            let ch = if i == 0 || i + 1 == len || local.ends_with('.') {
+                alphanumeric[rng.r#gen_range(0..alphanumeric.len())] as char
+            } else {
+                valid_chars[rng.r#gen_range(0..valid_chars.len())] as char
+            };
+            local.push(ch);
+        }
+        local
     }
 
     fn generate_domain(&self, rng: &mut dyn rand::RngCore) -> String {
         let len = rng.r#gen_range(self.min_domain_len..=self.max_domain_len);
         let valid_chars = b"abcdefghijklmnopqrstuvwxyz0123456789-";
+        let alphanumeric = b"abcdefghijklmnopqrstuvwxyz0123456789";
 
+        // Hostname labels must not start or end with a hyphen
         let domain_name: String = (0..len)
-            .map(|_| valid_chars[rng.r#gen_range(0..valid_chars.len())] as char)
+            .map(|i| {
+                if i == 0 || i + 1 == len {
+                    alphanumeric[rng.r#gen_range(0..alphanumeric.len())] as char
+                } else {
+                    valid_chars[rng.r#gen_range(0..valid_chars.len())] as char
+                }
+            })
             .collect();
 
         // Add TLD
@@ -163,6 +186,41 @@ impl EmailGenerator {
     }
 }
 
+/// Check an address against the RFC 5321/5322 dot-atom grammar
+///
+/// The local part is one or more atoms of `atext` separated by single dots,
+/// at most 64 octets. The domain is two or more hostname labels of letters,
+/// digits and inner hyphens, each at most 63 octets, with a non-numeric
+/// top-level label and at most 253 octets overall. Quoted local parts and
+/// address literals are not generated, so they are treated as invalid.
+fn is_valid_email(address: &str) -> bool {
+    let Some((local, domain)) = address.split_once('@') else {
+        return false;
+    };
+
+    let is_atext = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c);
+    let local_ok = local.len() <= 64
+        && local
+            .split('.')
+            .all(|atom| !atom.is_empty() && atom.chars().all(is_atext));
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    let domain_ok = domain.len() <= 253
+        && labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        && labels
+            .last()
+            .is_some_and(|tld| !tld.chars().all(|c| c.is_ascii_digit()));
+
+    local_ok && domain_ok
+}
+
 impl Default for EmailGenerator {
     fn default() -> Self {
         Self::new()
@@ -203,10 +261,152 @@ impl Generator<String> for EmailGenerator {
             }
         }
 
+        // Truncation can leave a trailing dot or hyphen; drop those candidates
+        shrinks.retain(|candidate| is_valid_email(candidate));
         Box::new(shrinks.into_iter())
     }
 }
 
+// ============================================================================
+// Labeled Email Generator
+// ============================================================================
+
+/// Generator for a mix of valid and malformed email addresses
+///
+/// Produces `(address, is_valid)` pairs so a property can check that a
+/// validator accepts exactly the addresses labeled valid. Malformed addresses
+/// are missing the `@`, contain a double dot, end in a trailing `@`, or contain
+/// a space. Every label is checked against the RFC 5321/5322 dot-atom grammar,
+/// so an address is only tagged `true` if it really is valid.
+///
+/// Shrinking keeps the label: valid addresses shrink to valid addresses, and
+/// malformed ones shrink to addresses with the same kind of defect.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig};
+/// use protest_extras::generators::network::EmailGenerator;
+/// use rand::thread_rng;
+///
+/// let generator = EmailGenerator::new().with_invalid_ratio(0.3);
+/// let (email, is_valid) = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// if !is_valid {
+///     assert!(email.matches('@').count() != 1 || email.ends_with('@') || email.contains("..") || email.contains(' '));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LabeledEmailGenerator {
+    inner: EmailGenerator,
+    invalid_ratio: f64,
+}
+
+impl LabeledEmailGenerator {
+    /// Create a labeled generator from an email generator and an invalid ratio
+    ///
+    /// The ratio is clamped to [0.0, 1.0].
+    pub fn new(inner: EmailGenerator, invalid_ratio: f64) -> Self {
+        Self {
+            inner,
+            invalid_ratio: invalid_ratio.clamp(0.0, 1.0),
+        }
+    }
+
+    fn malform(email: &str, rng: &mut dyn rand::RngCore) -> String {
+        let (local, domain) = email.split_once('@').unwrap_or((email, ""));
+        match rng.r#gen_range(0..4) {
+            // Missing @
+            0 => format!("{}{}", local, domain),
+            // Double dot
+            1 => format!("{}@{}", local, domain.replacen('.', "..", 1)),
+            // Trailing @
+            2 => format!("{}@", local),
+            // Space inside the address
+            _ => {
+                let pos = rng.r#gen_range(0..=local.len());
+                format!("{} {}@{}", &local[..pos], &local[pos..], domain)
+            }
+        }
+    }
+}
+
+impl Generator<(String, bool)> for LabeledEmailGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> (String, bool) {
+        let mut email = self.inner.generate(rng, config);
+        if rng.r#gen_bool(self.invalid_ratio) {
+            email = Self::malform(&email, rng);
+        }
+        let is_valid = is_valid_email(&email);
+        (email, is_valid)
+    }
+
+    fn shrink(&self, value: &(String, bool)) -> Box<dyn Iterator<Item = (String, bool)>> {
+        let (email, is_valid) = value;
+
+        if *is_valid {
+            return Box::new(self.inner.shrink(email).map(|e| (e, true)));
+        }
+
+        // Shrink malformed addresses toward the minimal example of the same
+        // defect, then by deleting single characters that keep the defect
+        let defect = EmailDefect::of(email);
+        let mut shrinks: Vec<String> = Vec::new();
+        if let Some(minimal) = defect.minimal_example()
+            && minimal.len() < email.len()
+        {
+            shrinks.push(minimal.to_string());
+        }
+        for (index, _) in email.char_indices() {
+            let mut candidate = email.clone();
+            candidate.remove(index);
+            if !is_valid_email(&candidate)
+                && EmailDefect::of(&candidate) == defect
+                && !shrinks.contains(&candidate)
+            {
+                shrinks.push(candidate);
+            }
+        }
+
+        Box::new(shrinks.into_iter().map(|e| (e, false)))
+    }
+}
+
+/// The kind of defect that makes an address malformed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EmailDefect {
+    MissingAt,
+    TrailingAt,
+    Space,
+    DoubleDot,
+    Other,
+}
+
+impl EmailDefect {
+    fn of(email: &str) -> Self {
+        if !email.contains('@') {
+            Self::MissingAt
+        } else if email.ends_with('@') {
+            Self::TrailingAt
+        } else if email.contains(' ') {
+            Self::Space
+        } else if email.contains("..") {
+            Self::DoubleDot
+        } else {
+            Self::Other
+        }
+    }
+
+    fn minimal_example(self) -> Option<&'static str> {
+        match self {
+            Self::MissingAt => Some("a"),
+            Self::TrailingAt => Some("a@"),
+            Self::Space => Some("a b@b.com"),
+            Self::DoubleDot => Some("a@b..com"),
+            Self::Other => None,
+        }
+    }
+}
+
 // ============================================================================
 // URL Generator
 // ============================================================================
@@ -427,4 +627,127 @@ mod tests {
             assert!(url.starts_with("http://") || url.starts_with("https://"));
         }
     }
+
+    #[test]
+    fn test_labeled_email_generator() {
+        let generator = EmailGenerator::new().with_invalid_ratio(0.3);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let mut invalid = 0;
+        for _ in 0..1000 {
+            let (email, is_valid) = generator.generate(&mut rng, &config);
+            let malformed = email.matches('@').count() != 1
+                || email.ends_with('@')
+                || email.contains("..")
+                || email.contains(' ');
+            if is_valid {
+                assert!(is_valid_email(&email), "{}", email);
+            } else {
+                assert!(malformed, "expected malformed address: {}", email);
+                invalid += 1;
+            }
+        }
+
+        // Roughly 30% should be malformed
+        assert!((200..400).contains(&invalid), "invalid count: {}", invalid);
+    }
+
+    #[test]
+    fn test_labeled_email_ratio_bounds() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let generator = EmailGenerator::new().with_invalid_ratio(0.0);
+        assert!((0..50).all(|_| generator.generate(&mut rng, &config).1));
+
+        let generator = EmailGenerator::new().with_invalid_ratio(2.0);
+        assert!((0..50).all(|_| !generator.generate(&mut rng, &config).1));
+    }
+
+    #[test]
+    fn test_labeled_email_shrink_preserves_label() {
+        let generator = EmailGenerator::new().with_invalid_ratio(0.5);
+
+        let valid = ("hello@example.com".to_string(), true);
+        assert!(generator.shrink(&valid).all(|(_, is_valid)| is_valid));
+
+        let invalid = ("hello example.com".to_string(), false);
+        let shrinks: Vec<_> = generator.shrink(&invalid).collect();
+        assert!(!shrinks.is_empty());
+        assert!(shrinks.iter().all(|(_, is_valid)| !is_valid));
+    }
+
+    #[test]
+    fn test_is_valid_email() {
+        for valid in ["a@b.com", "first.last@mail.example.org", "x-y_z@a-b.io"] {
+            assert!(is_valid_email(valid), "{}", valid);
+        }
+        for invalid in [
+            "a..b@example.com",
+            ".a@example.com",
+            "a.@example.com",
+            "a@example..com",
+            "a@example.com.",
+            "a@-example.com",
+            "a@example-.com",
+            "a@example.-com",
+            "a@localhost",
+            "a@example.123",
+            "a b@example.com",
+            "a@b@example.com",
+            "@example.com",
+            "a@",
+            "a",
+        ] {
+            assert!(!is_valid_email(invalid), "{}", invalid);
+        }
+        assert!(!is_valid_email(&format!("{}@example.com", "a".repeat(65))));
+        assert!(!is_valid_email(&format!("a@{}.com", "b".repeat(64))));
+    }
+
+    #[test]
+    fn test_email_generator_is_rfc_valid() {
+        let generator = EmailGenerator::with_lengths(1, 30, 1, 30);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..1000 {
+            let email = generator.generate(&mut rng, &config);
+            assert!(is_valid_email(&email), "{}", email);
+            for shrunk in generator.shrink(&email) {
+                assert!(is_valid_email(&shrunk), "{} -> {}", email, shrunk);
+            }
+        }
+    }
+
+    #[test]
+    fn test_labeled_email_shrink_keeps_defect() {
+        let generator = EmailGenerator::new().with_invalid_ratio(0.5);
+
+        for malformed in [
+            "hello.world",
+            "hello@",
+            "hel lo@example.com",
+            "hello@example..com",
+            "hello.@example.com",
+            "hello@-example.com",
+        ] {
+            let defect = EmailDefect::of(malformed);
+            let shrinks: Vec<_> = generator.shrink(&(malformed.to_string(), false)).collect();
+            assert!(!shrinks.is_empty(), "{}", malformed);
+            for (shrunk, is_valid) in shrinks {
+                assert!(!is_valid);
+                assert!(!is_valid_email(&shrunk), "{} -> {}", malformed, shrunk);
+                assert_eq!(
+                    EmailDefect::of(&shrunk),
+                    defect,
+                    "{} -> {}",
+                    malformed,
+                    shrunk
+                );
+                assert!(shrunk.len() < malformed.len());
+            }
+        }
+    }
 }
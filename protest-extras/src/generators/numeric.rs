@@ -2,13 +2,16 @@
 //!
 //! This module provides generators for numbers with specific constraints:
 //! - Positive integers
-//! - Even numbers
+//! - Non-zero integers (`NonZeroU32`, `NonZeroI64`, ...)
+//! - Even and odd numbers, and multiples of a factor
 //! - Prime numbers
 //! - Percentage values (0.0-100.0)
+//! - Fixed-point decimals (requires the `rust_decimal` feature)
 //!
-//! All generators use std library only.
+//! All generators use std library only, except `DecimalGenerator`, which is
+//! only available with the `rust_decimal` feature.
 
-use protest::{Generator, GeneratorConfig};
+use protest::{Generator, GeneratorConfig, GeneratorError};
 use rand::Rng;
 
 // ============================================================================
@@ -76,91 +79,352 @@ where
 }
 
 // ============================================================================
-// Even Number Generator
+// Non-Zero Generator
 // ============================================================================
 
-/// Generator for even numbers
+/// Generator for `NonZero*` integers, e.g. `NonZeroU32` or `NonZeroI64`
+///
+/// Generic over the primitive type; `NonZeroGenerator<u32>` implements
+/// `Generator<NonZeroU32>`. Zero is skipped when the range contains it, and
+/// shrinking heads toward `1` (or the in-range value closest to it).
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig};
+/// use protest_extras::generators::numeric::NonZeroGenerator;
+/// use rand::thread_rng;
+/// use std::num::NonZeroU32;
+///
+/// let generator = NonZeroGenerator::<u32>::new(0, 100).unwrap();
+/// let n: NonZeroU32 = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert!(n.get() <= 100);
+/// ```
 #[derive(Debug, Clone)]
-pub struct EvenNumberGenerator<T> {
+pub struct NonZeroGenerator<T> {
     min: T,
     max: T,
 }
 
-impl<T> EvenNumberGenerator<T>
+impl<T> NonZeroGenerator<T>
 where
     T: num_traits::PrimInt,
 {
-    /// Create a new even number generator
+    /// Create a new non-zero generator between `min` and `max` (inclusive)
     ///
-    /// Note: min and max will be adjusted to the nearest even numbers if needed
-    pub fn new(min: T, max: T) -> Self {
-        let two = T::one() + T::one();
+    /// Fails if the range is empty or contains only zero.
+    pub fn new(min: T, max: T) -> Result<Self, GeneratorError> {
+        if min > max || (min == T::zero() && max == T::zero()) {
+            return Err(GeneratorError::new("range must contain a non-zero value"));
+        }
+        Ok(Self { min, max })
+    }
+}
 
-        // Adjust min to nearest even number >= min
-        let adjusted_min = if min % two == T::zero() {
-            min
-        } else {
-            min + T::one()
+/// Draw a non-zero value from `min..=max`, widened so every supported type fits
+fn generate_non_zero(rng: &mut dyn rand::RngCore, min: i128, max: i128) -> i128 {
+    if min <= 0 && max >= 0 {
+        // Draw from one fewer value and skip over zero
+        let value = rng.r#gen_range(min..=max - 1);
+        if value >= 0 { value + 1 } else { value }
+    } else {
+        rng.r#gen_range(min..=max)
+    }
+}
+
+/// Shrink candidates toward the in-range non-zero value closest to `1`
+fn shrink_non_zero(value: i128, min: i128, max: i128) -> Vec<i128> {
+    let target = if min > 0 {
+        min
+    } else if max < 0 {
+        max
+    } else if max >= 1 {
+        1
+    } else {
+        -1
+    };
+
+    let mut shrinks = Vec::new();
+    if value == target {
+        return shrinks;
+    }
+
+    // Try the target, halfway toward it, then one step toward it
+    shrinks.push(target);
+
+    let half = target + (value - target) / 2;
+    let toward = (target - value).signum();
+    let step = match value + toward {
+        0 => toward,
+        step => step,
+    };
+
+    for candidate in [half, step] {
+        if candidate != 0 && candidate != value && !shrinks.contains(&candidate) {
+            shrinks.push(candidate);
+        }
+    }
+
+    shrinks
+}
+
+macro_rules! non_zero_generators {
+    ($($prim:ty => $non_zero:ty),* $(,)?) => {
+        $(
+            impl Generator<$non_zero> for NonZeroGenerator<$prim> {
+                fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> $non_zero {
+                    let value = generate_non_zero(rng, self.min.into(), self.max.into());
+                    <$non_zero>::new(value as $prim).expect("generated value is non-zero")
+                }
+
+                fn shrink(&self, value: &$non_zero) -> Box<dyn Iterator<Item = $non_zero>> {
+                    let shrinks = shrink_non_zero(value.get().into(), self.min.into(), self.max.into());
+                    Box::new(
+                        shrinks
+                            .into_iter()
+                            .filter_map(|shrunk| <$non_zero>::new(shrunk as $prim)),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+non_zero_generators!(
+    u8 => std::num::NonZeroU8,
+    u16 => std::num::NonZeroU16,
+    u32 => std::num::NonZeroU32,
+    u64 => std::num::NonZeroU64,
+    i8 => std::num::NonZeroI8,
+    i16 => std::num::NonZeroI16,
+    i32 => std::num::NonZeroI32,
+    i64 => std::num::NonZeroI64,
+);
+
+// ============================================================================
+// Multiple-Of Generator
+// ============================================================================
+
+/// Generator for multiples of a factor, e.g. aligned sizes or offsets
+///
+/// A uniform draw from the range is rounded to the nearest multiple of `factor`
+/// that lies within the range. Shrinking steps toward the in-range multiple
+/// closest to zero (zero itself when the range contains it).
+#[derive(Debug, Clone)]
+pub struct MultipleOfGenerator<T> {
+    factor: T,
+    remainder: T,
+    min: T,
+    max: T,
+}
+
+impl<T> MultipleOfGenerator<T>
+where
+    T: num_traits::PrimInt,
+{
+    /// Create a generator for multiples of `factor` between `min` and `max`
+    ///
+    /// Fails if `factor` is not positive or no multiple lies in the range.
+    pub fn new(factor: T, min: T, max: T) -> Result<Self, GeneratorError> {
+        Self::with_remainder(factor, T::zero(), min, max)
+    }
+
+    /// Values congruent to `remainder` modulo `factor`, with bounds adjusted
+    /// inward to the nearest such values
+    fn with_remainder(factor: T, remainder: T, min: T, max: T) -> Result<Self, GeneratorError> {
+        if factor <= T::zero() {
+            return Err(GeneratorError::new("factor must be positive"));
+        }
+
+        let mut generator = Self {
+            factor,
+            remainder,
+            min,
+            max,
         };
 
-        // Adjust max to nearest even number <= max
-        let adjusted_max = if max % two == T::zero() {
-            max
+        let up = match generator.offset(min) {
+            offset if offset == T::zero() => offset,
+            offset => factor - offset,
+        };
+        let adjusted = min
+            .checked_add(&up)
+            .zip(max.checked_sub(&generator.offset(max)));
+
+        match adjusted {
+            Some((adjusted_min, adjusted_max)) if adjusted_min <= adjusted_max => {
+                generator.min = adjusted_min;
+                generator.max = adjusted_max;
+                Ok(generator)
+            }
+            _ => Err(GeneratorError::new("no value in range matches the factor")),
+        }
+    }
+
+    /// Distance from `value` down to the nearest matching value, in `0..factor`
+    fn offset(&self, value: T) -> T {
+        let rem = value % self.factor;
+        let rem = if rem < T::zero() {
+            rem + self.factor
         } else {
-            max - T::one()
+            rem
         };
 
-        Self {
-            min: adjusted_min,
-            max: adjusted_max,
+        if rem >= self.remainder {
+            rem - self.remainder
+        } else {
+            rem + (self.factor - self.remainder)
+        }
+    }
+
+    /// The matching value in range closest to zero, preferring positive on ties
+    fn target(&self) -> T {
+        if self.min >= T::zero() {
+            self.min
+        } else if self.max <= T::zero() {
+            self.max
+        } else if self.remainder <= self.factor - self.remainder {
+            self.remainder
+        } else {
+            self.remainder - self.factor
         }
     }
 }
 
-impl<T> Generator<T> for EvenNumberGenerator<T>
+impl<T> Generator<T> for MultipleOfGenerator<T>
 where
     T: num_traits::PrimInt + rand::distributions::uniform::SampleUniform + Clone + 'static,
 {
     fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> T {
-        let two = T::one() + T::one();
-        let range = (self.max - self.min) / two + T::one();
-        let offset = rng.r#gen_range(T::zero()..range);
-        self.min + (offset * two)
+        let value = rng.r#gen_range(self.min..=self.max);
+        let offset = self.offset(value);
+        let down = value - offset;
+
+        // Round to the nearest match, staying in range
+        match down.checked_add(&self.factor) {
+            Some(up) if offset >= self.factor - offset && up <= self.max => up,
+            _ => down,
+        }
     }
 
     fn shrink(&self, value: &T) -> Box<dyn Iterator<Item = T>> {
         let mut shrinks = Vec::new();
+        let value = *value;
+        let target = self.target();
         let two = T::one() + T::one();
 
-        // Try zero if in range
-        if T::zero() >= self.min && T::zero() <= self.max && *value != T::zero() {
-            shrinks.push(T::zero());
+        if value == target {
+            return Box::new(shrinks.into_iter());
         }
 
-        // Try min
-        if *value > self.min {
-            shrinks.push(self.min);
-        }
+        // Try the match closest to zero
+        shrinks.push(target);
 
-        // Try half (rounded to even)
-        if *value > two {
-            let half = *value / two;
-            let half_even = if half % two == T::zero() {
-                half
+        // Try halfway toward it, then one step toward it
+        let mid = target / two + value / two;
+        let (half, step) = if value > target {
+            (mid - self.offset(mid), value - self.factor)
+        } else {
+            let up = self.offset(mid);
+            let up = if up == T::zero() {
+                up
             } else {
-                half - T::one()
+                self.factor - up
             };
-            if half_even >= self.min {
-                shrinks.push(half_even);
+            (mid + up, value + self.factor)
+        };
+
+        for candidate in [half, step] {
+            if candidate != target && candidate != value && !shrinks.contains(&candidate) {
+                shrinks.push(candidate);
             }
         }
 
-        // Try value - 2
-        if *value > self.min + two {
-            shrinks.push(*value - two);
+        Box::new(shrinks.into_iter())
+    }
+}
+
+// ============================================================================
+// Even Number Generator
+// ============================================================================
+
+/// Generator for even numbers
+#[derive(Debug, Clone)]
+pub struct EvenNumberGenerator<T> {
+    inner: MultipleOfGenerator<T>,
+}
+
+impl<T> EvenNumberGenerator<T>
+where
+    T: num_traits::PrimInt,
+{
+    /// Create a new even number generator
+    ///
+    /// Note: min and max will be adjusted to the nearest even numbers if needed
+    ///
+    /// # Panics
+    ///
+    /// Panics if no even number lies in the range.
+    pub fn new(min: T, max: T) -> Self {
+        Self {
+            inner: MultipleOfGenerator::new(T::one() + T::one(), min, max)
+                .expect("range must contain an even number"),
+        }
+    }
+}
+
+impl<T> Generator<T> for EvenNumberGenerator<T>
+where
+    T: num_traits::PrimInt + rand::distributions::uniform::SampleUniform + Clone + 'static,
+{
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> T {
+        self.inner.generate(rng, config)
+    }
+
+    fn shrink(&self, value: &T) -> Box<dyn Iterator<Item = T>> {
+        self.inner.shrink(value)
+    }
+}
+
+// ============================================================================
+// Odd Number Generator
+// ============================================================================
+
+/// Generator for odd numbers
+#[derive(Debug, Clone)]
+pub struct OddNumberGenerator<T> {
+    inner: MultipleOfGenerator<T>,
+}
+
+impl<T> OddNumberGenerator<T>
+where
+    T: num_traits::PrimInt,
+{
+    /// Create a new odd number generator
+    ///
+    /// Note: min and max will be adjusted to the nearest odd numbers if needed
+    ///
+    /// # Panics
+    ///
+    /// Panics if no odd number lies in the range.
+    pub fn new(min: T, max: T) -> Self {
+        Self {
+            inner: MultipleOfGenerator::with_remainder(T::one() + T::one(), T::one(), min, max)
+                .expect("range must contain an odd number"),
         }
+    }
+}
 
-        Box::new(shrinks.into_iter())
+impl<T> Generator<T> for OddNumberGenerator<T>
+where
+    T: num_traits::PrimInt + rand::distributions::uniform::SampleUniform + Clone + 'static,
+{
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> T {
+        self.inner.generate(rng, config)
+    }
+
+    fn shrink(&self, value: &T) -> Box<dyn Iterator<Item = T>> {
+        self.inner.shrink(value)
     }
 }
 
@@ -169,18 +433,32 @@ where
 // ============================================================================
 
 /// Generator for prime numbers
+///
+/// For `max` up to [`PrimeNumberGenerator::SIEVE_LIMIT`], all primes in range
+/// are precomputed once at construction with a sieve of Eratosthenes, which costs
+/// O(max log log max) time and roughly `max` bytes of temporary memory. After that,
+/// each `generate` call is O(1). Above the limit no sieve is built; generation
+/// instead scans from a random starting point, testing each candidate with a
+/// deterministic Miller–Rabin test, which is slower per call but avoids huge
+/// allocations and stays fast across the whole `u64` range.
 #[derive(Debug, Clone)]
 pub struct PrimeNumberGenerator {
     min: u64,
-    #[allow(dead_code)]
     max: u64,
     primes_cache: Vec<u64>,
 }
 
 impl PrimeNumberGenerator {
+    /// Largest `max` for which a sieve is precomputed
+    pub const SIEVE_LIMIT: u64 = 1 << 24;
+
     /// Create a new prime number generator
     pub fn new(min: u64, max: u64) -> Self {
-        let primes = Self::generate_primes_in_range(min, max);
+        let primes = if max <= Self::SIEVE_LIMIT {
+            Self::sieve(min, max)
+        } else {
+            Vec::new()
+        };
         Self {
             min,
             max,
@@ -188,52 +466,129 @@ impl PrimeNumberGenerator {
         }
     }
 
+    /// Deterministic Miller–Rabin primality test
+    ///
+    /// The first twelve primes as witnesses are enough for every `u64`.
     fn is_prime(n: u64) -> bool {
+        const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
         if n < 2 {
             return false;
         }
-        if n == 2 {
-            return true;
+        for &p in &WITNESSES {
+            if n.is_multiple_of(p) {
+                return n == p;
+            }
         }
-        if n.is_multiple_of(2) {
-            return false;
+
+        let mul_mod = |a: u64, b: u64| (a as u128 * b as u128 % n as u128) as u64;
+        let pow_mod = |mut base: u64, mut exp: u64| {
+            let mut result = 1;
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    result = mul_mod(result, base);
+                }
+                base = mul_mod(base, base);
+                exp >>= 1;
+            }
+            result
+        };
+
+        // n - 1 = d * 2^s with d odd
+        let s = (n - 1).trailing_zeros();
+        let d = (n - 1) >> s;
+
+        WITNESSES.iter().all(|&a| {
+            let mut x = pow_mod(a, d);
+            if x == 1 || x == n - 1 {
+                return true;
+            }
+            for _ in 1..s {
+                x = mul_mod(x, x);
+                if x == n - 1 {
+                    return true;
+                }
+            }
+            false
+        })
+    }
+
+    /// Sieve of Eratosthenes, returning the primes in `min..=max`
+    fn sieve(min: u64, max: u64) -> Vec<u64> {
+        if max < 2 {
+            return Vec::new();
         }
 
-        let sqrt = (n as f64).sqrt() as u64;
-        for i in (3..=sqrt).step_by(2) {
-            if n.is_multiple_of(i) {
-                return false;
+        let max = max as usize;
+        let mut composite = vec![false; max + 1];
+        let mut i = 2;
+        while i * i <= max {
+            if !composite[i] {
+                for multiple in (i * i..=max).step_by(i) {
+                    composite[multiple] = true;
+                }
             }
+            i += 1;
         }
-        true
+
+        (min.max(2) as usize..=max)
+            .filter(|&n| !composite[n])
+            .map(|n| n as u64)
+            .collect()
     }
 
-    fn generate_primes_in_range(min: u64, max: u64) -> Vec<u64> {
-        let start = if min <= 2 { 2 } else { min };
-        (start..=max).filter(|&n| Self::is_prime(n)).collect()
+    /// Find a prime in `from..=to`, scanning upward
+    fn next_prime(from: u64, to: u64) -> Option<u64> {
+        (from..=to).find(|&n| Self::is_prime(n))
+    }
+
+    /// Find a prime in `to..=from`, scanning downward
+    fn prev_prime(from: u64, to: u64) -> Option<u64> {
+        (to..=from).rev().find(|&n| Self::is_prime(n))
     }
 }
 
 impl Generator<u64> for PrimeNumberGenerator {
     fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> u64 {
-        if self.primes_cache.is_empty() {
-            // No primes in range, return min
-            self.min
-        } else {
+        if !self.primes_cache.is_empty() {
             let idx = rng.r#gen_range(0..self.primes_cache.len());
-            self.primes_cache[idx]
+            return self.primes_cache[idx];
         }
+
+        if self.max > Self::SIEVE_LIMIT && self.min <= self.max {
+            let start = rng.r#gen_range(self.min..=self.max);
+            if let Some(prime) =
+                Self::next_prime(start, self.max).or_else(|| Self::prev_prime(start, self.min))
+            {
+                return prime;
+            }
+        }
+
+        // No primes in range, return min
+        self.min
     }
 
     fn shrink(&self, value: &u64) -> Box<dyn Iterator<Item = u64>> {
         let mut shrinks = Vec::new();
 
-        // Try smaller primes
-        for &prime in &self.primes_cache {
-            if prime < *value {
+        if self.primes_cache.is_empty() {
+            // No sieve: try the smallest prime in range and one near half the value
+            if let Some(prime) = Self::next_prime(self.min, value.saturating_sub(1)) {
+                shrinks.push(prime);
+            }
+            if let Some(prime) = Self::prev_prime(*value / 2, self.min)
+                && !shrinks.contains(&prime)
+            {
                 shrinks.push(prime);
-                if shrinks.len() >= 5 {
-                    break;
+            }
+        } else {
+            // Try smaller primes from the sieve
+            for &prime in &self.primes_cache {
+                if prime < *value {
+                    shrinks.push(prime);
+                    if shrinks.len() >= 5 {
+                        break;
+                    }
                 }
             }
         }
@@ -251,6 +606,7 @@ impl Generator<u64> for PrimeNumberGenerator {
 pub struct PercentageGenerator {
     allow_zero: bool,
     allow_hundred: bool,
+    decimals: Option<u32>,
 }
 
 impl PercentageGenerator {
@@ -259,6 +615,7 @@ impl PercentageGenerator {
         Self {
             allow_zero: true,
             allow_hundred: true,
+            decimals: None,
         }
     }
 
@@ -267,6 +624,7 @@ impl PercentageGenerator {
         Self {
             allow_zero: false,
             allow_hundred: true,
+            decimals: None,
         }
     }
 
@@ -275,6 +633,47 @@ impl PercentageGenerator {
         Self {
             allow_zero,
             allow_hundred,
+            decimals: None,
+        }
+    }
+
+    /// Create a generator for whole percentages (0 to 100 inclusive)
+    pub fn integer() -> IntegerPercentageGenerator {
+        IntegerPercentageGenerator
+    }
+
+    /// Round generated values to the given number of decimal places
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protest::{Generator, GeneratorConfig};
+    /// use protest_extras::generators::numeric::PercentageGenerator;
+    /// use rand::thread_rng;
+    ///
+    /// let generator = PercentageGenerator::new().with_decimals(2);
+    /// let p = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+    /// assert_eq!((p * 100.0).round() / 100.0, p);
+    /// ```
+    pub fn with_decimals(mut self, decimals: u32) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        let min = if self.allow_zero { 0.0 } else { 0.01 };
+        let max = if self.allow_hundred { 100.0 } else { 99.99 };
+        (min, max)
+    }
+
+    fn round(&self, value: f64) -> f64 {
+        match self.decimals {
+            Some(decimals) => {
+                let factor = 10f64.powi(decimals as i32);
+                let (min, max) = self.bounds();
+                ((value * factor).round() / factor).clamp(min, max)
+            }
+            None => value,
         }
     }
 }
@@ -287,15 +686,22 @@ impl Default for PercentageGenerator {
 
 impl Generator<f64> for PercentageGenerator {
     fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> f64 {
-        let min = if self.allow_zero { 0.0 } else { 0.01 };
-        let max = if self.allow_hundred { 100.0 } else { 99.99 };
-
-        rng.r#gen_range(min..=max)
+        let (min, max) = self.bounds();
+        self.round(rng.r#gen_range(min..=max))
     }
 
     fn shrink(&self, value: &f64) -> Box<dyn Iterator<Item = f64>> {
         let mut shrinks = Vec::new();
 
+        // With fixed precision, try the nearest whole number first
+        if self.decimals.is_some() {
+            let (min, max) = self.bounds();
+            let whole = value.round();
+            if whole != *value && (min..=max).contains(&whole) {
+                shrinks.push(whole);
+            }
+        }
+
         // Try common values
         if self.allow_zero && *value > 0.0 {
             shrinks.push(0.0);
@@ -315,7 +721,178 @@ impl Generator<f64> for PercentageGenerator {
 
         // Try half
         if *value > 1.0 {
-            shrinks.push(*value / 2.0);
+            shrinks.push(self.round(*value / 2.0));
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
+/// Generator for whole percentages (0 to 100 inclusive)
+///
+/// Created through [`PercentageGenerator::integer`]. Shrinks toward 0.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IntegerPercentageGenerator;
+
+impl Generator<u8> for IntegerPercentageGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> u8 {
+        rng.r#gen_range(0..=100)
+    }
+
+    fn shrink(&self, value: &u8) -> Box<dyn Iterator<Item = u8>> {
+        let value = (*value).min(100);
+        let mut shrinks = Vec::new();
+
+        if value > 0 {
+            shrinks.push(0);
+        }
+
+        if value > 2 {
+            shrinks.push(value / 2);
+        }
+
+        if value > 1 {
+            shrinks.push(value - 1);
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
+// ============================================================================
+// Decimal Generator
+// ============================================================================
+
+/// Generator for fixed-point `rust_decimal::Decimal` values
+///
+/// Every value has exactly the configured scale and lies within `[min, max]`,
+/// so `1.50` stays `1.50` rather than collapsing to `1.5`. Shrinks toward zero
+/// (or the range bound nearest zero) without changing the scale.
+///
+/// Requires the `rust_decimal` feature.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig};
+/// use protest_extras::generators::numeric::DecimalGenerator;
+/// use rand::thread_rng;
+///
+/// let generator = DecimalGenerator::money(2);
+/// let amount = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert_eq!(amount.scale(), 2);
+/// ```
+#[cfg(feature = "rust_decimal")]
+#[derive(Debug, Clone, Copy)]
+pub struct DecimalGenerator {
+    min: rust_decimal::Decimal,
+    max: rust_decimal::Decimal,
+    scale: u32,
+}
+
+#[cfg(feature = "rust_decimal")]
+impl DecimalGenerator {
+    /// Create a generator for values in `[min, max]` with `scale` decimal places
+    ///
+    /// The scale is clamped to `Decimal::MAX_SCALE`.
+    pub fn new(min: rust_decimal::Decimal, max: rust_decimal::Decimal, scale: u32) -> Self {
+        Self {
+            min: min.min(max),
+            max: max.max(min),
+            scale: scale.min(rust_decimal::Decimal::MAX_SCALE),
+        }
+    }
+
+    /// Create a generator for currency amounts between -1,000,000 and 1,000,000
+    ///
+    /// `currency_decimals` is the number of minor-unit digits (0 for JPY, 2 for
+    /// USD) and is clamped to 2.
+    pub fn money(currency_decimals: u32) -> Self {
+        let limit = rust_decimal::Decimal::from(1_000_000);
+        Self::new(-limit, limit, currency_decimals.min(2))
+    }
+
+    /// Set the range of generated values
+    pub fn with_range(self, min: rust_decimal::Decimal, max: rust_decimal::Decimal) -> Self {
+        Self::new(min, max, self.scale)
+    }
+
+    /// Set the number of decimal places
+    pub fn with_scale(self, scale: u32) -> Self {
+        Self::new(self.min, self.max, scale)
+    }
+
+    /// Convert a bound to a count of minor units at this generator's scale
+    fn units(&self, value: rust_decimal::Decimal, round_up: bool) -> i128 {
+        use rust_decimal::RoundingStrategy;
+
+        let strategy = if round_up {
+            RoundingStrategy::ToPositiveInfinity
+        } else {
+            RoundingStrategy::ToNegativeInfinity
+        };
+        let mut rounded = value.round_dp_with_strategy(self.scale, strategy);
+        rounded.rescale(self.scale);
+        rounded.mantissa()
+    }
+
+    fn decimal(&self, units: i128) -> rust_decimal::Decimal {
+        rust_decimal::Decimal::from_i128_with_scale(units, self.scale)
+    }
+
+    fn unit_bounds(&self) -> (i128, i128) {
+        let lo = self.units(self.min, true);
+        let hi = self.units(self.max, false);
+        (lo, hi.max(lo))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Default for DecimalGenerator {
+    fn default() -> Self {
+        Self::money(2)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Generator<rust_decimal::Decimal> for DecimalGenerator {
+    fn generate(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        _config: &GeneratorConfig,
+    ) -> rust_decimal::Decimal {
+        let (lo, hi) = self.unit_bounds();
+        self.decimal(rng.r#gen_range(lo..=hi))
+    }
+
+    fn shrink(
+        &self,
+        value: &rust_decimal::Decimal,
+    ) -> Box<dyn Iterator<Item = rust_decimal::Decimal>> {
+        let (lo, hi) = self.unit_bounds();
+        let units = self.units(*value, value.is_sign_negative()).clamp(lo, hi);
+        let target = 0i128.clamp(lo, hi);
+        let one = 10i128.pow(self.scale);
+
+        let mut candidates = vec![target];
+
+        // Drop the fractional part, keeping the scale
+        candidates.push(units / one * one);
+
+        // Halve the distance to the target, then step one minor unit toward it
+        candidates.push(target + (units - target) / 2);
+        candidates.push(units - (units - target).signum());
+
+        let mut shrinks: Vec<rust_decimal::Decimal> = Vec::new();
+        for candidate in candidates {
+            let shrunk = self.decimal(candidate);
+            if candidate != units
+                && (lo..=hi).contains(&candidate)
+                && (candidate - target).abs() < (units - target).abs()
+                && !shrinks.contains(&shrunk)
+            {
+                shrinks.push(shrunk);
+            }
         }
 
         Box::new(shrinks.into_iter())
@@ -367,6 +944,114 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_odd_number_generator() {
+        let generator = OddNumberGenerator::new(-10i32, 10i32);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let n = generator.generate(&mut rng, &config);
+            assert!((-9..=9).contains(&n));
+            assert_eq!(n.rem_euclid(2), 1, "Generated number {} should be odd", n);
+        }
+
+        let shrinks: Vec<_> = generator.shrink(&9).collect();
+        assert_eq!(shrinks, vec![1, 3, 7]);
+        let shrinks: Vec<_> = generator.shrink(&-9).collect();
+        assert_eq!(shrinks, vec![1, -3, -7]);
+    }
+
+    #[test]
+    fn test_non_zero_generator() {
+        use std::num::{NonZeroI8, NonZeroU32, NonZeroU64};
+
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let generator = NonZeroGenerator::<u32>::new(0, 3).unwrap();
+        for _ in 0..50 {
+            let n: NonZeroU32 = generator.generate(&mut rng, &config);
+            assert!((1..=3).contains(&n.get()));
+        }
+
+        let generator = NonZeroGenerator::<i8>::new(-1, 0).unwrap();
+        for _ in 0..10 {
+            let n: NonZeroI8 = generator.generate(&mut rng, &config);
+            assert_eq!(n.get(), -1);
+        }
+
+        // Full ranges do not overflow
+        let generator = NonZeroGenerator::<u64>::new(0, u64::MAX).unwrap();
+        let _: NonZeroU64 = generator.generate(&mut rng, &config);
+        let generator = NonZeroGenerator::<i8>::new(i8::MIN, i8::MAX).unwrap();
+        let _: NonZeroI8 = generator.generate(&mut rng, &config);
+
+        // Shrinks toward 1, stepping over zero
+        let shrink = |value: i8| -> Vec<i8> {
+            generator
+                .shrink(&NonZeroI8::new(value).unwrap())
+                .map(NonZeroI8::get)
+                .collect()
+        };
+        assert_eq!(shrink(100), vec![1, 50, 99]);
+        assert_eq!(shrink(-1), vec![1]);
+        assert_eq!(shrink(-2), vec![1, -1]);
+        assert!(shrink(1).is_empty());
+    }
+
+    #[test]
+    fn test_non_zero_generator_rejects_unusable_ranges() {
+        let error = NonZeroGenerator::<u32>::new(0, 0).unwrap_err();
+        assert!(error.message().contains("non-zero value"));
+        assert!(NonZeroGenerator::<i8>::new(5, -5).is_err());
+        assert!(matches!(
+            protest::PropertyError::from(error),
+            protest::PropertyError::GeneratorError { .. }
+        ));
+    }
+
+    #[test]
+    fn test_multiple_of_generator() {
+        let generator = MultipleOfGenerator::new(8u32, 3, 100).unwrap();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..50 {
+            let n = generator.generate(&mut rng, &config);
+            assert!((8..=96).contains(&n));
+            assert_eq!(n % 8, 0, "Generated number {} should be a multiple of 8", n);
+        }
+
+        // Shrinks toward the smallest in-range multiple
+        let shrinks: Vec<_> = generator.shrink(&96).collect();
+        assert_eq!(shrinks, vec![8, 48, 88]);
+        assert_eq!(generator.shrink(&8).count(), 0);
+
+        let generator = MultipleOfGenerator::new(8i64, -100, 100).unwrap();
+        assert_eq!(
+            generator.shrink(&-64).collect::<Vec<_>>(),
+            vec![0, -32, -56]
+        );
+
+        // Extreme bounds do not overflow
+        let generator = MultipleOfGenerator::new(100u8, 0, u8::MAX).unwrap();
+        for _ in 0..50 {
+            assert!([0, 100, 200].contains(&generator.generate(&mut rng, &config)));
+        }
+    }
+
+    #[test]
+    fn test_multiple_of_generator_rejects_unusable_ranges() {
+        let error = MultipleOfGenerator::new(8i32, 1, 7).unwrap_err();
+        assert!(error.message().contains("no value in range"));
+
+        let error = MultipleOfGenerator::new(0i32, 0, 100).unwrap_err();
+        assert!(error.message().contains("factor must be positive"));
+        assert!(MultipleOfGenerator::new(-8i32, 0, 100).is_err());
+        assert!(MultipleOfGenerator::with_remainder(2u8, 1, 4, 4).is_err());
+    }
+
     #[test]
     fn test_prime_number_generator() {
         let generator = PrimeNumberGenerator::new(2, 50);
@@ -399,6 +1084,90 @@ mod tests {
         assert!(!PrimeNumberGenerator::is_prime(100));
     }
 
+    #[test]
+    fn test_prime_sieve_matches_primality_test() {
+        let sieved = PrimeNumberGenerator::sieve(0, 100_000);
+        let tested: Vec<u64> = (0..=100_000)
+            .filter(|&n| PrimeNumberGenerator::is_prime(n))
+            .collect();
+        assert_eq!(sieved, tested);
+
+        assert_eq!(PrimeNumberGenerator::sieve(14, 16), Vec::<u64>::new());
+        assert_eq!(PrimeNumberGenerator::sieve(0, 1), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn test_is_prime_large_values() {
+        // Largest u64 prime and Mersenne primes
+        assert!(PrimeNumberGenerator::is_prime(18_446_744_073_709_551_557));
+        assert!(PrimeNumberGenerator::is_prime((1 << 61) - 1));
+        assert!(PrimeNumberGenerator::is_prime((1 << 31) - 1));
+
+        // Carmichael numbers, strong pseudoprimes to small bases, and
+        // squares of large primes
+        for composite in [
+            561,
+            1_105,
+            3_215_031_751,
+            3_825_123_056_546_413_051,
+            4_294_967_291 * 4_294_967_291,
+            u64::MAX,
+        ] {
+            assert!(!PrimeNumberGenerator::is_prime(composite), "{}", composite);
+        }
+    }
+
+    #[test]
+    fn test_prime_number_generator_near_u64_max() {
+        let generator = PrimeNumberGenerator::new(u64::MAX - 10_000, u64::MAX);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let n = generator.generate(&mut rng, &config);
+            assert!(n >= u64::MAX - 10_000);
+            assert!(PrimeNumberGenerator::is_prime(n));
+        }
+        assert!(generator.shrink(&18_446_744_073_709_551_557).count() > 0);
+    }
+
+    #[test]
+    fn test_prime_number_generator_large_range() {
+        let generator = PrimeNumberGenerator::new(1_000_000, 3_000_000);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..100 {
+            let n = generator.generate(&mut rng, &config);
+            assert!((1_000_000..=3_000_000).contains(&n));
+            assert!(PrimeNumberGenerator::is_prime(n));
+        }
+
+        let shrinks: Vec<u64> = generator.shrink(&2_000_003).collect();
+        assert_eq!(shrinks.first(), Some(&1_000_003));
+        assert!(shrinks.iter().all(|p| *p < 2_000_003));
+    }
+
+    #[test]
+    fn test_prime_number_generator_above_sieve_limit() {
+        let min = PrimeNumberGenerator::SIEVE_LIMIT * 4;
+        let generator = PrimeNumberGenerator::new(min, min + 10_000);
+        assert!(generator.primes_cache.is_empty());
+
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+        for _ in 0..20 {
+            let n = generator.generate(&mut rng, &config);
+            assert!((min..=min + 10_000).contains(&n));
+            assert!(PrimeNumberGenerator::is_prime(n));
+        }
+
+        let value = PrimeNumberGenerator::prev_prime(min + 10_000, min).unwrap();
+        let shrinks: Vec<u64> = generator.shrink(&value).collect();
+        assert!(!shrinks.is_empty());
+        assert!(shrinks.iter().all(|p| *p < value && *p >= min));
+    }
+
     #[test]
     fn test_percentage_generator() {
         let generator = PercentageGenerator::new();
@@ -422,4 +1191,90 @@ mod tests {
             assert!(p > 0.0 && p <= 100.0);
         }
     }
+
+    #[test]
+    fn test_integer_percentage_generator() {
+        let generator = PercentageGenerator::integer();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..200 {
+            assert!(generator.generate(&mut rng, &config) <= 100);
+        }
+
+        let shrinks: Vec<u8> = generator.shrink(&80).collect();
+        assert_eq!(shrinks, vec![0, 40, 79]);
+        assert_eq!(generator.shrink(&0).count(), 0);
+    }
+
+    #[test]
+    fn test_percentage_with_decimals() {
+        let generator = PercentageGenerator::new().with_decimals(2);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..100 {
+            let p = generator.generate(&mut rng, &config);
+            assert!((0.0..=100.0).contains(&p));
+            assert_eq!(format!("{:.2}", p).parse::<f64>().unwrap(), p);
+        }
+
+        // Shrinks to the nearest whole number before zero
+        let shrinks: Vec<f64> = generator.shrink(&42.57).collect();
+        assert_eq!(shrinks[0], 43.0);
+        assert_eq!(shrinks[1], 0.0);
+        assert!(shrinks.contains(&21.29));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal_generator_range_and_scale() {
+        use rust_decimal::Decimal;
+
+        let generator = DecimalGenerator::new(Decimal::new(-5, 1), Decimal::new(125, 1), 3);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..100 {
+            let d = generator.generate(&mut rng, &config);
+            assert_eq!(d.scale(), 3);
+            assert!(d >= Decimal::new(-5, 1) && d <= Decimal::new(125, 1));
+        }
+
+        let money = DecimalGenerator::money(4);
+        assert_eq!(money.generate(&mut rng, &config).scale(), 2);
+        let yen = DecimalGenerator::money(0);
+        assert_eq!(yen.generate(&mut rng, &config).scale(), 0);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal_generator_shrinks_toward_zero_preserving_scale() {
+        use rust_decimal::Decimal;
+
+        let generator = DecimalGenerator::money(2);
+        let shrinks: Vec<Decimal> = generator.shrink(&Decimal::new(1234, 2)).collect();
+        assert_eq!(
+            shrinks,
+            vec![
+                Decimal::new(0, 2),
+                Decimal::new(1200, 2),
+                Decimal::new(617, 2),
+                Decimal::new(1233, 2)
+            ]
+        );
+        assert!(shrinks.iter().all(|d| d.scale() == 2));
+
+        let negative: Vec<Decimal> = generator.shrink(&Decimal::new(-101, 2)).collect();
+        assert_eq!(negative[0], Decimal::new(0, 2));
+        assert!(negative.contains(&Decimal::new(-100, 2)));
+        assert!(negative.contains(&Decimal::new(-50, 2)));
+        assert_eq!(generator.shrink(&Decimal::new(0, 2)).count(), 0);
+
+        // Shrinks toward the bound nearest zero when zero is out of range
+        let positive = DecimalGenerator::new(Decimal::new(5, 0), Decimal::new(10, 0), 1);
+        let shrinks: Vec<Decimal> = positive.shrink(&Decimal::new(80, 1)).collect();
+        assert_eq!(shrinks[0], Decimal::new(50, 1));
+        assert!(shrinks.iter().all(|d| *d >= Decimal::new(5, 0)));
+    }
 }
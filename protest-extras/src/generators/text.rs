@@ -6,9 +6,14 @@
 //! - Programming identifiers (valid Rust/C/etc identifiers)
 //! - Sentences (realistic sentence-like text)
 //! - Paragraphs (multiple sentences)
+//! - Non-empty strings (wrapping any string generator)
+//! - Unicode strings (selectable blocks, grapheme-based lengths)
+//! - Strings matching a regular expression (requires the `regex` feature)
 //!
-//! All generators use std library only.
+//! All generators except `RegexGenerator` use std library only.
 
+#[cfg(feature = "regex")]
+use protest::GeneratorError;
 use protest::{Generator, GeneratorConfig};
 use rand::Rng;
 
@@ -170,22 +175,57 @@ impl Generator<String> for AlphanumericGenerator {
 // Identifier Generator
 // ============================================================================
 
+/// Rust strict and reserved keywords, plus `_`, none of which are usable identifiers
+const RUST_KEYWORDS: &[&str] = &[
+    "_", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
 /// Generator for valid programming identifiers (Rust/C style)
 ///
 /// Generates strings that:
 /// - Start with a letter or underscore
 /// - Contain only letters, digits, and underscores
 /// - Are valid identifiers in most programming languages
+///
+/// Reserved words can be excluded with [`IdentifierGenerator::avoiding_keywords`]
+/// or [`IdentifierGenerator::rust_safe`].
 #[derive(Debug, Clone)]
 pub struct IdentifierGenerator {
     min_len: usize,
     max_len: usize,
+    keywords: Vec<String>,
 }
 
 impl IdentifierGenerator {
     /// Create a new identifier generator with length bounds
     pub fn new(min_len: usize, max_len: usize) -> Self {
-        Self { min_len, max_len }
+        Self {
+            min_len,
+            max_len,
+            keywords: Vec::new(),
+        }
+    }
+
+    /// Never generate or shrink to any of the given reserved words
+    ///
+    /// A generated keyword is regenerated a few times, then suffixed with `_`.
+    pub fn avoiding_keywords(mut self, keywords: &[&str]) -> Self {
+        self.keywords
+            .extend(keywords.iter().map(|keyword| keyword.to_string()));
+        self
+    }
+
+    /// Never generate or shrink to a Rust keyword (or `_`)
+    pub fn rust_safe(self) -> Self {
+        self.avoiding_keywords(RUST_KEYWORDS)
+    }
+
+    fn is_keyword(&self, ident: &str) -> bool {
+        self.keywords.iter().any(|keyword| keyword == ident)
     }
 
     fn generate_first_char(&self, rng: &mut dyn rand::RngCore) -> char {
@@ -199,10 +239,8 @@ impl IdentifierGenerator {
         let chars = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_";
         chars[rng.r#gen_range(0..chars.len())] as char
     }
-}
 
-impl Generator<String> for IdentifierGenerator {
-    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> String {
+    fn generate_identifier(&self, rng: &mut dyn rand::RngCore) -> String {
         let len = rng.r#gen_range(self.min_len.max(1)..=self.max_len.max(1));
         let mut result = String::with_capacity(len);
 
@@ -216,6 +254,26 @@ impl Generator<String> for IdentifierGenerator {
 
         result
     }
+}
+
+impl Generator<String> for IdentifierGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> String {
+        let mut result = self.generate_identifier(rng);
+
+        // Keywords are short and rare, so a few retries almost always suffice
+        for _ in 0..8 {
+            if !self.is_keyword(&result) {
+                return result;
+            }
+            result = self.generate_identifier(rng);
+        }
+
+        while self.is_keyword(&result) {
+            result.push('_');
+        }
+
+        result
+    }
 
     fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
         let mut shrinks = Vec::new();
@@ -246,6 +304,8 @@ impl Generator<String> for IdentifierGenerator {
             }
         }
 
+        shrinks.retain(|shrunk| !self.is_keyword(shrunk));
+
         Box::new(shrinks.into_iter())
     }
 }
@@ -260,10 +320,14 @@ impl Generator<String> for IdentifierGenerator {
 /// - Start with capital letter
 /// - End with period
 /// - Contain words separated by spaces
+///
+/// Words are random lowercase letters unless a vocabulary is supplied with
+/// [`SentenceGenerator::with_words`].
 #[derive(Debug, Clone)]
 pub struct SentenceGenerator {
     min_words: usize,
     max_words: usize,
+    words: Vec<String>,
 }
 
 impl SentenceGenerator {
@@ -272,10 +336,29 @@ impl SentenceGenerator {
         Self {
             min_words,
             max_words,
+            words: Vec::new(),
         }
     }
 
+    /// Sample words from the given vocabulary instead of random letters
+    ///
+    /// Words should not contain whitespace or `.`. The first word of each
+    /// sentence is capitalized. An empty list keeps the random words.
+    pub fn with_words(mut self, words: Vec<String>) -> Self {
+        self.words = words;
+        self
+    }
+
     fn generate_word(&self, rng: &mut dyn rand::RngCore, is_first: bool) -> String {
+        if !self.words.is_empty() {
+            let word = &self.words[rng.r#gen_range(0..self.words.len())];
+            return if is_first {
+                capitalize(word)
+            } else {
+                word.clone()
+            };
+        }
+
         let len = rng.r#gen_range(2..8);
         let mut word = String::with_capacity(len);
 
@@ -292,6 +375,21 @@ impl SentenceGenerator {
 
         word
     }
+
+    /// The smallest sentence this generator shrinks to
+    fn minimal_sentence(&self) -> String {
+        let word = self.words.first().map_or("a", String::as_str);
+        format!("{}.", capitalize(word))
+    }
+}
+
+/// Uppercase the first character, leaving the rest unchanged
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
 }
 
 impl Generator<String> for SentenceGenerator {
@@ -312,29 +410,29 @@ impl Generator<String> for SentenceGenerator {
 
     fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
         let mut shrinks = Vec::new();
-
-        // Try minimal sentence
-        if value != "A." && self.min_words <= 1 {
-            shrinks.push("A.".to_string());
-        }
-
-        // Try removing words
         let words: Vec<&str> = value.trim_end_matches('.').split_whitespace().collect();
-        if words.len() > self.min_words {
+        let sentence = |words: &[&str]| format!("{}.", capitalize(&words.join(" ")));
+
+        // Reduce the word count first
+        if words.len() > self.min_words.max(1) {
             // Take first min_words
-            let shrunk_words = &words[..self.min_words.max(1)];
-            let mut shrunk = shrunk_words.join(" ");
-            // Capitalize first letter if needed
-            if let Some(first_char) = shrunk.chars().next()
-                && first_char.is_lowercase()
-            {
-                let capitalized = first_char.to_uppercase().collect::<String>() + &shrunk[1..];
-                shrunk = capitalized;
+            shrinks.push(sentence(&words[..self.min_words.max(1)]));
+
+            // Drop single words
+            for i in 0..words.len().min(3) {
+                let mut shrunk_words = words.clone();
+                shrunk_words.remove(i);
+                shrinks.push(sentence(&shrunk_words));
             }
-            shrunk.push('.');
-            shrinks.push(shrunk);
         }
 
+        // Then try the minimal sentence
+        let minimal = self.minimal_sentence();
+        if *value != minimal && self.min_words <= 1 {
+            shrinks.push(minimal);
+        }
+
+        shrinks.dedup();
         Box::new(shrinks.into_iter())
     }
 }
@@ -350,6 +448,7 @@ impl Generator<String> for SentenceGenerator {
 pub struct ParagraphGenerator {
     min_sentences: usize,
     max_sentences: usize,
+    words: Vec<String>,
 }
 
 impl ParagraphGenerator {
@@ -358,14 +457,25 @@ impl ParagraphGenerator {
         Self {
             min_sentences,
             max_sentences,
+            words: Vec::new(),
         }
     }
+
+    /// Sample words from the given vocabulary, as in [`SentenceGenerator::with_words`]
+    pub fn with_words(mut self, words: Vec<String>) -> Self {
+        self.words = words;
+        self
+    }
+
+    fn sentence_generator(&self) -> SentenceGenerator {
+        SentenceGenerator::new(3, 10).with_words(self.words.clone())
+    }
 }
 
 impl Generator<String> for ParagraphGenerator {
     fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> String {
         let num_sentences = rng.r#gen_range(self.min_sentences.max(1)..=self.max_sentences.max(1));
-        let sentence_gen = SentenceGenerator::new(3, 10);
+        let sentence_gen = self.sentence_generator();
 
         let sentences: Vec<String> = (0..num_sentences)
             .map(|_| sentence_gen.generate(rng, config))
@@ -377,11 +487,6 @@ impl Generator<String> for ParagraphGenerator {
     fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
         let mut shrinks = Vec::new();
 
-        // Try minimal paragraph
-        if value != "A." && self.min_sentences <= 1 {
-            shrinks.push("A.".to_string());
-        }
-
         // Try removing sentences
         let sentences: Vec<&str> = value.split(". ").collect();
         if sentences.len() > self.min_sentences {
@@ -393,10 +498,504 @@ impl Generator<String> for ParagraphGenerator {
             shrinks.push(shrunk);
         }
 
+        // Try minimal paragraph
+        let minimal = self.sentence_generator().minimal_sentence();
+        if *value != minimal && self.min_sentences <= 1 {
+            shrinks.push(minimal);
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
+// ============================================================================
+// Non-Empty String Generator
+// ============================================================================
+
+/// Generator wrapping any string generator so that it never yields an empty string
+///
+/// If the inner generator produces an empty string, generation is retried a few
+/// times before falling back to a single character drawn from the charset
+/// (lowercase ASCII letters by default). Shrinking delegates to the inner
+/// generator but never goes below length 1.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig};
+/// use protest_extras::generators::text::{AlphabeticGenerator, NonEmptyStringGenerator};
+/// use rand::thread_rng;
+///
+/// let generator = NonEmptyStringGenerator::new(AlphabeticGenerator::lowercase(0, 8))
+///     .with_charset("xyz");
+/// let value = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert!(!value.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct NonEmptyStringGenerator<G> {
+    inner: G,
+    charset: Vec<char>,
+    max_retries: usize,
+}
+
+impl<G: Generator<String>> NonEmptyStringGenerator<G> {
+    /// Wrap a string generator so that it always yields at least one character
+    pub fn new(inner: G) -> Self {
+        Self {
+            inner,
+            charset: ('a'..='z').collect(),
+            max_retries: 10,
+        }
+    }
+
+    /// Set the characters used to fill in when the inner generator yields an empty string
+    ///
+    /// # Panics
+    ///
+    /// Panics if `charset` is empty.
+    pub fn with_charset(mut self, charset: &str) -> Self {
+        let chars: Vec<char> = charset.chars().collect();
+        if chars.is_empty() {
+            panic!("NonEmptyStringGenerator charset cannot be empty");
+        }
+        self.charset = chars;
+        self
+    }
+}
+
+impl<G: Generator<String>> Generator<String> for NonEmptyStringGenerator<G> {
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> String {
+        for _ in 0..self.max_retries {
+            let value = self.inner.generate(rng, config);
+            if !value.is_empty() {
+                return value;
+            }
+        }
+
+        // Inner generator kept producing empty strings, append a single character
+        self.charset[rng.r#gen_range(0..self.charset.len())].to_string()
+    }
+
+    fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
+        let mut shrinks = Vec::new();
+
+        // Try the shortest non-empty prefix
+        if value.chars().count() > 1 {
+            shrinks.extend(value.chars().next().map(String::from));
+        }
+
+        shrinks.extend(self.inner.shrink(value).filter(|s| !s.is_empty()));
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
+// ============================================================================
+// Unicode String Generator
+// ============================================================================
+
+/// Unicode blocks that [`UnicodeStringGenerator`] can draw characters from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnicodeBlock {
+    /// Printable ASCII (U+0020–U+007E)
+    Ascii,
+    /// Latin-1 Supplement letters and symbols (U+00A0–U+00FF)
+    Latin1,
+    /// CJK Unified Ideographs (U+4E00–U+9FFF)
+    Cjk,
+    /// Emoticons (U+1F600–U+1F64F)
+    Emoji,
+    /// Combining Diacritical Marks (U+0300–U+036F), attached to a preceding base character
+    CombiningMarks,
+    /// Scalars on either side of the surrogate range (U+D7F0–U+D7FF, U+E000–U+E00F)
+    SurrogateAdjacent,
+}
+
+impl UnicodeBlock {
+    fn range(self) -> (u32, u32) {
+        match self {
+            UnicodeBlock::Ascii => (0x20, 0x7E),
+            UnicodeBlock::Latin1 => (0xA0, 0xFF),
+            UnicodeBlock::Cjk => (0x4E00, 0x9FFF),
+            UnicodeBlock::Emoji => (0x1F600, 0x1F64F),
+            UnicodeBlock::CombiningMarks => (0x0300, 0x036F),
+            UnicodeBlock::SurrogateAdjacent => (0xD7F0, 0xE00F),
+        }
+    }
+
+    fn sample(self, rng: &mut dyn rand::RngCore) -> char {
+        let (min, max) = self.range();
+        loop {
+            // Every range above only skips the surrogates themselves
+            if let Some(c) = char::from_u32(rng.r#gen_range(min..=max)) {
+                return c;
+            }
+        }
+    }
+}
+
+fn is_combining_mark(c: char) -> bool {
+    ('\u{0300}'..='\u{036F}').contains(&c)
+}
+
+/// Split a string into grapheme clusters as produced by [`UnicodeStringGenerator`]
+///
+/// Each cluster is a base character followed by any combining marks.
+fn graphemes(value: &str) -> Vec<&str> {
+    let mut clusters = Vec::new();
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        if i > start && !is_combining_mark(c) {
+            clusters.push(&value[start..i]);
+            start = i;
+        }
+    }
+    if start < value.len() {
+        clusters.push(&value[start..]);
+    }
+    clusters
+}
+
+/// Generator for Unicode strings drawn from selectable blocks
+///
+/// Lengths are measured in grapheme clusters, not bytes or chars. Combining
+/// marks are only ever attached to a preceding base character, so a cluster is
+/// never split. Shrinking first drops non-ASCII clusters, then shortens.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig};
+/// use protest_extras::generators::text::{UnicodeBlock, UnicodeStringGenerator};
+/// use rand::thread_rng;
+///
+/// let generator = UnicodeStringGenerator::new(1, 10)
+///     .with_blocks(vec![UnicodeBlock::Latin1, UnicodeBlock::CombiningMarks]);
+/// let text = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert!(!text.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnicodeStringGenerator {
+    min_graphemes: usize,
+    max_graphemes: usize,
+    blocks: Vec<UnicodeBlock>,
+}
+
+impl UnicodeStringGenerator {
+    /// Create a generator using every block, with lengths in grapheme clusters
+    pub fn new(min_graphemes: usize, max_graphemes: usize) -> Self {
+        Self {
+            min_graphemes,
+            max_graphemes,
+            blocks: vec![
+                UnicodeBlock::Ascii,
+                UnicodeBlock::Latin1,
+                UnicodeBlock::Cjk,
+                UnicodeBlock::Emoji,
+                UnicodeBlock::CombiningMarks,
+                UnicodeBlock::SurrogateAdjacent,
+            ],
+        }
+    }
+
+    /// Restrict generation to the given blocks
+    ///
+    /// If only [`UnicodeBlock::CombiningMarks`] is selected, marks are attached
+    /// to ASCII base characters.
+    pub fn with_blocks(mut self, blocks: Vec<UnicodeBlock>) -> Self {
+        self.blocks = blocks;
+        self
+    }
+
+    fn generate_grapheme(&self, rng: &mut dyn rand::RngCore, out: &mut String) {
+        let bases: Vec<UnicodeBlock> = self
+            .blocks
+            .iter()
+            .copied()
+            .filter(|b| *b != UnicodeBlock::CombiningMarks)
+            .collect();
+        let base = if bases.is_empty() {
+            UnicodeBlock::Ascii
+        } else {
+            bases[rng.r#gen_range(0..bases.len())]
+        };
+        out.push(base.sample(rng));
+
+        if self.blocks.contains(&UnicodeBlock::CombiningMarks) && rng.r#gen_bool(0.3) {
+            for _ in 0..rng.r#gen_range(1..=2) {
+                out.push(UnicodeBlock::CombiningMarks.sample(rng));
+            }
+        }
+    }
+}
+
+impl Generator<String> for UnicodeStringGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> String {
+        let len = rng.r#gen_range(self.min_graphemes..=self.max_graphemes);
+        let mut result = String::new();
+        for _ in 0..len {
+            self.generate_grapheme(rng, &mut result);
+        }
+        result
+    }
+
+    fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
+        let mut shrinks = Vec::new();
+        let clusters = graphemes(value);
+
+        // Try dropping non-ASCII clusters, or replacing them if that would be too short
+        if !value.is_ascii() {
+            let ascii: Vec<&str> = clusters.iter().copied().filter(|g| g.is_ascii()).collect();
+            if ascii.len() >= self.min_graphemes {
+                shrinks.push(ascii.concat());
+            }
+            shrinks.push(
+                clusters
+                    .iter()
+                    .map(|g| if g.is_ascii() { *g } else { "a" })
+                    .collect(),
+            );
+        }
+
+        // Try min length
+        if clusters.len() > self.min_graphemes {
+            shrinks.push(clusters[..self.min_graphemes].concat());
+        }
+
+        // Try removing one cluster at a time
+        if clusters.len() > self.min_graphemes {
+            for i in 0..clusters.len().min(3) {
+                let mut shrunk = clusters.clone();
+                shrunk.remove(i);
+                shrinks.push(shrunk.concat());
+            }
+        }
+
         Box::new(shrinks.into_iter())
     }
 }
 
+// ============================================================================
+// Regex Generator
+// ============================================================================
+
+/// Generator for strings matching a regular expression
+///
+/// Supports the syntax of the `regex` crate: literals, character classes,
+/// alternation, groups, repetition and flags such as `(?i)`. Anchors (`^`,
+/// `$`, `\A`, `\z`) match the whole generated string; word boundaries cannot
+/// be honoured and are rejected. Unbounded repetitions (`*`, `+`, `{n,}`)
+/// repeat at most `max_repeat` times beyond their minimum (default 8).
+///
+/// Shrinks to the simplest matching string: every repetition at its
+/// minimum, the first alternative, and the lowest character of each class.
+///
+/// Requires the `regex` feature.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig};
+/// use protest_extras::generators::text::RegexGenerator;
+/// use rand::thread_rng;
+///
+/// let generator = RegexGenerator::new("[a-z]{1,10}@example\\.com").unwrap();
+/// let email = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert!(email.ends_with("@example.com"));
+/// ```
+#[cfg(feature = "regex")]
+#[derive(Debug, Clone)]
+pub struct RegexGenerator {
+    pattern: String,
+    hir: regex_syntax::hir::Hir,
+    max_repeat: u32,
+}
+
+#[cfg(feature = "regex")]
+impl RegexGenerator {
+    /// Create a generator for strings matching `pattern`
+    ///
+    /// Fails if the pattern does not parse, can never match, or uses a
+    /// construct that cannot be generated (word boundaries, non-UTF-8 bytes).
+    pub fn new(pattern: &str) -> Result<Self, GeneratorError> {
+        let error = |reason: String| {
+            GeneratorError::new(format!("invalid regex `{}`: {}", pattern, reason))
+        };
+        let hir = regex_syntax::Parser::new()
+            .parse(pattern)
+            .map_err(|e| error(e.to_string()))?;
+        if !hir.properties().is_utf8() {
+            return Err(error("pattern can match invalid UTF-8".to_string()));
+        }
+        Self::check_supported(&hir).map_err(error)?;
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            hir,
+            max_repeat: 8,
+        })
+    }
+
+    /// Cap how many times unbounded repetitions repeat beyond their minimum
+    pub fn with_max_repeat(mut self, max_repeat: u32) -> Self {
+        self.max_repeat = max_repeat;
+        self
+    }
+
+    /// The pattern this generator matches
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    fn check_supported(hir: &regex_syntax::hir::Hir) -> Result<(), String> {
+        use regex_syntax::hir::{HirKind, Look};
+
+        match hir.kind() {
+            HirKind::Empty | HirKind::Literal(_) => Ok(()),
+            HirKind::Class(class) => {
+                if Self::class_ranges(class).is_empty() {
+                    Err("character class matches nothing".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+            HirKind::Look(
+                Look::Start
+                | Look::End
+                | Look::StartLF
+                | Look::EndLF
+                | Look::StartCRLF
+                | Look::EndCRLF,
+            ) => Ok(()),
+            HirKind::Look(look) => Err(format!("unsupported assertion {:?}", look)),
+            HirKind::Repetition(repetition) => Self::check_supported(&repetition.sub),
+            HirKind::Capture(capture) => Self::check_supported(&capture.sub),
+            HirKind::Concat(parts) => parts.iter().try_for_each(Self::check_supported),
+            HirKind::Alternation(parts) => parts.iter().try_for_each(Self::check_supported),
+        }
+    }
+
+    fn class_ranges(class: &regex_syntax::hir::Class) -> Vec<(char, char)> {
+        use regex_syntax::hir::Class;
+
+        match class {
+            Class::Unicode(class) => class
+                .ranges()
+                .iter()
+                .map(|r| (r.start(), r.end()))
+                .collect(),
+            Class::Bytes(class) => class
+                .ranges()
+                .iter()
+                .filter(|r| r.start().is_ascii())
+                .map(|r| (r.start() as char, r.end().min(0x7f) as char))
+                .collect(),
+        }
+    }
+
+    fn generate_class(ranges: &[(char, char)], rng: &mut dyn rand::RngCore) -> char {
+        let total: u64 = ranges
+            .iter()
+            .map(|&(start, end)| u64::from(end as u32 - start as u32) + 1)
+            .sum();
+        loop {
+            let mut offset = rng.r#gen_range(0..total);
+            for &(start, end) in ranges {
+                let size = u64::from(end as u32 - start as u32) + 1;
+                if offset < size {
+                    // Ranges may span the surrogate gap; redraw if we land in it
+                    if let Some(c) = char::from_u32(start as u32 + offset as u32) {
+                        return c;
+                    }
+                    break;
+                }
+                offset -= size;
+            }
+        }
+    }
+
+    fn generate_into(
+        &self,
+        hir: &regex_syntax::hir::Hir,
+        rng: &mut dyn rand::RngCore,
+        output: &mut String,
+    ) {
+        use regex_syntax::hir::HirKind;
+
+        match hir.kind() {
+            HirKind::Empty | HirKind::Look(_) => {}
+            HirKind::Literal(literal) => output.push_str(&String::from_utf8_lossy(&literal.0)),
+            HirKind::Class(class) => {
+                output.push(Self::generate_class(&Self::class_ranges(class), rng))
+            }
+            HirKind::Repetition(repetition) => {
+                let max = repetition
+                    .max
+                    .unwrap_or(repetition.min.saturating_add(self.max_repeat));
+                for _ in 0..rng.r#gen_range(repetition.min..=max) {
+                    self.generate_into(&repetition.sub, rng, output);
+                }
+            }
+            HirKind::Capture(capture) => self.generate_into(&capture.sub, rng, output),
+            HirKind::Concat(parts) => {
+                for part in parts {
+                    self.generate_into(part, rng, output);
+                }
+            }
+            HirKind::Alternation(parts) => {
+                let choice = rng.r#gen_range(0..parts.len());
+                self.generate_into(&parts[choice], rng, output);
+            }
+        }
+    }
+
+    fn simplest_into(hir: &regex_syntax::hir::Hir, output: &mut String) {
+        use regex_syntax::hir::HirKind;
+
+        match hir.kind() {
+            HirKind::Empty | HirKind::Look(_) => {}
+            HirKind::Literal(literal) => output.push_str(&String::from_utf8_lossy(&literal.0)),
+            HirKind::Class(class) => {
+                if let Some(&(start, _)) = Self::class_ranges(class).first() {
+                    output.push(start);
+                }
+            }
+            HirKind::Repetition(repetition) => {
+                for _ in 0..repetition.min {
+                    Self::simplest_into(&repetition.sub, output);
+                }
+            }
+            HirKind::Capture(capture) => Self::simplest_into(&capture.sub, output),
+            HirKind::Concat(parts) => {
+                for part in parts {
+                    Self::simplest_into(part, output);
+                }
+            }
+            HirKind::Alternation(parts) => Self::simplest_into(&parts[0], output),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Generator<String> for RegexGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> String {
+        let mut output = String::new();
+        self.generate_into(&self.hir, rng, &mut output);
+        output
+    }
+
+    fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
+        let mut simplest = String::new();
+        Self::simplest_into(&self.hir, &mut simplest);
+
+        if *value == simplest {
+            Box::new(std::iter::empty())
+        } else {
+            Box::new(std::iter::once(simplest))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -459,6 +1058,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_identifier_generator_rust_safe() {
+        let generator = IdentifierGenerator::new(1, 2)
+            .rust_safe()
+            .avoiding_keywords(&["a", "xy"]);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..200 {
+            let id = generator.generate(&mut rng, &config);
+            assert!(!RUST_KEYWORDS.contains(&id.as_str()), "keyword: {}", id);
+            assert_ne!(id, "a");
+        }
+
+        // Shrinking never lands on a keyword
+        let shrinks: Vec<_> = generator.shrink(&"fxn".to_string()).collect();
+        assert!(!shrinks.is_empty());
+        assert!(!shrinks.iter().any(|id| id == "fn" || id == "a"));
+        let shrinks: Vec<_> = generator.shrink(&"xyz".to_string()).collect();
+        assert!(!shrinks.contains(&"xy".to_string()));
+
+        // Every candidate a keyword: fall back to a `_` suffix
+        let generator = IdentifierGenerator::new(1, 1).avoiding_keywords(&["_"]);
+        let generator = ('a'..='z')
+            .chain('A'..='Z')
+            .map(|c| c.to_string())
+            .fold(generator, |generator, c| generator.avoiding_keywords(&[&c]));
+        assert!(generator.generate(&mut rng, &config).ends_with('_'));
+    }
+
     #[test]
     fn test_sentence_generator() {
         let generator = SentenceGenerator::new(3, 8);
@@ -494,4 +1123,202 @@ mod tests {
             assert!((2..=5).contains(&period_count));
         }
     }
+
+    #[test]
+    fn test_sentence_generator_with_words() {
+        let words: Vec<String> = ["über", "straße", "ёлка", "日本"]
+            .iter()
+            .map(|word| word.to_string())
+            .collect();
+        let generator = SentenceGenerator::new(2, 6).with_words(words.clone());
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let sentence = generator.generate(&mut rng, &config);
+            assert!(sentence.ends_with('.'));
+            for (i, word) in sentence.trim_end_matches('.').split(' ').enumerate() {
+                if i == 0 {
+                    assert!(words.iter().any(|w| capitalize(w) == word), "{}", word);
+                } else {
+                    assert!(words.iter().any(|w| w == word), "{}", word);
+                }
+            }
+        }
+
+        // Word count shrinks first, re-capitalizing the new first word
+        let shrinks: Vec<_> = generator.shrink(&"Über ёлка straße.".to_string()).collect();
+        assert_eq!(shrinks[0], "Über ёлка.");
+        assert_eq!(shrinks[1], "Ёлка straße.");
+
+        let paragraph = ParagraphGenerator::new(1, 3)
+            .with_words(words.clone())
+            .generate(&mut rng, &config);
+        assert!(
+            paragraph
+                .split([' ', '.'])
+                .filter(|word| !word.is_empty())
+                .all(|word| words.iter().any(|w| w == word || capitalize(w) == word))
+        );
+    }
+
+    #[test]
+    fn test_non_empty_string_generator() {
+        let generator = NonEmptyStringGenerator::new(AlphabeticGenerator::lowercase(0, 3));
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..50 {
+            let text = generator.generate(&mut rng, &config);
+            assert!(!text.is_empty());
+            assert!(text.len() <= 3);
+        }
+    }
+
+    #[test]
+    fn test_non_empty_string_fills_from_charset() {
+        let generator =
+            NonEmptyStringGenerator::new(AlphabeticGenerator::new(0, 0)).with_charset("#");
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        assert_eq!(generator.generate(&mut rng, &config), "#");
+    }
+
+    #[test]
+    fn test_non_empty_string_shrink_never_empty() {
+        let generator = NonEmptyStringGenerator::new(AlphabeticGenerator::lowercase(0, 10));
+
+        let shrinks: Vec<String> = generator.shrink(&"hello".to_string()).collect();
+        assert!(!shrinks.is_empty());
+        assert!(shrinks.iter().all(|s| !s.is_empty()));
+        assert!(shrinks.contains(&"h".to_string()));
+
+        assert_eq!(generator.shrink(&"h".to_string()).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "NonEmptyStringGenerator charset cannot be empty")]
+    fn test_non_empty_string_empty_charset() {
+        NonEmptyStringGenerator::new(AlphabeticGenerator::new(0, 5)).with_charset("");
+    }
+
+    #[test]
+    fn test_unicode_string_grapheme_length() {
+        let generator = UnicodeStringGenerator::new(2, 8);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..100 {
+            let text = generator.generate(&mut rng, &config);
+            let clusters = graphemes(&text);
+            assert!((2..=8).contains(&clusters.len()), "{:?}", text);
+            assert!(!text.starts_with(is_combining_mark));
+        }
+    }
+
+    #[test]
+    fn test_unicode_string_blocks() {
+        let generator = UnicodeStringGenerator::new(5, 10).with_blocks(vec![UnicodeBlock::Cjk]);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let text = generator.generate(&mut rng, &config);
+            assert!(text.chars().all(|c| ('\u{4E00}'..='\u{9FFF}').contains(&c)));
+        }
+
+        let generator =
+            UnicodeStringGenerator::new(5, 10).with_blocks(vec![UnicodeBlock::CombiningMarks]);
+        for _ in 0..20 {
+            let text = generator.generate(&mut rng, &config);
+            for cluster in graphemes(&text) {
+                assert!(cluster.chars().next().unwrap().is_ascii());
+            }
+        }
+    }
+
+    #[test]
+    fn test_unicode_string_shrink_drops_non_ascii_first() {
+        let generator = UnicodeStringGenerator::new(0, 10);
+        let value = "a\u{00E9}e\u{0301}\u{4E2D}b".to_string();
+
+        let shrinks: Vec<String> = generator.shrink(&value).collect();
+        assert_eq!(shrinks[0], "ab");
+        assert_eq!(shrinks[1], "aaaab");
+        // Shortening never leaves a dangling combining mark
+        for shrunk in &shrinks {
+            assert!(!shrunk.starts_with(is_combining_mark));
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regex_generator_matches_pattern() {
+        let patterns = [
+            "[a-z]{1,10}",
+            "(foo|bar)+baz?",
+            r"\d{3}-\d{4}",
+            "[A-Z][a-z]*",
+            "a.c",
+            "(?i)hello",
+            "^abc$",
+            r"\w+@\w+\.com",
+            "[^a-z]{2}",
+        ];
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for pattern in patterns {
+            let generator = RegexGenerator::new(pattern).unwrap();
+            let matcher = regex::Regex::new(&format!("^(?:{})$", pattern)).unwrap();
+            for _ in 0..50 {
+                let value = generator.generate(&mut rng, &config);
+                assert!(
+                    matcher.is_match(&value),
+                    "{:?} does not match {}",
+                    value,
+                    pattern
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regex_generator_max_repeat() {
+        let generator = RegexGenerator::new("a+").unwrap().with_max_repeat(3);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..50 {
+            let len = generator.generate(&mut rng, &config).len();
+            assert!((1..=4).contains(&len));
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regex_generator_shrinks_to_simplest_match() {
+        let generator = RegexGenerator::new("(x|y)[b-d]{2,5}z*").unwrap();
+        let shrinks: Vec<String> = generator.shrink(&"ydddzz".to_string()).collect();
+        assert_eq!(shrinks, vec!["xbb".to_string()]);
+        assert_eq!(generator.shrink(&"xbb".to_string()).count(), 0);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_regex_generator_rejects_unusable_patterns() {
+        let error = RegexGenerator::new("(unclosed").unwrap_err();
+        assert!(error.message().starts_with("invalid regex `(unclosed`"));
+
+        let error = RegexGenerator::new(r"\bword").unwrap_err();
+        assert!(error.message().contains("unsupported assertion"));
+        assert!(matches!(
+            protest::PropertyError::from(error),
+            protest::PropertyError::GeneratorError { .. }
+        ));
+
+        assert!(RegexGenerator::new(r"[^\s\S]").is_err());
+    }
 }
@@ -13,7 +13,11 @@
 //! - **Domain**: UUIDs, Base64, hex strings, file paths
 //!
 //! All features use **std library only** by default, with optional external dependencies
-//! available via feature flags.
+//! available via feature flags:
+//!
+//! - `chrono`: enables `Rfc3339Generator` for RFC3339 datetime strings
+//! - `regex`: enables `RegexGenerator` for strings matching a regular expression
+//! - `rust_decimal`: enables `DecimalGenerator` for fixed-point decimals
 //!
 //! ## No External Dependencies
 //!
@@ -72,6 +76,11 @@
 //! let ip = generator.generate(&mut rng, &config);
 //! assert!(ip.contains(':'));
 //!
+//! // Generate std::net types directly
+//! let generator = SocketAddrGenerator::new(IpAddrGenerator::ipv4()).with_ports(1024, 65535);
+//! let addr: std::net::SocketAddr = generator.generate(&mut rng, &config);
+//! assert!(addr.is_ipv4() && addr.port() >= 1024);
+//!
 //! // Generate email addresses
 //! let generator = EmailGenerator::new();
 //! let email = generator.generate(&mut rng, &config);
@@ -201,6 +210,11 @@
 //! let n = generator.generate(&mut rng, &config);
 //! assert_eq!(n % 2, 0);
 //!
+//! // Generate multiples of 8
+//! let generator = MultipleOfGenerator::new(8u32, 0, 4096).unwrap();
+//! let n = generator.generate(&mut rng, &config);
+//! assert_eq!(n % 8, 0);
+//!
 //! // Generate prime numbers
 //! let generator = PrimeNumberGenerator::new(2, 100);
 //! let n = generator.generate(&mut rng, &config);
@@ -262,37 +276,50 @@ pub mod prelude {
     //! Convenient re-exports of commonly used generators
 
     // Network generators
-    pub use crate::generators::network::{EmailGenerator, IpAddressGenerator, UrlGenerator};
+    pub use crate::generators::network::{
+        EmailGenerator, IpAddrGenerator, IpAddressGenerator, LabeledEmailGenerator,
+        SocketAddrGenerator, UrlGenerator,
+    };
 
     // DateTime generators
+    #[cfg(feature = "chrono")]
+    pub use crate::generators::datetime::Rfc3339Generator;
     pub use crate::generators::datetime::{
-        DurationGenerator, SystemTimeGenerator, TimestampGenerator,
+        CronGenerator, DurationGenerator, SystemTimeGenerator, TimestampGenerator,
     };
 
     // Text generators
+    #[cfg(feature = "regex")]
+    pub use crate::generators::text::RegexGenerator;
     pub use crate::generators::text::{
-        AlphabeticGenerator, AlphanumericGenerator, IdentifierGenerator, ParagraphGenerator,
-        SentenceGenerator,
+        AlphabeticGenerator, AlphanumericGenerator, IdentifierGenerator, NonEmptyStringGenerator,
+        ParagraphGenerator, SentenceGenerator, UnicodeBlock, UnicodeStringGenerator,
     };
 
     // Collection generators
     pub use crate::generators::collections::{
-        BoundedMapGenerator, NonEmptyVecGenerator, SortedVecGenerator, UniqueVecGenerator,
+        BoundedMapGenerator, NonEmptyVecGenerator, SortedMapGenerator, SortedVecGenerator,
+        UniqueVecGenerator,
     };
 
     // Numeric generators
+    #[cfg(feature = "rust_decimal")]
+    pub use crate::generators::numeric::DecimalGenerator;
     pub use crate::generators::numeric::{
-        EvenNumberGenerator, PercentageGenerator, PositiveIntGenerator, PrimeNumberGenerator,
+        EvenNumberGenerator, IntegerPercentageGenerator, MultipleOfGenerator, NonZeroGenerator,
+        OddNumberGenerator, PercentageGenerator, PositiveIntGenerator, PrimeNumberGenerator,
     };
 
     // Domain generators
     pub use crate::generators::domain::{
-        Base64Generator, HexGenerator, PathGenerator, UuidV4Generator,
+        Base64Generator, ColorGenerator, CoordinateGenerator, HexGenerator, PathGenerator,
+        RgbaGenerator, UuidV4Generator,
     };
 
     // Shrinking strategies
     pub use crate::shrinking::{
-        CascadingShrinker, ConfigurableShrinker, DeltaDebugShrinker, GuidedShrinker,
-        ShrinkStrategy, SmartShrink, TargetedShrinker,
+        CascadeResult, CascadeStrategy, CascadingShrinker, ConfigurableShrinker,
+        DeltaDebugShrinker, GuidedShrinker, RetainingShrinker, ShrinkStrategy, SmartShrink,
+        TargetedShrinker,
     };
 }
@@ -3,7 +3,7 @@
 //! This module provides advanced shrinking strategies that go beyond basic shrinking:
 //! - **Smart Shrinking**: Preserve invariants while shrinking
 //! - **Delta Debugging**: Binary search for minimal failing subsets
-//! - **Targeted Shrinking**: Shrink toward specific target values
+//! - **Targeted Shrinking**: Shrink toward specific target values, or keep a known-bad pattern
 //!
 //! # Examples
 //!
@@ -43,6 +43,7 @@
 //! ```
 
 use std::fmt::Debug;
+use std::sync::Arc;
 
 // ============================================================================
 // Smart Shrinking with Invariants
@@ -255,6 +256,84 @@ impl<T: Clone + Debug + PartialEq> DeltaDebugShrinker<T> {
         current
     }
 
+    /// Minimize `input` with the classic ddmin algorithm
+    ///
+    /// `is_interesting` should return `true` for inputs that still reproduce the
+    /// failure. The input is split into `n` chunks (starting at 2); if some chunk
+    /// or the complement of some chunk is still interesting the search continues
+    /// from it, otherwise the granularity is doubled until chunks are single
+    /// elements. The result is 1-minimal: removing any single element makes it
+    /// uninteresting. If `input` itself is not interesting it is returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use protest_extras::shrinking::DeltaDebugShrinker;
+    ///
+    /// let calls = vec!["login", "list", "upload", "delete", "upload", "logout"];
+    ///
+    /// // The bug needs a delete followed later by an upload
+    /// let minimal = DeltaDebugShrinker::minimize(calls, |calls| {
+    ///     let delete = calls.iter().position(|c| *c == "delete");
+    ///     let upload = calls.iter().rposition(|c| *c == "upload");
+    ///     matches!((delete, upload), (Some(d), Some(u)) if d < u)
+    /// });
+    ///
+    /// assert_eq!(minimal, vec!["delete", "upload"]);
+    /// ```
+    pub fn minimize<F>(input: Vec<T>, is_interesting: F) -> Vec<T>
+    where
+        F: Fn(&[T]) -> bool,
+    {
+        let mut current = input;
+
+        if !is_interesting(&current) {
+            return current;
+        }
+
+        let mut granularity = 2;
+
+        while current.len() >= 2 {
+            let len = current.len();
+            let bounds: Vec<(usize, usize)> = (0..granularity)
+                .map(|i| (i * len / granularity, (i + 1) * len / granularity))
+                .collect();
+
+            // Reduce to a single chunk
+            if let Some(&(start, end)) = bounds
+                .iter()
+                .find(|&&(start, end)| is_interesting(&current[start..end]))
+            {
+                current = current[start..end].to_vec();
+                granularity = 2;
+                continue;
+            }
+
+            // Reduce to the complement of a chunk
+            let complement = bounds.iter().find_map(|&(start, end)| {
+                let candidate: Vec<T> = current[..start]
+                    .iter()
+                    .chain(&current[end..])
+                    .cloned()
+                    .collect();
+                is_interesting(&candidate).then_some(candidate)
+            });
+            if let Some(candidate) = complement {
+                current = candidate;
+                granularity = (granularity - 1).max(2);
+                continue;
+            }
+
+            // Increase granularity
+            if granularity >= len {
+                break;
+            }
+            granularity = (granularity * 2).min(len);
+        }
+
+        current
+    }
+
     /// Shrink to minimal subset, yielding intermediate results
     ///
     /// This is useful if you want to see the shrinking process or
@@ -456,14 +535,145 @@ impl Iterator for TargetedShrinkIterator<f64> {
     }
 }
 
+/// Predicate used by [`RetainingShrinker`]
+type RetainFn<T> = Arc<dyn Fn(&T) -> bool + Send + Sync>;
+
+impl<T> TargetedShrinker<T> {
+    /// Create a shrinker that only accepts candidates matching `predicate`
+    ///
+    /// Use this to confirm a hypothesis about the root cause: if the bug is
+    /// suspected to need a particular substructure (say, an enum variant), the
+    /// shrinker will never shrink it away. See [`RetainingShrinker`].
+    pub fn retaining<P>(value: T, predicate: P) -> RetainingShrinker<T>
+    where
+        P: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        RetainingShrinker {
+            value,
+            predicate: Arc::new(predicate),
+            max_iterations: 1000,
+        }
+    }
+}
+
+/// Shrinker that keeps a known-bad pattern while shrinking
+///
+/// Wraps the value's own [`protest::Shrinkable`] candidates with a filter, so
+/// every candidate (and therefore the minimal result) still satisfies the
+/// predicate. Created with [`TargetedShrinker::retaining`].
+///
+/// # Examples
+///
+/// ```rust
+/// use protest_extras::shrinking::TargetedShrinker;
+///
+/// let value = vec![5, -3, 8, -1, 4];
+///
+/// // Keep at least one negative number while shrinking
+/// let shrinker = TargetedShrinker::retaining(value, |v: &Vec<i32>| v.iter().any(|&x| x < 0));
+/// let minimal = shrinker.find_minimal(|v| v.len() >= 2);
+///
+/// assert_eq!(minimal.len(), 2);
+/// assert!(minimal.iter().any(|&x| x < 0));
+/// ```
+#[derive(Clone)]
+pub struct RetainingShrinker<T> {
+    value: T,
+    predicate: RetainFn<T>,
+    max_iterations: usize,
+}
+
+impl<T: Debug> Debug for RetainingShrinker<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetainingShrinker")
+            .field("value", &self.value)
+            .field("max_iterations", &self.max_iterations)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: protest::Shrinkable + Clone + 'static> RetainingShrinker<T> {
+    /// Set the maximum number of shrink steps taken by [`Self::find_minimal`]
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Shrink candidates of the value that still match the predicate
+    pub fn shrink(&self) -> Box<dyn Iterator<Item = T>> {
+        let predicate = Arc::clone(&self.predicate);
+        Box::new(
+            self.value
+                .shrink()
+                .filter(move |candidate| predicate(candidate)),
+        )
+    }
+
+    /// Find the minimal failing case that still matches the predicate
+    ///
+    /// The test function should return `true` if the test fails (i.e., we want to keep shrinking).
+    /// If the original value does not match the predicate or does not fail, it is returned as-is.
+    pub fn find_minimal<F>(&self, test: F) -> T
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut current = self.value.clone();
+
+        if !(self.predicate)(&current) || !test(&current) {
+            return current;
+        }
+
+        for _ in 0..self.max_iterations {
+            let next = current
+                .shrink()
+                .find(|candidate| (self.predicate)(candidate) && test(candidate));
+
+            match next {
+                Some(candidate) => current = candidate,
+                None => break,
+            }
+        }
+
+        current
+    }
+}
+
 // ============================================================================
 // Cascading Shrinking Strategy
 // ============================================================================
 
+/// A single strategy that [`CascadingShrinker`] can cascade through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CascadeStrategy {
+    /// Structural removal: drop one element at a time
+    Removal,
+    /// Element shrinking: replace one element with one of its own shrinks
+    ElementShrink,
+    /// Delta debugging: remove chunks of decreasing size
+    DeltaDebug,
+}
+
+/// Result of [`CascadingShrinker::find_minimal`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CascadeResult<T> {
+    /// Smallest value found that still fails the test
+    pub minimal: T,
+    /// Strategy that produced the final shrink, or `None` if nothing shrank
+    pub strategy: Option<CascadeStrategy>,
+    /// Number of successful shrink steps across all strategies
+    pub steps: usize,
+}
+
 /// Cascading shrinker that applies multiple shrinking strategies in sequence
 ///
-/// This strategy tries multiple shrinking approaches and combines their results,
-/// allowing for more thorough exploration of the shrink space.
+/// [`CascadingShrinker::shrink`] combines the candidates of several strategies
+/// into a single list, allowing for more thorough exploration of the shrink space.
+///
+/// [`CascadingShrinker::find_minimal`] instead runs the strategies in a fixed
+/// order (see [`CascadingShrinker::with_order`]). Each strategy is applied until
+/// it makes no further progress before moving to the next one, and the cascade
+/// is repeated until a full pass makes no progress. The default order is
+/// removal, then element shrinking, then delta debugging.
 ///
 /// # Examples
 ///
@@ -479,21 +689,118 @@ impl Iterator for TargetedShrinkIterator<f64> {
 /// // Will explore many different shrinking paths
 /// assert!(!shrunk.is_empty());
 /// ```
+///
+/// ```rust
+/// use protest_extras::shrinking::{CascadeStrategy, CascadingShrinker};
+///
+/// let shrinker = CascadingShrinker::with_order(
+///     vec![10, 20, 30, 40, 50],
+///     vec![CascadeStrategy::Removal, CascadeStrategy::ElementShrink],
+/// );
+///
+/// // Fails whenever some element is at least 25
+/// let result = shrinker.find_minimal(|v| v.iter().any(|&x| x >= 25));
+///
+/// assert_eq!(result.minimal, vec![25]);
+/// assert_eq!(result.strategy, Some(CascadeStrategy::ElementShrink));
+/// ```
 #[derive(Debug, Clone)]
 pub struct CascadingShrinker<T> {
     value: T,
+    order: Vec<CascadeStrategy>,
 }
 
 impl<T: Clone> CascadingShrinker<T> {
-    /// Create a new cascading shrinker
+    /// Create a new cascading shrinker with the default cascade order
     pub fn new(value: T) -> Self {
-        Self { value }
+        Self::with_order(
+            value,
+            vec![
+                CascadeStrategy::Removal,
+                CascadeStrategy::ElementShrink,
+                CascadeStrategy::DeltaDebug,
+            ],
+        )
+    }
+
+    /// Create a cascading shrinker that applies strategies in the given order
+    pub fn with_order(value: T, order: Vec<CascadeStrategy>) -> Self {
+        Self { value, order }
     }
 
     /// Get the original value
     pub fn value(&self) -> &T {
         &self.value
     }
+
+    /// Get the cascade order
+    pub fn order(&self) -> &[CascadeStrategy] {
+        &self.order
+    }
+}
+
+impl<T: protest::Shrinkable + Clone + Debug + PartialEq + 'static> CascadingShrinker<Vec<T>> {
+    /// Find the minimal failing case by cascading through the configured strategies
+    ///
+    /// The test function should return `true` if the test fails (i.e., we want to keep shrinking).
+    pub fn find_minimal<F>(&self, test: F) -> CascadeResult<Vec<T>>
+    where
+        F: Fn(&Vec<T>) -> bool,
+    {
+        let mut result = CascadeResult {
+            minimal: self.value.clone(),
+            strategy: None,
+            steps: 0,
+        };
+
+        if !test(&result.minimal) {
+            return result;
+        }
+
+        loop {
+            let mut progressed = false;
+
+            for &strategy in &self.order {
+                while let Some(smaller) = Self::shrink_once(strategy, &result.minimal, &test) {
+                    result.minimal = smaller;
+                    result.strategy = Some(strategy);
+                    result.steps += 1;
+                    progressed = true;
+                }
+            }
+
+            if !progressed {
+                break;
+            }
+        }
+
+        result
+    }
+
+    fn shrink_once<F>(strategy: CascadeStrategy, current: &[T], test: &F) -> Option<Vec<T>>
+    where
+        F: Fn(&Vec<T>) -> bool,
+    {
+        match strategy {
+            CascadeStrategy::Removal => (0..current.len()).find_map(|i| {
+                let mut candidate = current.to_vec();
+                candidate.remove(i);
+                test(&candidate).then_some(candidate)
+            }),
+            CascadeStrategy::ElementShrink => (0..current.len()).find_map(|i| {
+                current[i].shrink().find_map(|element| {
+                    let mut candidate = current.to_vec();
+                    candidate[i] = element;
+                    test(&candidate).then_some(candidate)
+                })
+            }),
+            CascadeStrategy::DeltaDebug => {
+                let minimal = DeltaDebugShrinker::new(current.to_vec())
+                    .find_minimal(|subset| test(&subset.to_vec()));
+                (minimal.len() < current.len()).then_some(minimal)
+            }
+        }
+    }
 }
 
 impl<T: Clone + Debug + 'static> CascadingShrinker<Vec<T>> {
@@ -590,11 +897,20 @@ impl CascadingShrinker<i32> {
 // Guided Shrinking with Test Feedback
 // ============================================================================
 
+/// Cost function used by [`GuidedShrinker::with_cost`]
+type CostFn<T> = Arc<dyn Fn(&T) -> u64 + Send + Sync>;
+
 /// Guided shrinker that uses test feedback to prioritize shrinking directions
 ///
 /// This shrinker runs the test on shrink candidates and uses pass/fail information
 /// to guide the search toward minimal failing cases.
 ///
+/// By default each round greedily takes the first candidate that still fails.
+/// With [`GuidedShrinker::with_cost`] it instead takes the failing candidate with
+/// the lowest cost, which lets you minimize a custom notion of "smaller" such as
+/// the sum of element magnitudes. This is slower, because every candidate of a
+/// round has to be tested rather than stopping at the first failure.
+///
 /// # Examples
 ///
 /// ```rust
@@ -610,10 +926,34 @@ impl CascadingShrinker<i32> {
 /// assert!(minimal.iter().sum::<i32>() > 10);
 /// assert!(minimal.len() < 8);
 /// ```
-#[derive(Debug, Clone)]
+///
+/// ```rust
+/// use protest_extras::shrinking::GuidedShrinker;
+///
+/// let value = vec![9, 1, 7, 2, 8];
+///
+/// // Prefer the failing subset with the smallest total
+/// let shrinker = GuidedShrinker::new(value)
+///     .with_cost(|v: &Vec<i32>| v.iter().map(|x| x.unsigned_abs() as u64).sum());
+/// let minimal = shrinker.find_minimal(|v| v.iter().sum::<i32>() >= 10);
+///
+/// assert_eq!(minimal.iter().sum::<i32>(), 10);
+/// ```
+#[derive(Clone)]
 pub struct GuidedShrinker<T> {
     value: T,
     max_iterations: usize,
+    cost: Option<CostFn<T>>,
+}
+
+impl<T: Debug> Debug for GuidedShrinker<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GuidedShrinker")
+            .field("value", &self.value)
+            .field("max_iterations", &self.max_iterations)
+            .field("cost", &self.cost.as_ref().map(|_| "<cost fn>"))
+            .finish()
+    }
 }
 
 impl<T: Clone> GuidedShrinker<T> {
@@ -622,6 +962,7 @@ impl<T: Clone> GuidedShrinker<T> {
         Self {
             value,
             max_iterations: 1000,
+            cost: None,
         }
     }
 
@@ -630,8 +971,21 @@ impl<T: Clone> GuidedShrinker<T> {
         Self {
             value,
             max_iterations,
+            cost: None,
         }
     }
+
+    /// Prefer the failing candidate with the lowest cost in each round
+    ///
+    /// Every candidate of a round is tested, so this is slower than the default
+    /// first-failure selection. Ties go to the earliest candidate.
+    pub fn with_cost<C>(mut self, cost: C) -> Self
+    where
+        C: Fn(&T) -> u64 + Send + Sync + 'static,
+    {
+        self.cost = Some(Arc::new(cost));
+        self
+    }
 }
 
 impl<T: Clone + Debug + PartialEq + 'static> GuidedShrinker<Vec<T>> {
@@ -643,57 +997,7 @@ impl<T: Clone + Debug + PartialEq + 'static> GuidedShrinker<Vec<T>> {
     where
         F: Fn(&Vec<T>) -> bool,
     {
-        let mut current = self.value.clone();
-
-        if !test(&current) {
-            // Original doesn't fail, return as-is
-            return current;
-        }
-
-        for _ in 0..self.max_iterations {
-            let mut found_smaller = false;
-
-            // Try removing each element
-            for i in (0..current.len()).rev() {
-                let mut candidate = current.clone();
-                candidate.remove(i);
-
-                if test(&candidate) {
-                    // Still fails with this element removed
-                    current = candidate;
-                    found_smaller = true;
-                    break;
-                }
-            }
-
-            if !found_smaller {
-                // Try removing halves
-                if current.len() > 1 {
-                    let half = current.len() / 2;
-
-                    // Try first half
-                    let first_half = current[..half].to_vec();
-                    if test(&first_half) {
-                        current = first_half;
-                        found_smaller = true;
-                    } else {
-                        // Try second half
-                        let second_half = current[half..].to_vec();
-                        if test(&second_half) {
-                            current = second_half;
-                            found_smaller = true;
-                        }
-                    }
-                }
-            }
-
-            if !found_smaller {
-                // Can't shrink further
-                break;
-            }
-        }
-
-        current
+        self.find_minimal_with_stats(test).0
     }
 
     /// Find minimal failing case with detailed feedback
@@ -707,47 +1011,46 @@ impl<T: Clone + Debug + PartialEq + 'static> GuidedShrinker<Vec<T>> {
         let mut iterations = 0;
 
         if !test(&current) {
+            // Original doesn't fail, return as-is
             return (current, iterations);
         }
 
         for _ in 0..self.max_iterations {
             iterations += 1;
-            let mut found_smaller = false;
-
-            for i in (0..current.len()).rev() {
-                let mut candidate = current.clone();
-                candidate.remove(i);
-
-                if test(&candidate) {
-                    current = candidate;
-                    found_smaller = true;
-                    break;
-                }
-            }
-
-            if !found_smaller && current.len() > 1 {
-                let half = current.len() / 2;
-
-                let first_half = current[..half].to_vec();
-                if test(&first_half) {
-                    current = first_half;
-                    found_smaller = true;
-                } else {
-                    let second_half = current[half..].to_vec();
-                    if test(&second_half) {
-                        current = second_half;
-                        found_smaller = true;
-                    }
-                }
-            }
 
-            if !found_smaller {
-                break;
+            match self.next_candidate(&current, &test) {
+                Some(candidate) => current = candidate,
+                // Can't shrink further
+                None => break,
             }
         }
 
         (current, iterations)
     }
+
+    /// Pick the next failing candidate, or `None` if no candidate fails
+    fn next_candidate<F>(&self, current: &[T], test: &F) -> Option<Vec<T>>
+    where
+        F: Fn(&Vec<T>) -> bool,
+    {
+        // Removing each element, then removing halves
+        let removals = (0..current.len()).rev().map(|i| {
+            let mut candidate = current.to_vec();
+            candidate.remove(i);
+            candidate
+        });
+        let half = current.len() / 2;
+        let halves = (current.len() > 1)
+            .then(|| [current[..half].to_vec(), current[half..].to_vec()])
+            .into_iter()
+            .flatten();
+        let mut candidates = removals.chain(halves).filter(|candidate| test(candidate));
+
+        match &self.cost {
+            None => candidates.next(),
+            Some(cost) => candidates.min_by_key(|candidate| cost(candidate)),
+        }
+    }
 }
 
 // ============================================================================
@@ -992,6 +1295,44 @@ mod tests {
         assert_eq!(minimal, vec![3]);
     }
 
+    #[test]
+    fn test_ddmin_canonical_single_element() {
+        // Zeller's example: only the configuration containing change 7 fails
+        let input: Vec<u32> = (1..=8).collect();
+        let tests = std::cell::Cell::new(0);
+
+        let minimal = DeltaDebugShrinker::minimize(input, |subset| {
+            tests.set(tests.get() + 1);
+            subset.contains(&7)
+        });
+
+        assert_eq!(minimal, vec![7]);
+        // Halving converges in a logarithmic number of tests
+        assert!(tests.get() <= 8);
+    }
+
+    #[test]
+    fn test_ddmin_one_minimal() {
+        let input: Vec<u32> = (0..20).collect();
+        let is_interesting =
+            |subset: &[u32]| subset.contains(&3) && subset.contains(&11) && subset.contains(&17);
+
+        let minimal = DeltaDebugShrinker::minimize(input, is_interesting);
+
+        assert_eq!(minimal, vec![3, 11, 17]);
+        for i in 0..minimal.len() {
+            let mut smaller = minimal.clone();
+            smaller.remove(i);
+            assert!(!is_interesting(&smaller));
+        }
+    }
+
+    #[test]
+    fn test_ddmin_uninteresting_input_unchanged() {
+        let minimal = DeltaDebugShrinker::minimize(vec![1, 2, 3], |_| false);
+        assert_eq!(minimal, vec![1, 2, 3]);
+    }
+
     #[test]
     fn test_delta_debug_iterator() {
         let items = vec![1, 2, 3, 4, 5, 6];
@@ -1053,6 +1394,53 @@ mod tests {
         }
     }
 
+    #[derive(Debug, Clone, PartialEq)]
+    enum Op {
+        Push(i32),
+        Pop,
+        Clear,
+    }
+
+    impl protest::Shrinkable for Op {
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            match self {
+                Op::Push(n) => Box::new(n.shrink().map(Op::Push)),
+                Op::Pop | Op::Clear => Box::new(std::iter::empty()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_retaining_shrinker_keeps_pattern() {
+        let ops = vec![
+            Op::Push(40),
+            Op::Pop,
+            Op::Push(7),
+            Op::Clear,
+            Op::Push(3),
+            Op::Pop,
+        ];
+        let has_clear = |ops: &Vec<Op>| ops.contains(&Op::Clear);
+
+        // Without retaining, any single op reproduces the "failure"
+        let unconstrained = CascadingShrinker::new(ops.clone()).find_minimal(|ops| !ops.is_empty());
+        assert!(!has_clear(&unconstrained.minimal));
+
+        let shrinker = TargetedShrinker::retaining(ops, has_clear);
+        let minimal = shrinker.find_minimal(|ops| !ops.is_empty());
+        assert_eq!(minimal, vec![Op::Clear]);
+
+        for candidate in shrinker.shrink() {
+            assert!(has_clear(&candidate));
+        }
+    }
+
+    #[test]
+    fn test_retaining_shrinker_unmatched_original() {
+        let shrinker = TargetedShrinker::retaining(vec![1, 2, 3], |v: &Vec<i32>| v.contains(&9));
+        assert_eq!(shrinker.find_minimal(|_| true), vec![1, 2, 3]);
+    }
+
     #[test]
     fn test_cascading_shrinker_vec() {
         let value = vec![1, 2, 3, 4, 5, 6, 7, 8];
@@ -1136,6 +1524,24 @@ mod tests {
         assert_eq!(minimal, value);
     }
 
+    #[test]
+    fn test_guided_shrinker_with_cost() {
+        let value = vec![9, 1, 7, 2, 8, 3];
+        let cost = |v: &Vec<i32>| v.iter().map(|x| x.unsigned_abs() as u64).sum();
+        let test = |v: &Vec<i32>| v.iter().sum::<i32>() >= 10;
+
+        // First-failure selection strips from the end and keeps the large prefix
+        let plain = GuidedShrinker::new(value.clone()).find_minimal(test);
+        assert_eq!(plain, vec![9, 1]);
+
+        // Cost-guided selection keeps the subset with the smallest total
+        let guided = GuidedShrinker::new(value)
+            .with_cost(cost)
+            .find_minimal(test);
+        assert!(test(&guided));
+        assert_eq!(guided.iter().sum::<i32>(), 10);
+    }
+
     #[test]
     fn test_configurable_shrinker_depth_first() {
         let value = vec![1, 2, 3, 4, 5, 6, 7, 8];
@@ -1195,4 +1601,56 @@ mod tests {
         // Should include adjacent pairs
         assert!(shrunk.iter().any(|v| v.len() == 2));
     }
+
+    #[test]
+    fn test_cascading_shrinker_default_order() {
+        let shrinker = CascadingShrinker::new(vec![1]);
+        assert_eq!(
+            shrinker.order(),
+            &[
+                CascadeStrategy::Removal,
+                CascadeStrategy::ElementShrink,
+                CascadeStrategy::DeltaDebug
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cascading_shrinker_find_minimal_reports_strategy() {
+        let shrinker = CascadingShrinker::new(vec![3, 8, 1, 20, 7]);
+
+        let result = shrinker.find_minimal(|v| v.iter().sum::<i32>() >= 10);
+
+        assert_eq!(result.minimal, vec![10]);
+        assert_eq!(result.strategy, Some(CascadeStrategy::ElementShrink));
+        assert!(result.steps > 0);
+    }
+
+    #[test]
+    fn test_cascading_shrinker_custom_order() {
+        let value = vec![1, 2, 3, 4, 5, 6, 7, 8];
+
+        // Delta debugging alone removes everything but the culprit
+        let shrinker =
+            CascadingShrinker::with_order(value.clone(), vec![CascadeStrategy::DeltaDebug]);
+        let result = shrinker.find_minimal(|v| v.contains(&6));
+        assert_eq!(result.minimal, vec![6]);
+        assert_eq!(result.strategy, Some(CascadeStrategy::DeltaDebug));
+
+        // Element shrinking alone cannot change the length
+        let shrinker = CascadingShrinker::with_order(value, vec![CascadeStrategy::ElementShrink]);
+        let result = shrinker.find_minimal(|v| v.len() >= 3);
+        assert_eq!(result.minimal, vec![0; 8]);
+    }
+
+    #[test]
+    fn test_cascading_shrinker_no_progress() {
+        let shrinker = CascadingShrinker::new(vec![1, 2, 3]);
+
+        let result = shrinker.find_minimal(|_| false);
+
+        assert_eq!(result.minimal, vec![1, 2, 3]);
+        assert_eq!(result.strategy, None);
+        assert_eq!(result.steps, 0);
+    }
 }
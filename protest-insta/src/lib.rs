@@ -114,6 +114,8 @@ use std::fmt::Debug;
 ///
 /// This struct provides convenient methods for creating multiple snapshots
 /// in property-based tests. Each snapshot gets an automatic sequential name.
+/// The `*_named` methods take an explicit suffix instead, and the `*_for_input`
+/// methods derive the suffix from the input the snapshot was produced from.
 ///
 /// # Example
 ///
@@ -173,6 +175,30 @@ impl PropertySnapshots {
         name
     }
 
+    /// Get the snapshot name for an explicit suffix
+    fn named(&self, suffix: &str) -> String {
+        format!("{}_{}", self.base_name, suffix)
+    }
+
+    /// The snapshot suffix for an input: a 64-bit FNV-1a hash of its Debug form
+    ///
+    /// The suffix only depends on the input, so a snapshot keeps its name when
+    /// assertions are inserted or reordered, and a changed output for the same
+    /// input shows up as a diff of the existing snapshot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use protest_insta::PropertySnapshots;
+    ///
+    /// let suffix = PropertySnapshots::input_suffix(&(3, "abc"));
+    /// assert_eq!(suffix, PropertySnapshots::input_suffix(&(3, "abc")));
+    /// assert_ne!(suffix, PropertySnapshots::input_suffix(&(4, "abc")));
+    /// ```
+    pub fn input_suffix<I: Debug + ?Sized>(input: &I) -> String {
+        format!("{:016x}", stable_hash(&format!("{:?}", input)))
+    }
+
     /// Assert a JSON snapshot for the given value
     ///
     /// This serializes the value to pretty-printed JSON and creates a snapshot.
@@ -201,6 +227,53 @@ impl PropertySnapshots {
         insta::assert_json_snapshot!(name, value);
     }
 
+    /// Assert a JSON snapshot named `{base_name}_{suffix}`
+    ///
+    /// The name does not depend on the counter, so it is stable regardless of
+    /// how many snapshots were taken before it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use protest_insta::PropertySnapshots;
+    ///
+    /// # fn test() {
+    /// let snapshots = PropertySnapshots::new("config");
+    /// // Creates the snapshot config_defaults
+    /// snapshots.assert_json_snapshot_named("defaults", &vec![1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn assert_json_snapshot_named<T: Serialize>(&self, suffix: &str, value: &T) {
+        let name = self.named(suffix);
+        insta::assert_json_snapshot!(name, value);
+    }
+
+    /// Assert a JSON snapshot of `value`, named after the `input` it was produced from
+    ///
+    /// The snapshot is named `{base_name}_{suffix}` with the suffix from
+    /// [`input_suffix`](Self::input_suffix).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use protest_insta::PropertySnapshots;
+    ///
+    /// # fn test() {
+    /// let snapshots = PropertySnapshots::new("sorting");
+    /// let input = vec![3, 1, 2];
+    /// let mut output = input.clone();
+    /// output.sort();
+    /// snapshots.assert_json_snapshot_for_input(&input, &output);
+    /// # }
+    /// ```
+    pub fn assert_json_snapshot_for_input<I: Debug + ?Sized, T: Serialize>(
+        &self,
+        input: &I,
+        value: &T,
+    ) {
+        self.assert_json_snapshot_named(&Self::input_suffix(input), value);
+    }
+
     /// Assert a debug snapshot for the given value
     ///
     /// This uses the Debug trait to format the value and creates a snapshot.
@@ -225,6 +298,21 @@ impl PropertySnapshots {
         insta::assert_debug_snapshot!(name, value);
     }
 
+    /// Assert a debug snapshot named `{base_name}_{suffix}`
+    pub fn assert_debug_snapshot_named<T: Debug>(&self, suffix: &str, value: &T) {
+        let name = self.named(suffix);
+        insta::assert_debug_snapshot!(name, value);
+    }
+
+    /// Assert a debug snapshot of `value`, named after the `input` it was produced from
+    pub fn assert_debug_snapshot_for_input<I: Debug + ?Sized, T: Debug>(
+        &self,
+        input: &I,
+        value: &T,
+    ) {
+        self.assert_debug_snapshot_named(&Self::input_suffix(input), value);
+    }
+
     /// Assert a YAML snapshot for the given value
     ///
     /// This serializes the value to YAML and creates a snapshot.
@@ -253,6 +341,71 @@ impl PropertySnapshots {
         insta::assert_yaml_snapshot!(name, value);
     }
 
+    /// Assert a YAML snapshot named `{base_name}_{suffix}`
+    pub fn assert_yaml_snapshot_named<T: Serialize>(&self, suffix: &str, value: &T) {
+        let name = self.named(suffix);
+        insta::assert_yaml_snapshot!(name, value);
+    }
+
+    /// Assert a YAML snapshot of `value`, named after the `input` it was produced from
+    pub fn assert_yaml_snapshot_for_input<I: Debug + ?Sized, T: Serialize>(
+        &self,
+        input: &I,
+        value: &T,
+    ) {
+        self.assert_yaml_snapshot_named(&Self::input_suffix(input), value);
+    }
+
+    /// Assert a RON snapshot for the given value
+    ///
+    /// This serializes the value to RON and creates a snapshot. Requires the
+    /// `ron` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use protest_insta::PropertySnapshots;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Settings { debug: bool, timeout: u64 }
+    ///
+    /// let mut snapshots = PropertySnapshots::new("settings");
+    /// snapshots.assert_ron_snapshot(&Settings { debug: true, timeout: 30 });
+    /// ```
+    #[cfg(feature = "ron")]
+    pub fn assert_ron_snapshot<T: Serialize>(&mut self, value: &T) {
+        let name = self.next_name();
+        insta::assert_ron_snapshot!(name, value);
+    }
+
+    /// Assert a CSV snapshot for the given records
+    ///
+    /// Each record is serialized as one CSV row, in order, with a header row
+    /// taken from the field names. Requires the `csv` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use protest_insta::PropertySnapshots;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Row { name: String, total: u32 }
+    ///
+    /// let mut snapshots = PropertySnapshots::new("report");
+    /// let rows = vec![
+    ///     Row { name: "a".to_string(), total: 3 },
+    ///     Row { name: "b".to_string(), total: 5 },
+    /// ];
+    /// snapshots.assert_csv_snapshot(&rows);
+    /// ```
+    #[cfg(feature = "csv")]
+    pub fn assert_csv_snapshot<T: Serialize>(&mut self, records: &[T]) {
+        let name = self.next_name();
+        insta::assert_csv_snapshot!(name, records);
+    }
+
     /// Reset the counter back to 0
     ///
     /// Useful if you want to reuse the same PropertySnapshots instance
@@ -287,6 +440,13 @@ impl PropertySnapshots {
     }
 }
 
+/// 64-bit FNV-1a hash, stable across platforms and Rust versions
+fn stable_hash(content: &str) -> u64 {
+    content.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
 /// Helper function to run a property-based test with snapshot assertions
 ///
 /// This function generates multiple inputs using the provided generator
@@ -335,6 +495,76 @@ pub fn property_snapshot_test<T, G, F>(
 ) where
     G: protest::Generator<T>,
     F: FnMut(T, &mut PropertySnapshots),
+{
+    let mut snapshots = PropertySnapshots::new(test_name);
+    run_snapshot_samples(&generator, sample_count, seed, &mut snapshots, &mut test_fn);
+}
+
+/// Run a property-based snapshot test once per seed
+///
+/// Like [`property_snapshot_test`], but repeats the run for every seed in
+/// `seeds`. Snapshots for each seed are named `{test_name}_seed{seed}_{n}`,
+/// so adding or removing a seed never renames the snapshots of the others.
+///
+/// # Arguments
+///
+/// * `test_name` - Base name for the snapshots
+/// * `generator` - Generator for creating test inputs
+/// * `samples_per_seed` - Number of samples to generate for each seed
+/// * `seeds` - RNG seeds to sweep
+/// * `test_fn` - Function to test each generated value
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use protest::primitives::IntGenerator;
+/// use protest_insta::property_snapshot_test_seeds;
+///
+/// #[test]
+/// fn test_squaring_regressions() {
+///     property_snapshot_test_seeds(
+///         "square_function",
+///         IntGenerator::new(1, 10),
+///         3,
+///         &[1, 42, 1337],
+///         |value, snapshots| {
+///             snapshots.assert_debug_snapshot(&(value * value));
+///         }
+///     );
+/// }
+/// ```
+pub fn property_snapshot_test_seeds<T, G, F>(
+    test_name: &str,
+    generator: G,
+    samples_per_seed: usize,
+    seeds: &[u64],
+    mut test_fn: F,
+) where
+    G: protest::Generator<T>,
+    F: FnMut(T, &mut PropertySnapshots),
+{
+    for &seed in seeds {
+        let mut snapshots = PropertySnapshots::new(format!("{}_seed{}", test_name, seed));
+        run_snapshot_samples(
+            &generator,
+            samples_per_seed,
+            seed,
+            &mut snapshots,
+            &mut test_fn,
+        );
+    }
+}
+
+/// Generate `sample_count` values from a seeded RNG and pass each to `test_fn`
+fn run_snapshot_samples<T, G, F>(
+    generator: &G,
+    sample_count: usize,
+    seed: u64,
+    snapshots: &mut PropertySnapshots,
+    test_fn: &mut F,
+) where
+    G: protest::Generator<T>,
+    F: FnMut(T, &mut PropertySnapshots),
 {
     use protest::config::GeneratorConfig;
     use rand::SeedableRng;
@@ -342,11 +572,10 @@ pub fn property_snapshot_test<T, G, F>(
 
     let mut rng = StdRng::seed_from_u64(seed);
     let config = GeneratorConfig::default();
-    let mut snapshots = PropertySnapshots::new(test_name);
 
     for _ in 0..sample_count {
         let value = generator.generate(&mut rng, &config);
-        test_fn(value, &mut snapshots);
+        test_fn(value, snapshots);
     }
 }
 
@@ -373,6 +602,68 @@ mod tests {
         assert_eq!(snapshots.next_name(), "test_0");
     }
 
+    #[test]
+    fn test_explicit_suffix_naming() {
+        let mut snapshots = PropertySnapshots::new("test");
+        snapshots.next_name();
+        assert_eq!(snapshots.named("empty_input"), "test_empty_input");
+        assert_eq!(snapshots.count(), 1);
+    }
+
+    #[test]
+    fn test_input_suffix_is_input_based() {
+        let first = PropertySnapshots::input_suffix(&vec![1, 2, 3]);
+        let other = PropertySnapshots::input_suffix("other");
+        let again = PropertySnapshots::input_suffix(&vec![1, 2, 3]);
+
+        assert_eq!(first, again);
+        assert_ne!(first, other);
+        assert_eq!(first, format!("{:016x}", stable_hash("[1, 2, 3]")));
+
+        // Input-based names do not use the counter
+        let snapshots = PropertySnapshots::new("test");
+        assert_eq!(snapshots.named(&first), format!("test_{}", first));
+        assert_eq!(snapshots.count(), 0);
+    }
+
+    #[test]
+    fn test_stable_hash_matches_fnv1a() {
+        assert_eq!(stable_hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(stable_hash("a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_seed_sweep_names_snapshots_per_seed() {
+        use protest::primitives::IntGenerator;
+
+        let mut seen = Vec::new();
+        property_snapshot_test_seeds(
+            "sweep",
+            IntGenerator::new(0, 1000),
+            2,
+            &[7, 99],
+            |value, snapshots| seen.push((snapshots.next_name(), value)),
+        );
+
+        let names: Vec<_> = seen.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "sweep_seed7_0",
+                "sweep_seed7_1",
+                "sweep_seed99_0",
+                "sweep_seed99_1"
+            ]
+        );
+
+        // Each seed reproduces the same values as a single-seed run
+        let mut single = Vec::new();
+        property_snapshot_test("sweep", IntGenerator::new(0, 1000), 2, 99, |value, _| {
+            single.push(value)
+        });
+        assert_eq!(single, vec![seen[2].1, seen[3].1]);
+    }
+
     #[test]
     fn test_snapshot_count() {
         let mut snapshots = PropertySnapshots::new("test");
@@ -101,6 +101,12 @@
 use protest::{Generator, config::GeneratorConfig};
 use rand::RngCore;
 
+#[cfg(feature = "proptest")]
+mod proptest_bridge;
+
+#[cfg(feature = "proptest")]
+pub use proptest_bridge::ShrinkableValueTree;
+
 /// A helper function to convert a proptest-style range into a Protest generator
 ///
 /// This makes migration easier for simple integer range strategies.
@@ -124,6 +130,22 @@ where
 /// Adapter to wrap a Protest generator with proptest-like interface
 ///
 /// This can help when you have code that expects proptest's Strategy trait.
+/// With the `proptest` feature enabled the adapter implements
+/// `proptest::strategy::Strategy`, shrinking via Protest's `Shrinkable`, so it
+/// can be used directly in `proptest!` blocks:
+///
+/// ```rust,ignore
+/// use proptest::prelude::*;
+/// use protest::primitives::IntGenerator;
+/// use protest_proptest_compat::GeneratorAdapter;
+///
+/// proptest! {
+///     #[test]
+///     fn test_small(x in GeneratorAdapter::new(IntGenerator::new(0, 100))) {
+///         prop_assert!(x <= 100);
+///     }
+/// }
+/// ```
 pub struct GeneratorAdapter<G, T> {
     generator: G,
     _phantom: std::marker::PhantomData<T>,
@@ -148,6 +170,14 @@ impl<G, T> GeneratorAdapter<G, T> {
     }
 }
 
+impl<G, T> std::fmt::Debug for GeneratorAdapter<G, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GeneratorAdapter")
+            .field("value_type", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
 /// Helper to create a vector generator (common migration pattern)
 ///
 /// # Example
@@ -191,6 +221,168 @@ where
     protest::primitives::OptionGenerator::with_probability(inner, some_probability)
 }
 
+/// Helper to create a string generator from a regex (proptest's `"[a-z]{1,10}"`)
+///
+/// Proptest treats a `&str` as a strategy for strings matching it as a
+/// regular expression. This returns the equivalent Protest generator.
+///
+/// # Panics
+///
+/// Panics if the pattern is invalid or cannot be generated, as proptest does.
+/// Use `RegexGenerator::new` to handle the error instead.
+///
+/// # Example
+///
+/// ```rust
+/// use protest_proptest_compat::regex_strategy;
+///
+/// // Proptest style: "[a-z]{1,10}"
+/// let generator = regex_strategy("[a-z]{1,10}");
+/// ```
+pub fn regex_strategy(pattern: &str) -> protest_extras::generators::text::RegexGenerator {
+    protest_extras::generators::text::RegexGenerator::new(pattern)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Helper to create a character generator for an inclusive range
+///
+/// # Example
+///
+/// ```rust
+/// use protest_proptest_compat::char_range;
+///
+/// // Proptest style: prop::char::range('a', 'z')
+/// let generator = char_range('a', 'z');
+/// ```
+pub fn char_range(lo: char, hi: char) -> protest::primitives::CharGenerator {
+    protest::primitives::CharGenerator::with_ranges(vec![(lo, hi)])
+}
+
+/// Default number of rejected values before [`prop_filter`] gives up
+pub const DEFAULT_MAX_REJECTIONS: usize = 1000;
+
+/// Map the values of a generator, mirroring proptest's `prop_map`
+///
+/// `strategy.prop_map(f)` becomes `prop_map(generator, f)` with the same
+/// closure. Mapped values are not shrunk, since the mapping cannot be
+/// inverted; use `protest::Strategy::map` when shrinking through the map
+/// matters.
+///
+/// # Example
+///
+/// ```rust
+/// use protest_proptest_compat::prop_map;
+/// use protest::primitives::IntGenerator;
+///
+/// // Proptest style: (0..100i32).prop_map(|x| x * 2)
+/// let evens = prop_map(IntGenerator::new(0, 100), |x: i32| x * 2);
+/// ```
+pub fn prop_map<T, U, G, F>(generator: G, f: F) -> PropMap<G, T, F>
+where
+    G: Generator<T>,
+    F: Fn(T) -> U,
+{
+    PropMap {
+        generator,
+        mapper: f,
+        _phantom: std::marker::PhantomData,
+    }
+}
+
+/// Generator returned by [`prop_map`]
+pub struct PropMap<G, T, F> {
+    generator: G,
+    mapper: F,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<G, T, U, F> Generator<U> for PropMap<G, T, F>
+where
+    G: Generator<T>,
+    F: Fn(T) -> U,
+    U: 'static,
+{
+    fn generate(&self, rng: &mut dyn RngCore, config: &GeneratorConfig) -> U {
+        (self.mapper)(self.generator.generate(rng, config))
+    }
+
+    fn shrink(&self, _value: &U) -> Box<dyn Iterator<Item = U>> {
+        Box::new(std::iter::empty())
+    }
+}
+
+/// Filter the values of a generator, mirroring proptest's `prop_filter`
+///
+/// `strategy.prop_filter("reason", f)` becomes `prop_filter(generator, f)`
+/// with the same closure. Shrink candidates are filtered by the predicate too.
+///
+/// Rejected values are regenerated, up to [`DEFAULT_MAX_REJECTIONS`] times
+/// per value (see [`PropFilter::with_max_rejections`]). If no value passes
+/// within that budget, generation panics rather than looping forever, so an
+/// over-restrictive predicate fails fast. Prefer generators that only produce
+/// valid values when most values would be rejected.
+///
+/// # Example
+///
+/// ```rust
+/// use protest_proptest_compat::prop_filter;
+/// use protest::primitives::IntGenerator;
+///
+/// // Proptest style: (0..100i32).prop_filter("odd", |x| x % 2 == 1)
+/// let odds = prop_filter(IntGenerator::new(0, 100), |x: &i32| x % 2 == 1);
+/// ```
+pub fn prop_filter<T, G, F>(generator: G, predicate: F) -> PropFilter<G, F>
+where
+    G: Generator<T>,
+    F: Fn(&T) -> bool,
+{
+    PropFilter {
+        generator,
+        predicate: std::sync::Arc::new(predicate),
+        max_rejections: DEFAULT_MAX_REJECTIONS,
+    }
+}
+
+/// Generator returned by [`prop_filter`]
+pub struct PropFilter<G, F> {
+    generator: G,
+    predicate: std::sync::Arc<F>,
+    max_rejections: usize,
+}
+
+impl<G, F> PropFilter<G, F> {
+    /// Set how many values may be rejected before generation panics
+    pub fn with_max_rejections(mut self, max_rejections: usize) -> Self {
+        self.max_rejections = max_rejections;
+        self
+    }
+}
+
+impl<G, T, F> Generator<T> for PropFilter<G, F>
+where
+    G: Generator<T>,
+    F: Fn(&T) -> bool + 'static,
+    T: 'static,
+{
+    fn generate(&self, rng: &mut dyn RngCore, config: &GeneratorConfig) -> T {
+        for _ in 0..=self.max_rejections {
+            let value = self.generator.generate(rng, config);
+            if (self.predicate)(&value) {
+                return value;
+            }
+        }
+        panic!(
+            "prop_filter rejected {} values in a row; the predicate is too restrictive for this generator",
+            self.max_rejections + 1
+        );
+    }
+
+    fn shrink(&self, value: &T) -> Box<dyn Iterator<Item = T>> {
+        let predicate = std::sync::Arc::clone(&self.predicate);
+        Box::new(self.generator.shrink(value).filter(move |v| predicate(v)))
+    }
+}
+
 /// Migration checklist and common patterns
 ///
 /// Use this as a reference when migrating tests:
@@ -205,14 +397,16 @@ where
 /// - `0..100i32` → `generator!(i32, 0, 100)` or `IntGenerator::new(0, 100)`
 /// - `Vec<i32>` → `VecGenerator::new(IntGenerator::new(...), min, max)`
 /// - `prop::option::of(...)` → `OptionGenerator::new(..., probability)`
+/// - `"[a-z]{1,10}"` → [`regex_strategy`]`("[a-z]{1,10}")`
+/// - `prop::char::range('a', 'z')` → [`char_range`]`('a', 'z')`
 ///
 /// ## 4. Replace assertions
 /// - Properties return `bool` or use `assert!` inside
 /// - Proptest style: `prop_assert!` → Protest style: `assert!`
 ///
 /// ## 5. Configuration
-/// - Proptest: `.prop_map()` → Protest: `.map()` on generators
-/// - Proptest: `.prop_filter()` → Protest: `.filter()` or preconditions in property
+/// - Proptest: `.prop_map()` → Protest: `.map()` on generators, or [`prop_map`]
+/// - Proptest: `.prop_filter()` → Protest: `.filter()` or preconditions in property, or [`prop_filter`]
 ///
 /// ## 6. Shrinking
 /// - Protest has automatic shrinking built-in, no special configuration needed
@@ -267,6 +461,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_regex_strategy() {
+        let generator = regex_strategy("[a-z]{1,10}");
+        let mut rng = StdRng::seed_from_u64(42);
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let value = generator.generate(&mut rng, &config);
+            assert!((1..=10).contains(&value.len()));
+            assert!(value.chars().all(|c| c.is_ascii_lowercase()));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid regex `[a-z`")]
+    fn test_regex_strategy_panics_on_invalid_pattern() {
+        regex_strategy("[a-z");
+    }
+
+    #[test]
+    fn test_char_range() {
+        let generator = char_range('a', 'f');
+        let mut rng = StdRng::seed_from_u64(42);
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            assert!(('a'..='f').contains(&generator.generate(&mut rng, &config)));
+        }
+    }
+
+    #[test]
+    fn test_prop_map_applies_closure() {
+        let generator = prop_map(IntGenerator::new(0, 50), |x: i32| x * 2);
+        let mut rng = StdRng::seed_from_u64(42);
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let value = generator.generate(&mut rng, &config);
+            assert!(value % 2 == 0 && (0..=100).contains(&value));
+        }
+        assert_eq!(generator.shrink(&10).count(), 0);
+    }
+
+    #[test]
+    fn test_prop_filter_generates_and_shrinks_matching_values() {
+        let generator = prop_filter(IntGenerator::new(0, 100), |x: &i32| x % 2 == 1);
+        let mut rng = StdRng::seed_from_u64(42);
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            assert_eq!(generator.generate(&mut rng, &config) % 2, 1);
+        }
+        assert!(generator.shrink(&99).all(|x| x % 2 == 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "prop_filter rejected 11 values in a row")]
+    fn test_prop_filter_gives_up_after_max_rejections() {
+        let generator =
+            prop_filter(IntGenerator::new(0, 100), |_: &i32| false).with_max_rejections(10);
+        let mut rng = StdRng::seed_from_u64(42);
+        generator.generate(&mut rng, &GeneratorConfig::default());
+    }
+
     #[test]
     fn test_option_generator_helper() {
         let generator = option_generator(IntGenerator::new(0, 100), 0.5);
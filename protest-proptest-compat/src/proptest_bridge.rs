@@ -0,0 +1,169 @@
+//! Bridge from Protest generators to proptest's `Strategy` trait
+//!
+//! Enabled by the `proptest` feature. [`GeneratorAdapter`] implements
+//! [`proptest::strategy::Strategy`], so a Protest generator can be used inside
+//! existing `proptest!` blocks while a test suite is migrated incrementally.
+
+use crate::GeneratorAdapter;
+// proptest's `TestRng` implements `Rng` from the rand version proptest re-exports
+use proptest::prelude::Rng as _;
+use proptest::strategy::{NewTree, Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+use protest::{Generator, Shrinkable, config::GeneratorConfig};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::fmt;
+
+impl<G, T> Strategy for GeneratorAdapter<G, T>
+where
+    G: Generator<T>,
+    T: Shrinkable + Clone + fmt::Debug + 'static,
+{
+    type Tree = ShrinkableValueTree<T>;
+    type Value = T;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        // Seed from proptest's RNG so runs stay reproducible under its seed;
+        // Protest's own rand version only sees the derived `StdRng`
+        let mut rng = StdRng::seed_from_u64(runner.rng().next_u64());
+        let value = self
+            .generator
+            .generate(&mut rng, &GeneratorConfig::default());
+        Ok(ShrinkableValueTree::new(value))
+    }
+}
+
+/// A proptest [`ValueTree`] that shrinks using Protest's [`Shrinkable`]
+///
+/// `simplify` moves to the first shrink candidate of the current failing
+/// value. When that candidate passes, `complicate` moves on to the next
+/// candidate of the same failing value, and returns `false` once they are
+/// exhausted, leaving the last failing value as the minimal one.
+pub struct ShrinkableValueTree<T> {
+    current: T,
+    last_failing: Option<T>,
+    candidates: Box<dyn Iterator<Item = T>>,
+}
+
+impl<T: Shrinkable + 'static> ShrinkableValueTree<T> {
+    /// Create a value tree rooted at `value`
+    pub fn new(value: T) -> Self {
+        Self {
+            current: value,
+            last_failing: None,
+            candidates: Box::new(std::iter::empty()),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ShrinkableValueTree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShrinkableValueTree")
+            .field("current", &self.current)
+            .field("last_failing", &self.last_failing)
+            .finish()
+    }
+}
+
+impl<T> ValueTree for ShrinkableValueTree<T>
+where
+    T: Shrinkable + Clone + fmt::Debug + 'static,
+{
+    type Value = T;
+
+    fn current(&self) -> T {
+        self.current.clone()
+    }
+
+    fn simplify(&mut self) -> bool {
+        let mut candidates = self.current.shrink();
+        match candidates.next() {
+            Some(candidate) => {
+                self.last_failing = Some(std::mem::replace(&mut self.current, candidate));
+                self.candidates = candidates;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self.candidates.next() {
+            Some(candidate) => {
+                self.current = candidate;
+                true
+            }
+            None => {
+                if let Some(failing) = self.last_failing.take() {
+                    self.current = failing;
+                }
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::test_runner::TestError;
+    use protest::primitives::IntGenerator;
+
+    #[test]
+    fn test_new_tree_uses_wrapped_generator() {
+        let adapter = GeneratorAdapter::new(IntGenerator::new(10, 20));
+        let mut runner = TestRunner::deterministic();
+
+        for _ in 0..20 {
+            let tree = adapter.new_tree(&mut runner).unwrap();
+            assert!((10..=20).contains(&tree.current()));
+        }
+    }
+
+    #[test]
+    fn test_value_tree_simplify_and_complicate() {
+        let mut tree = ShrinkableValueTree::new(100i32);
+
+        assert!(tree.simplify());
+        let first = tree.current();
+        assert!(first.abs() < 100);
+
+        // Every candidate passes: complicate walks the siblings, then restores
+        // the last failing value
+        while tree.complicate() {
+            assert_ne!(tree.current(), 100);
+        }
+        assert_eq!(tree.current(), 100);
+    }
+
+    #[test]
+    fn test_value_tree_without_candidates() {
+        let mut tree = ShrinkableValueTree::new(0i32);
+        assert!(!tree.simplify());
+        assert!(!tree.complicate());
+        assert_eq!(tree.current(), 0);
+    }
+
+    #[test]
+    fn test_runner_shrinks_with_protest_shrinking() {
+        let adapter = GeneratorAdapter::new(IntGenerator::new(0, 1000));
+        let mut runner = TestRunner::deterministic();
+
+        let result = runner.run(&adapter, |v: i32| {
+            if v < 50 {
+                Ok(())
+            } else {
+                Err(proptest::test_runner::TestCaseError::fail("too big"))
+            }
+        });
+
+        match result {
+            Err(TestError::Fail(_, minimal)) => {
+                // The minimal value still fails, and none of its shrinks do
+                assert!(minimal >= 50);
+                assert!(minimal.shrink().all(|candidate| candidate < 50));
+            }
+            other => panic!("expected a shrunk failure, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Runs Protest generators inside `proptest!` blocks
+//!
+//! Only built with `--features proptest`.
+
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::{Config, TestError, TestRunner};
+use protest::primitives::{IntGenerator, VecGenerator};
+use protest_proptest_compat::GeneratorAdapter;
+
+proptest! {
+    #[test]
+    fn adapter_values_stay_in_range(x in GeneratorAdapter::new(IntGenerator::new(0, 100))) {
+        prop_assert!((0..=100).contains(&x));
+    }
+
+    #[test]
+    fn adapter_composes_with_proptest_strategies(
+        items in GeneratorAdapter::new(VecGenerator::new(IntGenerator::new(0, 9), 0, 10)),
+        extra in 0u8..10,
+    ) {
+        prop_assert!(items.len() <= 10);
+        prop_assert!(extra < 10);
+    }
+}
+
+#[test]
+fn failing_property_shrinks_through_protest() {
+    let adapter = GeneratorAdapter::new(IntGenerator::new(0, 10_000));
+    let mut runner = TestRunner::new(Config::with_cases(256));
+
+    let result = runner.run(&adapter, |v: i32| {
+        prop_assert!(v < 500);
+        Ok(())
+    });
+
+    match result {
+        Err(TestError::Fail(_, minimal)) => assert!(minimal >= 500),
+        other => panic!("expected a failure, got {:?}", other),
+    }
+}
+
+#[test]
+fn same_seed_generates_same_values() {
+    let adapter = GeneratorAdapter::new(IntGenerator::new(0, 1_000_000));
+    let values = |runner: &mut TestRunner| {
+        (0..10)
+            .map(|_| adapter.new_tree(runner).unwrap().current())
+            .collect::<Vec<i32>>()
+    };
+
+    assert_eq!(
+        values(&mut TestRunner::deterministic()),
+        values(&mut TestRunner::deterministic())
+    );
+}
@@ -3,6 +3,11 @@
 //! Test parallel operations on concurrent data structures
 
 pub mod linearizability;
+pub mod schedule;
+
+pub use schedule::{
+    InterleavedRun, Interleaving, Schedule, explore_interleavings, run_interleaved, yield_point,
+};
 
 use crate::operations::Operation;
 use std::fmt::Debug;
@@ -37,6 +42,13 @@ impl Default for ConcurrentConfig {
 }
 
 /// Run operations concurrently and check for race conditions
+///
+/// Threads are scheduled by the OS, so failures may not reproduce. Use
+/// [`run_interleaved`] for a deterministic, replayable interleaving.
+///
+/// A panicking operation stops its thread, and the failure names it in
+/// [`ConcurrentTestFailure::panic`]. Panics from other threads unwrapping the
+/// mutex it poisoned are not blamed.
 pub fn run_concurrent<Op>(
     initial_state: Op::State,
     operations: Vec<Vec<Op>>,
@@ -47,14 +59,19 @@ where
     Op::State: Send + Clone,
 {
     let state = Arc::new(Mutex::new(initial_state));
+    let panics = Arc::new(Mutex::new(Vec::new()));
     let mut handles = vec![];
 
     // Spawn threads
-    for thread_ops in operations {
+    for (thread, thread_ops) in operations.into_iter().enumerate() {
         let state_clone = Arc::clone(&state);
+        let panics = Arc::clone(&panics);
         let handle = thread::spawn(move || {
-            for op in thread_ops {
-                op.execute_concurrent(&state_clone);
+            for (index, op) in thread_ops.iter().enumerate() {
+                if let Err(panic) = execute_caught(thread, index, op, &state_clone) {
+                    panics.lock().unwrap().push(panic);
+                    break;
+                }
             }
         });
         handles.push(handle);
@@ -64,31 +81,129 @@ where
     for handle in handles {
         handle.join().map_err(|_| ConcurrentTestFailure {
             description: "Thread panicked during execution".to_string(),
+            schedule: None,
+            panic: None,
         })?;
     }
 
+    let panics = std::mem::take(&mut *panics.lock().unwrap());
+    if let Some(panic) = blame(panics) {
+        return Err(ConcurrentTestFailure::from_panic(panic, None));
+    }
+
     // Extract final state
     let final_state = Arc::try_unwrap(state)
         .map_err(|_| ConcurrentTestFailure {
             description: "Could not unwrap Arc".to_string(),
+            schedule: None,
+            panic: None,
         })?
         .into_inner()
         .map_err(|_| ConcurrentTestFailure {
             description: "Mutex poisoned".to_string(),
+            schedule: None,
+            panic: None,
         })?;
 
     Ok(final_state)
 }
 
+/// Execute one operation, catching a panic as an [`OperationPanic`]
+pub(crate) fn execute_caught<Op: ConcurrentOperation>(
+    thread: usize,
+    index: usize,
+    op: &Op,
+    state: &Arc<Mutex<Op::State>>,
+) -> Result<(), OperationPanic> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        op.execute_concurrent(state)
+    }))
+    .map_err(|payload| OperationPanic {
+        thread,
+        index,
+        operation: format!("{:?}", op),
+        message: panic_message(payload.as_ref()),
+    })
+}
+
+/// The panic that failed a run: the first one not caused by a poisoned mutex
+///
+/// A panicking operation poisons the state mutex while unwinding, so other
+/// threads may record the resulting `PoisonError` panics before it.
+pub(crate) fn blame(panics: Vec<OperationPanic>) -> Option<OperationPanic> {
+    let first = panics.first().cloned();
+    panics
+        .into_iter()
+        .find(|panic| !panic.message.contains("PoisonError"))
+        .or(first)
+}
+
+/// Readable message from a panic payload
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// An operation that panicked during a concurrent run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationPanic {
+    /// Index of the thread that ran the operation
+    pub thread: usize,
+    /// Position of the operation in its thread's operation list
+    pub index: usize,
+    /// The operation's `Debug` representation
+    pub operation: String,
+    /// The panic message
+    pub message: String,
+}
+
+impl std::fmt::Display for OperationPanic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Operation {} (thread {}, operation {}) panicked: {}",
+            self.operation, self.thread, self.index, self.message
+        )
+    }
+}
+
 /// Represents a failure in concurrent testing
 #[derive(Debug)]
 pub struct ConcurrentTestFailure {
     pub description: String,
+    /// Interleaving that produced the failure, for runs under a [`Schedule`]
+    pub schedule: Option<Schedule>,
+    /// The operation whose panic failed the run, if one did
+    pub panic: Option<OperationPanic>,
+}
+
+impl ConcurrentTestFailure {
+    /// Failure caused by a panicking operation
+    pub(crate) fn from_panic(panic: OperationPanic, schedule: Option<Schedule>) -> Self {
+        Self {
+            description: panic.to_string(),
+            schedule,
+            panic: Some(panic),
+        }
+    }
 }
 
 impl std::fmt::Display for ConcurrentTestFailure {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Concurrent test failure: {}", self.description)
+        write!(f, "Concurrent test failure: {}", self.description)?;
+        if let Some(schedule) = &self.schedule {
+            write!(
+                f,
+                "\n  schedule: {} (replay with Interleaving::Replay(\"{}\".parse()?))",
+                schedule, schedule
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -106,6 +221,7 @@ mod tests {
     #[derive(Debug, Clone)]
     enum CounterOp {
         Increment,
+        Explode,
     }
 
     impl Operation for CounterOp {
@@ -114,6 +230,7 @@ mod tests {
         fn execute(&self, state: &mut Self::State) {
             match self {
                 CounterOp::Increment => state.value += 1,
+                CounterOp::Explode => panic!("counter exploded at {}", state.value),
             }
         }
     }
@@ -149,4 +266,35 @@ mod tests {
         let final_state = result.unwrap();
         assert_eq!(final_state.value, (ops_per_thread * thread_count) as i32);
     }
+
+    #[test]
+    fn test_concurrent_panic_names_operation() {
+        let operations = vec![
+            vec![CounterOp::Increment; 50],
+            vec![
+                CounterOp::Increment,
+                CounterOp::Explode,
+                CounterOp::Increment,
+            ],
+            vec![CounterOp::Increment; 50],
+        ];
+
+        let failure = run_concurrent(
+            Counter { value: 0 },
+            operations,
+            ConcurrentConfig::default(),
+        )
+        .expect_err("an operation panics");
+
+        // Threads that later hit the poisoned mutex are not blamed
+        let panic = failure.panic.clone().expect("the panic is pinpointed");
+        assert_eq!((panic.thread, panic.index), (1, 1));
+        assert_eq!(panic.operation, "Explode");
+        assert!(panic.message.starts_with("counter exploded at "));
+        assert!(
+            failure
+                .to_string()
+                .contains("Operation Explode (thread 1, operation 1) panicked: counter exploded")
+        );
+    }
 }
@@ -0,0 +1,483 @@
+//! Deterministic, replayable thread interleavings
+//!
+//! [`run_concurrent`](super::run_concurrent) leaves scheduling to the OS, so a
+//! race that shows up once may never show up again. The runners here execute
+//! each thread's operations on real threads but let only one run at a time:
+//! control passes between threads at operation boundaries and at explicit
+//! [`yield_point`] calls, and the scheduler picks the next thread from a seeded
+//! RNG or a recorded [`Schedule`]. Every choice is recorded, so the schedule of
+//! a failing run replays it exactly.
+//!
+//! # Example
+//!
+//! ```rust
+//! use protest_stateful::concurrent::*;
+//! use protest_stateful::operations::Operation;
+//! use std::sync::{Arc, Mutex};
+//!
+//! #[derive(Debug, Clone)]
+//! struct Increment;
+//!
+//! impl Operation for Increment {
+//!     type State = u32;
+//!     fn execute(&self, state: &mut u32) {
+//!         *state += 1;
+//!     }
+//! }
+//!
+//! impl ConcurrentOperation for Increment {
+//!     // A racy read-modify-write: another thread may run between the two steps
+//!     fn execute_concurrent(&self, state: &Arc<Mutex<u32>>) {
+//!         let read = *state.lock().unwrap();
+//!         yield_point();
+//!         *state.lock().unwrap() = read + 1;
+//!     }
+//! }
+//!
+//! let operations = vec![vec![Increment], vec![Increment]];
+//! let failure = explore_interleavings(0, operations.clone(), 42, 100, |count| *count == 2)
+//!     .expect_err("some interleaving loses an update");
+//!
+//! // The recorded schedule reproduces the lost update every time
+//! let schedule = failure.schedule.clone().unwrap();
+//! let run = run_interleaved(0, operations, Interleaving::Replay(schedule.clone())).unwrap();
+//! assert_eq!(run.state, 1);
+//! assert_eq!(run.schedule, schedule);
+//! ```
+
+use super::{ConcurrentOperation, ConcurrentTestFailure, execute_caught, panic_message};
+use rand::Rng;
+use rand::rngs::StdRng;
+use std::cell::RefCell;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+/// The order in which a run handed control to its threads
+///
+/// Each step is the index of the thread that ran next. Displays as
+/// comma-separated thread indices, which [`FromStr`] parses back.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schedule {
+    steps: Vec<usize>,
+}
+
+impl Schedule {
+    /// Create a schedule from thread indices
+    pub fn new(steps: Vec<usize>) -> Self {
+        Self { steps }
+    }
+
+    /// Thread indices in the order they ran
+    pub fn steps(&self) -> &[usize] {
+        &self.steps
+    }
+
+    /// Number of scheduling decisions
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether no thread was ever scheduled
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let steps: Vec<String> = self.steps.iter().map(|step| step.to_string()).collect();
+        write!(f, "{}", steps.join(","))
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        let steps = s
+            .split(',')
+            .map(|step| step.trim().parse())
+            .collect::<Result<_, _>>()?;
+        Ok(Self { steps })
+    }
+}
+
+/// How [`run_interleaved`] picks the next thread to run
+#[derive(Debug, Clone)]
+pub enum Interleaving {
+    /// Pick uniformly among runnable threads with an RNG seeded by `seed`
+    Random { seed: u64 },
+    /// Follow a recorded schedule
+    ///
+    /// Steps naming a finished thread, and steps past the end of the schedule,
+    /// run the lowest-indexed runnable thread instead.
+    Replay(Schedule),
+}
+
+/// Result of a [`run_interleaved`] run
+#[derive(Debug, Clone)]
+pub struct InterleavedRun<S> {
+    /// Final shared state
+    pub state: S,
+    /// The interleaving the run followed
+    pub schedule: Schedule,
+}
+
+/// Source of scheduling decisions
+enum Chooser {
+    Random(Box<StdRng>),
+    Replay { steps: Vec<usize>, next: usize },
+}
+
+/// Scheduler state shared by the threads of one run
+struct Control {
+    /// Thread allowed to run, if any
+    current: Option<usize>,
+    finished: Vec<bool>,
+    chooser: Chooser,
+    recorded: Vec<usize>,
+}
+
+impl Control {
+    /// Grant the next turn to a runnable thread, recording the choice
+    fn choose_next(&mut self) {
+        let runnable: Vec<usize> = (0..self.finished.len())
+            .filter(|&thread| !self.finished[thread])
+            .collect();
+        if runnable.is_empty() {
+            self.current = None;
+            return;
+        }
+
+        let choice = match &mut self.chooser {
+            Chooser::Random(rng) => runnable[rng.gen_range(0..runnable.len())],
+            Chooser::Replay { steps, next } => {
+                let step = steps.get(*next).copied();
+                *next += 1;
+                step.filter(|step| runnable.contains(step))
+                    .unwrap_or(runnable[0])
+            }
+        };
+        self.recorded.push(choice);
+        self.current = Some(choice);
+    }
+}
+
+/// Turn-passing between the threads of one run
+struct Scheduler {
+    control: Mutex<Control>,
+    turn: Condvar,
+}
+
+impl Scheduler {
+    /// Block until `thread` holds the turn
+    fn wait_turn(&self, thread: usize) {
+        let mut control = self.control.lock().unwrap();
+        while control.current != Some(thread) {
+            control = self.turn.wait(control).unwrap();
+        }
+    }
+
+    /// Give up the turn; unless `finished`, block until it comes back
+    fn hand_over(&self, thread: usize, finished: bool) {
+        let mut control = self.control.lock().unwrap();
+        if finished {
+            control.finished[thread] = true;
+        }
+        control.choose_next();
+        self.turn.notify_all();
+        if !finished {
+            while control.current != Some(thread) {
+                control = self.turn.wait(control).unwrap();
+            }
+        }
+    }
+}
+
+thread_local! {
+    /// Scheduler and thread index of the interleaved run on this thread, if any
+    static CURRENT: RefCell<Option<(Arc<Scheduler>, usize)>> = const { RefCell::new(None) };
+}
+
+/// Let the scheduler run another thread before continuing
+///
+/// Call this inside [`ConcurrentOperation::execute_concurrent`] between the
+/// steps that may race, such as a read and the write that depends on it.
+/// Outside [`run_interleaved`] it does nothing.
+///
+/// Never call it while holding a lock other threads need: the thread holding
+/// the turn would block on that lock forever.
+pub fn yield_point() {
+    let current = CURRENT.with(|current| current.borrow().clone());
+    if let Some((scheduler, thread)) = current {
+        scheduler.hand_over(thread, false);
+    }
+}
+
+/// Run each thread's operations under a deterministic interleaving
+///
+/// `operations[i]` runs in order on thread `i`. Only one thread runs at a time;
+/// control may switch before each operation and at every [`yield_point`]. The
+/// same `interleaving` always produces the same schedule, and the returned
+/// schedule replays the run with [`Interleaving::Replay`].
+///
+/// A panicking operation fails the run; the failure names it and carries the
+/// schedule.
+pub fn run_interleaved<Op>(
+    initial_state: Op::State,
+    operations: Vec<Vec<Op>>,
+    interleaving: Interleaving,
+) -> Result<InterleavedRun<Op::State>, ConcurrentTestFailure>
+where
+    Op: ConcurrentOperation + 'static,
+    Op::State: Send,
+{
+    let chooser = match interleaving {
+        Interleaving::Random { seed } => {
+            Chooser::Random(Box::new(protest::create_seeded_rng(seed)))
+        }
+        Interleaving::Replay(schedule) => Chooser::Replay {
+            steps: schedule.steps,
+            next: 0,
+        },
+    };
+    let scheduler = Arc::new(Scheduler {
+        control: Mutex::new(Control {
+            current: None,
+            finished: vec![false; operations.len()],
+            chooser,
+            recorded: Vec::new(),
+        }),
+        turn: Condvar::new(),
+    });
+    let state = Arc::new(Mutex::new(initial_state));
+    // Threads run one at a time, so the first panic recorded caused any later
+    // ones (through the mutex it poisoned)
+    let first_panic = Arc::new(Mutex::new(None));
+
+    let handles: Vec<_> = operations
+        .into_iter()
+        .enumerate()
+        .map(|(thread, thread_ops)| {
+            let scheduler = Arc::clone(&scheduler);
+            let state = Arc::clone(&state);
+            let first_panic = Arc::clone(&first_panic);
+            thread::spawn(move || {
+                CURRENT.with(|current| {
+                    *current.borrow_mut() = Some((Arc::clone(&scheduler), thread));
+                });
+                scheduler.wait_turn(thread);
+
+                // A panicking operation stops its thread
+                for (index, op) in thread_ops.iter().enumerate() {
+                    if index > 0 {
+                        yield_point();
+                    }
+                    if let Err(panic) = execute_caught(thread, index, op, &state) {
+                        first_panic.lock().unwrap().get_or_insert(panic);
+                        break;
+                    }
+                }
+
+                CURRENT.with(|current| *current.borrow_mut() = None);
+                scheduler.hand_over(thread, true);
+            })
+        })
+        .collect();
+
+    // Start the first thread
+    {
+        let mut control = scheduler.control.lock().unwrap();
+        control.choose_next();
+        scheduler.turn.notify_all();
+    }
+
+    let results: Vec<_> = handles.into_iter().map(|handle| handle.join()).collect();
+    let schedule = Schedule::new(scheduler.control.lock().unwrap().recorded.clone());
+
+    for (thread, result) in results.into_iter().enumerate() {
+        if let Err(payload) = result {
+            return Err(ConcurrentTestFailure {
+                description: format!(
+                    "Thread {} panicked: {}",
+                    thread,
+                    panic_message(payload.as_ref())
+                ),
+                schedule: Some(schedule),
+                panic: None,
+            });
+        }
+    }
+    if let Some(panic) = first_panic.lock().unwrap().take() {
+        return Err(ConcurrentTestFailure::from_panic(panic, Some(schedule)));
+    }
+
+    // Every thread has exited, so this is the last reference
+    let state = Arc::try_unwrap(state)
+        .map_err(|_| ConcurrentTestFailure {
+            description: "Could not unwrap Arc".to_string(),
+            schedule: Some(schedule.clone()),
+            panic: None,
+        })?
+        .into_inner()
+        .map_err(|_| ConcurrentTestFailure {
+            description: "Mutex poisoned".to_string(),
+            schedule: Some(schedule.clone()),
+            panic: None,
+        })?;
+
+    Ok(InterleavedRun { state, schedule })
+}
+
+/// Search seeded interleavings for one that breaks `check`
+///
+/// Runs `operations` under `attempts` random interleavings derived from `seed`
+/// and checks each final state. The first run that panics or fails `check` is
+/// returned as a failure carrying its schedule, ready for
+/// [`Interleaving::Replay`].
+pub fn explore_interleavings<Op, F>(
+    initial_state: Op::State,
+    operations: Vec<Vec<Op>>,
+    seed: u64,
+    attempts: usize,
+    check: F,
+) -> Result<(), ConcurrentTestFailure>
+where
+    Op: ConcurrentOperation + 'static,
+    Op::State: Send + Clone,
+    F: Fn(&Op::State) -> bool,
+{
+    for attempt in 0..attempts {
+        let interleaving = Interleaving::Random {
+            seed: protest::split_seed(seed, attempt as u64),
+        };
+        let run = run_interleaved(initial_state.clone(), operations.clone(), interleaving)?;
+
+        if !check(&run.state) {
+            return Err(ConcurrentTestFailure {
+                description: format!(
+                    "Final state check failed on interleaving {} of {}",
+                    attempt + 1,
+                    attempts
+                ),
+                schedule: Some(run.schedule),
+                panic: None,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::Operation;
+
+    #[derive(Debug, Clone)]
+    enum CounterOp {
+        /// Read, yield, then write the incremented value
+        RacyIncrement,
+        Fail,
+    }
+
+    impl Operation for CounterOp {
+        type State = u32;
+
+        fn execute(&self, state: &mut u32) {
+            match self {
+                CounterOp::RacyIncrement => *state += 1,
+                CounterOp::Fail => panic!("operation failed"),
+            }
+        }
+    }
+
+    impl ConcurrentOperation for CounterOp {
+        fn execute_concurrent(&self, state: &Arc<Mutex<u32>>) {
+            match self {
+                CounterOp::RacyIncrement => {
+                    let read = *state.lock().unwrap();
+                    yield_point();
+                    *state.lock().unwrap() = read + 1;
+                }
+                CounterOp::Fail => self.execute(&mut state.lock().unwrap()),
+            }
+        }
+    }
+
+    fn racy_operations() -> Vec<Vec<CounterOp>> {
+        vec![vec![CounterOp::RacyIncrement; 3]; 3]
+    }
+
+    #[test]
+    fn test_same_seed_same_schedule() {
+        let first =
+            run_interleaved(0, racy_operations(), Interleaving::Random { seed: 7 }).unwrap();
+        let second =
+            run_interleaved(0, racy_operations(), Interleaving::Random { seed: 7 }).unwrap();
+
+        assert_eq!(first.schedule, second.schedule);
+        assert_eq!(first.state, second.state);
+        // Every operation start and yield point is a scheduling decision
+        assert_eq!(first.schedule.len(), 3 * 3 * 2);
+    }
+
+    #[test]
+    fn test_failing_schedule_replays() {
+        let failure = explore_interleavings(0, racy_operations(), 1, 200, |count| *count == 9)
+            .expect_err("some interleaving loses an update");
+        let schedule = failure.schedule.clone().unwrap();
+        assert!(failure.to_string().contains(&schedule.to_string()));
+
+        let lost =
+            run_interleaved(0, racy_operations(), Interleaving::Replay(schedule.clone())).unwrap();
+        assert!(lost.state < 9);
+        assert_eq!(lost.schedule, schedule);
+
+        for _ in 0..5 {
+            let replayed =
+                run_interleaved(0, racy_operations(), Interleaving::Replay(schedule.clone()))
+                    .unwrap();
+            assert_eq!(replayed.state, lost.state);
+        }
+    }
+
+    #[test]
+    fn test_serial_schedule_loses_nothing() {
+        // Run thread 0 to completion, then thread 1
+        let schedule: Schedule = "0,0,0,0,1,1,1,1".parse().unwrap();
+        let operations = vec![vec![CounterOp::RacyIncrement; 2]; 2];
+        let run = run_interleaved(0, operations, Interleaving::Replay(schedule.clone())).unwrap();
+
+        assert_eq!(run.state, 4);
+        assert_eq!(run.schedule, schedule);
+    }
+
+    #[test]
+    fn test_panic_reports_schedule() {
+        let operations = vec![vec![CounterOp::RacyIncrement], vec![CounterOp::Fail]];
+        let failure = run_interleaved(0, operations, Interleaving::Random { seed: 3 })
+            .expect_err("thread 1 panics");
+
+        // The poisoned mutex may make thread 0 panic too, but only afterwards
+        let panic = failure.panic.expect("the failing operation is reported");
+        assert_eq!((panic.thread, panic.index), (1, 0));
+        assert_eq!(panic.operation, "Fail");
+        assert_eq!(panic.message, "operation failed");
+        assert!(failure.schedule.is_some());
+    }
+
+    #[test]
+    fn test_schedule_round_trip() {
+        let schedule = Schedule::new(vec![2, 0, 1, 1]);
+        assert_eq!(schedule.to_string(), "2,0,1,1");
+        assert_eq!("2, 0,1,1".parse::<Schedule>().unwrap(), schedule);
+        assert!("".parse::<Schedule>().unwrap().is_empty());
+        assert!("0,x".parse::<Schedule>().is_err());
+    }
+}
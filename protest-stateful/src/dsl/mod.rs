@@ -2,8 +2,12 @@
 
 use crate::invariants::{InvariantSet, InvariantViolation};
 use crate::operations::{Operation, OperationSequence};
+use crate::temporal::TemporalProperty;
 use std::fmt::Debug;
 
+/// A check over a single transition: the operation plus the states before and after it
+type TransitionCheck<Op, State> = Box<dyn Fn(&Op, &State, &State) -> bool>;
+
 /// A stateful property test
 pub struct StatefulTest<State, Op>
 where
@@ -12,6 +16,8 @@ where
 {
     initial_state: State,
     invariants: InvariantSet<State>,
+    transition_invariants: Vec<(String, TransitionCheck<Op, State>)>,
+    temporal_properties: Vec<Box<dyn TemporalProperty<State>>>,
     _phantom: std::marker::PhantomData<Op>,
 }
 
@@ -25,6 +31,8 @@ where
         Self {
             initial_state,
             invariants: InvariantSet::new(),
+            transition_invariants: Vec::new(),
+            temporal_properties: Vec::new(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -38,18 +46,134 @@ where
         self
     }
 
+    /// Add an invariant whose `Err` message explains the violation
+    ///
+    /// The message is reported instead of the invariant's name, e.g.
+    /// "balance -50 is negative" rather than "balance_non_negative".
+    pub fn invariant_with_reason<F>(mut self, name: impl Into<String>, check_fn: F) -> Self
+    where
+        F: Fn(&State) -> Result<(), String> + 'static,
+    {
+        self.invariants.add_fn_with_reason(name, check_fn);
+        self
+    }
+
+    /// Add an invariant over each transition, checked after every operation
+    ///
+    /// The check receives the operation that just ran along with the state
+    /// before and after it, e.g. "after a Withdraw, balance must have decreased".
+    pub fn transition_invariant<F>(mut self, name: impl Into<String>, check_fn: F) -> Self
+    where
+        F: Fn(&Op, &State, &State) -> bool + 'static,
+    {
+        self.transition_invariants
+            .push((name.into(), Box::new(check_fn)));
+        self
+    }
+
+    /// Add a temporal property to check against the full state history after a successful run
+    pub fn temporal<P>(mut self, property: P) -> Self
+    where
+        P: TemporalProperty<State> + 'static,
+    {
+        self.temporal_properties.push(Box::new(property));
+        self
+    }
+
+    /// Check temporal properties against a state history
+    fn temporal_violations(&self, states: &[State], collect: bool) -> Vec<InvariantViolation> {
+        let failing = self
+            .temporal_properties
+            .iter()
+            .filter(|property| !property.check(states))
+            .map(|property| InvariantViolation {
+                description: format!("temporal property {}", property.description()),
+            });
+        if collect {
+            failing.collect()
+        } else {
+            failing.take(1).collect()
+        }
+    }
+
+    /// Check all transition invariants for a single step
+    fn check_transition(
+        &self,
+        op: &Op,
+        before: &State,
+        after: &State,
+    ) -> Result<(), InvariantViolation> {
+        for (name, check_fn) in &self.transition_invariants {
+            if !check_fn(op, before, after) {
+                return Err(InvariantViolation {
+                    description: name.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Collect every state and transition invariant violated by a single step
+    fn step_violations(
+        &self,
+        transition: Option<(&Op, &State)>,
+        state: &State,
+    ) -> Vec<InvariantViolation> {
+        let mut violations = self.invariants.violations(state);
+        if let Some((op, before)) = transition {
+            violations.extend(
+                self.transition_invariants
+                    .iter()
+                    .filter(|(_, check_fn)| !check_fn(op, before, state))
+                    .map(|(name, _)| InvariantViolation {
+                        description: name.clone(),
+                    }),
+            );
+        }
+        violations
+    }
+
     /// Execute a sequence of operations and check invariants
     pub fn run(&self, sequence: &OperationSequence<Op>) -> Result<State, StatefulTestFailure> {
+        self.run_inner(sequence, false)
+    }
+
+    /// Execute a sequence of operations, reporting every invariant violated at the failing step
+    ///
+    /// Unlike [`run`](Self::run), which stops at the first violated invariant,
+    /// this checks all remaining invariants at the failing step and records
+    /// them in [`StatefulTestFailure::additional_violations`].
+    pub fn run_collecting(
+        &self,
+        sequence: &OperationSequence<Op>,
+    ) -> Result<State, StatefulTestFailure> {
+        self.run_inner(sequence, true)
+    }
+
+    fn run_inner(
+        &self,
+        sequence: &OperationSequence<Op>,
+        collect: bool,
+    ) -> Result<State, StatefulTestFailure> {
         let mut state = self.initial_state.clone();
+        let track_history = !self.temporal_properties.is_empty();
+        let mut history = Vec::new();
+        if track_history {
+            history.push(state.clone());
+        }
 
         // Check initial invariants
-        if let Err(violation) = self.invariants.check_all(&state) {
-            return Err(StatefulTestFailure {
-                operation_index: None,
-                operation: None,
-                state_before: None,
-                violation,
-            });
+        let initial = if collect {
+            self.step_violations(None, &state)
+        } else {
+            self.invariants
+                .check_all(&state)
+                .err()
+                .into_iter()
+                .collect()
+        };
+        if let Some(failure) = StatefulTestFailure::from_violations(None, None, None, initial) {
+            return Err(failure);
         }
 
         // Execute operations one by one
@@ -65,6 +189,7 @@ where
                     violation: InvariantViolation {
                         description: format!("Precondition failed for operation: {:?}", op),
                     },
+                    additional_violations: Vec::new(),
                 });
             }
 
@@ -72,16 +197,36 @@ where
             op.execute(&mut state);
 
             // Check invariants
-            if let Err(violation) = self.invariants.check_all(&state) {
-                return Err(StatefulTestFailure {
-                    operation_index: Some(idx),
-                    operation: Some(format!("{:?}", op)),
-                    state_before: Some(format!("{:?}", state_before)),
-                    violation,
-                });
+            let violations = if collect {
+                self.step_violations(Some((op, &state_before)), &state)
+            } else {
+                self.invariants
+                    .check_all(&state)
+                    .and_then(|_| self.check_transition(op, &state_before, &state))
+                    .err()
+                    .into_iter()
+                    .collect()
+            };
+            if let Some(failure) = StatefulTestFailure::from_violations(
+                Some(idx),
+                Some(format!("{:?}", op)),
+                Some(format!("{:?}", state_before)),
+                violations,
+            ) {
+                return Err(failure);
+            }
+
+            if track_history {
+                history.push(state.clone());
             }
         }
 
+        // Check temporal properties over the whole run
+        let temporal = self.temporal_violations(&history, collect);
+        if let Some(failure) = StatefulTestFailure::from_violations(None, None, None, temporal) {
+            return Err(failure);
+        }
+
         Ok(state)
     }
 
@@ -104,6 +249,7 @@ where
                     violation: InvariantViolation {
                         description: format!("Precondition failed for operation: {:?}", op),
                     },
+                    additional_violations: Vec::new(),
                 });
             }
 
@@ -111,16 +257,29 @@ where
 
             trace.add_step(op.description(), state.clone());
 
-            if let Err(violation) = self.invariants.check_all(&state) {
+            if let Err(violation) = self
+                .invariants
+                .check_all(&state)
+                .and_then(|_| self.check_transition(op, &state_before, &state))
+            {
                 return Err(StatefulTestFailure {
                     operation_index: Some(idx),
                     operation: Some(format!("{:?}", op)),
                     state_before: Some(format!("{:?}", state_before)),
                     violation,
+                    additional_violations: Vec::new(),
                 });
             }
         }
 
+        if !self.temporal_properties.is_empty() {
+            let temporal = self.temporal_violations(&trace.states(), false);
+            if let Some(failure) = StatefulTestFailure::from_violations(None, None, None, temporal)
+            {
+                return Err(failure);
+            }
+        }
+
         Ok(trace)
     }
 }
@@ -132,11 +291,49 @@ pub struct StatefulTestFailure {
     pub operation: Option<String>,
     pub state_before: Option<String>,
     pub violation: InvariantViolation,
+    /// Further violations at the same step, populated by `run_collecting`
+    additional_violations: Vec<InvariantViolation>,
+}
+
+impl StatefulTestFailure {
+    /// Build a failure from the violations at one step, if there are any
+    fn from_violations(
+        operation_index: Option<usize>,
+        operation: Option<String>,
+        state_before: Option<String>,
+        violations: Vec<InvariantViolation>,
+    ) -> Option<Self> {
+        let mut violations = violations.into_iter();
+        let violation = violations.next()?;
+        Some(Self {
+            operation_index,
+            operation,
+            state_before,
+            violation,
+            additional_violations: violations.collect(),
+        })
+    }
+
+    /// Violations at the failing step besides `violation`
+    ///
+    /// Only [`StatefulTest::run_collecting`] records these; other runs stop at
+    /// the first violation.
+    pub fn additional_violations(&self) -> &[InvariantViolation] {
+        &self.additional_violations
+    }
+
+    /// All violations recorded for the failing step, first violation included
+    pub fn violations(&self) -> impl Iterator<Item = &InvariantViolation> {
+        std::iter::once(&self.violation).chain(&self.additional_violations)
+    }
 }
 
 impl std::fmt::Display for StatefulTestFailure {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Stateful test failed: {}", self.violation)?;
+        for violation in &self.additional_violations {
+            write!(f, "\n  Also: {}", violation)?;
+        }
         if let Some(idx) = self.operation_index {
             write!(f, "\n  At operation index: {}", idx)?;
         }
@@ -187,6 +384,99 @@ impl<State: Clone> ExecutionTrace<State> {
     pub fn final_state(&self) -> Option<&State> {
         self.steps.last().map(|(_, state)| state)
     }
+
+    /// Get the full state sequence, starting with the initial state
+    pub fn states(&self) -> Vec<State> {
+        std::iter::once(&self.initial_state)
+            .chain(self.steps.iter().map(|(_, state)| state))
+            .cloned()
+            .collect()
+    }
+
+    /// Check a temporal property against the trace's state sequence
+    pub fn check_temporal(&self, property: &dyn TemporalProperty<State>) -> bool {
+        property.check(&self.states())
+    }
+
+    /// Check several temporal properties, reporting the first that fails
+    pub fn check_all_temporal(
+        &self,
+        properties: &[&dyn TemporalProperty<State>],
+    ) -> Result<(), InvariantViolation> {
+        let states = self.states();
+        for property in properties {
+            if !property.check(&states) {
+                return Err(InvariantViolation {
+                    description: property.description().to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<State: Debug> ExecutionTrace<State> {
+    /// Render the trace as a Graphviz DOT graph
+    ///
+    /// Each state becomes a node labeled with its (truncated) `Debug` output,
+    /// and each step becomes an edge labeled with the operation that produced
+    /// the next state. Render with e.g. `dot -Tsvg trace.dot -o trace.svg`.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph ExecutionTrace {\n");
+        out.push_str("    rankdir=TB;\n");
+        out.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+        out.push_str(&format!(
+            "    s0 [label=\"initial\\n{}\"];\n",
+            dot_state_label(&self.initial_state)
+        ));
+        for (i, (operation, state)) in self.steps.iter().enumerate() {
+            out.push_str(&format!(
+                "    s{} [label=\"#{} {}\\n{}\"];\n",
+                i + 1,
+                i,
+                dot_escape(operation),
+                dot_state_label(state)
+            ));
+            out.push_str(&format!(
+                "    s{} -> s{} [label=\"{}\"];\n",
+                i,
+                i + 1,
+                dot_escape(operation)
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Maximum number of characters of a state's `Debug` output shown in a DOT node
+pub(crate) const DOT_STATE_LABEL_MAX: usize = 60;
+
+/// Escape a string for use inside a double-quoted DOT label
+pub(crate) fn dot_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Format a state's `Debug` output as an escaped, truncated DOT label
+pub(crate) fn dot_state_label<T: Debug>(state: &T) -> String {
+    let debug = format!("{:?}", state);
+    let truncated = if debug.chars().count() > DOT_STATE_LABEL_MAX {
+        let mut short: String = debug.chars().take(DOT_STATE_LABEL_MAX).collect();
+        short.push_str("...");
+        short
+    } else {
+        debug
+    };
+    dot_escape(&truncated)
 }
 
 #[cfg(test)]
@@ -254,6 +544,129 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_invariant_with_reason_failure() {
+        let test = StatefulTest::new(Counter { value: 0 }).invariant_with_reason(
+            "less_than_2",
+            |state: &Counter| {
+                if state.value < 2 {
+                    Ok(())
+                } else {
+                    Err(format!("value {} is not less than 2", state.value))
+                }
+            },
+        );
+
+        let mut seq = OperationSequence::new();
+        seq.push(CounterOp::Increment);
+        seq.push(CounterOp::Increment);
+
+        let failure = test.run(&seq).unwrap_err();
+        assert_eq!(failure.violation.description, "value 2 is not less than 2");
+    }
+
+    #[test]
+    fn test_transition_invariant() {
+        let test = StatefulTest::new(Counter { value: 0 }).transition_invariant(
+            "decrement_lowers_value",
+            |op: &CounterOp, before: &Counter, after: &Counter| {
+                !matches!(op, CounterOp::Decrement) || after.value < before.value
+            },
+        );
+
+        let mut seq = OperationSequence::new();
+        seq.push(CounterOp::Increment);
+        seq.push(CounterOp::Decrement);
+        assert!(test.run(&seq).is_ok());
+
+        let broken = StatefulTest::new(Counter { value: 0 }).transition_invariant(
+            "increment_lowers_value",
+            |op: &CounterOp, before: &Counter, after: &Counter| {
+                !matches!(op, CounterOp::Increment) || after.value < before.value
+            },
+        );
+        let failure = broken.run(&seq).unwrap_err();
+        assert_eq!(failure.operation_index, Some(0));
+        assert_eq!(failure.violation.description, "increment_lowers_value");
+        assert!(broken.run_with_trace(&seq).is_err());
+    }
+
+    #[test]
+    fn test_run_collecting_reports_all_violations() {
+        let test = StatefulTest::new(Counter { value: 0 })
+            .invariant("less_than_1", |state: &Counter| state.value < 1)
+            .invariant("non_negative", |state: &Counter| state.value >= 0)
+            .invariant("less_than_2", |state: &Counter| state.value < 2)
+            .transition_invariant(
+                "never_increments",
+                |op: &CounterOp, _: &Counter, _: &Counter| !matches!(op, CounterOp::Increment),
+            );
+
+        let mut seq = OperationSequence::new();
+        seq.push(CounterOp::Increment);
+
+        // Default behavior still short-circuits
+        let failure = test.run(&seq).unwrap_err();
+        assert_eq!(failure.violation.description, "less_than_1");
+        assert!(failure.additional_violations().is_empty());
+
+        let failure = test.run_collecting(&seq).unwrap_err();
+        assert_eq!(failure.operation_index, Some(0));
+        let names: Vec<_> = failure
+            .violations()
+            .map(|v| v.description.as_str())
+            .collect();
+        assert_eq!(names, vec!["less_than_1", "never_increments"]);
+        assert!(
+            failure
+                .to_string()
+                .contains("Also: Invariant violated: never_increments")
+        );
+    }
+
+    #[test]
+    fn test_trace_check_temporal() {
+        use crate::temporal::{Always, Eventually};
+
+        let test = StatefulTest::new(Counter { value: 0 });
+        let mut seq = OperationSequence::new();
+        seq.push(CounterOp::Increment);
+        seq.push(CounterOp::Increment);
+        let trace = test.run_with_trace(&seq).unwrap();
+
+        assert_eq!(trace.states().len(), 3);
+        let reaches_two = Eventually::new("reaches_two", |s: &Counter| s.value == 2);
+        let below_two = Always::new("below_two", |s: &Counter| s.value < 2);
+        assert!(trace.check_temporal(&reaches_two));
+        assert!(!trace.check_temporal(&below_two));
+
+        let violation = trace
+            .check_all_temporal(&[&reaches_two, &below_two])
+            .unwrap_err();
+        assert_eq!(violation.description, "below_two");
+    }
+
+    #[test]
+    fn test_stateful_test_temporal_properties() {
+        use crate::temporal::{Always, Eventually};
+
+        let mut seq = OperationSequence::new();
+        seq.push(CounterOp::Increment);
+        seq.push(CounterOp::Decrement);
+
+        let test = StatefulTest::new(Counter { value: 0 })
+            .temporal(Eventually::new("reaches_one", |s: &Counter| s.value == 1));
+        assert!(test.run(&seq).is_ok());
+        assert!(test.run_with_trace(&seq).is_ok());
+
+        let test = StatefulTest::new(Counter { value: 0 })
+            .temporal(Always::new("stays_zero", |s: &Counter| s.value == 0));
+        let failure = test.run(&seq).unwrap_err();
+        assert_eq!(failure.operation_index, None);
+        assert!(failure.violation.description.contains("stays_zero"));
+        assert!(test.run_with_trace(&seq).is_err());
+    }
+
     #[test]
     fn test_execution_trace() {
         let test = StatefulTest::new(Counter { value: 0 });
@@ -266,4 +679,31 @@ mod tests {
         assert_eq!(trace.steps().len(), 2);
         assert_eq!(trace.final_state().unwrap().value, 2);
     }
+
+    #[test]
+    fn test_execution_trace_to_dot() {
+        let test = StatefulTest::new(Counter { value: 0 });
+
+        let mut seq = OperationSequence::new();
+        seq.push(CounterOp::Increment);
+        seq.push(CounterOp::Decrement);
+
+        let dot = test.run_with_trace(&seq).unwrap().to_dot();
+        assert!(dot.starts_with("digraph ExecutionTrace {"));
+        assert!(dot.contains("s0 [label=\"initial\\nCounter { value: 0 }\"];"));
+        assert!(dot.contains("s1 [label=\"#0 Increment\\nCounter { value: 1 }\"];"));
+        assert!(dot.contains("s1 -> s2 [label=\"Decrement\"];"));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_dot_state_label_escapes_and_truncates() {
+        assert_eq!(dot_escape("say \"hi\"\n"), "say \\\"hi\\\"\\n");
+
+        let long = "x".repeat(DOT_STATE_LABEL_MAX + 10);
+        let label = dot_state_label(&long);
+        assert!(label.ends_with("..."));
+        // Debug of a String adds a leading quote, which is escaped
+        assert!(label.starts_with("\\\"x"));
+    }
 }
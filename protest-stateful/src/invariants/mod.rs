@@ -9,6 +9,49 @@ pub trait Invariant<State> {
 
     /// Get a description of this invariant
     fn description(&self) -> &str;
+
+    /// Check the invariant, explaining why it does not hold
+    ///
+    /// The `Err` message becomes the [`InvariantViolation`] description, so
+    /// override this to report the offending values, e.g. "balance -50 is
+    /// negative". Defaults to [`Invariant::check`] with the invariant's
+    /// description as the message.
+    fn check_with_reason(&self, state: &State) -> Result<(), String> {
+        if self.check(state) {
+            Ok(())
+        } else {
+            Err(self.description().to_string())
+        }
+    }
+
+    /// Combine with another invariant; both must hold
+    fn and<I>(self, other: I) -> AndInvariant<Self, I>
+    where
+        Self: Sized,
+        I: Invariant<State>,
+    {
+        let name = format!("({} && {})", self.description(), other.description());
+        AndInvariant::new(name, self, other)
+    }
+
+    /// Combine with another invariant; at least one must hold
+    fn or<I>(self, other: I) -> OrInvariant<Self, I>
+    where
+        Self: Sized,
+        I: Invariant<State>,
+    {
+        let name = format!("({} || {})", self.description(), other.description());
+        OrInvariant::new(name, self, other)
+    }
+
+    /// Negate this invariant
+    fn not(self) -> NotInvariant<Self>
+    where
+        Self: Sized,
+    {
+        let name = format!("!{}", self.description());
+        NotInvariant::new(name, self)
+    }
 }
 
 /// A simple function-based invariant
@@ -48,6 +91,172 @@ where
     }
 }
 
+/// A function-based invariant that explains its violations
+pub struct ReasonFnInvariant<State, F>
+where
+    F: Fn(&State) -> Result<(), String>,
+{
+    name: String,
+    check_fn: F,
+    _phantom: std::marker::PhantomData<State>,
+}
+
+impl<State, F> ReasonFnInvariant<State, F>
+where
+    F: Fn(&State) -> Result<(), String>,
+{
+    /// Create a new invariant whose `Err` message describes the violation
+    pub fn new(name: impl Into<String>, check_fn: F) -> Self {
+        Self {
+            name: name.into(),
+            check_fn,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<State, F> Invariant<State> for ReasonFnInvariant<State, F>
+where
+    F: Fn(&State) -> Result<(), String>,
+{
+    fn check(&self, state: &State) -> bool {
+        (self.check_fn)(state).is_ok()
+    }
+
+    fn description(&self) -> &str {
+        &self.name
+    }
+
+    fn check_with_reason(&self, state: &State) -> Result<(), String> {
+        (self.check_fn)(state)
+    }
+}
+
+/// An invariant that holds when both inner invariants hold
+pub struct AndInvariant<A, B> {
+    name: String,
+    left: A,
+    right: B,
+}
+
+impl<A, B> AndInvariant<A, B> {
+    /// Create a named conjunction of two invariants
+    pub fn new(name: impl Into<String>, left: A, right: B) -> Self {
+        Self {
+            name: name.into(),
+            left,
+            right,
+        }
+    }
+}
+
+impl<State, A, B> Invariant<State> for AndInvariant<A, B>
+where
+    A: Invariant<State>,
+    B: Invariant<State>,
+{
+    fn check(&self, state: &State) -> bool {
+        self.left.check(state) && self.right.check(state)
+    }
+
+    fn description(&self) -> &str {
+        &self.name
+    }
+
+    /// Reports the reason of the first inner invariant that does not hold
+    fn check_with_reason(&self, state: &State) -> Result<(), String> {
+        self.left.check_with_reason(state)?;
+        self.right.check_with_reason(state)
+    }
+}
+
+/// An invariant that holds when at least one inner invariant holds
+pub struct OrInvariant<A, B> {
+    name: String,
+    left: A,
+    right: B,
+}
+
+impl<A, B> OrInvariant<A, B> {
+    /// Create a named disjunction of two invariants
+    pub fn new(name: impl Into<String>, left: A, right: B) -> Self {
+        Self {
+            name: name.into(),
+            left,
+            right,
+        }
+    }
+}
+
+impl<State, A, B> Invariant<State> for OrInvariant<A, B>
+where
+    A: Invariant<State>,
+    B: Invariant<State>,
+{
+    fn check(&self, state: &State) -> bool {
+        self.left.check(state) || self.right.check(state)
+    }
+
+    fn description(&self) -> &str {
+        &self.name
+    }
+
+    /// When neither side holds, reports the first non-empty inner reason
+    fn check_with_reason(&self, state: &State) -> Result<(), String> {
+        let left = match self.left.check_with_reason(state) {
+            Ok(()) => return Ok(()),
+            Err(reason) => reason,
+        };
+        let right = match self.right.check_with_reason(state) {
+            Ok(()) => return Ok(()),
+            Err(reason) => reason,
+        };
+
+        Err([left, right]
+            .into_iter()
+            .find(|reason| !reason.is_empty())
+            .unwrap_or_else(|| self.name.clone()))
+    }
+}
+
+/// An invariant that holds when the inner invariant does not
+pub struct NotInvariant<I> {
+    name: String,
+    inner: I,
+}
+
+impl<I> NotInvariant<I> {
+    /// Create a named negation of an invariant
+    pub fn new(name: impl Into<String>, inner: I) -> Self {
+        Self {
+            name: name.into(),
+            inner,
+        }
+    }
+}
+
+impl<State, I> Invariant<State> for NotInvariant<I>
+where
+    I: Invariant<State>,
+{
+    fn check(&self, state: &State) -> bool {
+        !self.inner.check(state)
+    }
+
+    fn description(&self) -> &str {
+        &self.name
+    }
+
+    /// The inner invariant holding is the violation, so the reason is this
+    /// invariant's own description
+    fn check_with_reason(&self, state: &State) -> Result<(), String> {
+        match self.inner.check_with_reason(state) {
+            Ok(()) => Err(self.name.clone()),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
 /// A collection of invariants to check
 pub struct InvariantSet<State: 'static> {
     invariants: Vec<Box<dyn Invariant<State>>>,
@@ -74,18 +283,32 @@ impl<State> InvariantSet<State> {
         self.add(FnInvariant::new(name, check_fn));
     }
 
+    /// Add a function-based invariant that explains its violations
+    pub fn add_fn_with_reason<F>(&mut self, name: impl Into<String>, check_fn: F)
+    where
+        F: Fn(&State) -> Result<(), String> + 'static,
+    {
+        self.add(ReasonFnInvariant::new(name, check_fn));
+    }
+
     /// Check all invariants
     pub fn check_all(&self, state: &State) -> Result<(), InvariantViolation> {
         for inv in &self.invariants {
-            if !inv.check(state) {
-                return Err(InvariantViolation {
-                    description: inv.description().to_string(),
-                });
-            }
+            inv.check_with_reason(state)
+                .map_err(|description| InvariantViolation { description })?;
         }
         Ok(())
     }
 
+    /// Check all invariants, returning every violation instead of just the first
+    pub fn violations(&self, state: &State) -> Vec<InvariantViolation> {
+        self.invariants
+            .iter()
+            .filter_map(|inv| inv.check_with_reason(state).err())
+            .map(|description| InvariantViolation { description })
+            .collect()
+    }
+
     /// Get the number of invariants
     pub fn len(&self) -> usize {
         self.invariants.len()
@@ -150,5 +373,116 @@ mod tests {
 
         let state3 = Counter { value: 150 };
         assert!(set.check_all(&state3).is_err());
+        assert_eq!(set.violations(&state3).len(), 1);
+    }
+
+    #[test]
+    fn test_invariant_combinators() {
+        let non_negative = FnInvariant::new("non_negative", |state: &Counter| state.value >= 0);
+        let small = FnInvariant::new("small", |state: &Counter| state.value < 10);
+        let is_zero = FnInvariant::new("is_zero", |state: &Counter| state.value == 0);
+
+        let bounded = non_negative.and(small);
+        assert_eq!(bounded.description(), "(non_negative && small)");
+        assert!(bounded.check(&Counter { value: 5 }));
+        assert!(!bounded.check(&Counter { value: 50 }));
+
+        let either = bounded.or(FnInvariant::new("sentinel", |state: &Counter| {
+            state.value == 100
+        }));
+        assert!(either.check(&Counter { value: 100 }));
+        assert!(!either.check(&Counter { value: -1 }));
+
+        let nonzero = is_zero.not();
+        assert_eq!(nonzero.description(), "!is_zero");
+        assert!(nonzero.check(&Counter { value: 1 }));
+        assert!(!nonzero.check(&Counter { value: 0 }));
+
+        let mut set = InvariantSet::new();
+        set.add(AndInvariant::new("bounded_nonzero", either, nonzero));
+        // The violation names the inner invariant that failed
+        let violation = set.check_all(&Counter { value: 0 }).unwrap_err();
+        assert_eq!(violation.description, "!is_zero");
+    }
+
+    #[test]
+    fn test_invariant_combinators_keep_inner_reasons() {
+        let non_negative = ReasonFnInvariant::new("non_negative", |state: &Counter| {
+            if state.value >= 0 {
+                Ok(())
+            } else {
+                Err(format!("value {} is negative", state.value))
+            }
+        });
+        let small = ReasonFnInvariant::new("small", |state: &Counter| {
+            if state.value < 10 {
+                Ok(())
+            } else {
+                Err(format!("value {} is not below 10", state.value))
+            }
+        });
+        let bounded = non_negative.and(small);
+        assert_eq!(bounded.check_with_reason(&Counter { value: 5 }), Ok(()));
+        assert_eq!(
+            bounded.check_with_reason(&Counter { value: -3 }),
+            Err("value -3 is negative".to_string())
+        );
+        assert_eq!(
+            bounded.check_with_reason(&Counter { value: 12 }),
+            Err("value 12 is not below 10".to_string())
+        );
+
+        let silent = ReasonFnInvariant::new("silent", |_: &Counter| Err(String::new()));
+        let either = silent.or(bounded);
+        assert_eq!(either.check_with_reason(&Counter { value: 5 }), Ok(()));
+        assert_eq!(
+            either.check_with_reason(&Counter { value: 12 }),
+            Err("value 12 is not below 10".to_string())
+        );
+
+        let both_silent = ReasonFnInvariant::new("a", |_: &Counter| Err(String::new())).or(
+            ReasonFnInvariant::new("b", |_: &Counter| Err(String::new())),
+        );
+        assert_eq!(
+            both_silent.check_with_reason(&Counter { value: 0 }),
+            Err("(a || b)".to_string())
+        );
+
+        let not_small = FnInvariant::new("small", |state: &Counter| state.value < 10).not();
+        assert_eq!(not_small.check_with_reason(&Counter { value: 12 }), Ok(()));
+        assert_eq!(
+            not_small.check_with_reason(&Counter { value: 1 }),
+            Err("!small".to_string())
+        );
+    }
+
+    #[test]
+    fn test_invariant_with_reason() {
+        let plain = FnInvariant::new("non_negative", |state: &Counter| state.value >= 0);
+        assert_eq!(
+            plain.check_with_reason(&Counter { value: -1 }),
+            Err("non_negative".to_string())
+        );
+
+        let mut set = InvariantSet::new();
+        set.add_fn_with_reason("non_negative", |state: &Counter| {
+            if state.value >= 0 {
+                Ok(())
+            } else {
+                Err(format!("value {} is negative", state.value))
+            }
+        });
+        set.add_fn("less_than_100", |state: &Counter| state.value < 100);
+
+        assert!(set.check_all(&Counter { value: 5 }).is_ok());
+        let violation = set.check_all(&Counter { value: -50 }).unwrap_err();
+        assert_eq!(violation.description, "value -50 is negative");
+        assert_eq!(
+            violation.to_string(),
+            "Invariant violated: value -50 is negative"
+        );
+        let violations = set.violations(&Counter { value: 150 });
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].description, "less_than_100");
     }
 }
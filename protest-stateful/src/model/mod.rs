@@ -1,5 +1,6 @@
 //! Model-based testing - compare system against reference implementation
 
+use crate::dsl::{dot_escape, dot_state_label};
 use crate::operations::{Operation, OperationSequence};
 use std::fmt::Debug;
 
@@ -30,6 +31,63 @@ pub trait Model: Clone + Debug {
     }
 }
 
+/// A model over the same state type as the system, compared with `==`
+///
+/// Wraps a reference state and a step function that applies an operation to
+/// it, so `matches` is plain equality and needs no hand-written impl. Implement
+/// [`Model`] directly when the comparison has to be approximate.
+pub struct EqModel<S, Op> {
+    state: S,
+    step: fn(&mut S, &Op),
+}
+
+impl<S, Op> EqModel<S, Op> {
+    /// Create a model from an initial state and a step function
+    pub fn new(initial_state: S, step: fn(&mut S, &Op)) -> Self {
+        Self {
+            state: initial_state,
+            step,
+        }
+    }
+
+    /// Get the current model state
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S: Clone, Op> Clone for EqModel<S, Op> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            step: self.step,
+        }
+    }
+}
+
+impl<S: Debug, Op> Debug for EqModel<S, Op> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("EqModel").field(&self.state).finish()
+    }
+}
+
+impl<S, Op> Model for EqModel<S, Op>
+where
+    S: Clone + Debug + PartialEq,
+    Op: Operation<State = S>,
+{
+    type SystemState = S;
+    type Operation = Op;
+
+    fn execute_model(&mut self, op: &Self::Operation) {
+        (self.step)(&mut self.state, op);
+    }
+
+    fn matches(&self, system_state: &Self::SystemState) -> bool {
+        self.state == *system_state
+    }
+}
+
 /// Model-based testing runner
 pub struct ModelBasedTest<M>
 where
@@ -147,8 +205,8 @@ impl std::error::Error for ModelMismatch {}
 /// Trace of model-based execution
 #[derive(Debug, Clone)]
 pub struct ModelTrace<M, S> {
-    _initial_model: M,
-    _initial_system: S,
+    initial_model: M,
+    initial_system: S,
     steps: Vec<(String, M, S)>, // (operation, model state, system state)
 }
 
@@ -156,8 +214,8 @@ impl<M: Clone, S: Clone> ModelTrace<M, S> {
     /// Create a new trace
     pub fn new(initial_model: M, initial_system: S) -> Self {
         Self {
-            _initial_model: initial_model,
-            _initial_system: initial_system,
+            initial_model,
+            initial_system,
             steps: Vec::new(),
         }
     }
@@ -173,12 +231,75 @@ impl<M: Clone, S: Clone> ModelTrace<M, S> {
     }
 }
 
+impl<M: Debug, S: Debug> ModelTrace<M, S> {
+    /// Render the trace as a Graphviz DOT graph
+    ///
+    /// Model and system states are drawn as two side-by-side columns, with
+    /// operation-labeled edges between consecutive states and a dashed edge
+    /// pairing the model and system state at each step.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph ModelTrace {\n");
+        out.push_str("    rankdir=TB;\n");
+        out.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+
+        let states = std::iter::once(("initial", &self.initial_model, &self.initial_system)).chain(
+            self.steps
+                .iter()
+                .map(|(op, model, system)| (op.as_str(), model, system)),
+        );
+        let mut model_nodes = String::new();
+        let mut system_nodes = String::new();
+        let mut pairs = String::new();
+        for (i, (_, model, system)) in states.enumerate() {
+            model_nodes.push_str(&format!(
+                "        m{} [label=\"{}\"];\n",
+                i,
+                dot_state_label(model)
+            ));
+            system_nodes.push_str(&format!(
+                "        s{} [label=\"{}\"];\n",
+                i,
+                dot_state_label(system)
+            ));
+            pairs.push_str(&format!(
+                "    {{ rank=same; m{i}; s{i}; }}\n    m{i} -> s{i} [style=dashed, arrowhead=none];\n"
+            ));
+        }
+
+        out.push_str("    subgraph cluster_model {\n        label=\"model\";\n");
+        out.push_str(&model_nodes);
+        out.push_str("    }\n");
+        out.push_str("    subgraph cluster_system {\n        label=\"system\";\n");
+        out.push_str(&system_nodes);
+        out.push_str("    }\n");
+        out.push_str(&pairs);
+
+        for (i, (operation, _, _)) in self.steps.iter().enumerate() {
+            let label = format!("#{} {}", i, dot_escape(operation));
+            out.push_str(&format!(
+                "    m{} -> m{} [label=\"{}\"];\n",
+                i,
+                i + 1,
+                label
+            ));
+            out.push_str(&format!(
+                "    s{} -> s{} [label=\"{}\"];\n",
+                i,
+                i + 1,
+                label
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     // Simple key-value store system
-    #[derive(Debug, Clone)]
+    #[derive(Debug, Clone, PartialEq)]
     struct KVStore {
         data: std::collections::HashMap<String, String>,
     }
@@ -255,4 +376,54 @@ mod tests {
         let result = test.run(&seq);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_model_trace_to_dot() {
+        let mut trace = ModelTrace::new(0u32, 0u32);
+        trace.add_step("Inc".to_string(), 1, 1);
+        trace.add_step("Inc".to_string(), 2, 3);
+
+        let dot = trace.to_dot();
+        assert!(dot.starts_with("digraph ModelTrace {"));
+        assert!(dot.contains("subgraph cluster_model {"));
+        assert!(dot.contains("subgraph cluster_system {"));
+        assert!(dot.contains("m2 [label=\"2\"];"));
+        assert!(dot.contains("s2 [label=\"3\"];"));
+        assert!(dot.contains("m1 -> m2 [label=\"#1 Inc\"];"));
+        assert!(dot.contains("m2 -> s2 [style=dashed, arrowhead=none];"));
+    }
+
+    #[test]
+    fn test_eq_model() {
+        let empty = || KVStore {
+            data: std::collections::HashMap::new(),
+        };
+        let model = EqModel::new(empty(), |state: &mut KVStore, op: &KVOp| match op {
+            KVOp::Set(k, v) => {
+                state.data.insert(k.clone(), v.clone());
+            }
+            KVOp::Get(_) => {}
+            KVOp::Delete(k) => {
+                state.data.remove(k);
+            }
+        });
+        let test = ModelBasedTest::new(model.clone(), empty());
+
+        let mut seq = OperationSequence::new();
+        seq.push(KVOp::Set("key1".to_string(), "value1".to_string()));
+        seq.push(KVOp::Delete("key1".to_string()));
+        assert!(test.run(&seq).is_ok());
+
+        // A model that forgets deletes diverges from the system
+        let forgetful = EqModel::new(empty(), |state: &mut KVStore, op: &KVOp| {
+            if let KVOp::Set(k, v) = op {
+                state.data.insert(k.clone(), v.clone());
+            }
+        });
+        let mismatch = ModelBasedTest::new(forgetful, empty())
+            .run(&seq)
+            .unwrap_err();
+        assert_eq!(mismatch.operation_index, Some(1));
+        assert!(model.state().data.is_empty());
+    }
 }
@@ -5,7 +5,9 @@ pub mod sequence;
 pub mod shrinking;
 
 pub use generator::WeightedGenerator;
+pub use sequence::{OperationSequenceGenerator, SequenceGenerator};
 
+use rand::Rng;
 use std::fmt::Debug;
 
 /// Represents a single operation that can be applied to a state
@@ -34,7 +36,7 @@ pub trait Operation: Debug + Clone {
 }
 
 /// A sequence of operations to be executed
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct OperationSequence<Op> {
     operations: Vec<Op>,
 }
@@ -72,6 +74,56 @@ impl<Op: Operation> OperationSequence<Op> {
         self.operations.is_empty()
     }
 
+    /// Concatenate two sequences, running `other` after this one
+    pub fn then(mut self, other: Self) -> Self {
+        self.operations.extend(other.operations);
+        self
+    }
+
+    /// Interleave several sequences round-robin
+    ///
+    /// Takes one operation from each sequence in turn, skipping exhausted
+    /// ones, so every sequence keeps its internal order.
+    pub fn interleave(sequences: &[Self]) -> Self {
+        let longest = sequences.iter().map(Self::len).max().unwrap_or(0);
+        let operations = (0..longest)
+            .flat_map(|idx| {
+                sequences
+                    .iter()
+                    .filter_map(move |seq| seq.operations.get(idx))
+            })
+            .cloned()
+            .collect();
+        Self::from_vec(operations)
+    }
+
+    /// Interleave several sequences in a random order
+    ///
+    /// Every sequence keeps its internal order. Each step draws from a
+    /// sequence in proportion to its remaining operations, which makes every
+    /// valid merge equally likely.
+    pub fn interleave_with<R: Rng>(sequences: &[Self], rng: &mut R) -> Self {
+        let mut next = vec![0; sequences.len()];
+        let mut remaining: usize = sequences.iter().map(Self::len).sum();
+        let mut operations = Vec::with_capacity(remaining);
+
+        while remaining > 0 {
+            let mut pick = rng.gen_range(0..remaining);
+            for (seq, next) in sequences.iter().zip(next.iter_mut()) {
+                let left = seq.len() - *next;
+                if pick < left {
+                    operations.push(seq.operations[*next].clone());
+                    *next += 1;
+                    break;
+                }
+                pick -= left;
+            }
+            remaining -= 1;
+        }
+
+        Self::from_vec(operations)
+    }
+
     /// Execute all operations in sequence
     pub fn execute_all(&self, state: &mut Op::State) {
         for op in &self.operations {
@@ -183,6 +235,66 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[derive(Debug, Clone, PartialEq)]
+    struct Tagged(char, usize);
+
+    impl Operation for Tagged {
+        type State = Vec<(char, usize)>;
+
+        fn execute(&self, state: &mut Self::State) {
+            state.push((self.0, self.1));
+        }
+    }
+
+    fn tagged(tag: char, len: usize) -> OperationSequence<Tagged> {
+        OperationSequence::from_vec((0..len).map(|idx| Tagged(tag, idx)).collect())
+    }
+
+    #[test]
+    fn test_sequence_then() {
+        let seq = tagged('a', 2).then(tagged('b', 1));
+        assert_eq!(
+            seq.operations(),
+            &[Tagged('a', 0), Tagged('a', 1), Tagged('b', 0)]
+        );
+    }
+
+    #[test]
+    fn test_sequence_interleave() {
+        let seq = OperationSequence::interleave(&[tagged('a', 3), tagged('b', 1), tagged('c', 2)]);
+        assert_eq!(
+            seq.operations(),
+            &[
+                Tagged('a', 0),
+                Tagged('b', 0),
+                Tagged('c', 0),
+                Tagged('a', 1),
+                Tagged('c', 1),
+                Tagged('a', 2),
+            ]
+        );
+        assert!(OperationSequence::<Tagged>::interleave(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_sequence_interleave_with_preserves_order() {
+        use rand::SeedableRng;
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        let sequences = [tagged('a', 4), tagged('b', 3), tagged('c', 2)];
+
+        for _ in 0..20 {
+            let seq = OperationSequence::interleave_with(&sequences, &mut rng);
+            assert_eq!(seq.len(), 9);
+
+            for source in &sequences {
+                let tag = source.operations()[0].0;
+                let picked: Vec<_> = seq.operations().iter().filter(|op| op.0 == tag).collect();
+                let expected: Vec<_> = source.operations().iter().collect();
+                assert_eq!(picked, expected);
+            }
+        }
+    }
+
     #[test]
     fn test_sequence_shrinking() {
         let mut seq = OperationSequence::new();
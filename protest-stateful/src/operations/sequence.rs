@@ -5,6 +5,10 @@ use protest::GeneratorConfig;
 use rand::Rng;
 
 /// Generator for operation sequences
+///
+/// Implements [`protest::Generator`] so that operation sequences can be composed
+/// with other generators and driven by the regular `check`/`PropertyTest` runner.
+/// Shrinking delegates to [`OperationSequence::shrink`].
 pub struct SequenceGenerator<Op, OpGen> {
     op_generator: OpGen,
     min_length: usize,
@@ -81,9 +85,151 @@ where
 
     fn shrink(
         &self,
-        _value: &OperationSequence<Op>,
+        value: &OperationSequence<Op>,
     ) -> Box<dyn Iterator<Item = OperationSequence<Op>>> {
-        // Shrinking is handled by OperationSequence::shrink()
-        Box::new(std::iter::empty())
+        let min_length = self.min_length;
+        Box::new(
+            value
+                .shrink()
+                .into_iter()
+                .filter(move |candidate| candidate.len() >= min_length),
+        )
+    }
+}
+
+/// Alias for [`SequenceGenerator`], named after the value it produces
+///
+/// # Example
+///
+/// ```
+/// use protest::OneOfGenerator;
+/// use protest::ergonomic::check_with_closure;
+/// use protest_stateful::prelude::*;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum CounterOp {
+///     Increment,
+///     Decrement,
+/// }
+///
+/// impl Operation for CounterOp {
+///     type State = i32;
+///
+///     fn execute(&self, state: &mut i32) {
+///         match self {
+///             CounterOp::Increment => *state += 1,
+///             CounterOp::Decrement => *state -= 1,
+///         }
+///     }
+/// }
+///
+/// let generator = OperationSequenceGenerator::new(
+///     OneOfGenerator::new(vec![CounterOp::Increment, CounterOp::Decrement]),
+///     0,
+///     10,
+/// );
+///
+/// let result = check_with_closure(generator, |seq: OperationSequence<CounterOp>| {
+///     let mut state = 0;
+///     seq.execute_all(&mut state);
+///     state.unsigned_abs() as usize <= seq.len()
+/// });
+/// assert!(result.is_ok());
+/// ```
+pub type OperationSequenceGenerator<Op, OpGen> = SequenceGenerator<Op, OpGen>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protest::ergonomic::check_with_closure_config;
+    use protest::{Generator, OneOfGenerator, TestConfig};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum CounterOp {
+        Increment,
+        Decrement,
+    }
+
+    impl Operation for CounterOp {
+        type State = i32;
+
+        fn execute(&self, state: &mut Self::State) {
+            match self {
+                CounterOp::Increment => *state += 1,
+                CounterOp::Decrement => *state -= 1,
+            }
+        }
+    }
+
+    fn counter_ops() -> OneOfGenerator<CounterOp> {
+        OneOfGenerator::new(vec![CounterOp::Increment, CounterOp::Decrement])
+    }
+
+    #[test]
+    fn test_generated_length_within_bounds() {
+        let generator = OperationSequenceGenerator::new(counter_ops(), 2, 6);
+        let mut rng = ChaCha8Rng::seed_from_u64(42);
+        let config = GeneratorConfig::default();
+
+        for _ in 0..50 {
+            let seq = generator.generate(&mut rng, &config);
+            assert!((2..=6).contains(&seq.len()));
+        }
+    }
+
+    #[test]
+    fn test_shrink_delegates_to_sequence() {
+        let generator = OperationSequenceGenerator::new(counter_ops(), 0, 10);
+        let seq = OperationSequence::from_vec(vec![
+            CounterOp::Increment,
+            CounterOp::Decrement,
+            CounterOp::Increment,
+        ]);
+
+        let shrunk: Vec<_> = generator.shrink(&seq).collect();
+        assert_eq!(shrunk.len(), seq.shrink().len());
+        assert!(shrunk.iter().all(|s| s.len() < seq.len()));
+    }
+
+    #[test]
+    fn test_shrink_respects_min_length() {
+        let generator = OperationSequenceGenerator::new(counter_ops(), 2, 10);
+        let seq = OperationSequence::from_vec(vec![
+            CounterOp::Increment,
+            CounterOp::Increment,
+            CounterOp::Increment,
+        ]);
+
+        assert!(generator.shrink(&seq).all(|s| s.len() >= 2));
+    }
+
+    #[test]
+    fn test_check_shrinks_failing_sequence() {
+        let generator = OperationSequenceGenerator::new(counter_ops(), 1, 20);
+        let config = TestConfig {
+            seed: Some(7),
+            ..TestConfig::default()
+        };
+
+        let result = check_with_closure_config(
+            generator,
+            |seq: OperationSequence<CounterOp>| {
+                let mut state = 0;
+                seq.execute_all(&mut state);
+                state < 3
+            },
+            config,
+        );
+
+        let failure = result.expect_err("a sequence reaching 3 should be found");
+        let original = failure.original_input.len();
+        let minimal = failure
+            .shrunk_input
+            .as_ref()
+            .map(|seq| seq.len())
+            .unwrap_or(original);
+        assert!(minimal <= original);
     }
 }
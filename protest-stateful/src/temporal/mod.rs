@@ -123,6 +123,8 @@ where
 }
 
 /// "P leads to Q" - If P holds, then Q must eventually hold later
+///
+/// With [`LeadsTo::within`], Q must hold within a bounded number of steps.
 pub struct LeadsTo<State, F1, F2>
 where
     F1: Fn(&State) -> bool,
@@ -131,6 +133,7 @@ where
     name: String,
     p_predicate: F1,
     q_predicate: F2,
+    window: usize,
     _phantom: std::marker::PhantomData<State>,
 }
 
@@ -141,10 +144,18 @@ where
 {
     /// Create a new "leads to" property
     pub fn new(name: impl Into<String>, p_predicate: F1, q_predicate: F2) -> Self {
+        Self::within(name, p_predicate, q_predicate, usize::MAX)
+    }
+
+    /// Create a bounded "leads to" property
+    ///
+    /// Whenever P holds at index `i`, Q must hold somewhere in `trace[i..i + steps]`.
+    pub fn within(name: impl Into<String>, p_predicate: F1, q_predicate: F2, steps: usize) -> Self {
         Self {
             name: name.into(),
             p_predicate,
             q_predicate,
+            window: steps,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -158,8 +169,9 @@ where
     fn check(&self, trace: &[State]) -> bool {
         for (i, state) in trace.iter().enumerate() {
             if (self.p_predicate)(state) {
-                // P holds at position i, check if Q holds at some point after i
-                if !trace[i..].iter().any(|s| (self.q_predicate)(s)) {
+                // P holds at position i, check if Q holds within the window
+                let end = i.saturating_add(self.window).min(trace.len());
+                if !trace[i..end].iter().any(|s| (self.q_predicate)(s)) {
                     return false; // Q never holds after P
                 }
             }
@@ -294,4 +306,24 @@ mod tests {
 
         assert!(!prop.check(&trace2));
     }
+
+    #[test]
+    fn test_leads_to_within() {
+        let state = |value, flag| State { value, flag };
+        let prop = LeadsTo::within(
+            "flag leads to value 10 within 2 steps",
+            |s: &State| s.flag,
+            |s: &State| s.value == 10,
+            2,
+        );
+
+        let fast = vec![state(0, true), state(10, false), state(0, false)];
+        assert!(prop.check(&fast));
+
+        let slow = vec![state(0, true), state(5, false), state(10, false)];
+        assert!(!prop.check(&slow));
+
+        let unbounded = LeadsTo::new("unbounded", |s: &State| s.flag, |s: &State| s.value == 10);
+        assert!(unbounded.check(&slow));
+    }
 }
@@ -47,6 +47,7 @@ fn example_1_parallel_execution() {
         num_threads: 4,
         batch_size: 25,
         enabled: true,
+        pregenerate_inputs: false,
     };
 
     // Sequential execution
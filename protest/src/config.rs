@@ -1,7 +1,10 @@
 //! Configuration types for controlling test behavior and generation parameters.
 
+use crate::shrink::ShrinkConfig;
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Configuration validation errors
@@ -15,6 +18,13 @@ pub enum ConfigError {
     InvalidTimeout,
     /// Invalid max depth (must be > 0)
     InvalidMaxDepth(usize),
+    /// A configuration file could not be read or parsed
+    InvalidConfigFile {
+        /// Path of the configuration file
+        path: String,
+        /// Why the file was rejected
+        reason: String,
+    },
 }
 
 impl std::fmt::Display for ConfigError {
@@ -32,6 +42,9 @@ impl std::fmt::Display for ConfigError {
             ConfigError::InvalidMaxDepth(n) => {
                 write!(f, "Invalid max depth: {} (must be > 0)", n)
             }
+            ConfigError::InvalidConfigFile { path, reason } => {
+                write!(f, "Invalid config file {}: {}", path, reason)
+            }
         }
     }
 }
@@ -39,26 +52,25 @@ impl std::fmt::Display for ConfigError {
 impl std::error::Error for ConfigError {}
 
 /// Configuration for generators
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GeneratorConfig {
     /// Hint for the size of generated collections
     pub size_hint: usize,
     /// Maximum depth for nested structures
+    ///
+    /// Pointer generators such as `BoxGenerator` generate their value with
+    /// [`GeneratorConfig::nested`], and derived enum generators only pick
+    /// variants that do not refer to the enum itself once the depth reaches 1,
+    /// so recursive types stay bounded.
     pub max_depth: usize,
     /// Custom ranges and constraints for specific types
-    pub custom_ranges: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
-}
-
-impl Clone for GeneratorConfig {
-    fn clone(&self) -> Self {
-        Self {
-            size_hint: self.size_hint,
-            max_depth: self.max_depth,
-            // We can't clone the custom_ranges, so we create a new empty HashMap
-            // This is acceptable for now as custom ranges will be handled differently
-            custom_ranges: HashMap::new(),
-        }
-    }
+    ///
+    /// Keyed by the `TypeId` of the generated type. Integer generators look up a
+    /// `RangeInclusive` of their own type here and clamp their range to the
+    /// intersection with it (see [`GeneratorConfig::with_custom_range`]). Entries
+    /// of any other shape are ignored. Entries are shared, so clones of the
+    /// config (for example per thread or per replay) keep them.
+    pub custom_ranges: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
 }
 
 impl Default for GeneratorConfig {
@@ -85,10 +97,53 @@ impl GeneratorConfig {
         Ok(Self {
             size_hint,
             max_depth,
-            custom_ranges,
+            custom_ranges: custom_ranges
+                .into_iter()
+                .map(|(type_id, range)| (type_id, Arc::from(range)))
+                .collect(),
         })
     }
 
+    /// Register a global range for values of type `T`
+    ///
+    /// Integer generators for `T` only produce values in the intersection of their
+    /// own range and this one, so the range also applies to generators created by
+    /// `Arbitrary` and derived implementations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protest::{Generator, GeneratorConfig, IntGenerator};
+    ///
+    /// let config = GeneratorConfig::default().with_custom_range(0i32..=9);
+    /// let generator = IntGenerator::<i32>::full_range();
+    ///
+    /// let value = generator.generate(&mut rand::thread_rng(), &config);
+    /// assert!((0..=9).contains(&value));
+    /// ```
+    pub fn with_custom_range<T: Any + Send + Sync>(mut self, range: RangeInclusive<T>) -> Self {
+        self.custom_ranges
+            .insert(TypeId::of::<T>(), Arc::new(range));
+        self
+    }
+
+    /// Get the global range registered for values of type `T`, if any
+    pub fn custom_range<T: Any>(&self) -> Option<&RangeInclusive<T>> {
+        self.custom_ranges
+            .get(&TypeId::of::<T>())
+            .and_then(|range| range.downcast_ref::<RangeInclusive<T>>())
+    }
+
+    /// Configuration for a value nested one level deeper
+    ///
+    /// Identical to this configuration with `max_depth` reduced by one, bottoming
+    /// out at 1.
+    pub fn nested(&self) -> Self {
+        let mut config = self.clone();
+        config.max_depth = self.max_depth.saturating_sub(1).max(1);
+        config
+    }
+
     /// Validate the generator configuration
     pub fn validate(&self) -> Result<(), ConfigError> {
         if self.max_depth == 0 {
@@ -99,8 +154,10 @@ impl GeneratorConfig {
 
     /// Merge this configuration with another, with this config taking precedence for non-default values
     pub fn merge_with(self, _other: &GeneratorConfig) -> Self {
-        // For now, we can't clone custom_ranges due to trait object limitations
-        // In a real implementation, we'd need a different approach for custom ranges
+        // Custom ranges from both, with this config's range winning for a shared type
+        let mut custom_ranges = _other.custom_ranges.clone();
+        custom_ranges.extend(self.custom_ranges);
+
         Self {
             size_hint: if self.size_hint != 10 {
                 self.size_hint
@@ -112,11 +169,44 @@ impl GeneratorConfig {
             } else {
                 _other.max_depth
             },
-            custom_ranges: self.custom_ranges, // Always use self's custom ranges
+            custom_ranges,
         }
     }
 }
 
+/// How the runner scales `GeneratorConfig::size_hint` across iterations
+///
+/// Starting small and growing the inputs keeps early counterexamples tiny and
+/// easy to read, while later iterations still reach the configured size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SizeGrowth {
+    /// Every iteration uses the configured size hint
+    #[default]
+    Constant,
+    /// Size hint grows linearly from 1 to the configured size hint
+    Linear,
+    /// Size hint grows exponentially from 1 to the configured size hint
+    Exponential,
+}
+
+impl SizeGrowth {
+    /// Size hint for `iteration` (0-based) out of `iterations`, ramping up to `max_size`
+    pub fn size_for(self, iteration: usize, iterations: usize, max_size: usize) -> usize {
+        if max_size <= 1 || iterations <= 1 {
+            return max_size;
+        }
+
+        let progress = iteration.min(iterations - 1) as f64 / (iterations - 1) as f64;
+        let size = match self {
+            SizeGrowth::Constant => return max_size,
+            SizeGrowth::Linear => 1.0 + (max_size - 1) as f64 * progress,
+            SizeGrowth::Exponential => (max_size as f64).powf(progress),
+        };
+
+        (size.round() as usize).clamp(1, max_size)
+    }
+}
+
 /// Configuration for individual property tests
 #[derive(Debug, Clone)]
 pub struct TestConfig {
@@ -126,10 +216,20 @@ pub struct TestConfig {
     pub max_shrink_iterations: usize,
     /// Timeout for shrinking process
     pub shrink_timeout: Duration,
+    /// Shrink budget as a multiple of the failing check's duration; replaces `shrink_timeout` when set
+    pub shrink_budget_multiplier: Option<f64>,
     /// Optional seed for reproducible tests
     pub seed: Option<u64>,
     /// Generator configuration overrides
     pub generator_config: GeneratorConfig,
+    /// How `generator_config.size_hint` is scaled across iterations
+    pub size_growth: SizeGrowth,
+    /// Print a coverage summary at the end of coverage-guided runs
+    pub coverage_report: bool,
+    /// Inputs [`crate::assume`] may reject in one run before it fails
+    pub max_rejections: usize,
+    /// Minimum coverage of [`crate::classify`] labels, checked after all iterations
+    pub coverage_thresholds: Option<CoverageThresholds>,
 }
 
 impl Default for TestConfig {
@@ -138,8 +238,13 @@ impl Default for TestConfig {
             iterations: 100,
             max_shrink_iterations: 1000,
             shrink_timeout: Duration::from_secs(10),
+            shrink_budget_multiplier: None,
             seed: None,
             generator_config: GeneratorConfig::default(),
+            size_growth: SizeGrowth::Constant,
+            coverage_report: false,
+            max_rejections: 1000,
+            coverage_thresholds: None,
         }
     }
 }
@@ -167,8 +272,13 @@ impl TestConfig {
             iterations,
             max_shrink_iterations,
             shrink_timeout,
+            shrink_budget_multiplier: None,
             seed,
             generator_config,
+            size_growth: SizeGrowth::Constant,
+            coverage_report: false,
+            max_rejections: 1000,
+            coverage_thresholds: None,
         })
     }
 
@@ -189,14 +299,65 @@ impl TestConfig {
         Ok(())
     }
 
+    /// Shrinking limits of this configuration as a [`ShrinkConfig`]
+    ///
+    /// The runner asks the result for its budget with
+    /// [`ShrinkConfig::budget_for`], so the runner and a standalone
+    /// `ShrinkEngine` pick between the flat timeout and the multiplier the
+    /// same way.
+    pub fn shrink_config(&self) -> ShrinkConfig {
+        let mut shrink_config =
+            ShrinkConfig::new(self.max_shrink_iterations, self.shrink_timeout, false);
+        shrink_config.budget_multiplier = self.shrink_budget_multiplier;
+        shrink_config
+    }
+
+    /// Size hint to use for the given (0-based) iteration under `size_growth`
+    pub fn size_hint_for_iteration(&self, iteration: usize) -> usize {
+        self.size_growth
+            .size_for(iteration, self.iterations, self.generator_config.size_hint)
+    }
+
+    /// Apply the `PROTEST_SEED` and `PROTEST_ITERATIONS` environment variables
+    ///
+    /// Parseable values override the configured seed and iteration count, so a
+    /// failing seed reported by CI can be reproduced locally without editing code.
+    /// Each override is logged to stderr. Unset, unparseable or zero values are
+    /// ignored.
+    pub fn apply_env_overrides(&mut self) {
+        self.apply_overrides_from(&env_lookup);
+    }
+
+    fn apply_overrides_from(&mut self, lookup: &dyn Fn(&str) -> Option<String>) {
+        if let Some(seed) = seed_override(lookup) {
+            eprintln!(
+                "protest: using seed {} from {} (configured: {:?})",
+                seed, SEED_ENV_VAR, self.seed
+            );
+            self.seed = Some(seed);
+        }
+        if let Some(iterations) = iterations_override(lookup) {
+            eprintln!(
+                "protest: running {} iterations from {} (configured: {})",
+                iterations, ITERATIONS_ENV_VAR, self.iterations
+            );
+            self.iterations = iterations;
+        }
+    }
+
     /// Merge this configuration with a global configuration, with this config taking precedence
     pub fn merge_with_global(self, global: &GlobalConfig) -> Self {
         Self {
             iterations: self.iterations,
             max_shrink_iterations: self.max_shrink_iterations,
             shrink_timeout: self.shrink_timeout,
+            shrink_budget_multiplier: self.shrink_budget_multiplier,
             seed: self.seed.or(global.default_seed),
             generator_config: self.generator_config.merge_with(&global.generator_config),
+            size_growth: self.size_growth,
+            coverage_report: self.coverage_report,
+            max_rejections: self.max_rejections,
+            coverage_thresholds: self.coverage_thresholds,
         }
     }
 
@@ -209,11 +370,16 @@ impl TestConfig {
     ) -> Result<Self, ConfigError> {
         let config = Self {
             iterations: iterations.unwrap_or(global.default_iterations),
-            max_shrink_iterations: 1000,             // Default value
-            shrink_timeout: Duration::from_secs(10), // Default value
+            max_shrink_iterations: 1000, // Default value
+            shrink_timeout: global.default_shrink_timeout,
+            shrink_budget_multiplier: None,
             seed: seed.or(global.default_seed),
             generator_config: generator_overrides
                 .unwrap_or_else(|| global.generator_config.clone()),
+            size_growth: SizeGrowth::Constant,
+            coverage_report: false,
+            max_rejections: 1000,
+            coverage_thresholds: None,
         };
         config.validate()?;
         Ok(config)
@@ -227,6 +393,8 @@ pub struct GlobalConfig {
     pub default_iterations: usize,
     /// Default seed for reproducible tests
     pub default_seed: Option<u64>,
+    /// Default timeout for the shrinking process
+    pub default_shrink_timeout: Duration,
     /// Default generator configuration
     pub generator_config: GeneratorConfig,
 }
@@ -236,6 +404,7 @@ impl Default for GlobalConfig {
         Self {
             default_iterations: 100,
             default_seed: None,
+            default_shrink_timeout: Duration::from_secs(10),
             generator_config: GeneratorConfig::default(),
         }
     }
@@ -255,6 +424,7 @@ impl GlobalConfig {
         Ok(Self {
             default_iterations,
             default_seed,
+            default_shrink_timeout: Duration::from_secs(10),
             generator_config,
         })
     }
@@ -264,6 +434,9 @@ impl GlobalConfig {
         if self.default_iterations == 0 {
             return Err(ConfigError::InvalidIterations(self.default_iterations));
         }
+        if self.default_shrink_timeout.is_zero() {
+            return Err(ConfigError::InvalidTimeout);
+        }
         self.generator_config.validate()?;
         Ok(())
     }
@@ -489,22 +662,24 @@ impl NumericCoverage {
     }
 
     /// Get coverage percentage for a specific range
+    ///
+    /// The fraction (0.0 to 1.0) of `[min, max]` spanned by the smallest and
+    /// largest recorded values. A degenerate range counts as covered once a
+    /// recorded span contains it.
     pub fn get_range_coverage(&self, min: f64, max: f64) -> f64 {
         if self.total_count == 0 {
             return 0.0;
         }
 
-        let values_in_range = self
-            .range_distribution
-            .iter()
-            .filter(|(range, _)| {
-                // Simple range parsing - in practice, this would be more robust
-                range.contains(&min.to_string()) || range.contains(&max.to_string())
-            })
-            .map(|(_, count)| *count)
-            .sum::<usize>();
-
-        values_in_range as f64 / self.total_count as f64
+        let low = self.min_value.max(min);
+        let high = self.max_value.min(max);
+        if high < low {
+            return 0.0;
+        }
+        if max <= min {
+            return 1.0;
+        }
+        (high - low) / (max - min)
     }
 }
 
@@ -895,42 +1070,115 @@ impl GenerationStats {
     }
 
     /// Check if coverage meets specified thresholds
+    ///
+    /// A numeric or enum threshold for a type nothing was recorded for fails.
     pub fn check_coverage_thresholds(&self, thresholds: &CoverageThresholds) -> CoverageReport {
         let mut report = CoverageReport::new();
-
-        // Check numeric coverage
-        for (type_name, coverage) in &self.coverage_info.numeric_coverage {
-            if let Some(threshold) = thresholds.numeric_thresholds.get(type_name) {
-                let range_coverage =
-                    coverage.get_range_coverage(threshold.min_value, threshold.max_value);
-                report.add_numeric_result(
-                    type_name.clone(),
-                    range_coverage >= threshold.min_coverage,
-                );
+        for (kind, type_name, coverage, required) in self.threshold_coverage(thresholds) {
+            let passed = coverage >= required;
+            match kind {
+                ThresholdKind::Numeric => report.add_numeric_result(type_name, passed),
+                ThresholdKind::Boolean => report.add_boolean_result(type_name, passed),
+                ThresholdKind::Enum => report.add_enum_result(type_name, passed),
             }
         }
+        report
+    }
 
-        // Check boolean coverage
-        for (type_name, coverage) in &self.coverage_info.boolean_coverage {
-            if thresholds.require_full_boolean_coverage {
-                report.add_boolean_result(type_name.clone(), coverage.has_full_coverage());
-            }
+    /// First numeric, boolean or enum threshold these statistics fall short of
+    ///
+    /// Returns a description of what was not covered, the observed coverage and
+    /// the required coverage. Thresholds are checked by kind, then by type name.
+    pub fn first_unmet_threshold(
+        &self,
+        thresholds: &CoverageThresholds,
+    ) -> Option<(String, f64, f64)> {
+        self.threshold_coverage(thresholds)
+            .into_iter()
+            .find(|(_, _, coverage, required)| coverage < required)
+            .map(|(kind, type_name, coverage, required)| {
+                let target = match kind {
+                    ThresholdKind::Numeric => {
+                        let threshold = &thresholds.numeric_thresholds[&type_name];
+                        format!(
+                            "{} range [{}, {}]",
+                            type_name, threshold.min_value, threshold.max_value
+                        )
+                    }
+                    ThresholdKind::Boolean => format!("{} values", type_name),
+                    ThresholdKind::Enum => format!("{} variants", type_name),
+                };
+                (target, coverage, required)
+            })
+    }
+
+    /// Observed and required coverage for every type threshold, in check order
+    fn threshold_coverage(
+        &self,
+        thresholds: &CoverageThresholds,
+    ) -> Vec<(ThresholdKind, String, f64, f64)> {
+        let mut results = Vec::new();
+
+        let mut numeric: Vec<_> = thresholds.numeric_thresholds.iter().collect();
+        numeric.sort_by(|a, b| a.0.cmp(b.0));
+        for (type_name, threshold) in numeric {
+            let coverage = self
+                .coverage_info
+                .numeric_coverage
+                .get(type_name)
+                .map_or(0.0, |coverage| {
+                    coverage.get_range_coverage(threshold.min_value, threshold.max_value)
+                });
+            results.push((
+                ThresholdKind::Numeric,
+                type_name.clone(),
+                coverage,
+                threshold.min_coverage,
+            ));
         }
 
-        // Check enum coverage
-        for (type_name, coverage) in &self.coverage_info.enum_coverage {
-            if let Some(threshold) = thresholds.enum_thresholds.get(type_name) {
-                report.add_enum_result(
+        if thresholds.require_full_boolean_coverage {
+            let mut boolean: Vec<_> = self.coverage_info.boolean_coverage.iter().collect();
+            boolean.sort_by(|a, b| a.0.cmp(b.0));
+            for (type_name, coverage) in boolean {
+                let seen =
+                    usize::from(coverage.true_count > 0) + usize::from(coverage.false_count > 0);
+                results.push((
+                    ThresholdKind::Boolean,
                     type_name.clone(),
-                    coverage.coverage_percentage >= threshold.min_coverage,
-                );
+                    seen as f64 / 2.0,
+                    1.0,
+                ));
             }
         }
 
-        report
+        let mut enums: Vec<_> = thresholds.enum_thresholds.iter().collect();
+        enums.sort_by(|a, b| a.0.cmp(b.0));
+        for (type_name, threshold) in enums {
+            let coverage = self
+                .coverage_info
+                .enum_coverage
+                .get(type_name)
+                .map_or(0.0, |coverage| coverage.coverage_percentage);
+            results.push((
+                ThresholdKind::Enum,
+                type_name.clone(),
+                coverage,
+                threshold.min_coverage,
+            ));
+        }
+
+        results
     }
 }
 
+/// Which part of [`CoverageThresholds`] a coverage result belongs to
+enum ThresholdKind {
+    Numeric,
+    Boolean,
+    Enum,
+}
+
 /// Thresholds for coverage checking
 #[derive(Debug, Clone)]
 pub struct CoverageThresholds {
@@ -940,6 +1188,51 @@ pub struct CoverageThresholds {
     pub require_full_boolean_coverage: bool,
     /// Thresholds for enum types
     pub enum_thresholds: HashMap<String, EnumThreshold>,
+    /// Minimum fraction (0.0 to 1.0) of tested inputs each [`crate::classify`] label must cover
+    pub(crate) label_thresholds: HashMap<String, f64>,
+}
+
+impl CoverageThresholds {
+    /// Minimum fraction of tested inputs each [`crate::classify`] label must cover
+    ///
+    /// Set with [`CoverageThresholdsBuilder::label_threshold`](crate::CoverageThresholdsBuilder::label_threshold).
+    pub fn label_thresholds(&self) -> &HashMap<String, f64> {
+        &self.label_thresholds
+    }
+
+    /// Whether any numeric, boolean or enum threshold is set
+    ///
+    /// These are checked against generation statistics, so the runner collects
+    /// statistics whenever one is set.
+    pub fn has_type_thresholds(&self) -> bool {
+        !self.numeric_thresholds.is_empty()
+            || self.require_full_boolean_coverage
+            || !self.enum_thresholds.is_empty()
+    }
+
+    /// First label (by name) covering less than its threshold of `tested` inputs
+    ///
+    /// Returns a description of the label, its observed coverage and the
+    /// required coverage.
+    pub fn first_uncovered_label(
+        &self,
+        label_counts: &HashMap<String, usize>,
+        tested: usize,
+    ) -> Option<(String, f64, f64)> {
+        let mut labels: Vec<_> = self.label_thresholds.iter().collect();
+        labels.sort_by(|a, b| a.0.cmp(b.0));
+
+        labels.into_iter().find_map(|(label, &required)| {
+            let count = label_counts.get(label).copied().unwrap_or(0);
+            let coverage = if tested == 0 {
+                0.0
+            } else {
+                count as f64 / tested as f64
+            };
+            (coverage < required)
+                .then(|| (format!("inputs labelled '{}'", label), coverage, required))
+        })
+    }
 }
 
 /// Threshold for numeric type coverage
@@ -1052,6 +1345,47 @@ impl CoverageReport {
     }
 }
 
+/// Environment variable that overrides the configured seed
+pub const SEED_ENV_VAR: &str = "PROTEST_SEED";
+
+/// Environment variable that overrides the configured number of iterations
+pub const ITERATIONS_ENV_VAR: &str = "PROTEST_ITERATIONS";
+
+/// Look up an environment variable, treating non-Unicode values as unset
+fn env_lookup(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+/// Look up and parse an override, ignoring unset or unparseable values
+fn parse_override<T: std::str::FromStr>(
+    lookup: &dyn Fn(&str) -> Option<String>,
+    name: &str,
+) -> Option<T> {
+    lookup(name)?.trim().parse().ok()
+}
+
+/// The [`SEED_ENV_VAR`] override, ignoring unset or unparseable values
+fn seed_override(lookup: &dyn Fn(&str) -> Option<String>) -> Option<u64> {
+    parse_override(lookup, SEED_ENV_VAR)
+}
+
+/// The [`ITERATIONS_ENV_VAR`] override, ignoring unset, unparseable or zero values
+fn iterations_override(lookup: &dyn Fn(&str) -> Option<String>) -> Option<usize> {
+    parse_override(lookup, ITERATIONS_ENV_VAR).filter(|&n| n > 0)
+}
+
+/// Keys accepted in a `protest.toml` file
+#[cfg(feature = "toml")]
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TomlConfigFile {
+    iterations: Option<usize>,
+    seed: Option<u64>,
+    shrink_timeout_ms: Option<u64>,
+    size_hint: Option<usize>,
+    max_depth: Option<usize>,
+}
+
 /// Global configuration manager for hierarchical configuration
 pub struct ConfigManager {
     global_config: GlobalConfig,
@@ -1071,6 +1405,68 @@ impl ConfigManager {
         Ok(Self { global_config })
     }
 
+    /// Create a configuration manager from a `protest.toml` file
+    ///
+    /// All keys are optional; missing keys keep their defaults:
+    ///
+    /// ```toml
+    /// iterations = 500
+    /// seed = 12345
+    /// shrink_timeout_ms = 5000
+    /// size_hint = 20
+    /// max_depth = 8
+    /// ```
+    ///
+    /// The `PROTEST_ITERATIONS` and `PROTEST_SEED` environment variables take
+    /// precedence over the values in the file, and are ignored when unparseable
+    /// or zero as in [`TestConfig::apply_env_overrides`]. Unreadable files,
+    /// malformed TOML and unknown keys are reported as
+    /// [`ConfigError::InvalidConfigFile`].
+    #[cfg(feature = "toml")]
+    pub fn from_toml_path(path: impl AsRef<std::path::Path>) -> Result<Self, ConfigError> {
+        Self::from_toml_path_with(path.as_ref(), &env_lookup)
+    }
+
+    /// Load a `protest.toml` file, reading overrides through `lookup`
+    #[cfg(feature = "toml")]
+    fn from_toml_path_with(
+        path: &std::path::Path,
+        lookup: &dyn Fn(&str) -> Option<String>,
+    ) -> Result<Self, ConfigError> {
+        let invalid = |reason: String| ConfigError::InvalidConfigFile {
+            path: path.display().to_string(),
+            reason,
+        };
+
+        let contents = std::fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+        let file: TomlConfigFile = toml::from_str(&contents).map_err(|e| invalid(e.to_string()))?;
+
+        let mut global = GlobalConfig::default();
+        if let Some(iterations) = file.iterations {
+            global.default_iterations = iterations;
+        }
+        global.default_seed = file.seed;
+        if let Some(timeout_ms) = file.shrink_timeout_ms {
+            global.default_shrink_timeout = Duration::from_millis(timeout_ms);
+        }
+        if let Some(size_hint) = file.size_hint {
+            global.generator_config.size_hint = size_hint;
+        }
+        if let Some(max_depth) = file.max_depth {
+            global.generator_config.max_depth = max_depth;
+        }
+
+        // Environment variables win over the file
+        if let Some(iterations) = iterations_override(lookup) {
+            global.default_iterations = iterations;
+        }
+        if let Some(seed) = seed_override(lookup) {
+            global.default_seed = Some(seed);
+        }
+
+        Self::with_global_config(global)
+    }
+
     /// Get the current global configuration
     pub fn global_config(&self) -> &GlobalConfig {
         &self.global_config
@@ -1231,6 +1627,7 @@ mod tests {
         let global = GlobalConfig {
             default_iterations: 50,
             default_seed: Some(123),
+            default_shrink_timeout: Duration::from_secs(10),
             generator_config: GeneratorConfig {
                 size_hint: 20,
                 max_depth: 3,
@@ -1242,12 +1639,17 @@ mod tests {
             iterations: 200,
             max_shrink_iterations: 500,
             shrink_timeout: Duration::from_secs(5),
+            shrink_budget_multiplier: None,
             seed: Some(456),
             generator_config: GeneratorConfig {
                 size_hint: 15,
                 max_depth: 7,
                 custom_ranges: HashMap::new(),
             },
+            size_growth: SizeGrowth::Constant,
+            coverage_report: false,
+            max_rejections: 1000,
+            coverage_thresholds: None,
         };
 
         let merged = test_config.merge_with_global(&global);
@@ -1264,6 +1666,7 @@ mod tests {
         let global = GlobalConfig {
             default_iterations: 50,
             default_seed: Some(123),
+            default_shrink_timeout: Duration::from_secs(10),
             generator_config: GeneratorConfig {
                 size_hint: 20,
                 max_depth: 3,
@@ -1275,8 +1678,13 @@ mod tests {
             iterations: 200,
             max_shrink_iterations: 500,
             shrink_timeout: Duration::from_secs(5),
+            shrink_budget_multiplier: None,
             seed: None,                                   // Should inherit from global
             generator_config: GeneratorConfig::default(), // Should merge with global
+            size_growth: SizeGrowth::Constant,
+            coverage_report: false,
+            max_rejections: 1000,
+            coverage_thresholds: None,
         };
 
         let merged = test_config.merge_with_global(&global);
@@ -1293,6 +1701,7 @@ mod tests {
         let global = GlobalConfig {
             default_iterations: 50,
             default_seed: Some(123),
+            default_shrink_timeout: Duration::from_secs(10),
             generator_config: GeneratorConfig {
                 size_hint: 20,
                 max_depth: 3,
@@ -1334,6 +1743,7 @@ mod tests {
         let new_global = GlobalConfig {
             default_iterations: 200,
             default_seed: Some(789),
+            default_shrink_timeout: Duration::from_secs(10),
             generator_config: GeneratorConfig::default(),
         };
         manager.set_global_config(new_global).unwrap();
@@ -1365,6 +1775,7 @@ mod tests {
         let new_global = GlobalConfig {
             default_iterations: 150,
             default_seed: Some(555),
+            default_shrink_timeout: Duration::from_secs(10),
             generator_config: GeneratorConfig::default(),
         };
         set_global_config(new_global).unwrap();
@@ -1405,6 +1816,35 @@ mod tests {
         assert_eq!(format!("{}", error), "Invalid max depth: 0 (must be > 0)");
     }
 
+    #[test]
+    fn test_generator_config_clone_keeps_custom_ranges() {
+        let config = GeneratorConfig::default().with_custom_range(5i32..=9);
+        let cloned = config.clone();
+        assert_eq!(cloned.custom_range::<i32>(), Some(&(5..=9)));
+        assert_eq!(config.nested().custom_range::<i32>(), Some(&(5..=9)));
+
+        let merged = GeneratorConfig::default()
+            .with_custom_range(0u8..=1)
+            .merge_with(&config.with_custom_range(0u8..=9));
+        assert_eq!(merged.custom_range::<i32>(), Some(&(5..=9)));
+        assert_eq!(merged.custom_range::<u8>(), Some(&(0..=1)));
+    }
+
+    #[test]
+    fn test_generator_config_nested() {
+        let config = GeneratorConfig {
+            size_hint: 20,
+            max_depth: 3,
+            custom_ranges: HashMap::new(),
+        };
+
+        let nested = config.nested();
+        assert_eq!(nested.size_hint, 20);
+        assert_eq!(nested.max_depth, 2);
+        assert_eq!(nested.nested().max_depth, 1);
+        assert_eq!(nested.nested().nested().max_depth, 1);
+    }
+
     #[test]
     fn test_generator_config_merge() {
         let base = GeneratorConfig {
@@ -1429,4 +1869,211 @@ mod tests {
         assert_eq!(merged.size_hint, 15); // From override
         assert_eq!(merged.max_depth, 7); // From override
     }
+
+    #[test]
+    fn test_shrink_config() {
+        let mut config = TestConfig::default();
+        let shrink_config = config.shrink_config();
+        assert_eq!(shrink_config.max_iterations, config.max_shrink_iterations);
+        assert_eq!(
+            shrink_config.budget_for(Duration::from_secs(1)),
+            config.shrink_timeout
+        );
+
+        config.shrink_budget_multiplier = Some(10.0);
+        let shrink_config = config.shrink_config();
+        assert_eq!(
+            shrink_config.budget_for(Duration::from_secs(1)),
+            Duration::from_secs(10)
+        );
+        assert_eq!(
+            shrink_config.budget_for(Duration::ZERO),
+            crate::shrink::MIN_SHRINK_BUDGET
+        );
+    }
+
+    #[test]
+    fn test_global_shrink_timeout_inherited() {
+        let global = GlobalConfig {
+            default_shrink_timeout: Duration::from_millis(250),
+            ..GlobalConfig::default()
+        };
+        let manager = ConfigManager::with_global_config(global).unwrap();
+        assert_eq!(
+            manager.create_test_config().shrink_timeout,
+            Duration::from_millis(250)
+        );
+
+        let global = GlobalConfig {
+            default_shrink_timeout: Duration::ZERO,
+            ..GlobalConfig::default()
+        };
+        assert!(matches!(
+            ConfigManager::with_global_config(global),
+            Err(ConfigError::InvalidTimeout)
+        ));
+    }
+
+    #[test]
+    fn test_size_growth() {
+        // Constant ignores the iteration
+        assert_eq!(SizeGrowth::Constant.size_for(0, 100, 50), 50);
+
+        // Linear ramps evenly from 1 to the maximum
+        assert_eq!(SizeGrowth::Linear.size_for(0, 101, 101), 1);
+        assert_eq!(SizeGrowth::Linear.size_for(50, 101, 101), 51);
+        assert_eq!(SizeGrowth::Linear.size_for(100, 101, 101), 101);
+
+        // Exponential stays small for longer
+        assert_eq!(SizeGrowth::Exponential.size_for(0, 101, 100), 1);
+        assert_eq!(SizeGrowth::Exponential.size_for(50, 101, 100), 10);
+        assert_eq!(SizeGrowth::Exponential.size_for(100, 101, 100), 100);
+
+        // Degenerate inputs fall back to the maximum
+        assert_eq!(SizeGrowth::Linear.size_for(0, 1, 30), 30);
+        assert_eq!(SizeGrowth::Exponential.size_for(3, 10, 0), 0);
+
+        let config = TestConfig {
+            iterations: 11,
+            size_growth: SizeGrowth::Linear,
+            ..TestConfig::default()
+        };
+        let sizes: Vec<_> = (0..11).map(|i| config.size_hint_for_iteration(i)).collect();
+        assert_eq!(sizes, vec![1, 2, 3, 4, 5, 6, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        let lookup = |name: &str| match name {
+            SEED_ENV_VAR => Some("12345".to_string()),
+            ITERATIONS_ENV_VAR => Some(" 7 ".to_string()),
+            _ => None,
+        };
+        let mut config = TestConfig {
+            seed: Some(1),
+            ..TestConfig::default()
+        };
+        config.apply_overrides_from(&lookup);
+        assert_eq!(config.seed, Some(12345));
+        assert_eq!(config.iterations, 7);
+
+        // Unparseable and zero values leave the configuration untouched
+        let lookup = |name: &str| match name {
+            SEED_ENV_VAR => Some("not-a-seed".to_string()),
+            ITERATIONS_ENV_VAR => Some("0".to_string()),
+            _ => None,
+        };
+        let mut config = TestConfig::default();
+        config.apply_overrides_from(&lookup);
+        assert_eq!(config.seed, None);
+        assert_eq!(config.iterations, 100);
+    }
+
+    #[cfg(feature = "toml")]
+    mod toml_file {
+        use super::*;
+        use tempfile::TempDir;
+
+        struct ConfigFile {
+            _dir: TempDir,
+            path: std::path::PathBuf,
+        }
+
+        impl ConfigFile {
+            fn path(&self) -> &std::path::Path {
+                &self.path
+            }
+        }
+
+        fn write_config(contents: &str) -> ConfigFile {
+            let dir = TempDir::new().unwrap();
+            let path = dir.path().join("protest.toml");
+            std::fs::write(&path, contents).unwrap();
+            ConfigFile { _dir: dir, path }
+        }
+
+        #[test]
+        fn test_from_toml_path() {
+            let file = write_config(
+                "# shared tuning\niterations = 500\nseed = 12345\nshrink_timeout_ms = 2500\nsize_hint = 20\nmax_depth = 8\n",
+            );
+
+            let manager = ConfigManager::from_toml_path(file.path()).unwrap();
+            let global = manager.global_config();
+            assert_eq!(global.default_iterations, 500);
+            assert_eq!(global.default_seed, Some(12345));
+            assert_eq!(global.generator_config.size_hint, 20);
+            assert_eq!(global.generator_config.max_depth, 8);
+
+            let test_config = manager.create_test_config();
+            assert_eq!(test_config.iterations, 500);
+            assert_eq!(test_config.seed, Some(12345));
+            assert_eq!(test_config.shrink_timeout, Duration::from_millis(2500));
+            assert_eq!(test_config.generator_config.size_hint, 20);
+        }
+
+        #[test]
+        fn test_from_toml_path_partial() {
+            let file = write_config("size_hint = 3\n");
+
+            let global = ConfigManager::from_toml_path(file.path())
+                .unwrap()
+                .global_config()
+                .clone();
+            assert_eq!(global.generator_config.size_hint, 3);
+            assert_eq!(global.generator_config.max_depth, 5);
+            assert_eq!(global.default_shrink_timeout, Duration::from_secs(10));
+        }
+
+        #[test]
+        fn test_from_toml_path_env_overrides() {
+            let file = write_config("iterations = 500\nseed = 1\n");
+            let load = |iterations: &'static str| {
+                let lookup = move |name: &str| match name {
+                    ITERATIONS_ENV_VAR => Some(iterations.to_string()),
+                    SEED_ENV_VAR => Some("99".to_string()),
+                    _ => None,
+                };
+                ConfigManager::from_toml_path_with(file.path(), &lookup)
+                    .unwrap()
+                    .global_config()
+                    .clone()
+            };
+
+            let global = load("25");
+            assert_eq!(global.default_iterations, 25);
+            assert_eq!(global.default_seed, Some(99));
+
+            // Zero and unparseable counts are ignored, as by apply_env_overrides
+            assert_eq!(load("0").default_iterations, 500);
+            assert_eq!(load("many").default_iterations, 500);
+        }
+
+        #[test]
+        fn test_from_toml_path_errors() {
+            let malformed = write_config("iterations = = 3\n");
+            let err = ConfigManager::from_toml_path(malformed.path())
+                .err()
+                .unwrap();
+            assert!(matches!(err, ConfigError::InvalidConfigFile { .. }));
+            assert!(err.to_string().contains("Invalid config file"));
+
+            let unknown = write_config("iteratons = 3\n");
+            assert!(matches!(
+                ConfigManager::from_toml_path(unknown.path()),
+                Err(ConfigError::InvalidConfigFile { .. })
+            ));
+
+            let invalid = write_config("iterations = 0\n");
+            assert!(matches!(
+                ConfigManager::from_toml_path(invalid.path()),
+                Err(ConfigError::InvalidIterations(0))
+            ));
+
+            assert!(matches!(
+                ConfigManager::from_toml_path("/nonexistent/protest.toml"),
+                Err(ConfigError::InvalidConfigFile { .. })
+            ));
+        }
+    }
 }
@@ -2,13 +2,53 @@
 //!
 //! This module provides functionality to track code coverage during property testing
 //! and build a corpus of interesting test cases that maximize coverage.
-
+//!
+//! Properties mark interesting branches with [`record`]. In a coverage-guided run
+//! (see [`PropertyTestBuilder::coverage_guided`](crate::PropertyTestBuilder::coverage_guided)),
+//! the points each input reaches are combined into its path hash; inputs that
+//! reach a new path are saved to the [`CoverageCorpus`] and later inputs are
+//! often mutated from them instead of generated from scratch.
+
+use crate::config::GeneratorConfig;
+use crate::generator::Generator;
 use crate::persistence::TestCorpus;
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
+thread_local! {
+    /// Coverage points reached by the input currently under test, if recording
+    static RECORDED_POINTS: RefCell<Option<Vec<u64>>> = const { RefCell::new(None) };
+}
+
+/// Record that the property reached a coverage point
+///
+/// Call this at the branches you want coverage feedback on, e.g.
+/// `protest::coverage::record("overflow")`. Outside a coverage-guided run this
+/// does nothing.
+pub fn record<P: Hash>(point: P) {
+    RECORDED_POINTS.with(|points| {
+        if let Some(points) = points.borrow_mut().as_mut() {
+            points.push(path_hash(&[point]));
+        }
+    });
+}
+
+/// Start collecting coverage points on this thread
+pub(crate) fn start_recording() {
+    RECORDED_POINTS.with(|points| *points.borrow_mut() = Some(Vec::new()));
+}
+
+/// Stop collecting and return the path hash of the points reached, if any
+pub(crate) fn finish_recording() -> Option<u64> {
+    RECORDED_POINTS
+        .with(|points| points.borrow_mut().take())
+        .filter(|points| !points.is_empty())
+        .map(|points| path_hash(&points))
+}
+
 /// Tracks coverage information during test execution
 #[derive(Debug, Clone)]
 pub struct CoverageTracker {
@@ -54,6 +94,11 @@ impl CoverageTracker {
             .insert(path_hash);
     }
 
+    /// Check whether a path has been seen before
+    pub fn has_path(&self, path_hash: u64) -> bool {
+        self.paths_seen.lock().unwrap().contains(&path_hash)
+    }
+
     /// Get the number of unique paths seen
     pub fn unique_paths(&self) -> usize {
         *self.total_paths.lock().unwrap()
@@ -106,6 +151,11 @@ impl CoverageTracker {
 #[derive(Debug, Clone)]
 pub struct CoverageCorpusConfig {
     /// Minimum coverage increase required to add to corpus (percentage)
+    ///
+    /// A new path grows `n` known paths by `100 / n` percent, so any positive
+    /// threshold stops admitting inputs once `100 / threshold` paths are known
+    /// (100 paths for a threshold of 1.0). The default of 0.0 admits every input
+    /// that reaches a new path.
     pub min_coverage_increase: f64,
 
     /// Maximum corpus size
@@ -121,7 +171,7 @@ pub struct CoverageCorpusConfig {
 impl Default for CoverageCorpusConfig {
     fn default() -> Self {
         Self {
-            min_coverage_increase: 1.0, // 1% minimum increase
+            min_coverage_increase: 0.0, // Any new path
             max_corpus_size: 1000,
             corpus_dir: PathBuf::from(".protest/corpus"),
             auto_optimize: true,
@@ -137,6 +187,7 @@ impl CoverageCorpusConfig {
         }
     }
 
+    /// Set [`CoverageCorpusConfig::min_coverage_increase`]
     pub fn with_min_coverage(mut self, percent: f64) -> Self {
         self.min_coverage_increase = percent;
         self
@@ -158,6 +209,7 @@ pub struct CoverageCorpus {
     config: CoverageCorpusConfig,
     tracker: CoverageTracker,
     corpus: TestCorpus,
+    path_history: Vec<usize>,
 }
 
 impl CoverageCorpus {
@@ -167,10 +219,15 @@ impl CoverageCorpus {
             config,
             tracker: CoverageTracker::new(),
             corpus,
+            path_history: Vec::new(),
         })
     }
 
     /// Add input to corpus if it increases coverage significantly
+    ///
+    /// An input is added when its path has not been seen before and the new
+    /// path grows the number of known paths by at least `min_coverage_increase`
+    /// percent.
     pub fn try_add<T: std::fmt::Debug>(
         &mut self,
         input: &T,
@@ -179,14 +236,17 @@ impl CoverageCorpus {
         let input_str = format!("{:?}", input);
         let input_id = hash_string(&input_str);
 
-        // Record this path
+        // Record this path, noting whether it is new
+        let previous_paths = self.tracker.unique_paths();
+        let is_new_path = !self.tracker.has_path(path_hash);
         self.tracker.record_path(&input_id, path_hash);
 
         // Check if this input provides new coverage
         let current_paths = self.tracker.unique_paths();
-        let coverage_increase = self.calculate_coverage_increase(&input_id, current_paths);
+        self.path_history.push(current_paths);
+        let coverage_increase = Self::calculate_coverage_increase(is_new_path, previous_paths);
 
-        if coverage_increase >= self.config.min_coverage_increase {
+        if is_new_path && coverage_increase >= self.config.min_coverage_increase {
             let reason = format!(
                 "Coverage increase: {:.2}% (total paths: {})",
                 coverage_increase, current_paths
@@ -236,13 +296,99 @@ impl CoverageCorpus {
             total_paths: self.tracker.unique_paths(),
             corpus_size: self.corpus.load_all().map(|c| c.len()).unwrap_or(0),
             inputs_by_coverage: self.tracker.inputs_by_coverage(),
+            path_history: self.path_history.clone(),
         }
     }
 
-    fn calculate_coverage_increase(&self, _input_id: &str, _current_paths: usize) -> f64 {
-        // Simplified calculation - in practice this would be more sophisticated
-        // For now, assume any new path is valuable
-        10.0 // Return 10% as placeholder
+    /// Percentage by which a path grows the set of `previous_paths` known paths
+    fn calculate_coverage_increase(is_new_path: bool, previous_paths: usize) -> f64 {
+        match (is_new_path, previous_paths) {
+            (false, _) => 0.0,
+            (true, 0) => 100.0,
+            (true, previous) => 100.0 / previous as f64,
+        }
+    }
+}
+
+/// Number of shrink candidates considered when mutating a corpus entry
+const MUTATION_CANDIDATES: usize = 32;
+
+/// Coverage feedback for input selection in a property test run
+///
+/// Inputs whose path hash is new are added to the wrapped [`CoverageCorpus`]
+/// and kept in memory. When choosing the next input, the guide mutates a
+/// random kept entry with probability `mutation_probability` (0.5 by default)
+/// and otherwise generates a fresh value.
+///
+/// There are no dedicated mutation operators: a mutation is a random pick among
+/// the first few shrink candidates the generator offers for that entry. It
+/// therefore only moves an entry towards simpler values, staying close to an
+/// input already known to reach an interesting path, and an entry the generator
+/// cannot shrink falls back to a fresh value.
+pub struct CoverageGuide<T> {
+    corpus: CoverageCorpus,
+    entries: Vec<T>,
+    mutation_probability: f64,
+}
+
+impl<T: Clone + std::fmt::Debug> CoverageGuide<T> {
+    /// Create a guide that records interesting inputs into `corpus`
+    pub fn new(corpus: CoverageCorpus) -> Self {
+        Self {
+            corpus,
+            entries: Vec::new(),
+            mutation_probability: 0.5,
+        }
+    }
+
+    /// Set how often the next input is mutated from a corpus entry
+    pub fn with_mutation_probability(mut self, probability: f64) -> Self {
+        self.mutation_probability = probability.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Inputs kept so far because they reached a new path
+    pub fn entries(&self) -> &[T] {
+        &self.entries
+    }
+
+    /// The underlying corpus
+    pub fn corpus(&mut self) -> &mut CoverageCorpus {
+        &mut self.corpus
+    }
+
+    /// Choose the next input: a mutated corpus entry or a freshly generated value
+    pub fn next_input<G>(
+        &self,
+        generator: &G,
+        rng: &mut dyn rand::RngCore,
+        config: &GeneratorConfig,
+    ) -> T
+    where
+        G: Generator<T> + ?Sized,
+    {
+        use rand::Rng;
+
+        if !self.entries.is_empty() && rng.gen_bool(self.mutation_probability) {
+            let entry = &self.entries[rng.gen_range(0..self.entries.len())];
+            let mut candidates: Vec<T> =
+                generator.shrink(entry).take(MUTATION_CANDIDATES).collect();
+            if !candidates.is_empty() {
+                let index = rng.gen_range(0..candidates.len());
+                return candidates.swap_remove(index);
+            }
+        }
+
+        generator.generate(rng, config)
+    }
+
+    /// Report the path an input took, keeping it if the path is new
+    pub fn observe(&mut self, input: &T, path_hash: u64) -> std::io::Result<bool> {
+        let added = self.corpus.try_add(input, path_hash)?;
+        if added {
+            self.entries.push(input.clone());
+        }
+        Ok(added)
     }
 }
 
@@ -252,6 +398,77 @@ pub struct CoverageStats {
     pub total_paths: usize,
     pub corpus_size: usize,
     pub inputs_by_coverage: Vec<(String, usize)>,
+    /// Number of unique paths known after each observed input
+    pub path_history: Vec<usize>,
+}
+
+/// Number of windows the new-paths trend is split into
+const TREND_WINDOWS: usize = 5;
+
+impl CoverageStats {
+    /// New paths found in each of up to `windows` equal slices of the run
+    ///
+    /// Each entry is `(first_input, last_input, new_paths)`, with inputs
+    /// numbered from 1 in the order they were observed.
+    pub fn new_paths_trend(&self, windows: usize) -> Vec<(usize, usize, usize)> {
+        let observed = self.path_history.len();
+        if observed == 0 || windows == 0 {
+            return Vec::new();
+        }
+
+        let window_size = observed.div_ceil(windows);
+        let mut trend = Vec::new();
+        let mut known_before = 0;
+        for (index, window) in self.path_history.chunks(window_size).enumerate() {
+            let known_after = window[window.len() - 1];
+            let first = index * window_size + 1;
+            trend.push((first, first + window.len() - 1, known_after - known_before));
+            known_before = known_after;
+        }
+        trend
+    }
+
+    /// Human-readable summary of how much behavior the run explored
+    pub fn report(&self) -> String {
+        let mut report = String::new();
+
+        report.push_str("🧭 COVERAGE SUMMARY:\n");
+        report.push_str(&format!("   Unique paths: {}\n", self.total_paths));
+        report.push_str(&format!("   Corpus size: {}\n", self.corpus_size));
+        report.push_str(&format!(
+            "   Observed inputs: {}\n",
+            self.path_history.len()
+        ));
+
+        let trend = self.new_paths_trend(TREND_WINDOWS);
+        if trend.is_empty() {
+            report.push_str("   No coverage points were recorded\n");
+            return report;
+        }
+
+        report.push_str("   New paths per iteration:\n");
+        for &(first, last, new_paths) in &trend {
+            let inputs = last - first + 1;
+            report.push_str(&format!(
+                "     inputs {:>5}-{:<5} {:>4} new ({:.2}/input)\n",
+                first,
+                last,
+                new_paths,
+                new_paths as f64 / inputs as f64
+            ));
+        }
+
+        if let Some(&(first, last, 0)) = trend.last()
+            && trend.len() > 1
+        {
+            report.push_str(&format!(
+                "   ⚠️  No new paths in the last {} inputs; generation may have stopped exploring\n",
+                last - first + 1
+            ));
+        }
+
+        report
+    }
 }
 
 /// Hash a string to a unique identifier
@@ -297,6 +514,64 @@ mod tests {
         assert_ne!(hash1, hash3);
     }
 
+    #[test]
+    fn test_record_only_collects_while_recording() {
+        record("ignored");
+        assert_eq!(finish_recording(), None);
+
+        start_recording();
+        record("a");
+        record(7u32);
+        assert_eq!(
+            finish_recording(),
+            Some(path_hash(&[path_hash(&["a"]), path_hash(&[7u32])]))
+        );
+
+        start_recording();
+        assert_eq!(finish_recording(), None);
+    }
+
+    #[test]
+    fn test_coverage_increase() {
+        assert_eq!(CoverageCorpus::calculate_coverage_increase(false, 3), 0.0);
+        assert_eq!(CoverageCorpus::calculate_coverage_increase(true, 0), 100.0);
+        assert_eq!(CoverageCorpus::calculate_coverage_increase(true, 4), 25.0);
+    }
+
+    fn stats_with_history(path_history: Vec<usize>) -> CoverageStats {
+        CoverageStats {
+            total_paths: path_history.last().copied().unwrap_or(0),
+            corpus_size: 3,
+            inputs_by_coverage: Vec::new(),
+            path_history,
+        }
+    }
+
+    #[test]
+    fn test_new_paths_trend() {
+        let stats = stats_with_history(vec![1, 2, 2, 3, 3, 3, 3]);
+
+        assert_eq!(
+            stats.new_paths_trend(3),
+            vec![(1, 3, 2), (4, 6, 1), (7, 7, 0)]
+        );
+        assert!(stats_with_history(Vec::new()).new_paths_trend(3).is_empty());
+    }
+
+    #[test]
+    fn test_coverage_report() {
+        let report = stats_with_history(vec![1, 2, 3, 3, 3, 3, 3, 3, 3, 3]).report();
+
+        assert!(report.contains("Unique paths: 3"));
+        assert!(report.contains("Corpus size: 3"));
+        assert!(report.contains("Observed inputs: 10"));
+        assert!(report.contains("New paths per iteration"));
+        assert!(report.contains("No new paths in the last 2 inputs"));
+
+        let empty = stats_with_history(Vec::new()).report();
+        assert!(empty.contains("No coverage points were recorded"));
+    }
+
     #[test]
     fn test_hash_string() {
         let h1 = hash_string("test");
@@ -6,6 +6,7 @@
 //! - Automatic generator inference
 //! - Fluent builder APIs
 //! - Common property patterns
+//! - Seeded sample collection
 //!
 //! # Examples
 //!
@@ -24,13 +25,15 @@ pub mod builder;
 pub mod closure_property;
 pub mod macros;
 pub mod patterns;
+pub mod samples;
 
 // Re-export main types
-pub use auto_gen::{AutoGen, InferredGenerator};
+pub use auto_gen::{ArbitraryGenerator, AutoGen, InferredGenerator};
 pub use builder::{ErgonomicPropertyTest, ErgonomicPropertyTestWithClosure, property};
 pub use closure_property::{
     ClosureProperty, PropertyClosure, check_with_closure, check_with_closure_config,
 };
 pub use patterns::*;
+pub use samples::{samples, samples_with};
 
 // Macros are exported at crate root via #[macro_export]
@@ -3,13 +3,15 @@
 //! This module provides the `AutoGen` trait which allows automatic generator
 //! creation based on type information, dramatically reducing boilerplate.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 use std::marker::PhantomData;
 
+use crate::arbitrary::Arbitrary;
 use crate::config::GeneratorConfig;
 use crate::generator::Generator;
 use crate::primitives::*;
+use crate::strategy::Strategy;
 
 /// Trait for types that can automatically provide a generator
 ///
@@ -65,6 +67,40 @@ impl<T: AutoGen> Generator<T> for InferredGenerator<T> {
     }
 }
 
+/// A generator backed by a type's [`Arbitrary`] strategy
+///
+/// `#[derive(Generator)]` uses this as the [`AutoGen`] generator of derived
+/// types, so they can be inferred by `#[property_test]` and nested inside
+/// collections such as `Vec<T>` and `Option<T>`.
+pub struct ArbitraryGenerator<T: Arbitrary> {
+    strategy: T::Strategy,
+}
+
+impl<T: Arbitrary> ArbitraryGenerator<T> {
+    /// Create a generator from `T::arbitrary()`
+    pub fn new() -> Self {
+        Self {
+            strategy: T::arbitrary(),
+        }
+    }
+}
+
+impl<T: Arbitrary> Default for ArbitraryGenerator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Arbitrary> Generator<T> for ArbitraryGenerator<T> {
+    fn generate(&self, mut rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> T {
+        Strategy::generate(&self.strategy, &mut rng, config)
+    }
+
+    fn shrink(&self, value: &T) -> Box<dyn Iterator<Item = T>> {
+        Strategy::shrink(&self.strategy, value)
+    }
+}
+
 // ============================================================================
 // AutoGen implementations for primitive integer types
 // ============================================================================
@@ -228,8 +264,16 @@ where
     }
 }
 
-// TODO: HashSetGenerator is not yet implemented in primitives.rs
-// impl<T: AutoGen + Eq + Hash + 'static> AutoGen for HashSet<T>
+impl<T: AutoGen + Clone + Eq + Hash + 'static> AutoGen for HashSet<T>
+where
+    T::Generator: 'static,
+{
+    type Generator = HashSetGenerator<T, T::Generator>;
+
+    fn auto_generator() -> Self::Generator {
+        HashSetGenerator::new(T::auto_generator(), 0, 100)
+    }
+}
 
 impl<K: AutoGen + Clone + Eq + Hash + 'static, V: AutoGen + Clone + 'static> AutoGen
     for HashMap<K, V>
@@ -8,7 +8,7 @@
 use std::marker::PhantomData;
 use std::time::Duration;
 
-use crate::config::TestConfig;
+use crate::config::{SizeGrowth, TestConfig};
 use crate::ergonomic::auto_gen::{AutoGen, InferredGenerator};
 use crate::ergonomic::closure_property::{ClosureProperty, PropertyClosure};
 use crate::error::PropertyResult;
@@ -108,6 +108,25 @@ impl<T> ErgonomicPropertyTest<T> {
         self
     }
 
+    /// Make the shrink budget a multiple of the failing check's duration
+    ///
+    /// Replaces the flat shrink timeout. Panics if `multiplier` is not finite
+    /// and positive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use protest::ergonomic::builder::ErgonomicPropertyTest;
+    ///
+    /// let builder = ErgonomicPropertyTest::<i32>::new()
+    ///     .shrink_budget_multiplier(10.0);
+    /// ```
+    pub fn shrink_budget_multiplier(mut self, multiplier: f64) -> Self {
+        self.config.shrink_budget_multiplier =
+            Some(crate::shrink::checked_budget_multiplier(multiplier));
+        self
+    }
+
     /// Set the generator size hint
     ///
     /// # Examples
@@ -123,6 +142,24 @@ impl<T> ErgonomicPropertyTest<T> {
         self
     }
 
+    /// Set how the size hint grows across iterations
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use protest::SizeGrowth;
+    /// use protest::ergonomic::builder::ErgonomicPropertyTest;
+    ///
+    /// // Start with tiny vectors and ramp up to the size hint
+    /// let builder = ErgonomicPropertyTest::<Vec<i32>>::new()
+    ///     .size_hint(100)
+    ///     .size_growth(SizeGrowth::Linear);
+    /// ```
+    pub fn size_growth(mut self, growth: SizeGrowth) -> Self {
+        self.config.size_growth = growth;
+        self
+    }
+
     /// Set the maximum generation depth for nested structures
     ///
     /// # Examples
@@ -279,12 +316,25 @@ where
         self
     }
 
+    /// Make the shrink budget a multiple of the failing check's duration
+    pub fn shrink_budget_multiplier(mut self, multiplier: f64) -> Self {
+        self.config.shrink_budget_multiplier =
+            Some(crate::shrink::checked_budget_multiplier(multiplier));
+        self
+    }
+
     /// Set the generator size hint
     pub fn size_hint(mut self, hint: usize) -> Self {
         self.config.generator_config.size_hint = hint;
         self
     }
 
+    /// Set how the size hint grows across iterations
+    pub fn size_growth(mut self, growth: SizeGrowth) -> Self {
+        self.config.size_growth = growth;
+        self
+    }
+
     /// Set the maximum generation depth for nested structures
     pub fn max_depth(mut self, depth: usize) -> Self {
         self.config.generator_config.max_depth = depth;
@@ -0,0 +1,81 @@
+//! Collecting generated samples for inspection.
+//!
+//! These helpers build a seeded RNG and collect values, which is handy for
+//! eyeballing a generator's output or checking its distribution.
+
+use crate::arbitrary::Arbitrary;
+use crate::config::GeneratorConfig;
+use crate::generator::Generator;
+use crate::rng::create_seeded_rng;
+use crate::strategy::Strategy;
+
+/// Generate `n` values of `T` from its [`Arbitrary`] strategy
+///
+/// The same seed always produces the same samples.
+///
+/// # Examples
+///
+/// ```rust
+/// use protest::ergonomic::samples;
+///
+/// let values: Vec<u8> = samples(5, 42);
+/// assert_eq!(values.len(), 5);
+/// assert_eq!(values, samples::<u8>(5, 42));
+/// ```
+pub fn samples<T: Arbitrary>(n: usize, seed: u64) -> Vec<T> {
+    let strategy = T::arbitrary();
+    let mut rng = create_seeded_rng(seed);
+    let config = GeneratorConfig::default();
+
+    (0..n)
+        .map(|_| strategy.generate(&mut rng, &config))
+        .collect()
+}
+
+/// Generate `n` values from `generator`
+///
+/// The same seed always produces the same samples.
+///
+/// # Examples
+///
+/// ```rust
+/// use protest::IntGenerator;
+/// use protest::ergonomic::samples_with;
+///
+/// let values = samples_with(IntGenerator::new(1, 6), 100, 7);
+/// assert!(values.iter().all(|x| (1..=6).contains(x)));
+/// ```
+pub fn samples_with<T, G: Generator<T>>(generator: G, n: usize, seed: u64) -> Vec<T> {
+    let mut rng = create_seeded_rng(seed);
+    let config = GeneratorConfig::default();
+
+    (0..n)
+        .map(|_| generator.generate(&mut rng, &config))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::IntGenerator;
+
+    #[test]
+    fn test_samples_are_seeded() {
+        let first: Vec<i64> = samples(50, 123);
+        assert_eq!(first.len(), 50);
+        assert_eq!(first, samples::<i64>(50, 123));
+        assert_ne!(first, samples::<i64>(50, 124));
+
+        assert!(samples::<String>(0, 1).is_empty());
+    }
+
+    #[test]
+    fn test_samples_with_generator() {
+        let generator = IntGenerator::new(10u32, 20);
+        let values = samples_with(generator.clone(), 200, 9);
+
+        assert_eq!(values.len(), 200);
+        assert!(values.iter().all(|x| (10..=20).contains(x)));
+        assert_eq!(values, samples_with(generator, 200, 9));
+    }
+}
@@ -22,6 +22,28 @@ pub enum PropertyError {
         context: Option<String>,
     },
 
+    /// A generator could not be constructed
+    GeneratorError { message: String },
+
+    /// The input was discarded by [`crate::assume`]; the runner tries another
+    InputRejected,
+
+    /// More inputs were discarded by [`crate::assume`] than the run allows
+    TooManyRejections { rejections: usize },
+
+    /// A filtering strategy found no value passing its predicate
+    FilterExhausted { attempts: usize },
+
+    /// A run fell short of one of its [`crate::config::CoverageThresholds`]
+    ///
+    /// `target` describes what was covered, such as a [`crate::classify`] label
+    /// or the range of a numeric type; `coverage` and `required` are fractions.
+    InsufficientCoverage {
+        target: String,
+        coverage: f64,
+        required: f64,
+    },
+
     /// Shrinkage process timed out
     ShrinkageTimeout {
         iterations: usize,
@@ -68,6 +90,31 @@ impl fmt::Display for PropertyError {
                 }
                 Ok(())
             }
+            PropertyError::GeneratorError { message } => {
+                write!(f, "Generator construction failed: {}", message)
+            }
+            PropertyError::InputRejected => write!(f, "Input rejected by assume"),
+            PropertyError::TooManyRejections { rejections } => write!(
+                f,
+                "Too many rejections: assume discarded {} generated inputs",
+                rejections
+            ),
+            PropertyError::FilterExhausted { attempts } => write!(
+                f,
+                "Filter exhausted: no generated value passed the predicate in {} attempts",
+                attempts
+            ),
+            PropertyError::InsufficientCoverage {
+                target,
+                coverage,
+                required,
+            } => write!(
+                f,
+                "Insufficient coverage of {}: {:.1}%, required at least {:.1}%",
+                target,
+                coverage * 100.0,
+                required * 100.0
+            ),
             PropertyError::ShrinkageTimeout {
                 iterations,
                 last_successful_shrink,
@@ -104,6 +151,45 @@ impl fmt::Display for PropertyError {
 
 impl std::error::Error for PropertyError {}
 
+/// Error returned by fallible generator constructors
+///
+/// Converts into [`PropertyError::GeneratorError`], so a property can build its
+/// generator with `?` and report a bad configuration as a test failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratorError {
+    message: String,
+}
+
+impl GeneratorError {
+    /// Create a generator error with the given message
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// Why the generator could not be constructed
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for GeneratorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for GeneratorError {}
+
+impl From<GeneratorError> for PropertyError {
+    fn from(error: GeneratorError) -> Self {
+        Self::GeneratorError {
+            message: error.message,
+        }
+    }
+}
+
 /// Result of a property test execution
 pub type PropertyResult<T> = Result<TestSuccess<T>, TestFailure<T>>;
 
@@ -118,6 +204,27 @@ impl PropertyError {
         }
     }
 
+    /// Create a property failed error from a caught panic payload
+    ///
+    /// String payloads (from `panic!`, `assert!` and friends) become the message,
+    /// and a [`PropertyError`] payload is returned as is. A rejection from [`crate::assume`] becomes [`PropertyError::InputRejected`].
+    pub fn from_panic(payload: Box<dyn std::any::Any + Send>) -> Self {
+        if crate::property::is_rejection(&*payload) {
+            return Self::InputRejected;
+        }
+        if let Some(error) = payload.downcast_ref::<PropertyError>() {
+            return error.clone();
+        }
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            (*message).to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "property panicked with a non-string payload".to_string()
+        };
+        Self::property_failed(message)
+    }
+
     /// Create a property failed error with context
     pub fn property_failed_with_context(
         message: impl Into<String>,
@@ -142,6 +249,13 @@ impl PropertyError {
         }
     }
 
+    /// Create a generator construction error
+    pub fn generator_error(message: impl Into<String>) -> Self {
+        Self::GeneratorError {
+            message: message.into(),
+        }
+    }
+
     /// Create a configuration error with field information
     pub fn config_error_with_field(
         message: impl Into<String>,
@@ -234,6 +348,8 @@ pub struct TestFailure<T> {
     pub test_duration: std::time::Duration,
     /// Time spent on shrinking
     pub shrink_duration: std::time::Duration,
+    /// Worker thread that found the failure, for parallel runs
+    pub thread_id: Option<usize>,
 }
 
 impl<T> TestFailure<T> {
@@ -257,6 +373,7 @@ impl<T> TestFailure<T> {
             failed_iteration,
             test_duration,
             shrink_duration,
+            thread_id: None,
         }
     }
 
@@ -271,6 +388,9 @@ impl<T> TestFailure<T> {
             "Property test failed on iteration {}\n",
             self.failed_iteration
         ));
+        if let Some(thread_id) = self.thread_id {
+            report.push_str(&format!("Found by worker thread {}\n", thread_id));
+        }
         report.push_str(&format!("Error: {}\n", self.error));
         report.push_str(&format!("Original input: {:?}\n", self.original_input));
 
@@ -527,6 +647,34 @@ impl ErrorReporter {
                     context.push_str(&format!("   Context: {}\n", ctx));
                 }
             }
+            PropertyError::GeneratorError { message } => {
+                context.push_str("   Type: Generator construction failure\n");
+                context.push_str(&format!("   Message: {}\n", message));
+            }
+            PropertyError::InputRejected => {
+                context.push_str("   Type: Input rejected by assume\n");
+            }
+            PropertyError::TooManyRejections { rejections } => {
+                context.push_str("   Type: Too many rejected inputs\n");
+                context.push_str(&format!("   Rejections: {}\n", rejections));
+            }
+            PropertyError::FilterExhausted { attempts } => {
+                context.push_str("   Type: Filter exhausted\n");
+                context.push_str(&format!("   Attempts: {}\n", attempts));
+            }
+            PropertyError::InsufficientCoverage {
+                target,
+                coverage,
+                required,
+            } => {
+                context.push_str("   Type: Insufficient coverage\n");
+                context.push_str(&format!("   Target: {}\n", target));
+                context.push_str(&format!(
+                    "   Coverage: {:.1}% (required {:.1}%)\n",
+                    coverage * 100.0,
+                    required * 100.0
+                ));
+            }
             PropertyError::ShrinkageTimeout {
                 iterations,
                 last_successful_shrink,
@@ -586,6 +734,29 @@ impl ErrorReporter {
                 suggestions.push_str("   • Verify generator constraints are satisfiable\n");
                 suggestions.push_str("   • Consider adding bounds checking to your generator\n");
             }
+            PropertyError::GeneratorError { .. } => {
+                suggestions
+                    .push_str("   • Check the arguments passed to the generator constructor\n");
+                suggestions.push_str("   • Verify the generator's documented limits\n");
+            }
+            PropertyError::InputRejected | PropertyError::TooManyRejections { .. } => {
+                suggestions
+                    .push_str("   • Generate inputs that satisfy the precondition directly\n");
+                suggestions.push_str("   • Raise max_rejections if rejections are expected\n");
+            }
+            PropertyError::FilterExhausted { .. } => {
+                suggestions.push_str("   • Loosen the filter predicate\n");
+                suggestions
+                    .push_str("   • Generate passing values directly with map or filter_map\n");
+                suggestions.push_str("   • Raise the limit with with_max_attempts\n");
+            }
+            PropertyError::InsufficientCoverage { .. } => {
+                suggestions.push_str(
+                    "   • Adjust the generator so it produces the uncovered case more often\n",
+                );
+                suggestions.push_str("   • Check that the classify condition is correct\n");
+                suggestions.push_str("   • Run more iterations if the case is rare but expected\n");
+            }
             PropertyError::ShrinkageTimeout { .. } => {
                 suggestions.push_str("   • Increase shrink timeout if needed\n");
                 suggestions.push_str("   • Optimize your shrinking strategy\n");
@@ -856,6 +1027,21 @@ mod tests {
         assert!(display.contains("source: internal failure"));
     }
 
+    #[test]
+    fn test_property_error_from_panic() {
+        let payload = std::panic::catch_unwind(|| panic!("boom {}", 1)).unwrap_err();
+        assert!(matches!(
+            PropertyError::from_panic(payload),
+            PropertyError::PropertyFailed { message, .. } if message == "boom 1"
+        ));
+
+        let payload = std::panic::catch_unwind(|| panic!("static")).unwrap_err();
+        assert_eq!(
+            PropertyError::from_panic(payload).to_string(),
+            PropertyError::property_failed("static").to_string()
+        );
+    }
+
     #[test]
     fn test_property_error_helper_functions() {
         let error = PropertyError::property_failed_with_context(
@@ -893,6 +1079,16 @@ mod tests {
             }
             _ => panic!("Expected ConfigError variant"),
         }
+
+        let error = PropertyError::from(GeneratorError::new("bad pattern"));
+        assert!(matches!(
+            &error,
+            PropertyError::GeneratorError { message } if message == "bad pattern"
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Generator construction failed: bad pattern"
+        );
     }
 
     #[test]
@@ -1,10 +1,11 @@
 //! Property test execution engine for running synchronous and asynchronous property tests.
 
+use std::collections::HashMap;
 use std::fmt;
 use std::marker::PhantomData;
 use std::time::{Duration, Instant};
 
-use crate::config::TestConfig;
+use crate::config::{CoverageThresholds, SizeGrowth, TestConfig};
 use crate::error::{
     ErrorReporter, PropertyError, PropertyResult, ShrinkProgress, ShrinkStep, TestFailure,
     TestSuccess,
@@ -12,8 +13,41 @@ use crate::error::{
 use crate::generator::Generator;
 use crate::property::{AsyncProperty, Property};
 use crate::rng::create_seeded_rng;
+use crate::shrink::Shrinkable;
 use crate::statistics::StatisticsCollector;
 
+/// Draw a seed for runs that were not given one explicitly
+pub(crate) fn fresh_seed() -> u64 {
+    use rand::RngCore;
+    crate::rng::create_rng().next_u64()
+}
+
+/// Error for a generator that panicked while generating the input for `iteration`
+///
+/// A [`PropertyError`] payload, such as [`PropertyError::FilterExhausted`], is
+/// reported as is.
+pub(crate) fn generation_error(
+    payload: Box<dyn std::any::Any + Send>,
+    iteration: usize,
+) -> PropertyError {
+    match payload.downcast::<PropertyError>() {
+        Ok(error) => *error,
+        Err(_) => PropertyError::generation_failed_with_context(
+            "Generator panicked during value generation",
+            Some(format!("iteration {}", iteration)),
+        ),
+    }
+}
+
+/// End a run that failed without a counterexample
+///
+/// A [`TestFailure`] needs an input to blame, so the run instead unwinds with
+/// `error` as the panic payload, which [`PropertyError::from_panic`] recovers.
+pub(crate) fn fail_run(error: PropertyError) -> ! {
+    eprintln!("Property test failed: {}", error);
+    std::panic::resume_unwind(Box::new(error))
+}
+
 /// Core property test execution struct
 pub struct PropertyTest<T, G, P> {
     generator: G,
@@ -25,9 +59,19 @@ pub struct PropertyTest<T, G, P> {
     persistence_config: Option<crate::persistence::PersistenceConfig>,
     #[cfg(feature = "persistence")]
     test_name: Option<String>,
+    #[cfg(feature = "persistence")]
+    coverage_guide: Option<crate::coverage::CoverageGuide<T>>,
+    examples: Vec<T>,
     _phantom: PhantomData<T>,
 }
 
+/// Builds a future that completes after a duration, using the caller's async runtime
+type SleepFn = Box<
+    dyn Fn(Duration) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>>
+        + Send
+        + Sync,
+>;
+
 /// Async property test execution struct
 pub struct AsyncPropertyTest<T, G, P> {
     generator: G,
@@ -35,6 +79,7 @@ pub struct AsyncPropertyTest<T, G, P> {
     config: TestConfig,
     error_reporter: ErrorReporter,
     statistics_collector: Option<StatisticsCollector>,
+    iteration_timeout: Option<(Duration, SleepFn)>,
     _phantom: PhantomData<T>,
 }
 
@@ -56,6 +101,9 @@ where
             persistence_config: None,
             #[cfg(feature = "persistence")]
             test_name: None,
+            #[cfg(feature = "persistence")]
+            coverage_guide: None,
+            examples: Vec::new(),
             _phantom: PhantomData,
         }
     }
@@ -77,6 +125,9 @@ where
             persistence_config: None,
             #[cfg(feature = "persistence")]
             test_name: None,
+            #[cfg(feature = "persistence")]
+            coverage_guide: None,
+            examples: Vec::new(),
             _phantom: PhantomData,
         }
     }
@@ -98,6 +149,9 @@ where
             persistence_config: None,
             #[cfg(feature = "persistence")]
             test_name: None,
+            #[cfg(feature = "persistence")]
+            coverage_guide: None,
+            examples: Vec::new(),
             _phantom: PhantomData,
         }
     }
@@ -120,6 +174,9 @@ where
             persistence_config: None,
             #[cfg(feature = "persistence")]
             test_name: None,
+            #[cfg(feature = "persistence")]
+            coverage_guide: None,
+            examples: Vec::new(),
             _phantom: PhantomData,
         }
     }
@@ -143,23 +200,94 @@ where
             statistics_collector,
             persistence_config,
             test_name,
+            coverage_guide: None,
+            examples: Vec::new(),
             _phantom: PhantomData,
         }
     }
 
+    /// Test these inputs, in order, before any generated ones
+    ///
+    /// A failing example is shrunk with the generator like a generated input,
+    /// so known-tricky cases surface first and minimized.
+    pub fn with_examples(mut self, examples: impl IntoIterator<Item = T>) -> Self {
+        self.examples.extend(examples);
+        self
+    }
+
+    /// Select inputs with coverage feedback (see [`crate::coverage`])
+    #[cfg(feature = "persistence")]
+    pub fn with_coverage_guide(mut self, guide: crate::coverage::CoverageGuide<T>) -> Self {
+        self.coverage_guide = Some(guide);
+        self
+    }
+
+    /// Test one input, feeding the path it takes to the coverage guide if enabled
+    fn test_input(&mut self, input: &T) -> Result<(), PropertyError> {
+        #[cfg(feature = "persistence")]
+        if self.coverage_guide.is_some() {
+            crate::coverage::start_recording();
+            let result = self.call_property(input);
+            if let Some(path) = crate::coverage::finish_recording()
+                && let Some(guide) = self.coverage_guide.as_mut()
+                && let Err(e) = guide.observe(input, path)
+            {
+                eprintln!("⚠️  Failed to update coverage corpus: {}", e);
+            }
+            return result;
+        }
+
+        self.call_property(input)
+    }
+
+    /// Call the property, turning an [`crate::assume`] rejection into an error
+    fn call_property(&self, input: &T) -> Result<(), PropertyError> {
+        crate::property::catch_rejection(|| self.property.test(input.clone()).map(|_| ()))
+    }
+
+    /// Whether `candidate` fails the property; rejected candidates do not count
+    fn still_fails(&self, candidate: &T) -> bool {
+        !matches!(
+            self.call_property(candidate),
+            Ok(()) | Err(PropertyError::InputRejected)
+        )
+    }
+
+    /// Print the coverage summary if `coverage_report` is set and a guide is attached
+    fn emit_coverage_report(&mut self) {
+        #[cfg(feature = "persistence")]
+        if self.config.coverage_report
+            && let Some(guide) = self.coverage_guide.as_mut()
+        {
+            eprintln!("{}", guide.corpus().stats().report());
+        }
+    }
+
     /// Execute the property test
+    ///
+    /// The `PROTEST_SEED` and `PROTEST_ITERATIONS` environment variables override
+    /// the configured seed and iteration count (see [`TestConfig::apply_env_overrides`]).
     pub fn run(mut self) -> PropertyResult<T> {
         let test_start = Instant::now();
-        let mut rng = if let Some(seed) = self.config.seed {
-            create_seeded_rng(seed)
-        } else {
-            crate::rng::create_rng()
-        };
+        self.config.apply_env_overrides();
+        // Always run from a known seed so failures report how to reproduce them
+        let seed = *self.config.seed.get_or_insert_with(fresh_seed);
+        let mut rng = create_seeded_rng(seed);
         let mut stats_collector = self
             .statistics_collector
             .take()
             .unwrap_or_else(StatisticsCollector::disabled);
 
+        // In verify mode, only check whether saved failures still reproduce
+        #[cfg(feature = "persistence")]
+        if let Some(report) = std::env::var_os(crate::persistence::VERIFY_ENV_VAR)
+            && let Some(persistence_cfg) = self.persistence_config.clone()
+            && let Some(test_name) = self.test_name.clone()
+        {
+            let verified = self.verify_saved_failures(&test_name, &persistence_cfg, report);
+            return Ok(TestSuccess::new(verified, self.config, None));
+        }
+
         // Replay previously saved failures first
         #[cfg(feature = "persistence")]
         {
@@ -174,45 +302,106 @@ where
             }
         }
 
-        for iteration in 0..self.config.iterations {
+        // Then the explicit examples, before anything is generated
+        let examples = std::mem::take(&mut self.examples);
+        let example_count = examples.len();
+        for (index, input) in examples.into_iter().enumerate() {
+            let check_start = Instant::now();
+            match self.test_input(&input) {
+                Ok(()) | Err(PropertyError::InputRejected) => {}
+                Err(error) => {
+                    let error = match error {
+                        PropertyError::PropertyFailed {
+                            message,
+                            context: None,
+                            iteration,
+                        } => PropertyError::PropertyFailed {
+                            message,
+                            context: Some(format!("example {}", index)),
+                            iteration,
+                        },
+                        other => other,
+                    };
+                    let check_time = check_start.elapsed();
+                    return Err(self.fail(error, input, index, test_start, check_time));
+                }
+            }
+        }
+
+        // Type coverage thresholds are checked against generation statistics
+        let report_stats = stats_collector.is_enabled();
+        if !report_stats
+            && self
+                .config
+                .coverage_thresholds
+                .as_ref()
+                .is_some_and(CoverageThresholds::has_type_thresholds)
+        {
+            stats_collector = StatisticsCollector::new();
+        }
+
+        let mut rejections = 0;
+        let mut label_counts: HashMap<String, usize> = HashMap::new();
+        let mut iteration = 0;
+        while iteration < self.config.iterations {
             // Start timing generation
             stats_collector.start_generation_timing();
 
-            // Generate test input
-            let input = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            // Generate test input, scaling the size hint for this iteration
+            let size_hint = self.config.size_hint_for_iteration(iteration);
+            let configured_size_hint =
+                std::mem::replace(&mut self.config.generator_config.size_hint, size_hint);
+            let generated = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                #[cfg(feature = "persistence")]
+                if let Some(guide) = &self.coverage_guide {
+                    return guide.next_input(
+                        &self.generator,
+                        &mut rng,
+                        &self.config.generator_config,
+                    );
+                }
                 self.generator
                     .generate(&mut rng, &self.config.generator_config)
-            })) {
+            }));
+            self.config.generator_config.size_hint = configured_size_hint;
+            let input = match generated {
                 Ok(input) => input,
-                Err(_) => {
-                    let error = PropertyError::generation_failed_with_context(
-                        "Generator panicked during value generation",
-                        Some(format!("iteration {}", iteration)),
-                    );
-                    return Err(TestFailure::new(
-                        error,
-                        // We can't provide the original input since generation failed
-                        // This is a limitation we'll need to handle differently in practice
-                        self.generator
-                            .generate(&mut rng, &self.config.generator_config),
-                        None,
-                        0,
-                        self.config,
-                        iteration,
-                        test_start.elapsed(),
-                        Duration::from_secs(0),
-                    ));
-                }
+                Err(payload) => fail_run(generation_error(payload, iteration)),
             };
 
             // End timing and record the generated value
             stats_collector.end_generation_timing();
             stats_collector.record_generated_value(&input, std::any::type_name::<T>());
 
-            // Test the property
-            match self.property.test(input.clone()) {
-                Ok(_) => {
+            // Test the property, timing it in case a shrink budget multiplier is set
+            let check_start = Instant::now();
+            let (result, labels) = crate::property::collect_labels(|| self.test_input(&input));
+            let check_time = check_start.elapsed();
+            match result {
+                Ok(()) => {
                     // Property passed, continue to next iteration
+                    for label in labels {
+                        *label_counts.entry(label).or_insert(0) += 1;
+                    }
+                    iteration += 1;
+                    continue;
+                }
+                Err(PropertyError::InputRejected) => {
+                    // Discarded by `assume`: generate a fresh input for this iteration
+                    rejections += 1;
+                    if rejections > self.config.max_rejections {
+                        self.emit_coverage_report();
+                        return Err(TestFailure::new(
+                            PropertyError::TooManyRejections { rejections },
+                            input,
+                            None,
+                            0,
+                            self.config,
+                            iteration,
+                            test_start.elapsed(),
+                            Duration::from_secs(0),
+                        ));
+                    }
                     continue;
                 }
                 Err(mut error) => {
@@ -230,71 +419,104 @@ where
                         other => other,
                     };
 
-                    // Property failed, attempt shrinking
-                    let shrink_start = Instant::now();
-                    let shrink_result = self.shrink_failure(input.clone(), &error);
-                    let shrink_duration = shrink_start.elapsed();
-
-                    let failure = TestFailure::new(
-                        error,
-                        input,
-                        shrink_result.0,
-                        shrink_result.1,
-                        self.config,
-                        iteration,
-                        test_start.elapsed(),
-                        shrink_duration,
-                    );
-
-                    // Print detailed error report if verbose mode is enabled
-                    if self.error_reporter.verbose {
-                        eprintln!("{}", self.error_reporter.format_failure(&failure));
-                    } else {
-                        eprintln!("{}", self.error_reporter.format_summary(&failure));
-                    }
-
-                    // Print shrink progress if enabled
-                    if self.error_reporter.show_shrink_progress && !shrink_result.2.steps.is_empty()
-                    {
-                        eprintln!("{}", shrink_result.2.visualize());
-                        eprintln!("{}", shrink_result.2.statistics());
-                    }
-
-                    // Save failure if persistence is enabled
-                    #[cfg(feature = "persistence")]
-                    {
-                        if let Some(ref persistence_cfg) = self.persistence_config
-                            && persistence_cfg.persist_failures
-                        {
-                            let test_name = self.test_name.as_deref().unwrap_or("unnamed_test");
-                            Self::save_failure_static(&failure, test_name, persistence_cfg);
-                        }
-                    }
-
-                    return Err(failure);
+                    return Err(self.fail(error, input, iteration, test_start, check_time));
                 }
             }
         }
 
         // All iterations passed
-        let final_stats = if stats_collector.is_enabled() {
-            Some(stats_collector.into_stats())
-        } else {
-            None
-        };
+        self.emit_coverage_report();
+
+        let stats = stats_collector
+            .is_enabled()
+            .then(|| stats_collector.into_stats());
+
+        // Fail runs that fall short of their coverage thresholds; no single
+        // input is to blame, so there is no counterexample to return
+        if let Some(thresholds) = &self.config.coverage_thresholds
+            && let Some((target, coverage, required)) = thresholds
+                .first_uncovered_label(&label_counts, iteration)
+                .or_else(|| {
+                    stats
+                        .as_ref()
+                        .and_then(|stats| stats.first_unmet_threshold(thresholds))
+                })
+        {
+            fail_run(PropertyError::InsufficientCoverage {
+                target,
+                coverage,
+                required,
+            });
+        }
+
+        let final_stats = if report_stats { stats } else { None };
 
         Ok(TestSuccess::new(
-            self.config.iterations,
+            self.config.iterations + example_count,
             self.config,
             final_stats,
         ))
     }
 
+    /// Shrink a failing input, then report, persist and return the failure
+    fn fail(
+        &mut self,
+        error: PropertyError,
+        input: T,
+        iteration: usize,
+        test_start: Instant,
+        check_time: Duration,
+    ) -> TestFailure<T> {
+        // Property failed, attempt shrinking
+        let shrink_start = Instant::now();
+        let budget = self.config.shrink_config().budget_for(check_time);
+        let shrink_result = self.shrink_failure(input.clone(), budget);
+        let shrink_duration = shrink_start.elapsed();
+
+        self.emit_coverage_report();
+        let failure = TestFailure::new(
+            error,
+            input,
+            shrink_result.0,
+            shrink_result.1,
+            self.config.clone(),
+            iteration,
+            test_start.elapsed(),
+            shrink_duration,
+        );
+
+        // Print detailed error report if verbose mode is enabled
+        if self.error_reporter.verbose {
+            eprintln!("{}", self.error_reporter.format_failure(&failure));
+        } else {
+            eprintln!("{}", self.error_reporter.format_summary(&failure));
+        }
+
+        // Print shrink progress if enabled
+        if self.error_reporter.show_shrink_progress && !shrink_result.2.steps.is_empty() {
+            eprintln!("{}", shrink_result.2.visualize());
+            eprintln!("{}", shrink_result.2.statistics());
+        }
+
+        // Save failure if persistence is enabled
+        #[cfg(feature = "persistence")]
+        {
+            if let Some(ref persistence_cfg) = self.persistence_config
+                && persistence_cfg.persist_failures
+            {
+                let test_name = self.test_name.as_deref().unwrap_or("unnamed_test");
+                Self::save_failure_static(&failure, test_name, persistence_cfg);
+            }
+        }
+
+        failure
+    }
+
     /// Attempt to shrink a failing input to find a minimal example with progress tracking
     fn shrink_failure(
         &self,
         original_input: T,
-        _error: &PropertyError,
+        budget: Duration,
     ) -> (Option<T>, usize, ShrinkProgress) {
         let start_time = Instant::now();
         let mut shrink_steps = 0;
@@ -306,7 +528,7 @@ where
 
         for candidate in shrink_candidates {
             // Check timeout
-            if start_time.elapsed() > self.config.shrink_timeout {
+            if start_time.elapsed() > budget {
                 if self.error_reporter.show_shrink_progress {
                     eprintln!("Shrinking timed out after {:?}", start_time.elapsed());
                 }
@@ -328,7 +550,7 @@ where
             shrink_steps += 1;
 
             // Test if the candidate still fails
-            if let Err(_) = self.property.test(candidate.clone()) {
+            if self.still_fails(&candidate) {
                 let step_time = step_start.elapsed();
 
                 // Record successful shrink step
@@ -357,7 +579,7 @@ where
                     // For now, just take the first shrink candidate
                     if let Some(further_candidate) = further_shrinks.into_iter().next() {
                         let further_step_start = Instant::now();
-                        if let Err(_) = self.property.test(further_candidate.clone()) {
+                        if self.still_fails(&further_candidate) {
                             let further_step_time = further_step_start.elapsed();
                             shrink_steps += 1;
 
@@ -433,12 +655,26 @@ where
                 // TODO: Enhance to serialize when T: Serialize
                 let input_str = format!("{:?}", failure.original_input);
 
-                let failure_case = crate::persistence::FailureCase::new(
+                let mut failure_case = crate::persistence::FailureCase::new(
                     seed,
                     input_str,
                     failure.error.to_string(),
                     failure.shrink_steps,
+                )
+                .with_metadata(
+                    crate::persistence::ITERATIONS_KEY.to_string(),
+                    failure.config.iterations.to_string(),
+                )
+                .with_metadata(
+                    crate::persistence::FAILED_ITERATION_KEY.to_string(),
+                    failure.failed_iteration.to_string(),
                 );
+                if let Some(shrunk) = &failure.shrunk_input {
+                    failure_case = failure_case.with_metadata(
+                        crate::persistence::MINIMAL_INPUT_KEY.to_string(),
+                        format!("{:?}", shrunk),
+                    );
+                }
 
                 match snapshot.save_failure(test_name, &failure_case) {
                     Ok(path) => {
@@ -456,6 +692,113 @@ where
         }
     }
 
+    /// Reproduce a saved failure by running the property once on its input
+    ///
+    /// The RNG is seeded with the failure's seed and the input is regenerated
+    /// the way the original run produced it, using the failed iteration stored
+    /// under [`crate::persistence::FAILED_ITERATION_KEY`] (iteration 0 when
+    /// absent). Regeneration is exact unless earlier inputs of that run were
+    /// discarded by [`crate::assume`].
+    ///
+    /// Returns the failure, unshrunk, if the property still fails. If it now
+    /// passes, a warning is printed since the bug has most likely been fixed.
+    #[cfg(feature = "persistence")]
+    pub fn reproduce(&self, failure: &crate::persistence::FailureCase) -> PropertyResult<T> {
+        let test_start = Instant::now();
+        let (input, config, failed_iteration) = self.regenerate_failure_input(failure);
+
+        match self.call_property(&input) {
+            Ok(()) | Err(PropertyError::InputRejected) => {
+                eprintln!(
+                    "⚠️  Failure with seed={} no longer reproduces; the bug may have been fixed",
+                    failure.seed
+                );
+                Ok(TestSuccess::new(1, config, None))
+            }
+            Err(error) => Err(TestFailure::new(
+                error,
+                input,
+                None,
+                0,
+                config,
+                failed_iteration,
+                test_start.elapsed(),
+                Duration::from_secs(0),
+            )),
+        }
+    }
+
+    /// Regenerate the input a saved failure was found on from its seed
+    ///
+    /// Returns the input along with the config and iteration it was replayed at.
+    #[cfg(feature = "persistence")]
+    fn regenerate_failure_input(
+        &self,
+        failure: &crate::persistence::FailureCase,
+    ) -> (T, TestConfig, usize) {
+        let failed_iteration = failure
+            .metadata
+            .get(crate::persistence::FAILED_ITERATION_KEY)
+            .and_then(|n| n.parse::<usize>().ok())
+            .unwrap_or(0);
+        let mut config = self.config.clone();
+        config.seed = Some(failure.seed);
+        if let Some(iterations) = failure
+            .metadata
+            .get(crate::persistence::ITERATIONS_KEY)
+            .and_then(|n| n.parse::<usize>().ok())
+        {
+            config.iterations = iterations;
+        }
+
+        let mut rng = create_seeded_rng(failure.seed);
+        let mut generator_config = config.generator_config.clone();
+        let mut input = None;
+        for iteration in 0..=failed_iteration {
+            generator_config.size_hint = config.size_hint_for_iteration(iteration);
+            input = Some(self.generator.generate(&mut rng, &generator_config));
+        }
+        let input = input.expect("at least one input is generated");
+        (input, config, failed_iteration)
+    }
+
+    /// Reproduce each saved failure and append the outcomes to a verify report
+    ///
+    /// Returns the number of failures that were checked.
+    #[cfg(feature = "persistence")]
+    fn verify_saved_failures(
+        &self,
+        test_name: &str,
+        persistence_cfg: &crate::persistence::PersistenceConfig,
+        report: impl AsRef<std::path::Path>,
+    ) -> usize {
+        let failures = crate::persistence::FailureSnapshot::new(&persistence_cfg.failure_dir)
+            .and_then(|snapshot| snapshot.load_failures(test_name))
+            .unwrap_or_default();
+
+        for failure_case in &failures {
+            let (input, _, _) = self.regenerate_failure_input(failure_case);
+            // An input rejected by `assume` was never checked, so it is recorded
+            // without an input and counts as not verified
+            let (error_message, input) = match self.call_property(&input) {
+                Ok(()) => (None, Some(format!("{:?}", input))),
+                Err(PropertyError::InputRejected) => (None, None),
+                Err(error) => (Some(error.to_string()), Some(format!("{:?}", input))),
+            };
+            let record = crate::persistence::VerifyRecord {
+                test_name: test_name.to_string(),
+                seed: failure_case.seed,
+                error_message,
+                input,
+            };
+            if let Err(e) = record.append_to(&report) {
+                eprintln!("⚠️  Failed to write verify report: {}", e);
+            }
+        }
+
+        failures.len()
+    }
+
     /// Replay previously saved failures to verify they're fixed
     #[cfg(feature = "persistence")]
     fn replay_saved_failures(
@@ -494,11 +837,14 @@ where
                                     .generate(&mut rng, &self.config.generator_config);
 
                                 // Test if it still fails
-                                match self.property.test(input.clone()) {
-                                    Ok(_) => {
+                                match self.call_property(&input) {
+                                    Ok(()) => {
                                         eprintln!("    ✅ Now passing!");
                                         now_passing.push(failure_case.seed);
                                     }
+                                    Err(PropertyError::InputRejected) => {
+                                        eprintln!("    ⏭️  Input rejected by assume; not replayed");
+                                    }
                                     Err(err) => {
                                         eprintln!("    ❌ Still failing: {}", err);
                                         still_failing.push(failure_case.seed);
@@ -559,6 +905,7 @@ where
             config,
             error_reporter: ErrorReporter::new(),
             statistics_collector: Some(StatisticsCollector::new()),
+            iteration_timeout: None,
             _phantom: PhantomData,
         }
     }
@@ -576,6 +923,7 @@ where
             config,
             error_reporter,
             statistics_collector: Some(StatisticsCollector::new()),
+            iteration_timeout: None,
             _phantom: PhantomData,
         }
     }
@@ -593,6 +941,7 @@ where
             config,
             error_reporter: ErrorReporter::new(),
             statistics_collector,
+            iteration_timeout: None,
             _phantom: PhantomData,
         }
     }
@@ -611,62 +960,123 @@ where
             config,
             error_reporter,
             statistics_collector,
+            iteration_timeout: None,
             _phantom: PhantomData,
         }
     }
 
+    /// Fail any single property invocation that takes longer than `timeout`
+    ///
+    /// The library stays runtime-agnostic: `sleep` must return a future that
+    /// completes after the given duration on the caller's runtime, such as
+    /// `tokio::time::sleep`. Each property future is raced against it, and a
+    /// timeout is reported as a property failure carrying the input, so a hang
+    /// in the code under test fails the test instead of stalling the suite.
+    /// Shrinking candidates are bounded by the same timeout.
+    pub fn per_iteration_timeout<F, Fut>(mut self, timeout: Duration, sleep: F) -> Self
+    where
+        F: Fn(Duration) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.iteration_timeout =
+            Some((timeout, Box::new(move |duration| Box::pin(sleep(duration)))));
+        self
+    }
+
+    /// Run the property on one input, racing it against the per-iteration timeout if set
+    async fn test_input(&self, input: T) -> Result<(), PropertyError> {
+        use std::future::Future;
+        use std::task::Poll;
+
+        let test = crate::property::catch_rejection_async(self.property.test(input));
+        let Some((timeout, sleep)) = &self.iteration_timeout else {
+            return test.await.map(|_| ());
+        };
+
+        let mut test = std::pin::pin!(test);
+        let mut deadline = sleep(*timeout);
+        std::future::poll_fn(|cx| {
+            if let Poll::Ready(result) = test.as_mut().poll(cx) {
+                return Poll::Ready(result.map(|_| ()));
+            }
+            if deadline.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(Err(PropertyError::PropertyFailed {
+                    message: format!("Property did not complete within {:?}", timeout),
+                    context: Some("per-iteration timeout".to_string()),
+                    iteration: None,
+                }));
+            }
+            Poll::Pending
+        })
+        .await
+    }
+
     /// Execute the async property test
+    ///
+    /// The `PROTEST_SEED` and `PROTEST_ITERATIONS` environment variables override
+    /// the configured seed and iteration count (see [`TestConfig::apply_env_overrides`]).
+    /// Inputs rejected by [`crate::assume`] are discarded and regenerated, as in
+    /// [`PropertyTest::run`].
     pub async fn run(mut self) -> PropertyResult<T> {
         let test_start = Instant::now();
-        let mut rng = if let Some(seed) = self.config.seed {
-            create_seeded_rng(seed)
-        } else {
-            crate::rng::create_rng()
-        };
+        self.config.apply_env_overrides();
+        // Always run from a known seed so failures report how to reproduce them
+        let seed = *self.config.seed.get_or_insert_with(fresh_seed);
+        let mut rng = create_seeded_rng(seed);
         let mut stats_collector = self
             .statistics_collector
             .take()
             .unwrap_or_else(StatisticsCollector::disabled);
 
-        for iteration in 0..self.config.iterations {
+        let mut rejections = 0;
+        let mut iteration = 0;
+        while iteration < self.config.iterations {
             // Start timing generation
             stats_collector.start_generation_timing();
 
-            // Generate test input
-            let input = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            // Generate test input, scaling the size hint for this iteration
+            let size_hint = self.config.size_hint_for_iteration(iteration);
+            let configured_size_hint =
+                std::mem::replace(&mut self.config.generator_config.size_hint, size_hint);
+            let generated = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
                 self.generator
                     .generate(&mut rng, &self.config.generator_config)
-            })) {
+            }));
+            self.config.generator_config.size_hint = configured_size_hint;
+            let input = match generated {
                 Ok(input) => input,
-                Err(_) => {
-                    let error = PropertyError::generation_failed_with_context(
-                        "Generator panicked during value generation",
-                        Some(format!("iteration {}", iteration)),
-                    );
-                    return Err(TestFailure::new(
-                        error,
-                        // We can't provide the original input since generation failed
-                        // This is a limitation we'll need to handle differently in practice
-                        self.generator
-                            .generate(&mut rng, &self.config.generator_config),
-                        None,
-                        0,
-                        self.config,
-                        iteration,
-                        test_start.elapsed(),
-                        Duration::from_secs(0),
-                    ));
-                }
+                Err(payload) => fail_run(generation_error(payload, iteration)),
             };
 
             // End timing and record the generated value
             stats_collector.end_generation_timing();
             stats_collector.record_generated_value(&input, std::any::type_name::<T>());
 
-            // Test the property asynchronously
-            match self.property.test(input.clone()).await {
+            // Test the property asynchronously, timing it for the shrink budget
+            let check_start = Instant::now();
+            let result = self.test_input(input.clone()).await;
+            let check_time = check_start.elapsed();
+            match result {
                 Ok(_) => {
                     // Property passed, continue to next iteration
+                    iteration += 1;
+                    continue;
+                }
+                Err(PropertyError::InputRejected) => {
+                    // Discarded by `assume`: generate a fresh input for this iteration
+                    rejections += 1;
+                    if rejections > self.config.max_rejections {
+                        return Err(TestFailure::new(
+                            PropertyError::TooManyRejections { rejections },
+                            input,
+                            None,
+                            0,
+                            self.config,
+                            iteration,
+                            test_start.elapsed(),
+                            Duration::from_secs(0),
+                        ));
+                    }
                     continue;
                 }
                 Err(mut error) => {
@@ -686,7 +1096,8 @@ where
 
                     // Property failed, attempt shrinking
                     let shrink_start = Instant::now();
-                    let shrink_result = self.shrink_failure_async(input.clone(), &error).await;
+                    let budget = self.config.shrink_config().budget_for(check_time);
+                    let shrink_result = self.shrink_failure_async(input.clone(), budget).await;
                     let shrink_duration = shrink_start.elapsed();
 
                     let failure = TestFailure::new(
@@ -737,7 +1148,7 @@ where
     async fn shrink_failure_async(
         &self,
         original_input: T,
-        _error: &PropertyError,
+        budget: Duration,
     ) -> (Option<T>, usize, ShrinkProgress) {
         let start_time = Instant::now();
         let mut shrink_steps = 0;
@@ -749,7 +1160,7 @@ where
 
         for candidate in shrink_candidates {
             // Check timeout
-            if start_time.elapsed() > self.config.shrink_timeout {
+            if start_time.elapsed() > budget {
                 if self.error_reporter.show_shrink_progress {
                     eprintln!("Async shrinking timed out after {:?}", start_time.elapsed());
                 }
@@ -770,8 +1181,10 @@ where
             let step_start = Instant::now();
             shrink_steps += 1;
 
-            // Test if the candidate still fails (async)
-            if let Err(_) = self.property.test(candidate.clone()).await {
+            // Test if the candidate still fails (async); rejected candidates never count
+            if let Err(error) = self.test_input(candidate.clone()).await
+                && !matches!(error, PropertyError::InputRejected)
+            {
                 let step_time = step_start.elapsed();
 
                 // Record successful shrink step
@@ -800,7 +1213,9 @@ where
                     // For now, just take the first shrink candidate
                     if let Some(further_candidate) = further_shrinks.into_iter().next() {
                         let further_step_start = Instant::now();
-                        if let Err(_) = self.property.test(further_candidate.clone()).await {
+                        if let Err(error) = self.test_input(further_candidate.clone()).await
+                            && !matches!(error, PropertyError::InputRejected)
+                        {
                             let further_step_time = further_step_start.elapsed();
                             shrink_steps += 1;
 
@@ -887,6 +1302,41 @@ where
     test.run()
 }
 
+/// Test a property against explicit inputs only, with no generation
+///
+/// Each example is tested in order, and a failing one is shrunk with
+/// [`Shrinkable`]. To run examples ahead of generated inputs instead, use
+/// [`PropertyTest::with_examples`] or [`PropertyTestBuilder::examples`].
+pub fn check_examples<T, P>(property: P, examples: impl IntoIterator<Item = T>) -> PropertyResult<T>
+where
+    T: Clone + fmt::Debug + PartialEq + Shrinkable + 'static,
+    P: Property<T>,
+{
+    let examples: Vec<T> = examples.into_iter().collect();
+    let config = TestConfig {
+        iterations: 0,
+        ..TestConfig::default()
+    };
+    PropertyTest::new(ShrinkableExamples(examples.clone()), property, config)
+        .with_examples(examples)
+        .run()
+}
+
+/// Generator over a fixed set of examples, shrinking with [`Shrinkable`]
+struct ShrinkableExamples<T>(Vec<T>);
+
+impl<T: Clone + Shrinkable> Generator<T> for ShrinkableExamples<T> {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &crate::config::GeneratorConfig) -> T {
+        use rand::Rng;
+        assert!(!self.0.is_empty(), "no examples to choose from");
+        self.0[rng.gen_range(0..self.0.len())].clone()
+    }
+
+    fn shrink(&self, value: &T) -> Box<dyn Iterator<Item = T>> {
+        value.shrink()
+    }
+}
+
 /// Execute an async property test with the default configuration
 pub async fn check_async<T, G, P>(generator: G, property: P) -> PropertyResult<T>
 where
@@ -921,6 +1371,9 @@ pub struct PropertyTestBuilder<T> {
     persistence_config: Option<crate::persistence::PersistenceConfig>,
     #[cfg(feature = "persistence")]
     test_name: Option<String>,
+    #[cfg(feature = "persistence")]
+    coverage_guide: Option<crate::coverage::CoverageGuide<T>>,
+    examples: Vec<T>,
     _phantom: PhantomData<T>,
 }
 
@@ -935,6 +1388,9 @@ impl<T: 'static> PropertyTestBuilder<T> {
             persistence_config: None,
             #[cfg(feature = "persistence")]
             test_name: None,
+            #[cfg(feature = "persistence")]
+            coverage_guide: None,
+            examples: Vec::new(),
             _phantom: PhantomData,
         }
     }
@@ -963,6 +1419,58 @@ impl<T: 'static> PropertyTestBuilder<T> {
         self
     }
 
+    /// Scale the shrink budget to the failing check instead of using `shrink_timeout`
+    ///
+    /// Shrinking may run for `multiplier` times as long as the property took
+    /// on the input that first failed, and at least
+    /// [`crate::shrink::MIN_SHRINK_BUDGET`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `multiplier` is not finite and positive.
+    pub fn shrink_budget_multiplier(mut self, multiplier: f64) -> Self {
+        self.config.shrink_budget_multiplier =
+            Some(crate::shrink::checked_budget_multiplier(multiplier));
+        self
+    }
+
+    /// Set how the generator size hint grows across iterations
+    pub fn size_growth(mut self, growth: SizeGrowth) -> Self {
+        self.config.size_growth = growth;
+        self
+    }
+
+    /// Set how many inputs [`crate::assume`] may reject before the run fails
+    pub fn max_rejections(mut self, max_rejections: usize) -> Self {
+        self.config.max_rejections = max_rejections;
+        self
+    }
+
+    /// Fail the run when it falls short of any of `thresholds`
+    ///
+    /// Label thresholds are checked against [`crate::classify`] labels, and
+    /// numeric, boolean and enum thresholds against generation statistics,
+    /// which are collected for the check even when statistics are disabled.
+    /// No input is to blame for a shortfall, so the run panics with
+    /// [`PropertyError::InsufficientCoverage`] instead of returning a
+    /// [`TestFailure`].
+    pub fn coverage_thresholds(mut self, thresholds: CoverageThresholds) -> Self {
+        self.config.coverage_thresholds = Some(thresholds);
+        self
+    }
+
+    /// Print a coverage summary at the end of coverage-guided runs
+    pub fn coverage_report(mut self) -> Self {
+        self.config.coverage_report = true;
+        self
+    }
+
+    /// Test these inputs, in order, before any generated ones
+    pub fn examples(mut self, examples: impl IntoIterator<Item = T>) -> Self {
+        self.examples.extend(examples);
+        self
+    }
+
     /// Enable verbose error reporting
     pub fn verbose_errors(mut self) -> Self {
         self.error_reporter = self.error_reporter.verbose();
@@ -1026,6 +1534,25 @@ impl<T: 'static> PropertyTestBuilder<T> {
         self
     }
 
+    /// Enable coverage-guided input selection backed by `corpus`
+    ///
+    /// Coverage points are reported from the property with
+    /// [`coverage::record`](crate::coverage::record).
+    #[cfg(feature = "persistence")]
+    pub fn coverage_guided(self, corpus: crate::coverage::CoverageCorpus) -> Self
+    where
+        T: Clone + fmt::Debug,
+    {
+        self.coverage_guide(crate::coverage::CoverageGuide::new(corpus))
+    }
+
+    /// Enable coverage-guided input selection with a configured guide
+    #[cfg(feature = "persistence")]
+    pub fn coverage_guide(mut self, guide: crate::coverage::CoverageGuide<T>) -> Self {
+        self.coverage_guide = Some(guide);
+        self
+    }
+
     /// Run the property test with the configured parameters
     pub fn run<G, P>(self, generator: G, property: P) -> PropertyResult<T>
     where
@@ -1035,7 +1562,7 @@ impl<T: 'static> PropertyTestBuilder<T> {
     {
         #[cfg(feature = "persistence")]
         {
-            let test = PropertyTest::with_full_config(
+            let mut test = PropertyTest::with_full_config(
                 generator,
                 property,
                 self.config,
@@ -1043,7 +1570,11 @@ impl<T: 'static> PropertyTestBuilder<T> {
                 self.statistics_collector,
                 self.persistence_config,
                 self.test_name,
-            );
+            )
+            .with_examples(self.examples);
+            if let Some(guide) = self.coverage_guide {
+                test = test.with_coverage_guide(guide);
+            }
             test.run()
         }
 
@@ -1055,7 +1586,8 @@ impl<T: 'static> PropertyTestBuilder<T> {
                 self.config,
                 self.error_reporter,
                 self.statistics_collector,
-            );
+            )
+            .with_examples(self.examples);
             test.run()
         }
     }
@@ -1129,6 +1661,54 @@ mod tests {
         }
     }
 
+    // Generator that produces the size hint it was given
+    struct SizeHintGenerator;
+    impl Generator<usize> for SizeHintGenerator {
+        fn generate(
+            &self,
+            _rng: &mut dyn rand::RngCore,
+            config: &crate::config::GeneratorConfig,
+        ) -> usize {
+            config.size_hint
+        }
+
+        fn shrink(&self, _value: &usize) -> Box<dyn Iterator<Item = usize>> {
+            Box::new(std::iter::empty())
+        }
+    }
+
+    // Property that records every input it sees
+    struct RecordingProperty(std::sync::Arc<std::sync::Mutex<Vec<usize>>>);
+    impl Property<usize> for RecordingProperty {
+        type Output = ();
+        fn test(&self, input: usize) -> Result<Self::Output, PropertyError> {
+            self.0.lock().unwrap().push(input);
+            Ok(())
+        }
+    }
+
+    fn observed_sizes(growth: SizeGrowth) -> Vec<usize> {
+        let sizes = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let result = PropertyTestBuilder::new()
+            .iterations(5)
+            .size_growth(growth)
+            .run(SizeHintGenerator, RecordingProperty(sizes.clone()));
+
+        // The reported configuration keeps the configured size hint
+        assert_eq!(result.unwrap().config.generator_config.size_hint, 10);
+        sizes.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn test_size_growth_in_runner() {
+        assert_eq!(observed_sizes(SizeGrowth::Constant), vec![10; 5]);
+        assert_eq!(observed_sizes(SizeGrowth::Linear), vec![1, 3, 6, 8, 10]);
+        assert_eq!(
+            observed_sizes(SizeGrowth::Exponential),
+            vec![1, 2, 3, 6, 10]
+        );
+    }
+
     #[test]
     fn test_property_test_always_passes() {
         let generator = ConstantGenerator::new(42);
@@ -1201,6 +1781,49 @@ mod tests {
         }
     }
 
+    // Generator for 1000 whose many shrink candidates all pass `SlowFailure`
+    struct ManyCandidatesGenerator;
+    impl Generator<usize> for ManyCandidatesGenerator {
+        fn generate(
+            &self,
+            _rng: &mut dyn rand::RngCore,
+            _config: &crate::config::GeneratorConfig,
+        ) -> usize {
+            1000
+        }
+
+        fn shrink(&self, _value: &usize) -> Box<dyn Iterator<Item = usize>> {
+            Box::new(0..100)
+        }
+    }
+
+    // Property that takes 20ms and fails only for 1000
+    struct SlowFailure;
+    impl Property<usize> for SlowFailure {
+        type Output = ();
+        fn test(&self, input: usize) -> Result<Self::Output, PropertyError> {
+            std::thread::sleep(Duration::from_millis(20));
+            if input == 1000 {
+                Err(PropertyError::property_failed("slow failure"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_shrink_budget_multiplier_in_runner() {
+        // 100 candidates at 20ms each would take 2s under the flat timeout
+        let result = PropertyTestBuilder::new()
+            .iterations(1)
+            .shrink_budget_multiplier(2.0)
+            .run(ManyCandidatesGenerator, SlowFailure);
+
+        let failure = result.unwrap_err();
+        assert_eq!(failure.config.shrink_budget_multiplier, Some(2.0));
+        assert!(failure.shrink_duration < Duration::from_secs(1));
+    }
+
     #[test]
     fn test_property_test_builder_default() {
         let builder = PropertyTestBuilder::<i32>::default();
@@ -1260,6 +1883,324 @@ mod tests {
         }
     }
 
+    // Property that only accepts even inputs and records the ones it tests
+    struct EvenOnlyProperty(std::sync::Arc<std::sync::Mutex<Vec<i32>>>);
+    impl Property<i32> for EvenOnlyProperty {
+        type Output = ();
+        fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+            crate::assume(input % 2 == 0);
+            self.0.lock().unwrap().push(input);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_assume_regenerates_rejected_inputs() {
+        let tested = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let result = PropertyTestBuilder::new().iterations(50).seed(3).run(
+            crate::primitives::IntGenerator::new(0, 1000),
+            EvenOnlyProperty(tested.clone()),
+        );
+
+        assert!(result.is_ok());
+        let tested = tested.lock().unwrap();
+        assert_eq!(tested.len(), 50);
+        assert!(tested.iter().all(|x| x % 2 == 0));
+    }
+
+    #[test]
+    fn test_assume_fails_after_too_many_rejections() {
+        let failure = PropertyTestBuilder::new()
+            .max_rejections(10)
+            .run(
+                ConstantGenerator::new(1),
+                EvenOnlyProperty(Default::default()),
+            )
+            .expect_err("every input is rejected");
+
+        assert!(matches!(
+            failure.error,
+            PropertyError::TooManyRejections { rejections: 11 }
+        ));
+        assert_eq!(failure.failed_iteration, 0);
+    }
+
+    // Property that labels its input by sign
+    struct SignLabelProperty;
+    impl Property<i32> for SignLabelProperty {
+        type Output = ();
+        fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+            crate::property::classify(input < 0, "negative");
+            crate::property::classify(input == 0, "zero");
+            Ok(())
+        }
+    }
+
+    // Error a run ended with because it failed without a counterexample
+    fn run_failure<T>(
+        run: impl FnOnce() -> Result<TestSuccess<T>, TestFailure<T>>,
+    ) -> PropertyError {
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(run)) {
+            Ok(_) => panic!("the run should have failed without a counterexample"),
+            Err(payload) => PropertyError::from_panic(payload),
+        }
+    }
+
+    #[test]
+    fn test_coverage_thresholds_pass() {
+        let thresholds = crate::statistics::CoverageThresholdsBuilder::new()
+            .label_threshold("negative", 0.3)
+            .build();
+        let result = PropertyTestBuilder::new()
+            .iterations(200)
+            .seed(5)
+            .coverage_thresholds(thresholds)
+            .run(
+                crate::primitives::IntGenerator::new(-10, 10),
+                SignLabelProperty,
+            );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_coverage_thresholds_fail_for_missing_label() {
+        let thresholds = crate::statistics::CoverageThresholdsBuilder::new()
+            .label_threshold("negative", 0.05)
+            .label_threshold("zero", 0.05)
+            .build();
+        let error = run_failure(|| {
+            PropertyTestBuilder::new()
+                .iterations(100)
+                .seed(5)
+                .coverage_thresholds(thresholds)
+                .run(
+                    crate::primitives::IntGenerator::new(1, 10),
+                    SignLabelProperty,
+                )
+        });
+
+        // Labels are checked by name, so "negative" is reported first
+        match &error {
+            PropertyError::InsufficientCoverage {
+                target,
+                coverage,
+                required,
+            } => {
+                assert_eq!(target, "inputs labelled 'negative'");
+                assert_eq!(*coverage, 0.0);
+                assert_eq!(*required, 0.05);
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+        assert!(error.to_string().contains(
+            "Insufficient coverage of inputs labelled 'negative': 0.0%, required at least 5.0%"
+        ));
+    }
+
+    // Passes every input without labelling any
+    struct UnlabelledProperty;
+    impl Property<i32> for UnlabelledProperty {
+        type Output = ();
+        fn test(&self, _input: i32) -> Result<Self::Output, PropertyError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_coverage_thresholds_enforce_numeric_ranges() {
+        let thresholds = crate::statistics::CoverageThresholdsBuilder::new()
+            .numeric_threshold("i32", 0.0, 100.0, 0.8)
+            .build();
+        let run = |max| {
+            PropertyTestBuilder::new()
+                .iterations(200)
+                .seed(5)
+                .disable_statistics()
+                .coverage_thresholds(thresholds.clone())
+                .run(
+                    crate::primitives::IntGenerator::new(0, max),
+                    UnlabelledProperty,
+                )
+        };
+
+        // Statistics are collected for the check but not reported when disabled
+        assert!(run(100).unwrap().stats.is_none());
+
+        // Values only span 10% of the range
+        let error = run_failure(|| run(10));
+        match &error {
+            PropertyError::InsufficientCoverage {
+                target, required, ..
+            } => {
+                assert_eq!(target, "i32 range [0, 100]");
+                assert_eq!(*required, 0.8);
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[test]
+    fn test_coverage_thresholds_enforce_boolean_and_enum_coverage() {
+        struct AcceptsBool;
+        impl Property<bool> for AcceptsBool {
+            type Output = ();
+            fn test(&self, _input: bool) -> Result<Self::Output, PropertyError> {
+                Ok(())
+            }
+        }
+
+        let thresholds = crate::statistics::CoverageThresholdsBuilder::new()
+            .require_full_boolean_coverage()
+            .build();
+        // False is never generated
+        let error = run_failure(|| {
+            PropertyTestBuilder::new()
+                .iterations(50)
+                .coverage_thresholds(thresholds.clone())
+                .run(crate::generator::ConstantGenerator::new(true), AcceptsBool)
+        });
+        assert!(
+            error
+                .to_string()
+                .contains("Insufficient coverage of bool values: 50.0%")
+        );
+
+        let result = PropertyTestBuilder::new()
+            .iterations(50)
+            .seed(1)
+            .coverage_thresholds(thresholds)
+            .run(crate::primitives::BoolGenerator, AcceptsBool);
+        assert!(result.is_ok());
+
+        // Nothing records variants of this enum, so its threshold is unmet
+        let thresholds = crate::statistics::CoverageThresholdsBuilder::new()
+            .enum_threshold("Color", 0.5)
+            .build();
+        let error = run_failure(|| {
+            PropertyTestBuilder::new()
+                .iterations(10)
+                .coverage_thresholds(thresholds)
+                .run(
+                    crate::primitives::IntGenerator::new(0, 10),
+                    UnlabelledProperty,
+                )
+        });
+        assert!(error.to_string().contains("Color variants"));
+    }
+
+    // Generator whose filter never accepts a value
+    struct NeverPassingFilterGenerator;
+    impl Generator<i32> for NeverPassingFilterGenerator {
+        fn generate(
+            &self,
+            mut rng: &mut dyn rand::RngCore,
+            config: &crate::config::GeneratorConfig,
+        ) -> i32 {
+            use crate::strategy::Strategy;
+            let strategy = crate::strategy::range(0, 10)
+                .filter(|x| *x > 100)
+                .with_max_attempts(50);
+            Strategy::generate(&strategy, &mut rng, config)
+        }
+
+        fn shrink(&self, _value: &i32) -> Box<dyn Iterator<Item = i32>> {
+            Box::new(std::iter::empty())
+        }
+    }
+
+    #[test]
+    fn test_filter_exhaustion_is_reported() {
+        let error = run_failure(|| {
+            PropertyTestBuilder::new().run(NeverPassingFilterGenerator, AlwaysPassProperty)
+        });
+
+        assert!(matches!(
+            error,
+            PropertyError::FilterExhausted { attempts: 50 }
+        ));
+    }
+
+    #[test]
+    fn test_generator_panic_reports_iteration() {
+        struct PanickingGenerator;
+        impl Generator<i32> for PanickingGenerator {
+            fn generate(
+                &self,
+                _rng: &mut dyn rand::RngCore,
+                _config: &crate::config::GeneratorConfig,
+            ) -> i32 {
+                panic!("no value")
+            }
+
+            fn shrink(&self, _value: &i32) -> Box<dyn Iterator<Item = i32>> {
+                Box::new(std::iter::empty())
+            }
+        }
+
+        let error =
+            run_failure(|| PropertyTestBuilder::new().run(PanickingGenerator, AlwaysPassProperty));
+        assert!(matches!(error, PropertyError::GenerationFailed { .. }));
+        assert!(error.to_string().contains("iteration 0"));
+    }
+
+    #[test]
+    fn test_shrinking_skips_rejected_candidates() {
+        struct FailsWhenAccepted;
+        impl Property<i32> for FailsWhenAccepted {
+            type Output = ();
+            fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+                crate::assume(input >= 700);
+                Err(PropertyError::property_failed("accepted inputs fail"))
+            }
+        }
+
+        let failure = PropertyTestBuilder::new()
+            .seed(5)
+            .run(
+                crate::primitives::IntGenerator::new(0, 1000),
+                FailsWhenAccepted,
+            )
+            .expect_err("property should fail");
+
+        assert!(failure.original_input >= 700);
+        assert!(failure.shrunk_input.is_none_or(|x| x >= 700));
+    }
+
+    #[test]
+    fn test_unseeded_failure_reports_reproducible_seed() {
+        struct FailsAboveProperty;
+        impl Property<i32> for FailsAboveProperty {
+            type Output = ();
+            fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+                if input > 500 {
+                    Err(PropertyError::property_failed("too large"))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
+        let generator = crate::primitives::IntGenerator::new(0, 1000);
+        let config = TestConfig {
+            iterations: 200,
+            ..TestConfig::default()
+        };
+        let failure = check_with_config(generator.clone(), FailsAboveProperty, config)
+            .expect_err("property should fail");
+        let seed = failure.config.seed.expect("failure should record its seed");
+
+        let replay_config = TestConfig {
+            iterations: 200,
+            seed: Some(seed),
+            ..TestConfig::default()
+        };
+        let replay = check_with_config(generator, FailsAboveProperty, replay_config)
+            .expect_err("replaying the seed should fail again");
+        assert_eq!(replay.original_input, failure.original_input);
+        assert_eq!(replay.failed_iteration, failure.failed_iteration);
+    }
+
     // Async property implementations for testing
     struct AlwaysPassAsyncProperty;
     impl AsyncProperty<i32> for AlwaysPassAsyncProperty {
@@ -1303,6 +2244,112 @@ mod tests {
         }
     }
 
+    struct HangsForNegativeAsyncProperty;
+    impl AsyncProperty<i32> for HangsForNegativeAsyncProperty {
+        type Output = ();
+        async fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+            if input < 0 {
+                std::future::pending::<()>().await;
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_per_iteration_timeout() {
+        let config = TestConfig {
+            iterations: 5,
+            ..TestConfig::default()
+        };
+
+        let passing = AsyncPropertyTest::new(
+            ConstantGenerator::new(1),
+            HangsForNegativeAsyncProperty,
+            config.clone(),
+        )
+        .per_iteration_timeout(Duration::from_millis(50), tokio::time::sleep);
+        assert!(passing.run().await.is_ok());
+
+        let hanging = AsyncPropertyTest::new(
+            ConstantGenerator::new(-1),
+            HangsForNegativeAsyncProperty,
+            config,
+        )
+        .per_iteration_timeout(Duration::from_millis(10), tokio::time::sleep);
+        let failure = hanging.run().await.expect_err("hang should time out");
+        assert_eq!(failure.original_input, -1);
+        assert_eq!(failure.failed_iteration, 0);
+        match failure.error {
+            PropertyError::PropertyFailed {
+                message, iteration, ..
+            } => {
+                assert!(message.contains("did not complete within"));
+                assert_eq!(iteration, Some(0));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    // Async property that discards odd inputs and fails on even inputs above 90
+    struct AssumesEvenAsyncProperty;
+    impl AsyncProperty<i32> for AssumesEvenAsyncProperty {
+        type Output = ();
+        async fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+            tokio::task::yield_now().await;
+            crate::assume(input % 2 == 0);
+            if input > 90 {
+                Err(PropertyError::property_failed("too large"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_property_test_discards_rejected_inputs() {
+        let config = TestConfig {
+            iterations: 50,
+            seed: Some(3),
+            ..TestConfig::default()
+        };
+        let success = AsyncPropertyTest::new(
+            crate::primitives::IntGenerator::new(0, 90),
+            AssumesEvenAsyncProperty,
+            config.clone(),
+        )
+        .run()
+        .await
+        .expect("odd inputs are discarded");
+        assert_eq!(success.iterations, 50);
+
+        let failure = AsyncPropertyTest::new(
+            crate::primitives::IntGenerator::new(0, 200),
+            AssumesEvenAsyncProperty,
+            config.clone(),
+        )
+        .run()
+        .await
+        .expect_err("large even inputs fail");
+        assert_eq!(failure.original_input % 2, 0);
+        assert!(failure.shrunk_input.is_none_or(|x| x % 2 == 0 && x > 90));
+
+        let failure = AsyncPropertyTest::new(
+            ConstantGenerator::new(1),
+            AssumesEvenAsyncProperty,
+            TestConfig {
+                max_rejections: 10,
+                ..config
+            },
+        )
+        .run()
+        .await
+        .expect_err("every input is rejected");
+        assert!(matches!(
+            failure.error,
+            PropertyError::TooManyRejections { rejections: 11 }
+        ));
+    }
+
     #[tokio::test]
     async fn test_async_property_test_always_passes() {
         let generator = ConstantGenerator::new(42);
@@ -1504,4 +2551,46 @@ mod tests {
             assert_eq!(failure.original_input, 0);
         }
     }
+
+    #[test]
+    fn test_examples_run_before_generated_inputs() {
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let result = PropertyTestBuilder::new()
+            .iterations(2)
+            .size_growth(SizeGrowth::Constant)
+            .examples([100, 200])
+            .run(SizeHintGenerator, RecordingProperty(seen.clone()));
+
+        assert_eq!(result.unwrap().iterations, 4);
+        assert_eq!(*seen.lock().unwrap(), vec![100, 200, 10, 10]);
+    }
+
+    struct LessThanTen;
+    impl Property<i32> for LessThanTen {
+        type Output = ();
+        fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+            if input < 10 {
+                Ok(())
+            } else {
+                Err(PropertyError::property_failed("too big"))
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_examples() {
+        assert_eq!(
+            check_examples(LessThanTen, [1, 5, 9]).unwrap().iterations,
+            3
+        );
+
+        let failure = check_examples(LessThanTen, [3, 50, 7]).unwrap_err();
+        assert_eq!(failure.original_input, 50);
+        let shrunk = failure.shrunk_input.expect("example should shrink");
+        assert!((10..50).contains(&shrunk));
+        assert!(matches!(
+            failure.error,
+            PropertyError::PropertyFailed { context: Some(ref context), .. } if context == "example 1"
+        ));
+    }
 }
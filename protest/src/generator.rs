@@ -1,9 +1,12 @@
 //! Core generator infrastructure and registry system.
 
 use std::any::{Any, TypeId};
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 use crate::config::GeneratorConfig;
+use crate::rng::{RecordingRng, ReplayRng};
 
 /// Core generator trait for creating random test data
 pub trait Generator<T> {
@@ -14,7 +17,19 @@ pub trait Generator<T> {
     fn shrink(&self, value: &T) -> Box<dyn Iterator<Item = T>>;
 }
 
+thread_local! {
+    static DEFAULT_REGISTRY: RefCell<GeneratorRegistry> = RefCell::new(GeneratorRegistry::new());
+}
+
 /// Type-safe registry for storing and retrieving generators
+///
+/// Each thread also has a default registry, reached through
+/// [`GeneratorRegistry::register_default`] and friends. `#[derive(Generator)]`
+/// consults it for every field without a `#[generator(...)]` attribute before
+/// falling back to `Arbitrary`, so registering a generator there overrides how
+/// that type is generated in every derived struct. Registrations are
+/// thread-local and are not visible from other threads, such as the workers
+/// of a parallel property test.
 pub struct GeneratorRegistry {
     generators: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
 }
@@ -63,6 +78,51 @@ impl GeneratorRegistry {
     pub fn is_empty(&self) -> bool {
         self.generators.is_empty()
     }
+
+    /// Generate a value with the generator registered for `T`, if any
+    pub fn generate<T: 'static>(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        config: &GeneratorConfig,
+    ) -> Option<T> {
+        self.get::<T>()
+            .map(|generator| generator.generate(rng, config))
+    }
+
+    /// Run `f` with mutable access to this thread's default registry
+    pub fn with_default<R>(f: impl FnOnce(&mut GeneratorRegistry) -> R) -> R {
+        DEFAULT_REGISTRY.with(|registry| f(&mut registry.borrow_mut()))
+    }
+
+    /// Register a generator for `T` in this thread's default registry
+    ///
+    /// Replaces any generator previously registered for `T`.
+    pub fn register_default<T: 'static, G: Generator<T> + Send + Sync + 'static>(generator: G) {
+        Self::with_default(|registry| registry.register::<T, G>(generator));
+    }
+
+    /// Remove the generator for `T` from this thread's default registry
+    pub fn remove_default<T: 'static>() -> bool {
+        Self::with_default(|registry| registry.remove::<T>())
+    }
+
+    /// Generate a value with the default registry's generator for `T`, if any
+    ///
+    /// Used by `#[derive(Generator)]`; returns `None` when nothing is
+    /// registered so the caller can fall back to `Arbitrary`.
+    pub fn generate_default<T: 'static>(
+        rng: &mut dyn rand::RngCore,
+        config: &GeneratorConfig,
+    ) -> Option<T> {
+        DEFAULT_REGISTRY.with(|registry| {
+            let registry = registry.borrow();
+            if registry.is_empty() {
+                None
+            } else {
+                registry.generate::<T>(rng, config)
+            }
+        })
+    }
 }
 
 impl Default for GeneratorRegistry {
@@ -134,8 +194,8 @@ impl<T: Clone + 'static> Generator<T> for ConstantGenerator<T> {
         self.value.clone()
     }
 
+    /// Never shrinks: any other value would not be the constant
     fn shrink(&self, _value: &T) -> Box<dyn Iterator<Item = T>> {
-        // A constant value cannot be shrunk
         Box::new(std::iter::empty())
     }
 }
@@ -170,9 +230,223 @@ impl<T: Clone + 'static> Generator<T> for OneOfGenerator<T> {
     }
 }
 
+/// A generator that samples from a fixed pool of values, such as a corpus of
+/// real-world inputs
+///
+/// Shrinking moves towards earlier entries in the pool, so order it from
+/// simplest to most complex.
+#[derive(Debug, Clone)]
+pub struct SampleGenerator<T> {
+    pool: Vec<T>,
+    /// Running totals of the entry weights; empty when sampling uniformly
+    cumulative_weights: Vec<u64>,
+}
+
+impl<T: Clone> SampleGenerator<T> {
+    /// Sample uniformly from `pool`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pool` is empty.
+    pub fn new(pool: Vec<T>) -> Self {
+        assert!(
+            !pool.is_empty(),
+            "SampleGenerator cannot be created with an empty pool"
+        );
+        Self {
+            pool,
+            cumulative_weights: Vec::new(),
+        }
+    }
+
+    /// Sample each entry in proportion to its weight
+    ///
+    /// Entries with a weight of zero are never generated and are dropped from
+    /// the pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no entry has a positive weight.
+    pub fn weighted(pool: Vec<(u32, T)>) -> Self {
+        let mut total = 0u64;
+        let (cumulative_weights, pool) = pool
+            .into_iter()
+            .filter(|(weight, _)| *weight > 0)
+            .map(|(weight, value)| {
+                total += u64::from(weight);
+                (total, value)
+            })
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        assert!(
+            !pool.is_empty(),
+            "SampleGenerator cannot be created with an empty pool"
+        );
+        Self {
+            pool,
+            cumulative_weights,
+        }
+    }
+
+    /// The values this generator samples from
+    pub fn pool(&self) -> &[T] {
+        &self.pool
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Generator<T> for SampleGenerator<T> {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> T {
+        use rand::Rng;
+        let index = match self.cumulative_weights.last() {
+            Some(&total) => {
+                let target = rng.gen_range(0..total);
+                self.cumulative_weights
+                    .partition_point(|&cumulative| cumulative <= target)
+            }
+            None => rng.gen_range(0..self.pool.len()),
+        };
+        self.pool[index].clone()
+    }
+
+    /// Earlier entries: the first, the one halfway back, then the previous one
+    fn shrink(&self, value: &T) -> Box<dyn Iterator<Item = T>> {
+        let Some(index) = self.pool.iter().position(|entry| entry == value) else {
+            return Box::new(std::iter::empty());
+        };
+
+        let mut indices = vec![0, index / 2, index.saturating_sub(1)];
+        indices.dedup();
+        let shrinks: Vec<T> = indices
+            .into_iter()
+            .filter(|&candidate| candidate < index)
+            .map(|candidate| self.pool[candidate].clone())
+            .collect();
+        Box::new(shrinks.into_iter())
+    }
+}
+
+/// A generator that records the raw RNG bytes each generated value consumed
+///
+/// Useful for answering "why did this seed produce this value": the recorded
+/// bytes can be diffed between runs, or fed back through [`replay`] to rebuild
+/// the value without the original seed.
+///
+/// [`replay`]: RecordingGenerator::replay
+#[derive(Debug)]
+pub struct RecordingGenerator<G> {
+    inner: G,
+    last_draws: Mutex<Vec<u8>>,
+}
+
+impl<G> RecordingGenerator<G> {
+    /// Wrap a generator so its RNG draws are recorded
+    pub fn new(inner: G) -> Self {
+        Self {
+            inner,
+            last_draws: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Bytes drawn by the most recent `generate` or `generate_recorded` call
+    pub fn last_draws(&self) -> Vec<u8> {
+        self.last_draws.lock().unwrap().clone()
+    }
+
+    /// Generate a value, returning it with the bytes it consumed
+    pub fn generate_recorded<T>(
+        &self,
+        rng: &mut dyn rand::RngCore,
+        config: &GeneratorConfig,
+    ) -> (T, Vec<u8>)
+    where
+        G: Generator<T>,
+    {
+        let mut recording = RecordingRng::new(rng);
+        let value = self.inner.generate(&mut recording, config);
+        let draws = recording.into_draws();
+        *self.last_draws.lock().unwrap() = draws.clone();
+        (value, draws)
+    }
+
+    /// Regenerate a value from previously recorded bytes
+    pub fn replay<T>(&self, draws: &[u8], config: &GeneratorConfig) -> T
+    where
+        G: Generator<T>,
+    {
+        self.inner.generate(&mut ReplayRng::new(draws), config)
+    }
+}
+
+impl<T, G: Generator<T>> Generator<T> for RecordingGenerator<G> {
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> T {
+        self.generate_recorded(rng, config).0
+    }
+
+    fn shrink(&self, value: &T) -> Box<dyn Iterator<Item = T>> {
+        self.inner.shrink(value)
+    }
+}
+
+// A tuple of generators is itself a generator of tuples (up to 8 elements), so
+// these only exist to spell the combination out at the call site.
+
+/// Combine 3 generators into one generator of flat 3-tuples
+pub fn zip3<GA, GB, GC>(a: GA, b: GB, c: GC) -> (GA, GB, GC) {
+    (a, b, c)
+}
+
+/// Combine 4 generators into one generator of flat 4-tuples
+pub fn zip4<GA, GB, GC, GD>(a: GA, b: GB, c: GC, d: GD) -> (GA, GB, GC, GD) {
+    (a, b, c, d)
+}
+
+/// Combine 5 generators into one generator of flat 5-tuples
+pub fn zip5<GA, GB, GC, GD, GE>(a: GA, b: GB, c: GC, d: GD, e: GE) -> (GA, GB, GC, GD, GE) {
+    (a, b, c, d, e)
+}
+
+/// Combine 6 generators into one generator of flat 6-tuples
+pub fn zip6<GA, GB, GC, GD, GE, GF>(
+    a: GA,
+    b: GB,
+    c: GC,
+    d: GD,
+    e: GE,
+    f: GF,
+) -> (GA, GB, GC, GD, GE, GF) {
+    (a, b, c, d, e, f)
+}
+
+/// Combine 7 generators into one generator of flat 7-tuples
+pub fn zip7<GA, GB, GC, GD, GE, GF, GG>(
+    a: GA,
+    b: GB,
+    c: GC,
+    d: GD,
+    e: GE,
+    f: GF,
+    g: GG,
+) -> (GA, GB, GC, GD, GE, GF, GG) {
+    (a, b, c, d, e, f, g)
+}
+
+/// Combine 8 generators into one generator of flat 8-tuples
+pub fn zip8<GA, GB, GC, GD, GE, GF, GG, GH>(
+    a: GA,
+    b: GB,
+    c: GC,
+    d: GD,
+    e: GE,
+    f: GF,
+    g: GG,
+    h: GH,
+) -> (GA, GB, GC, GD, GE, GF, GG, GH) {
+    (a, b, c, d, e, f, g, h)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::primitives::IntGenerator;
     use rand::thread_rng;
 
     #[test]
@@ -200,6 +474,45 @@ mod tests {
         assert!(registry.is_empty());
     }
 
+    #[test]
+    fn test_generator_registry_generate() {
+        let mut registry = GeneratorRegistry::new();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        assert_eq!(registry.generate::<i32>(&mut rng, &config), None);
+        registry.register::<i32, _>(ConstantGenerator::new(7));
+        assert_eq!(registry.generate::<i32>(&mut rng, &config), Some(7));
+    }
+
+    #[test]
+    fn test_default_registry_is_thread_local() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        GeneratorRegistry::register_default::<u16, _>(ConstantGenerator::new(3u16));
+        assert_eq!(
+            GeneratorRegistry::generate_default::<u16>(&mut rng, &config),
+            Some(3)
+        );
+
+        let other_thread = std::thread::spawn(|| {
+            GeneratorRegistry::generate_default::<u16>(
+                &mut thread_rng(),
+                &GeneratorConfig::default(),
+            )
+        })
+        .join()
+        .unwrap();
+        assert_eq!(other_thread, None);
+
+        assert!(GeneratorRegistry::remove_default::<u16>());
+        assert_eq!(
+            GeneratorRegistry::generate_default::<u16>(&mut rng, &config),
+            None
+        );
+    }
+
     #[test]
     fn test_generator_registry_type_safety() {
         let mut registry = GeneratorRegistry::new();
@@ -226,9 +539,10 @@ mod tests {
             assert_eq!(value, 42);
         }
 
-        // Should not produce any shrinks
+        // Should not produce any shrinks, even for values it didn't produce
         let shrinks: Vec<_> = generator.shrink(&42).collect();
         assert!(shrinks.is_empty());
+        assert_eq!(generator.shrink(&1000).count(), 0);
     }
 
     #[test]
@@ -251,6 +565,46 @@ mod tests {
         OneOfGenerator::<i32>::new(vec![]);
     }
 
+    #[test]
+    fn test_sample_generator() {
+        let pool = vec!["a", "b", "c", "d", "e", "f", "g"];
+        let generator = SampleGenerator::new(pool.clone());
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            assert!(pool.contains(&generator.generate(&mut rng, &config)));
+        }
+
+        // Shrinks towards earlier entries only
+        assert_eq!(
+            generator.shrink(&"g").collect::<Vec<_>>(),
+            vec!["a", "d", "f"]
+        );
+        assert_eq!(generator.shrink(&"b").collect::<Vec<_>>(), vec!["a"]);
+        assert_eq!(generator.shrink(&"a").count(), 0);
+        assert_eq!(generator.shrink(&"z").count(), 0);
+    }
+
+    #[test]
+    fn test_sample_generator_weighted() {
+        let generator = SampleGenerator::weighted(vec![(0, 1), (9, 2), (1, 3)]);
+        assert_eq!(generator.pool(), &[2, 3]);
+
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+        let twos = (0..1000)
+            .filter(|_| generator.generate(&mut rng, &config) == 2)
+            .count();
+        assert!(twos > 800, "expected about 900 twos, got {}", twos);
+    }
+
+    #[test]
+    #[should_panic(expected = "SampleGenerator cannot be created with an empty pool")]
+    fn test_sample_generator_all_zero_weights() {
+        SampleGenerator::weighted(vec![(0, 'x')]);
+    }
+
     #[test]
     fn test_boxed_generator() {
         let generator = BoxedGenerator::new(ConstantGenerator::new("test"));
@@ -290,4 +644,68 @@ mod tests {
             panic!("Expected String generator to be registered");
         }
     }
+
+    #[test]
+    fn test_zip_generators_produce_flat_tuples() {
+        let generator = zip4(
+            IntGenerator::new(0, 10),
+            ConstantGenerator::new("x"),
+            IntGenerator::new(100, 200),
+            ConstantGenerator::new(true),
+        );
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let (a, b, c, d) = generator.generate(&mut rng, &config);
+        assert!((0..=10).contains(&a));
+        assert_eq!(b, "x");
+        assert!((100..=200).contains(&c));
+        assert!(d);
+
+        // Shrinking works one element at a time
+        for (sa, sb, sc, sd) in generator.shrink(&(a, b, c, d)) {
+            assert_eq!((sb, sd), (b, d));
+            assert!(sa != a || sc != c);
+        }
+    }
+
+    #[test]
+    fn test_zip8_generator() {
+        let generator = zip8(
+            ConstantGenerator::new(1u8),
+            ConstantGenerator::new(2u16),
+            ConstantGenerator::new(3u32),
+            ConstantGenerator::new(4u64),
+            ConstantGenerator::new('5'),
+            ConstantGenerator::new("6"),
+            ConstantGenerator::new(7i8),
+            IntGenerator::new(8i32, 8),
+        );
+        let value = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+
+        assert_eq!(value, (1, 2, 3, 4, '5', "6", 7, 8));
+    }
+
+    #[test]
+    fn test_recording_generator_replays_draws() {
+        let generator = RecordingGenerator::new(crate::primitives::VecGenerator::new(
+            IntGenerator::new(0, 1000),
+            1,
+            10,
+        ));
+        let config = GeneratorConfig::default();
+        let mut rng = crate::rng::create_seeded_rng(42);
+
+        let (value, draws): (Vec<i32>, _) = generator.generate_recorded(&mut rng, &config);
+        assert!(!draws.is_empty());
+        assert_eq!(generator.last_draws(), draws);
+        assert_eq!(generator.replay::<Vec<i32>>(&draws, &config), value);
+
+        // Plain generate records too
+        let value: Vec<i32> = generator.generate(&mut rng, &config);
+        assert_eq!(
+            generator.replay::<Vec<i32>>(&generator.last_draws(), &config),
+            value
+        );
+    }
 }
@@ -49,40 +49,47 @@ pub mod test_runner;
 // Re-export the main public API
 pub use arbitrary::Arbitrary;
 pub use config::{
-    ConfigError, ConfigManager, GeneratorConfig, GlobalConfig, TestConfig, create_test_config,
-    create_test_config_with_overrides, get_global_config, set_global_config,
+    ConfigError, ConfigManager, GeneratorConfig, GlobalConfig, ITERATIONS_ENV_VAR, SEED_ENV_VAR,
+    SizeGrowth, TestConfig, create_test_config, create_test_config_with_overrides,
+    get_global_config, set_global_config,
 };
-pub use error::PropertyError;
+pub use error::{GeneratorError, PropertyError};
 pub use execution::{
     AsyncPropertyTest, PropertyTest, PropertyTestBuilder, check, check_async,
-    check_async_with_config, check_with_config,
+    check_async_with_config, check_examples, check_with_config,
 };
 pub use generator::{
     BoxedGenerator, ConstantGenerator, Generator, GeneratorRegistry, OneOfGenerator,
+    RecordingGenerator, SampleGenerator, zip3, zip4, zip5, zip6, zip7, zip8,
 };
 pub use performance::{
-    LazyGenerator, ParallelConfig, ParallelPropertyTest, StreamingShrinkStrategy, check_parallel,
-    lazy,
+    LazyGenerator, ParallelConfig, ParallelPropertyTest, StreamingShrinkIter,
+    StreamingShrinkStrategy, check_parallel, lazy,
 };
 // Note: ParallelAsyncPropertyTest and check_async_parallel have been removed
 // to keep the library runtime-agnostic. Use check_async with your own async runtime instead.
 #[cfg(feature = "persistence")]
 pub use coverage::{
-    CoverageCorpus, CoverageCorpusConfig, CoverageStats, CoverageTracker, path_hash,
+    CoverageCorpus, CoverageCorpusConfig, CoverageGuide, CoverageStats, CoverageTracker, path_hash,
 };
 #[cfg(feature = "persistence")]
-pub use persistence::{CorpusCase, FailureCase, FailureSnapshot, PersistenceConfig, TestCorpus};
+pub use persistence::{
+    CorpusCase, FailureCase, FailureSnapshot, PersistenceConfig, TestCorpus, VerifyRecord,
+};
 pub use primitives::*;
-pub use property::{AsyncProperty, Property};
+pub use property::{AsyncProperty, Property, assume, classify};
 #[cfg(feature = "persistence")]
 pub use regression::{RegressionConfig, RegressionGenerator};
-pub use rng::{DefaultRngProvider, RngManager, RngProvider, create_rng, create_seeded_rng};
+pub use rng::{
+    DefaultRngProvider, RecordingRng, ReplayRng, RngManager, RngProvider, create_rng,
+    create_seeded_rng, split_seed,
+};
 pub use shrink::{AsyncShrinkEngine, ShrinkConfig, ShrinkEngine, ShrinkResult, Shrinkable};
-pub use statistics::{CoverageThresholdsBuilder, StatisticsCollector};
-pub use strategy::Strategy;
+pub use statistics::{CoverageThresholdsBuilder, Histogram, StatisticsCollector};
+pub use strategy::{NoShrink, Strategy};
 pub use test_runner::{
-    DefaultFormatter, JsonFormatter, TestContext, TestOutputFormatter, TestResult, TestRunner,
-    VerboseFormatter,
+    DefaultFormatter, JUnitFormatter, JsonFormatter, TapFormatter, TestContext,
+    TestOutputFormatter, TestResult, TestRunner, VerboseFormatter,
 };
 
 // Re-export common types
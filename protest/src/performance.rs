@@ -2,14 +2,15 @@
 //! lazy evaluation, and memory-efficient strategies.
 
 use std::marker::PhantomData;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
 
-use crate::config::{GeneratorConfig, TestConfig};
+use crate::config::{GeneratorConfig, SizeGrowth, TestConfig};
 use crate::error::{PropertyError, PropertyResult, TestFailure, TestSuccess};
 use crate::generator::Generator;
 use crate::property::Property;
-use crate::rng::create_seeded_rng;
+use crate::rng::{DefaultRngProvider, RngManager};
 
 /// Configuration for parallel execution
 #[derive(Debug, Clone)]
@@ -20,6 +21,12 @@ pub struct ParallelConfig {
     pub batch_size: usize,
     /// Whether to enable parallel execution
     pub enabled: bool,
+    /// Generate every input across the threads before checking any of them
+    ///
+    /// Separates generation from checking, which helps when generators are
+    /// expensive and properties are cheap. Inputs are identical to the default
+    /// mode, but all of them are held in memory at once.
+    pub pregenerate_inputs: bool,
 }
 
 impl Default for ParallelConfig {
@@ -28,14 +35,31 @@ impl Default for ParallelConfig {
             num_threads: num_cpus::get(),
             batch_size: 10,
             enabled: true,
+            pregenerate_inputs: false,
         }
     }
 }
 
+impl ParallelConfig {
+    /// Generate all inputs up front, then distribute the property checks
+    pub fn with_pregenerated_inputs(mut self) -> Self {
+        self.pregenerate_inputs = true;
+        self
+    }
+}
+
 /// Lazy generator wrapper that defers expensive computations
+///
+/// The construction closure runs at most once, on the first call to `generate`
+/// or `shrink`; the resulting generator is cached and reused for every later
+/// call. The cache is a [`OnceLock`], so a `LazyGenerator` can be shared across
+/// the worker threads of [`check_parallel`]: if several threads hit an
+/// uninitialized generator at the same time, one runs the closure while the
+/// others block until it finishes. The closure must therefore be `Send + Sync`
+/// and should not depend on which thread runs it.
 pub struct LazyGenerator<T, F> {
     generator_fn: F,
-    _phantom: PhantomData<T>,
+    generator: OnceLock<Box<dyn Generator<T> + Send + Sync>>,
 }
 
 impl<T, F> LazyGenerator<T, F>
@@ -46,9 +70,20 @@ where
     pub fn new(generator_fn: F) -> Self {
         Self {
             generator_fn,
-            _phantom: PhantomData,
+            generator: OnceLock::new(),
         }
     }
+
+    /// Whether the inner generator has been constructed yet
+    pub fn is_initialized(&self) -> bool {
+        self.generator.get().is_some()
+    }
+
+    fn inner(&self) -> &(dyn Generator<T> + Send + Sync) {
+        self.generator
+            .get_or_init(|| (self.generator_fn)())
+            .as_ref()
+    }
 }
 
 impl<T, F> Generator<T> for LazyGenerator<T, F>
@@ -57,17 +92,22 @@ where
     F: Fn() -> Box<dyn Generator<T> + Send + Sync> + Send + Sync,
 {
     fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> T {
-        let generator = (self.generator_fn)();
-        generator.generate(rng, config)
+        self.inner().generate(rng, config)
     }
 
     fn shrink(&self, value: &T) -> Box<dyn Iterator<Item = T>> {
-        let generator = (self.generator_fn)();
-        generator.shrink(value)
+        self.inner().shrink(value)
     }
 }
 
 /// Memory-efficient shrinking strategy that uses streaming
+///
+/// [`candidates`](Self::candidates) yields shrink candidates for a collection
+/// one at a time, so peak memory is one shared copy of the input plus the
+/// candidate being tested, however many candidates there are. Candidates whose
+/// elements would take more than the configured memory limit are skipped. Use
+/// [`ShrinkEngine::shrink_streaming`](crate::ShrinkEngine::shrink_streaming) to
+/// drive a shrink with it.
 pub struct StreamingShrinkStrategy<T> {
     max_memory_usage: usize,
     current_memory_usage: usize,
@@ -98,6 +138,110 @@ impl<T> StreamingShrinkStrategy<T> {
     pub fn reset_memory_usage(&mut self) {
         self.current_memory_usage = 0;
     }
+
+    /// Lazily yield shrink candidates for `value`
+    ///
+    /// The empty collection comes first, then copies with a contiguous chunk
+    /// removed, trying chunks of half the length, then a quarter, and so on
+    /// down to single elements. That is roughly `2 * len` candidates in total,
+    /// each built only when the iterator is advanced.
+    pub fn candidates(&self, value: &[T]) -> StreamingShrinkIter<T>
+    where
+        T: Clone,
+    {
+        StreamingShrinkIter {
+            original: value.into(),
+            chunk: value.len() / 2,
+            start: 0,
+            empty_done: false,
+            max_memory_usage: self.max_memory_usage,
+        }
+    }
+}
+
+/// Iterator returned by [`StreamingShrinkStrategy::candidates`]
+pub struct StreamingShrinkIter<T> {
+    original: Arc<[T]>,
+    chunk: usize,
+    start: usize,
+    empty_done: bool,
+    max_memory_usage: usize,
+}
+
+impl<T: Clone> Iterator for StreamingShrinkIter<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let len = self.original.len();
+
+        if !self.empty_done {
+            self.empty_done = true;
+            if len > 0 {
+                return Some(Vec::new());
+            }
+        }
+
+        while self.chunk > 0 {
+            if self.start >= len {
+                self.chunk /= 2;
+                self.start = 0;
+                continue;
+            }
+
+            let start = self.start;
+            let end = (start + self.chunk).min(len);
+            self.start = end;
+
+            let remaining = len - (end - start);
+            if remaining.saturating_mul(std::mem::size_of::<T>()) > self.max_memory_usage {
+                continue;
+            }
+
+            let mut candidate = Vec::with_capacity(remaining);
+            candidate.extend_from_slice(&self.original[..start]);
+            candidate.extend_from_slice(&self.original[end..]);
+            return Some(candidate);
+        }
+
+        None
+    }
+}
+
+/// Why a worker thread stopped before finishing its iterations
+enum WorkerFailure<T> {
+    /// The property failed on an input
+    Property(Box<TestFailure<T>>),
+    /// The generator panicked, leaving no input to report
+    Generation {
+        iteration: usize,
+        error: PropertyError,
+    },
+}
+
+impl<T> WorkerFailure<T> {
+    /// The iteration the worker stopped at
+    fn iteration(&self) -> usize {
+        match self {
+            WorkerFailure::Property(failure) => failure.failed_iteration,
+            WorkerFailure::Generation { iteration, .. } => *iteration,
+        }
+    }
+}
+
+/// Generate the input for `iteration`, catching a generator panic
+fn generate_input<T, G: Generator<T> + ?Sized>(
+    generator: &G,
+    rng: &mut dyn rand::RngCore,
+    config: &GeneratorConfig,
+    iteration: usize,
+) -> Result<T, WorkerFailure<T>> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        generator.generate(rng, config)
+    }))
+    .map_err(|payload| WorkerFailure::Generation {
+        iteration,
+        error: crate::execution::generation_error(payload, iteration),
+    })
 }
 
 /// Parallel property test executor
@@ -132,32 +276,74 @@ where
     }
 
     /// Execute the property test in parallel
-    pub fn run(self) -> PropertyResult<T> {
+    ///
+    /// Like [`crate::PropertyTest::run`], this honours the `PROTEST_SEED` and
+    /// `PROTEST_ITERATIONS` environment variables.
+    ///
+    /// Every iteration is generated from its own stream split off the base seed
+    /// with [`RngManager::split`], so the input for an iteration does not depend
+    /// on which thread runs it, when, or how many threads there are. The
+    /// reported failure is always the one with the lowest iteration index, and
+    /// its `config` is set up to replay it serially: `seed` is that
+    /// iteration's seed and the size hint is pinned to the one it was generated
+    /// with, so `check_with_config(generator, property, failure.config)` fails
+    /// on its first iteration with the same input.
+    ///
+    /// An input rejected by [`crate::assume`] is replaced by the next one drawn
+    /// from its iteration's stream, and the run fails with
+    /// [`PropertyError::TooManyRejections`] once more than
+    /// `config.max_rejections` inputs are rejected across all threads.
+    ///
+    /// A generator panic leaves no input to report, so once every thread has
+    /// stopped the run unwinds with the [`PropertyError`] for the earliest
+    /// iteration whose generator panicked, as [`crate::PropertyTest::run`] does.
+    /// A panic in the property is passed on the same way.
+    ///
+    /// With [`ParallelConfig::pregenerate_inputs`], all inputs are generated in
+    /// parallel first and the checks are distributed afterwards; the inputs, and
+    /// so the reported failure, are the same either way.
+    pub fn run(mut self) -> PropertyResult<T> {
         if !self.parallel_config.enabled || self.config.iterations < self.parallel_config.batch_size
         {
             // Fall back to sequential execution for small test counts
             return self.run_sequential();
         }
 
+        self.config.apply_env_overrides();
+        let base_seed = *self
+            .config
+            .seed
+            .get_or_insert_with(crate::execution::fresh_seed);
         let test_start = Instant::now();
-        let num_threads = self.parallel_config.num_threads.min(self.config.iterations);
-        let iterations_per_thread = self.config.iterations / num_threads;
-        let remaining_iterations = self.config.iterations % num_threads;
+        let num_threads = self
+            .parallel_config
+            .num_threads
+            .clamp(1, self.config.iterations);
+        let lowest_failure = AtomicUsize::new(usize::MAX);
+        let rejections = AtomicUsize::new(0);
+        let streams = RngManager::with_seed(DefaultRngProvider, base_seed);
+
+        let pregenerated = if self.parallel_config.pregenerate_inputs {
+            match self.pregenerate(&streams, num_threads) {
+                Ok(inputs) => Some(inputs),
+                Err(failure) => return Self::finish(failure, test_start),
+            }
+        } else {
+            None
+        };
+        let pregenerated = pregenerated.as_deref();
 
         // Use crossbeam for scoped threads to avoid lifetime issues
-        let result = crossbeam::scope(|s| {
+        let (earliest, panic) = crossbeam::scope(|s| {
             let mut handles = Vec::new();
 
             for thread_id in 0..num_threads {
                 let generator = Arc::clone(&self.generator);
                 let property = Arc::clone(&self.property);
                 let config = self.config.clone();
-
-                let thread_iterations = if thread_id < remaining_iterations {
-                    iterations_per_thread + 1
-                } else {
-                    iterations_per_thread
-                };
+                let lowest_failure = &lowest_failure;
+                let rejections = &rejections;
+                let streams = &streams;
 
                 let handle = s.spawn(move |_| {
                     Self::run_thread_batch(
@@ -165,74 +351,199 @@ where
                         property,
                         config,
                         thread_id,
-                        thread_iterations,
+                        num_threads,
+                        streams,
+                        pregenerated,
+                        lowest_failure,
+                        rejections,
                     )
                 });
 
                 handles.push(handle);
             }
 
-            // Collect results from all threads
+            // Join every thread, keeping the earliest failing iteration
+            let mut earliest: Option<WorkerFailure<T>> = None;
+            let mut panic = None;
             for handle in handles {
                 match handle.join() {
-                    Ok(Ok(_)) => continue,                   // Thread succeeded
-                    Ok(Err(failure)) => return Err(failure), // Thread found a failure
-                    Err(_) => {
-                        // Thread panicked
-                        return Err(TestFailure::new(
-                            PropertyError::execution_failed(
-                                "Thread panicked during parallel execution",
-                            ),
-                            // We need a dummy value here - this is a limitation of the current design
-                            self.generator
-                                .generate(&mut create_seeded_rng(0), &self.config.generator_config),
-                            None,
-                            0,
-                            self.config.clone(),
-                            0,
-                            test_start.elapsed(),
-                            Duration::from_secs(0),
-                        ));
+                    Ok(Ok(())) => continue, // Thread succeeded
+                    Ok(Err(failure)) => {
+                        if earliest
+                            .as_ref()
+                            .is_none_or(|current| failure.iteration() < current.iteration())
+                        {
+                            earliest = Some(failure);
+                        }
+                    }
+                    Err(payload) => {
+                        panic.get_or_insert(payload);
                     }
                 }
             }
+            (earliest, panic)
+        })
+        .unwrap(); // Every thread has been joined
 
+        // A panicking property unwinds out of the run, as in a serial run
+        if let Some(payload) = panic {
+            std::panic::resume_unwind(payload);
+        }
+
+        match earliest {
+            Some(failure) => Self::finish(failure, test_start),
             // All threads succeeded
-            Ok(TestSuccess::new(
+            None => Ok(TestSuccess::new(
                 self.config.iterations,
                 self.config,
                 None, // Stats aggregation would need to be implemented
-            ))
-        });
+            )),
+        }
+    }
 
-        result.unwrap() // crossbeam::scope guarantees this won't panic
+    /// End the run with the failure a worker stopped at
+    ///
+    /// A generator panic leaves no input to report, so like
+    /// [`crate::PropertyTest::run`] the run unwinds with its [`PropertyError`].
+    fn finish(failure: WorkerFailure<T>, test_start: Instant) -> PropertyResult<T> {
+        match failure {
+            WorkerFailure::Property(mut failure) => {
+                failure.test_duration = test_start.elapsed();
+                Err(*failure)
+            }
+            WorkerFailure::Generation { error, .. } => crate::execution::fail_run(error),
+        }
     }
 
-    /// Run a batch of iterations in a single thread
+    /// Generate the input of every iteration across `num_threads` threads
+    ///
+    /// Fails with the earliest iteration whose generator panicked.
+    fn pregenerate(
+        &self,
+        streams: &RngManager<DefaultRngProvider>,
+        num_threads: usize,
+    ) -> Result<Vec<T>, WorkerFailure<T>> {
+        let iterations = self.config.iterations;
+        let (batches, earliest, panic) = crossbeam::scope(|s| {
+            let handles: Vec<_> = (0..num_threads)
+                .map(|thread_id| {
+                    let generator = &self.generator;
+                    let config = &self.config;
+                    s.spawn(move |_| {
+                        let mut generator_config = config.generator_config.clone();
+                        (thread_id..iterations)
+                            .step_by(num_threads)
+                            .map(|iteration| {
+                                generator_config.size_hint =
+                                    config.size_hint_for_iteration(iteration);
+                                let mut rng = streams.split(iteration as u64).get_rng();
+                                generate_input(
+                                    generator.as_ref(),
+                                    &mut rng,
+                                    &generator_config,
+                                    iteration,
+                                )
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                })
+                .collect();
+
+            // Join every thread before reporting any failure
+            let mut batches = Vec::with_capacity(num_threads);
+            let mut earliest: Option<WorkerFailure<T>> = None;
+            let mut panic = None;
+            for handle in handles {
+                match handle.join() {
+                    Ok(Ok(batch)) => batches.push(batch.into_iter()),
+                    Ok(Err(failure)) => {
+                        if earliest
+                            .as_ref()
+                            .is_none_or(|current| failure.iteration() < current.iteration())
+                        {
+                            earliest = Some(failure);
+                        }
+                    }
+                    Err(payload) => {
+                        panic.get_or_insert(payload);
+                    }
+                }
+            }
+            (batches, earliest, panic)
+        })
+        .unwrap(); // Every thread has been joined
+
+        if let Some(payload) = panic {
+            std::panic::resume_unwind(payload);
+        }
+        if let Some(failure) = earliest {
+            return Err(failure);
+        }
+
+        // Thread `t` generated iterations `t`, `t + num_threads`, ...
+        let mut batches = batches;
+        Ok((0..iterations)
+            .map(|iteration| {
+                batches[iteration % num_threads]
+                    .next()
+                    .expect("every thread generated its iterations")
+            })
+            .collect())
+    }
+
+    /// Run every `stride`-th iteration starting at `thread_id` in a single thread
+    ///
+    /// Inputs come from `pregenerated` when given, and are generated here
+    /// otherwise. Iterations past the lowest failure found so far by any thread
+    /// are skipped, since they can no longer be the reported failure.
+    /// `rejections` counts the inputs rejected by [`crate::assume`] across all
+    /// threads, against the run's `max_rejections`.
     fn run_thread_batch(
         generator: Arc<G>,
         property: Arc<P>,
-        config: TestConfig,
+        mut config: TestConfig,
         thread_id: usize,
-        iterations: usize,
-    ) -> PropertyResult<T> {
-        let mut rng = if let Some(seed) = config.seed {
-            // Create a unique seed for each thread to avoid correlation
-            create_seeded_rng(seed.wrapping_add(thread_id as u64))
-        } else {
-            crate::rng::create_rng()
-        };
-
-        for iteration in 0..iterations {
-            let global_iteration = thread_id * iterations + iteration;
-
-            // Generate test input
-            let input = generator.generate(&mut rng, &config.generator_config);
+        stride: usize,
+        streams: &RngManager<DefaultRngProvider>,
+        pregenerated: Option<&[T]>,
+        lowest_failure: &AtomicUsize,
+        rejections: &AtomicUsize,
+    ) -> Result<(), WorkerFailure<T>> {
+        for global_iteration in (thread_id..config.iterations).step_by(stride) {
+            if global_iteration > lowest_failure.load(Ordering::Acquire) {
+                break;
+            }
 
-            // Test the property
-            match property.test(input.clone()) {
-                Ok(_) => continue,
+            let stream = streams.split(global_iteration as u64);
+            let size_hint = config.size_hint_for_iteration(global_iteration);
+
+            // Generate test input, scaling the size hint for this iteration
+            let mut rng = stream.get_rng();
+            let configured_size_hint =
+                std::mem::replace(&mut config.generator_config.size_hint, size_hint);
+            let checked = Self::check_iteration(
+                generator.as_ref(),
+                property.as_ref(),
+                &config,
+                &mut rng,
+                pregenerated.map(|inputs| inputs[global_iteration].clone()),
+                global_iteration,
+                rejections,
+            );
+            config.generator_config.size_hint = configured_size_hint;
+
+            let (input, result) = match checked {
+                Ok(checked) => checked,
+                Err(failure) => {
+                    lowest_failure.fetch_min(global_iteration, Ordering::AcqRel);
+                    return Err(failure);
+                }
+            };
+            match result {
+                Ok(()) => continue,
                 Err(mut error) => {
+                    lowest_failure.fetch_min(global_iteration, Ordering::AcqRel);
+
                     // Add iteration context
                     error = match error {
                         PropertyError::PropertyFailed {
@@ -247,23 +558,74 @@ where
                         other => other,
                     };
 
+                    // Record the configuration that replays this iteration serially
+                    config.seed = stream.seed();
+                    config.generator_config.size_hint = size_hint;
+                    config.size_growth = SizeGrowth::Constant;
+
                     // For parallel execution, we don't shrink immediately to avoid complexity
                     // The caller can shrink the failure if needed
-                    return Err(TestFailure::new(
+                    let mut failure = TestFailure::new(
                         error,
                         input,
                         None, // No shrinking in parallel mode for now
                         0,
                         config,
                         global_iteration,
-                        Duration::from_secs(0), // Thread doesn't track total time
+                        Duration::from_secs(0), // Filled in by the caller
                         Duration::from_secs(0),
-                    ));
+                    );
+                    failure.thread_id = Some(thread_id);
+                    return Err(WorkerFailure::Property(Box::new(failure)));
                 }
             }
         }
 
-        Ok(TestSuccess::new(iterations, config, None))
+        Ok(())
+    }
+
+    /// Check the property on the input for `iteration`
+    ///
+    /// The input is `pregenerated` when it was generated up front and drawn
+    /// from `rng` otherwise. An input rejected by [`crate::assume`] is replaced
+    /// by the next one from `rng`, as a serial replay would draw. Returns the
+    /// last input checked along with the property's result.
+    fn check_iteration(
+        generator: &G,
+        property: &P,
+        config: &TestConfig,
+        rng: &mut dyn rand::RngCore,
+        pregenerated: Option<T>,
+        iteration: usize,
+        rejections: &AtomicUsize,
+    ) -> Result<(T, Result<(), PropertyError>), WorkerFailure<T>> {
+        let generator_config = &config.generator_config;
+        let mut drawn = pregenerated.is_none();
+        let mut input = match pregenerated {
+            Some(input) => input,
+            None => generate_input(generator, rng, generator_config, iteration)?,
+        };
+
+        loop {
+            match crate::property::catch_rejection(|| property.test(input.clone()).map(|_| ())) {
+                Err(PropertyError::InputRejected) => {
+                    let rejected = rejections.fetch_add(1, Ordering::AcqRel) + 1;
+                    if rejected > config.max_rejections {
+                        let error = PropertyError::TooManyRejections {
+                            rejections: rejected,
+                        };
+                        return Ok((input, Err(error)));
+                    }
+                    if !drawn {
+                        // Skip past the pregenerated input
+                        generate_input(generator, rng, generator_config, iteration)?;
+                        drawn = true;
+                    }
+                    input = generate_input(generator, rng, generator_config, iteration)?;
+                }
+                result => return Ok((input, result)),
+            }
+        }
     }
 
     /// Fall back to sequential execution
@@ -391,6 +753,28 @@ mod tests {
         assert!(shrinks.is_empty());
     }
 
+    #[test]
+    fn test_lazy_generator_constructs_once() {
+        let constructions = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&constructions);
+        let lazy_gen = lazy(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Box::new(ConstantGenerator::new(7))
+        });
+        assert!(!lazy_gen.is_initialized());
+        assert_eq!(constructions.load(Ordering::SeqCst), 0);
+
+        let mut rng = crate::rng::create_rng();
+        let config = GeneratorConfig::default();
+        for _ in 0..20 {
+            assert_eq!(lazy_gen.generate(&mut rng, &config), 7);
+        }
+        let _ = lazy_gen.shrink(&7).count();
+
+        assert!(lazy_gen.is_initialized());
+        assert_eq!(constructions.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_streaming_shrink_strategy() {
         let mut strategy = StreamingShrinkStrategy::<i32>::new(1); // 1MB limit
@@ -404,6 +788,45 @@ mod tests {
         assert!(strategy.within_memory_limit(1024 * 1024)); // Should be fine after reset
     }
 
+    #[test]
+    fn test_streaming_shrink_candidates() {
+        let strategy = StreamingShrinkStrategy::<i32>::new(1);
+        let original: Vec<i32> = (1..=8).collect();
+        let candidates: Vec<Vec<i32>> = strategy.candidates(&original).collect();
+
+        // Empty, then chunks of 4, 2 and 1 removed
+        assert_eq!(candidates.len(), 1 + 2 + 4 + 8);
+        assert_eq!(candidates[0], Vec::<i32>::new());
+        assert_eq!(candidates[1], vec![5, 6, 7, 8]);
+        assert_eq!(candidates[2], vec![1, 2, 3, 4]);
+        assert_eq!(candidates[14], vec![1, 2, 3, 4, 5, 6, 7]);
+
+        // With no memory budget only the empty candidate fits
+        let strategy = StreamingShrinkStrategy::<i32>::new(0);
+        let candidates: Vec<Vec<i32>> = strategy.candidates(&original).collect();
+        assert_eq!(candidates, vec![Vec::<i32>::new()]);
+    }
+
+    #[test]
+    fn test_shrink_engine_streams_large_collections() {
+        let strategy = StreamingShrinkStrategy::<u32>::new(16);
+        let original: Vec<u32> = (0..100_000).collect();
+
+        let result = crate::ShrinkEngine::new().shrink_streaming(
+            original,
+            |v| {
+                if v.contains(&777) {
+                    Err(PropertyError::property_failed("contains 777"))
+                } else {
+                    Ok(())
+                }
+            },
+            &strategy,
+        );
+
+        assert_eq!(result.minimal, vec![777]);
+    }
+
     #[test]
     fn test_parallel_property_test_sequential_fallback() {
         let generator = ConstantGenerator::new(42);
@@ -456,6 +879,7 @@ mod tests {
             enabled: true,
             num_threads: 2,
             batch_size: 5,
+            pregenerate_inputs: false,
         };
 
         let test = ParallelPropertyTest::new(generator, property, config, parallel_config);
@@ -481,6 +905,332 @@ mod tests {
         assert_eq!(counter.load(Ordering::SeqCst), 15);
     }
 
+    // Property that discards odd inputs and fails on even inputs above 90
+    struct AssumesEven;
+    impl Property<i32> for AssumesEven {
+        type Output = ();
+        fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+            crate::property::assume(input % 2 == 0);
+            if input > 90 {
+                Err(PropertyError::property_failed("too large"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_parallel_discards_rejected_inputs() {
+        let parallel_config = ParallelConfig {
+            enabled: true,
+            num_threads: 4,
+            batch_size: 10,
+            pregenerate_inputs: false,
+        };
+
+        let passing = check_parallel(
+            crate::primitives::IntGenerator::new(0, 90),
+            AssumesEven,
+            TestConfig {
+                iterations: 200,
+                seed: Some(7),
+                ..TestConfig::default()
+            },
+            parallel_config.clone(),
+        );
+        assert!(passing.is_ok());
+
+        let failure = check_parallel(
+            crate::primitives::IntGenerator::new(0, 100),
+            AssumesEven,
+            TestConfig {
+                iterations: 500,
+                seed: Some(7),
+                ..TestConfig::default()
+            },
+            parallel_config,
+        )
+        .unwrap_err();
+        assert!(failure.original_input > 90);
+        assert_eq!(failure.original_input % 2, 0);
+
+        // Rejected inputs are redrawn from the iteration's stream, so the
+        // failure replays serially from its config
+        let replayed = crate::execution::PropertyTest::new(
+            crate::primitives::IntGenerator::new(0, 100),
+            AssumesEven,
+            failure.config.clone(),
+        )
+        .run()
+        .unwrap_err();
+        assert_eq!(replayed.original_input, failure.original_input);
+    }
+
+    #[test]
+    fn test_parallel_fails_after_too_many_rejections() {
+        struct RejectsEverything;
+        impl Property<i32> for RejectsEverything {
+            type Output = ();
+            fn test(&self, _input: i32) -> Result<Self::Output, PropertyError> {
+                crate::property::assume(false);
+                Ok(())
+            }
+        }
+
+        for pregenerate_inputs in [false, true] {
+            let parallel_config = ParallelConfig {
+                enabled: true,
+                num_threads: 4,
+                batch_size: 10,
+                pregenerate_inputs,
+            };
+            let failure = check_parallel(
+                crate::primitives::IntGenerator::new(0, 100),
+                RejectsEverything,
+                TestConfig {
+                    iterations: 100,
+                    max_rejections: 50,
+                    ..TestConfig::default()
+                },
+                parallel_config,
+            )
+            .unwrap_err();
+            assert!(matches!(
+                failure.error,
+                PropertyError::TooManyRejections { rejections: 51.. }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parallel_generator_panics_report_the_earliest_iteration() {
+        // Generator whose filter never accepts a value, on every thread
+        struct NeverPassingFilterGenerator;
+        impl Generator<i32> for NeverPassingFilterGenerator {
+            fn generate(&self, mut rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> i32 {
+                use crate::strategy::Strategy;
+                let strategy = crate::strategy::range(0, 10)
+                    .filter(|x| *x > 100)
+                    .with_max_attempts(5);
+                Strategy::generate(&strategy, &mut rng, config)
+            }
+
+            fn shrink(&self, _value: &i32) -> Box<dyn Iterator<Item = i32>> {
+                Box::new(std::iter::empty())
+            }
+        }
+
+        for pregenerate_inputs in [false, true] {
+            let parallel_config = ParallelConfig {
+                enabled: true,
+                num_threads: 4,
+                batch_size: 10,
+                pregenerate_inputs,
+            };
+            let payload = std::panic::catch_unwind(|| {
+                check_parallel(
+                    NeverPassingFilterGenerator,
+                    CountingProperty::new().0,
+                    TestConfig {
+                        iterations: 40,
+                        ..TestConfig::default()
+                    },
+                    parallel_config,
+                )
+            })
+            .expect_err("every generator gives up");
+            assert!(matches!(
+                PropertyError::from_panic(payload),
+                PropertyError::FilterExhausted { attempts: 5 }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parallel_property_panics_unwind_after_every_thread_stops() {
+        struct PanicsOnOdd;
+        impl Property<i32> for PanicsOnOdd {
+            type Output = ();
+            fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+                assert!(input % 2 == 0, "odd input");
+                Ok(())
+            }
+        }
+
+        let parallel_config = ParallelConfig {
+            enabled: true,
+            num_threads: 4,
+            batch_size: 10,
+            pregenerate_inputs: false,
+        };
+        let payload = std::panic::catch_unwind(|| {
+            check_parallel(
+                crate::primitives::IntGenerator::new(0, 100),
+                PanicsOnOdd,
+                TestConfig {
+                    iterations: 100,
+                    ..TestConfig::default()
+                },
+                parallel_config,
+            )
+        })
+        .expect_err("odd inputs panic");
+        assert_eq!(
+            PropertyError::from_panic(payload).to_string(),
+            PropertyError::property_failed("odd input").to_string()
+        );
+    }
+
+    #[test]
+    fn test_parallel_runs_keep_custom_ranges() {
+        struct WithinCustomRange;
+        impl Property<i32> for WithinCustomRange {
+            type Output = ();
+            fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+                if (0..=9).contains(&input) {
+                    Ok(())
+                } else {
+                    Err(PropertyError::property_failed("outside the custom range"))
+                }
+            }
+        }
+
+        let mut config = TestConfig {
+            iterations: 200,
+            ..TestConfig::default()
+        };
+        config.generator_config = config.generator_config.with_custom_range(0i32..=9);
+
+        for pregenerate_inputs in [false, true] {
+            let parallel_config = ParallelConfig {
+                enabled: true,
+                num_threads: 4,
+                batch_size: 10,
+                pregenerate_inputs,
+            };
+            let result = check_parallel(
+                crate::primitives::IntGenerator::<i32>::full_range(),
+                WithinCustomRange,
+                config.clone(),
+                parallel_config,
+            );
+            assert!(result.is_ok(), "{:?}", result.err());
+        }
+    }
+
+    #[test]
+    fn test_parallel_inputs_do_not_depend_on_thread_count() {
+        struct RecordingProperty(Arc<std::sync::Mutex<Vec<i32>>>);
+        impl Property<i32> for RecordingProperty {
+            type Output = ();
+            fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+                self.0.lock().unwrap().push(input);
+                Ok(())
+            }
+        }
+
+        let inputs_with_threads = |num_threads, pregenerate_inputs| {
+            let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+            let config = TestConfig {
+                iterations: 200,
+                seed: Some(11),
+                ..TestConfig::default()
+            };
+            let parallel_config = ParallelConfig {
+                num_threads,
+                batch_size: 1,
+                enabled: true,
+                pregenerate_inputs,
+            };
+            check_parallel(
+                crate::primitives::IntGenerator::new(0, 1_000_000),
+                RecordingProperty(Arc::clone(&seen)),
+                config,
+                parallel_config,
+            )
+            .expect("property should pass");
+
+            let mut inputs = seen.lock().unwrap().clone();
+            inputs.sort_unstable();
+            inputs
+        };
+
+        let single = inputs_with_threads(1, false);
+        assert_eq!(single.len(), 200);
+        assert_eq!(inputs_with_threads(2, false), single);
+        assert_eq!(inputs_with_threads(4, false), single);
+
+        // Generating every input up front draws the same inputs
+        assert_eq!(inputs_with_threads(1, true), single);
+        assert_eq!(inputs_with_threads(3, true), single);
+    }
+
+    #[test]
+    fn test_parallel_failure_is_deterministic_and_replayable() {
+        struct FailsAbove900;
+        impl Property<i32> for FailsAbove900 {
+            type Output = ();
+            fn test(&self, input: i32) -> Result<Self::Output, PropertyError> {
+                if input > 900 {
+                    Err(PropertyError::property_failed("too large"))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+
+        let generator = crate::primitives::IntGenerator::new(0, 1000);
+        let config = TestConfig {
+            iterations: 500,
+            seed: Some(7),
+            size_growth: SizeGrowth::Linear,
+            ..TestConfig::default()
+        };
+        let parallel_config = ParallelConfig {
+            num_threads: 4,
+            batch_size: 1,
+            enabled: true,
+            pregenerate_inputs: false,
+        };
+
+        let run = || {
+            check_parallel(
+                generator.clone(),
+                FailsAbove900,
+                config.clone(),
+                parallel_config.clone(),
+            )
+            .expect_err("property should fail")
+        };
+        let first = run();
+        let second = run();
+        assert_eq!(first.failed_iteration, second.failed_iteration);
+        assert_eq!(first.original_input, second.original_input);
+        assert_eq!(first.thread_id, Some(first.failed_iteration % 4));
+        assert_eq!(
+            first.config.seed,
+            Some(crate::rng::split_seed(7, first.failed_iteration as u64))
+        );
+
+        // Pregenerated inputs find the same failure
+        let pregenerated = check_parallel(
+            generator.clone(),
+            FailsAbove900,
+            config.clone(),
+            parallel_config.clone().with_pregenerated_inputs(),
+        )
+        .expect_err("property should fail");
+        assert_eq!(pregenerated.failed_iteration, first.failed_iteration);
+        assert_eq!(pregenerated.original_input, first.original_input);
+        assert_eq!(pregenerated.config.seed, first.config.seed);
+
+        // A serial run with the reported configuration fails immediately on the same input
+        let replay = crate::execution::check_with_config(generator, FailsAbove900, first.config)
+            .expect_err("replay should fail");
+        assert_eq!(replay.failed_iteration, 0);
+        assert_eq!(replay.original_input, first.original_input);
+    }
+
     // Note: These tests use ParallelAsyncPropertyTest which requires tokio::spawn
     // Removed to keep library runtime-agnostic
     // #[tokio::test]
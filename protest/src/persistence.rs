@@ -9,6 +9,23 @@ use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
+/// Metadata key holding the shrunk input of a saved failure
+pub const MINIMAL_INPUT_KEY: &str = "minimal_input";
+
+/// Metadata key holding the iteration count of the run that failed
+pub const ITERATIONS_KEY: &str = "iterations";
+
+/// Metadata key holding the iteration at which the saved failure was found
+pub const FAILED_ITERATION_KEY: &str = "failed_iteration";
+
+/// Environment variable that switches property tests into verify mode
+///
+/// When set to a file path, a property test with a persistence config and a
+/// test name reproduces each of its saved failures once, appends a
+/// [`VerifyRecord`] per failure to that file, and skips its generated inputs.
+/// `protest verify` sets it while running the test suite.
+pub const VERIFY_ENV_VAR: &str = "PROTEST_VERIFY";
+
 /// A saved test failure case with all necessary information for replay
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FailureCase {
@@ -163,6 +180,62 @@ impl FailureSnapshot {
     }
 }
 
+/// Outcome of reproducing one saved failure in verify mode
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifyRecord {
+    /// Name of the test the failure was saved for
+    pub test_name: String,
+    /// Seed of the saved failure
+    pub seed: u64,
+    /// Error from the reproduction, or `None` if the property now passes
+    pub error_message: Option<String>,
+    /// Debug form of the input the reproduction regenerated from the seed
+    #[serde(default)]
+    pub input: Option<String>,
+}
+
+impl VerifyRecord {
+    /// Whether the saved failure still reproduces
+    pub fn still_failing(&self) -> bool {
+        self.error_message.is_some()
+    }
+
+    /// Whether this record shows that `failure` is fixed
+    ///
+    /// The property must pass on an input that matches the saved one. If the
+    /// generator or the iteration schedule changed since the failure was
+    /// saved, the seed regenerates a different input and nothing is shown.
+    pub fn confirms_fixed(&self, failure: &FailureCase) -> bool {
+        !self.still_failing() && self.input.as_deref() == Some(failure.input.as_str())
+    }
+
+    /// Append this record to a verify report as one JSON line
+    pub fn append_to<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut line = serde_json::to_string(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        line.push('\n');
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        file.write_all(line.as_bytes())
+    }
+
+    /// Load every record from a verify report, skipping malformed lines
+    pub fn load_all<P: AsRef<Path>>(path: P) -> io::Result<Vec<Self>> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        Ok(fs::read_to_string(path)?
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+}
+
 /// Manager for test corpus - interesting test cases that should be reused
 pub struct TestCorpus {
     /// Directory where corpus files are stored
@@ -398,4 +471,53 @@ mod tests {
         assert!(tests.contains(&"test1".to_string()));
         assert!(tests.contains(&"test2".to_string()));
     }
+
+    #[test]
+    fn test_verify_report_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let report = temp_dir.path().join("verify.jsonl");
+        assert!(VerifyRecord::load_all(&report).unwrap().is_empty());
+
+        let fixed = VerifyRecord {
+            test_name: "test1".to_string(),
+            seed: 1,
+            error_message: None,
+            input: Some("42".to_string()),
+        };
+        let failing = VerifyRecord {
+            test_name: "test2".to_string(),
+            seed: 2,
+            error_message: Some("still broken".to_string()),
+            input: Some("7".to_string()),
+        };
+        fixed.append_to(&report).unwrap();
+        failing.append_to(&report).unwrap();
+
+        let records = VerifyRecord::load_all(&report).unwrap();
+        assert_eq!(records, vec![fixed, failing]);
+        assert!(!records[0].still_failing());
+        assert!(records[1].still_failing());
+    }
+
+    #[test]
+    fn test_verify_record_confirms_fixed_only_for_matching_input() {
+        let failure = FailureCase::new(1, "42".to_string(), "boom".to_string(), 0);
+        let record = |error_message: Option<&str>, input: Option<&str>| VerifyRecord {
+            test_name: "test1".to_string(),
+            seed: 1,
+            error_message: error_message.map(str::to_string),
+            input: input.map(str::to_string),
+        };
+
+        assert!(record(None, Some("42")).confirms_fixed(&failure));
+        assert!(!record(None, Some("43")).confirms_fixed(&failure));
+        assert!(!record(None, None).confirms_fixed(&failure));
+        assert!(!record(Some("boom"), Some("42")).confirms_fixed(&failure));
+
+        // Reports written before the input was recorded still load
+        let legacy: VerifyRecord =
+            serde_json::from_str(r#"{"test_name":"test1","seed":1,"error_message":null}"#).unwrap();
+        assert_eq!(legacy.input, None);
+        assert!(!legacy.confirms_fixed(&failure));
+    }
 }
@@ -1,6 +1,11 @@
 //! Generators for primitive types and basic collections.
 
+use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::arbitrary::Arbitrary;
 use crate::config::GeneratorConfig;
@@ -28,10 +33,19 @@ impl Generator<bool> for BoolGenerator {
 }
 
 /// Generator for integer types with optional range constraints
+///
+/// If the [`GeneratorConfig`] has a custom range registered for the integer type
+/// (see [`GeneratorConfig::with_custom_range`]), generated values are clamped to
+/// the intersection of that range and the generator's own.
+///
+/// # Panics
+///
+/// Generation panics if the custom range does not overlap the generator's range.
 #[derive(Debug, Clone)]
 pub struct IntGenerator<T> {
     min: T,
     max: T,
+    edge_bias: f64,
 }
 
 impl<T> IntGenerator<T>
@@ -40,17 +54,51 @@ where
 {
     /// Create a new integer generator with the full range for the type
     pub fn new(min: T, max: T) -> Self {
-        Self { min, max }
+        Self {
+            min,
+            max,
+            edge_bias: 0.0,
+        }
+    }
+
+    /// Oversample boundary values
+    ///
+    /// With probability `p`, generation returns one of `0`, `1`, `-1`, the
+    /// type's `MIN` and `MAX`, and the ends of the range (and their neighbours),
+    /// restricted to the values in range. Otherwise values are uniform, as with
+    /// the default bias of `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not between `0.0` and `1.0`.
+    pub fn with_edge_bias(mut self, p: f64) -> Self {
+        self.edge_bias = checked_edge_bias(p);
+        self
     }
 }
 
+/// Validate an edge bias probability
+fn checked_edge_bias(p: f64) -> f64 {
+    assert!(
+        (0.0..=1.0).contains(&p),
+        "edge bias must be a probability between 0.0 and 1.0, got {}",
+        p
+    );
+    p
+}
+
 macro_rules! impl_int_generator {
     ($($t:ty),*) => {
         $(
             impl Generator<$t> for IntGenerator<$t> {
-                fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> $t {
+                fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> $t {
                     use rand::Rng;
-                    rng.r#gen_range(self.min..=self.max)
+                    let (min, max) = self.effective_range(config);
+                    if self.edge_bias > 0.0 && rng.gen_bool(self.edge_bias) {
+                        let edges = Self::edge_values(min, max);
+                        return edges[rng.gen_range(0..edges.len())];
+                    }
+                    rng.r#gen_range(min..=max)
                 }
 
                 fn shrink(&self, value: &$t) -> Box<dyn Iterator<Item = $t>> {
@@ -89,6 +137,53 @@ macro_rules! impl_int_generator {
                 pub fn full_range() -> Self {
                     Self::new(<$t>::MIN, <$t>::MAX)
                 }
+
+                /// Boundary values within `min..=max`, never empty
+                fn edge_values(min: $t, max: $t) -> Vec<$t> {
+                    let zero: $t = 0;
+                    let candidates = [
+                        Some(zero),
+                        Some(1),
+                        zero.checked_sub(1),
+                        Some(<$t>::MIN),
+                        Some(<$t>::MAX),
+                        Some(min),
+                        min.checked_add(1),
+                        Some(max),
+                        max.checked_sub(1),
+                    ];
+
+                    let mut edges: Vec<$t> = candidates
+                        .into_iter()
+                        .flatten()
+                        .filter(|value| (min..=max).contains(value))
+                        .collect();
+                    edges.sort_unstable();
+                    edges.dedup();
+                    edges
+                }
+
+                /// Intersect this generator's range with the config's custom range
+                fn effective_range(&self, config: &GeneratorConfig) -> ($t, $t) {
+                    match config.custom_range::<$t>() {
+                        None => (self.min, self.max),
+                        Some(range) => {
+                            let min = self.min.max(*range.start());
+                            let max = self.max.min(*range.end());
+                            if min > max {
+                                panic!(
+                                    "IntGenerator range {}..={} does not overlap the custom range {}..={} for {}",
+                                    self.min,
+                                    self.max,
+                                    range.start(),
+                                    range.end(),
+                                    stringify!($t)
+                                );
+                            }
+                            (min, max)
+                        }
+                    }
+                }
             }
         )*
     };
@@ -98,11 +193,20 @@ impl_int_generator!(
     i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
 );
 
+/// Probability that a generator with special values enabled emits one
+const SPECIAL_FLOAT_PROBABILITY: f64 = 0.1;
+
 /// Generator for floating-point types
+///
+/// By default values are drawn uniformly from the range, so `NaN`, the
+/// infinities, `-0.0` and subnormals are never produced. Use
+/// [`with_special_values`](Self::with_special_values) to include them.
 #[derive(Debug, Clone)]
 pub struct FloatGenerator<T> {
     min: T,
     max: T,
+    edge_bias: f64,
+    special_values: bool,
 }
 
 impl<T> FloatGenerator<T>
@@ -111,7 +215,38 @@ where
 {
     /// Create a new float generator with the specified range
     pub fn new(min: T, max: T) -> Self {
-        Self { min, max }
+        Self {
+            min,
+            max,
+            edge_bias: 0.0,
+            special_values: false,
+        }
+    }
+
+    /// Occasionally emit IEEE-754 special values
+    ///
+    /// When enabled, one in ten values is `NaN`, `INFINITY`, `NEG_INFINITY`,
+    /// `-0.0` or a subnormal, regardless of the range. `NaN`, the infinities
+    /// and `-0.0` shrink to `0.0`, or to the range end nearest to it.
+    pub fn with_special_values(mut self, enabled: bool) -> Self {
+        self.special_values = enabled;
+        self
+    }
+
+    /// Oversample special values
+    ///
+    /// With probability `p`, generation returns one of `0.0`, `-0.0`, `1.0`,
+    /// `-1.0`, `EPSILON`, the smallest normal and subnormal values, `MIN`, `MAX`
+    /// and the ends of the range, restricted to the values in range, or one of
+    /// `NaN`, `INFINITY` and `NEG_INFINITY`, which are picked regardless of the
+    /// range. Otherwise values are uniform, as with the default bias of `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not between `0.0` and `1.0`.
+    pub fn with_edge_bias(mut self, p: f64) -> Self {
+        self.edge_bias = checked_edge_bias(p);
+        self
     }
 }
 
@@ -121,13 +256,32 @@ macro_rules! impl_float_generator {
             impl Generator<$t> for FloatGenerator<$t> {
                 fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> $t {
                     use rand::Rng;
+                    if self.edge_bias > 0.0 && rng.gen_bool(self.edge_bias) {
+                        let edges = self.edge_values();
+                        return edges[rng.gen_range(0..edges.len())];
+                    }
+                    if self.special_values && rng.gen_bool(SPECIAL_FLOAT_PROBABILITY) {
+                        let specials = [
+                            <$t>::NAN,
+                            <$t>::INFINITY,
+                            <$t>::NEG_INFINITY,
+                            -0.0,
+                            <$t>::from_bits(1),
+                            <$t>::MIN_POSITIVE / 2.0,
+                        ];
+                        return specials[rng.gen_range(0..specials.len())];
+                    }
                     rng.r#gen_range(self.min..=self.max)
                 }
 
                 fn shrink(&self, value: &$t) -> Box<dyn Iterator<Item = $t>> {
                     let value = *value;
 
-                    if value == 0.0 || value.is_nan() || value.is_infinite() {
+                    if value.is_nan() || value.is_infinite() || (value == 0.0 && value.is_sign_negative()) {
+                        return Box::new(self.nearest_to_zero().into_iter());
+                    }
+
+                    if value == 0.0 {
                         return Box::new(std::iter::empty());
                     }
 
@@ -159,6 +313,43 @@ macro_rules! impl_float_generator {
                 pub fn reasonable_range() -> Self {
                     Self::new(-1000.0, 1000.0)
                 }
+
+                /// `0.0`, or the end of the range nearest to it if out of range
+                fn nearest_to_zero(&self) -> Option<$t> {
+                    if self.min <= 0.0 && 0.0 <= self.max {
+                        Some(0.0)
+                    } else if self.min > 0.0 {
+                        Some(self.min)
+                    } else if self.max < 0.0 {
+                        Some(self.max)
+                    } else {
+                        None
+                    }
+                }
+
+                /// Special values in range, plus the non-finite ones
+                fn edge_values(&self) -> Vec<$t> {
+                    let finite = [
+                        0.0,
+                        -0.0,
+                        1.0,
+                        -1.0,
+                        <$t>::EPSILON,
+                        <$t>::MIN_POSITIVE,
+                        <$t>::from_bits(1),
+                        <$t>::MIN,
+                        <$t>::MAX,
+                        self.min,
+                        self.max,
+                    ];
+
+                    let mut edges: Vec<$t> = finite
+                        .into_iter()
+                        .filter(|value| self.min <= *value && *value <= self.max)
+                        .collect();
+                    edges.extend([<$t>::NAN, <$t>::INFINITY, <$t>::NEG_INFINITY]);
+                    edges
+                }
             }
         )*
     };
@@ -166,37 +357,142 @@ macro_rules! impl_float_generator {
 
 impl_float_generator!(f32, f64);
 
+/// Relative frequencies of lowercase English letters and the space character
+///
+/// Letter percentages follow standard corpus counts; space is weighted to
+/// roughly one character in six, as in running text.
+const ENGLISH_LETTER_FREQUENCIES: [(char, f64); 27] = [
+    (' ', 18.0),
+    ('a', 8.2),
+    ('b', 1.5),
+    ('c', 2.8),
+    ('d', 4.3),
+    ('e', 12.7),
+    ('f', 2.2),
+    ('g', 2.0),
+    ('h', 6.1),
+    ('i', 7.0),
+    ('j', 0.15),
+    ('k', 0.77),
+    ('l', 4.0),
+    ('m', 2.4),
+    ('n', 6.7),
+    ('o', 7.5),
+    ('p', 1.9),
+    ('q', 0.095),
+    ('r', 6.0),
+    ('s', 6.3),
+    ('t', 9.1),
+    ('u', 2.8),
+    ('v', 0.98),
+    ('w', 2.4),
+    ('x', 0.15),
+    ('y', 2.0),
+    ('z', 0.074),
+];
+
 /// Generator for character values
+///
+/// Picks uniformly within its ranges by default. With
+/// [`with_frequencies`](Self::with_frequencies) or
+/// [`english_letter_frequency`](Self::english_letter_frequency) characters are
+/// drawn in proportion to their weights instead. Shrinking is the same either
+/// way: towards 'a', '0' or space, whichever can be generated.
 #[derive(Debug, Clone)]
 pub struct CharGenerator {
     /// Character ranges to generate from
     ranges: Vec<(char, char)>,
+    /// Characters with cumulative weights, used instead of `ranges` when non-empty
+    cumulative_weights: Vec<(char, f64)>,
 }
 
 impl CharGenerator {
     /// Create a new character generator with ASCII printable characters
     pub fn ascii_printable() -> Self {
-        Self {
-            ranges: vec![(' ', '~')],
-        }
+        Self::with_ranges(vec![(' ', '~')])
     }
 
     /// Create a new character generator with ASCII alphanumeric characters
     pub fn ascii_alphanumeric() -> Self {
-        Self {
-            ranges: vec![('0', '9'), ('A', 'Z'), ('a', 'z')],
-        }
+        Self::with_ranges(vec![('0', '9'), ('A', 'Z'), ('a', 'z')])
+    }
+
+    /// Create a character generator over every Unicode scalar value
+    ///
+    /// Draws uniformly from `'\0'..=char::MAX`, skipping the surrogates
+    /// `0xD800..=0xDFFF`, so astral-plane characters such as emoji appear in
+    /// proportion to their share of the code space. Shrinks towards 'a'.
+    pub fn any_scalar() -> Self {
+        Self::with_ranges(vec![('\0', char::MAX)])
     }
 
     /// Create a new character generator with custom ranges
     pub fn with_ranges(ranges: Vec<(char, char)>) -> Self {
-        Self { ranges }
+        Self {
+            ranges,
+            cumulative_weights: Vec::new(),
+        }
+    }
+
+    /// Create a character generator that draws each character in proportion to its weight
+    ///
+    /// Weights are relative and need not sum to 1. Characters with a zero,
+    /// negative or non-finite weight are never generated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protest::{CharGenerator, Generator, GeneratorConfig};
+    /// use rand::thread_rng;
+    /// use std::collections::HashMap;
+    ///
+    /// let generator = CharGenerator::with_frequencies(HashMap::from([('x', 9.0), ('y', 1.0)]));
+    /// let c = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+    /// assert!(c == 'x' || c == 'y');
+    /// ```
+    pub fn with_frequencies(frequencies: HashMap<char, f64>) -> Self {
+        let mut weighted: Vec<(char, f64)> = frequencies
+            .into_iter()
+            .filter(|(_, weight)| weight.is_finite() && *weight > 0.0)
+            .collect();
+        // Sort so a seeded RNG picks the same characters regardless of hash order
+        weighted.sort_by_key(|(c, _)| *c);
+
+        let mut total = 0.0;
+        let cumulative_weights = weighted
+            .iter()
+            .map(|(c, weight)| {
+                total += weight;
+                (*c, total)
+            })
+            .collect();
+
+        Self {
+            ranges: weighted.iter().map(|(c, _)| (*c, *c)).collect(),
+            cumulative_weights,
+        }
+    }
+
+    /// Create a character generator weighted by English letter frequencies
+    ///
+    /// Produces lowercase letters and spaces, with 'e', 't' and 'a' far more
+    /// common than 'q', 'x' or 'z', so generated text has a realistic entropy.
+    pub fn english_letter_frequency() -> Self {
+        Self::with_frequencies(ENGLISH_LETTER_FREQUENCIES.into_iter().collect())
     }
 }
 
 impl Generator<char> for CharGenerator {
     fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> char {
         use rand::Rng;
+        if let Some(&(_, total)) = self.cumulative_weights.last() {
+            let target = rng.r#gen_range(0.0..total);
+            let idx = self
+                .cumulative_weights
+                .partition_point(|(_, cumulative)| *cumulative <= target);
+            return self.cumulative_weights[idx.min(self.cumulative_weights.len() - 1)].0;
+        }
+
         if self.ranges.is_empty() {
             return 'a'; // Fallback
         }
@@ -205,11 +501,14 @@ impl Generator<char> for CharGenerator {
         let range_idx = rng.r#gen_range(0..self.ranges.len());
         let (start, end) = self.ranges[range_idx];
 
-        let start_code = start as u32;
-        let end_code = end as u32;
-        let code = rng.r#gen_range(start_code..=end_code);
-
-        char::from_u32(code).unwrap_or('a')
+        // Ranges may span the surrogates; redraw until the code is a valid char.
+        // Both ends are chars, so this terminates.
+        loop {
+            let code = rng.r#gen_range(start as u32..=end as u32);
+            if let Some(c) = char::from_u32(code) {
+                return c;
+            }
+        }
     }
 
     fn shrink(&self, value: &char) -> Box<dyn Iterator<Item = char>> {
@@ -252,6 +551,8 @@ pub struct StringGenerator {
     char_generator: CharGenerator,
     min_length: usize,
     max_length: usize,
+    prefix: String,
+    suffix: String,
 }
 
 impl StringGenerator {
@@ -261,6 +562,8 @@ impl StringGenerator {
             char_generator: CharGenerator::ascii_printable(),
             min_length,
             max_length,
+            prefix: String::new(),
+            suffix: String::new(),
         }
     }
 
@@ -270,6 +573,8 @@ impl StringGenerator {
             char_generator: CharGenerator::ascii_alphanumeric(),
             min_length,
             max_length,
+            prefix: String::new(),
+            suffix: String::new(),
         }
     }
 
@@ -283,52 +588,79 @@ impl StringGenerator {
             char_generator,
             min_length,
             max_length,
-        }
-    }
-}
-
-impl Generator<String> for StringGenerator {
-    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> String {
-        use rand::Rng;
-        let max_len = self.max_length.min(config.size_hint * 2);
-        let length = if self.min_length >= max_len {
-            self.min_length
-        } else {
-            rng.r#gen_range(self.min_length..=max_len)
-        };
-
-        (0..length)
-            .map(|_| self.char_generator.generate(rng, config))
-            .collect()
+            prefix: String::new(),
+            suffix: String::new(),
+        }
+    }
+
+    /// Start every generated string with fixed text
+    ///
+    /// The prefix counts towards the length bounds, so only the body between
+    /// prefix and suffix is random, and shrinking never touches the prefix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protest::{Generator, GeneratorConfig, StringGenerator};
+    /// use rand::thread_rng;
+    ///
+    /// let generator = StringGenerator::ascii_alphanumeric(7, 20)
+    ///     .with_prefix("SELECT ")
+    ///     .with_suffix(";");
+    /// let query = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+    /// assert!(query.starts_with("SELECT ") && query.ends_with(';'));
+    /// ```
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// End every generated string with fixed text
+    ///
+    /// Like [`with_prefix`](Self::with_prefix), the suffix counts towards the
+    /// length bounds and is preserved by shrinking.
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Length bounds for the random body, after subtracting the prefix and suffix
+    fn body_bounds(&self) -> (usize, usize) {
+        let affix_len = self.prefix.chars().count() + self.suffix.chars().count();
+        (
+            self.min_length.saturating_sub(affix_len),
+            self.max_length.saturating_sub(affix_len),
+        )
     }
 
-    fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
-        let value = value.clone();
+    /// Shrink candidates for the random body of a string
+    fn shrink_body(&self, value: &str, min_length: usize) -> Vec<String> {
+        let value = value.to_string();
         let mut shrinks = Vec::new();
 
         // 1. Try simple common strings first (if they meet min_length requirement)
         let simple_strings = ["", "a", "A", "0", "1", "test", "foo", "x"];
         for simple in simple_strings {
-            if simple.len() >= self.min_length && simple.len() <= value.len() && simple != value {
+            if simple.len() >= min_length && simple.len() <= value.len() && simple != value {
                 shrinks.push(simple.to_string());
             }
         }
 
         // 2. Shrink towards empty string (only if min_length allows it)
-        if self.min_length == 0 && !value.is_empty() && !shrinks.contains(&"".to_string()) {
+        if min_length == 0 && !value.is_empty() && !shrinks.contains(&"".to_string()) {
             shrinks.push("".to_string());
         }
 
         // 3. Shrink by removing characters from the end
-        if value.len() > self.min_length {
+        if value.len() > min_length {
             // Try removing half the string
             let half = value.len() / 2;
-            if half >= self.min_length {
+            if half >= min_length {
                 shrinks.push(value.chars().take(half).collect());
             }
 
             // Remove from the end progressively
-            for i in (self.min_length..value.len()).rev().step_by(2) {
+            for i in (min_length..value.len()).rev().step_by(2) {
                 shrinks.push(value.chars().take(i).collect());
             }
         }
@@ -372,16 +704,75 @@ impl Generator<String> for StringGenerator {
             }
         }
 
-        Box::new(shrinks.into_iter())
+        shrinks
+    }
+}
+
+impl Generator<String> for StringGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> String {
+        use rand::Rng;
+        let (min_length, max_length) = self.body_bounds();
+        let max_len = max_length.min(config.size_hint * 2);
+        let length = if min_length >= max_len {
+            min_length
+        } else {
+            rng.r#gen_range(min_length..=max_len)
+        };
+
+        let mut value = self.prefix.clone();
+        value.extend((0..length).map(|_| self.char_generator.generate(rng, config)));
+        value.push_str(&self.suffix);
+        value
+    }
+
+    fn shrink(&self, value: &String) -> Box<dyn Iterator<Item = String>> {
+        // Only the body between prefix and suffix shrinks
+        let Some(body) = value
+            .strip_prefix(self.prefix.as_str())
+            .and_then(|rest| rest.strip_suffix(self.suffix.as_str()))
+        else {
+            return Box::new(std::iter::empty());
+        };
+
+        let (min_length, _) = self.body_bounds();
+        let prefix = self.prefix.clone();
+        let suffix = self.suffix.clone();
+        Box::new(
+            self.shrink_body(body, min_length)
+                .into_iter()
+                .map(move |shrunk| format!("{}{}{}", prefix, shrunk, suffix)),
+        )
     }
 }
 
+/// Largest collection size allowed for a size hint scaled by `factor`
+fn scaled_max_size(max_size: usize, size_hint: usize, factor: f64) -> usize {
+    max_size.min((size_hint as f64 * factor) as usize)
+}
+
+/// Panic unless `factor` can scale a size hint
+fn checked_size_factor(factor: f64) -> f64 {
+    assert!(
+        factor.is_finite() && factor >= 0.0,
+        "size factor must be finite and non-negative, got {}",
+        factor
+    );
+    factor
+}
+
 /// Generator for `Vec<T>` collections
+///
+/// Lengths are drawn between `min_length` and the smaller of `max_length` and
+/// the config's `size_hint` times a size factor (1 by default), so vectors
+/// grow along with the size hint over a run. Use
+/// [`with_size_factor`](Self::with_size_factor) or [`scaled`](Self::scaled)
+/// to change how fast.
 #[derive(Debug)]
 pub struct VecGenerator<T, G> {
     element_generator: G,
     min_length: usize,
     max_length: usize,
+    size_factor: f64,
     _phantom: std::marker::PhantomData<T>,
 }
 
@@ -395,9 +786,44 @@ where
             element_generator,
             min_length,
             max_length,
+            size_factor: 1.0,
             _phantom: std::marker::PhantomData,
         }
     }
+
+    /// Create a vector generator bounded only by the scaled size hint
+    ///
+    /// The maximum length is `size_hint * factor`, so small inputs early in a
+    /// run grow into large ones as the size hint increases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protest::{Generator, GeneratorConfig, IntGenerator, VecGenerator};
+    /// use rand::thread_rng;
+    ///
+    /// let generator = VecGenerator::scaled(IntGenerator::new(0, 9), 1, 4.0);
+    /// let config = GeneratorConfig { size_hint: 5, ..GeneratorConfig::default() };
+    /// let values = generator.generate(&mut thread_rng(), &config);
+    /// assert!(!values.is_empty() && values.len() <= 20);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is negative or not finite.
+    pub fn scaled(element_generator: G, min_length: usize, factor: f64) -> Self {
+        Self::new(element_generator, min_length, usize::MAX).with_size_factor(factor)
+    }
+
+    /// Scale the size hint by `factor` when capping the length
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is negative or not finite.
+    pub fn with_size_factor(mut self, factor: f64) -> Self {
+        self.size_factor = checked_size_factor(factor);
+        self
+    }
 }
 
 impl<T, G> Generator<Vec<T>> for VecGenerator<T, G>
@@ -407,7 +833,7 @@ where
 {
     fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> Vec<T> {
         use rand::Rng;
-        let max_len = self.max_length.min(config.size_hint);
+        let max_len = scaled_max_size(self.max_length, config.size_hint, self.size_factor);
         let length = if self.min_length >= max_len {
             self.min_length
         } else {
@@ -450,12 +876,16 @@ where
 }
 
 /// Generator for `HashMap<K, V>` collections
+///
+/// Sizes are capped by the config's `size_hint` times a size factor, like
+/// [`VecGenerator`].
 #[derive(Debug)]
 pub struct HashMapGenerator<K, V, KG, VG> {
     key_generator: KG,
     value_generator: VG,
     min_size: usize,
     max_size: usize,
+    size_factor: f64,
     _phantom: std::marker::PhantomData<(K, V)>,
 }
 
@@ -471,9 +901,29 @@ where
             value_generator,
             min_size,
             max_size,
+            size_factor: 1.0,
             _phantom: std::marker::PhantomData,
         }
     }
+
+    /// Create a HashMap generator bounded only by the scaled size hint
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is negative or not finite.
+    pub fn scaled(key_generator: KG, value_generator: VG, min_size: usize, factor: f64) -> Self {
+        Self::new(key_generator, value_generator, min_size, usize::MAX).with_size_factor(factor)
+    }
+
+    /// Scale the size hint by `factor` when capping the size
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is negative or not finite.
+    pub fn with_size_factor(mut self, factor: f64) -> Self {
+        self.size_factor = checked_size_factor(factor);
+        self
+    }
 }
 
 impl<K, V, KG, VG> Generator<HashMap<K, V>> for HashMapGenerator<K, V, KG, VG>
@@ -485,7 +935,7 @@ where
 {
     fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> HashMap<K, V> {
         use rand::Rng;
-        let max_size = self.max_size.min(config.size_hint);
+        let max_size = scaled_max_size(self.max_size, config.size_hint, self.size_factor);
         let target_size = if self.min_size >= max_size {
             self.min_size
         } else {
@@ -598,6 +1048,42 @@ mod tests {
         assert!(shrinks.iter().all(|&x| (1..5).contains(&x)));
     }
 
+    #[test]
+    fn test_int_generator_custom_range() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default()
+            .with_custom_range(5i32..=50)
+            .with_custom_range(0u8..=3);
+
+        // Clamped to the intersection of both ranges
+        let generator = IntGenerator::new(1i32, 10);
+        for _ in 0..50 {
+            let value = generator.generate(&mut rng, &config);
+            assert!((5..=10).contains(&value));
+        }
+
+        // Full-range generators take the custom range as-is
+        let generator = IntGenerator::<u8>::full_range();
+        for _ in 0..50 {
+            assert!(generator.generate(&mut rng, &config) <= 3);
+        }
+
+        // Other types are unaffected
+        let generator = IntGenerator::new(100i64, 200);
+        for _ in 0..50 {
+            let value = generator.generate(&mut rng, &config);
+            assert!((100..=200).contains(&value));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "does not overlap the custom range")]
+    fn test_int_generator_disjoint_custom_range() {
+        let config = GeneratorConfig::default().with_custom_range(20i32..=30);
+        let generator = IntGenerator::new(1i32, 10);
+        generator.generate(&mut thread_rng(), &config);
+    }
+
     #[test]
     fn test_float_generator() {
         let generator = FloatGenerator::new(0.0, 1.0);
@@ -615,6 +1101,115 @@ mod tests {
         assert!(!shrinks.is_empty());
     }
 
+    #[test]
+    fn test_int_generator_edge_bias() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let generator = IntGenerator::new(-5i32, 100).with_edge_bias(1.0);
+        for _ in 0..100 {
+            let value = generator.generate(&mut rng, &config);
+            assert!([-5, -4, -1, 0, 1, 99, 100].contains(&value));
+        }
+
+        // Unsigned types have no -1, and the custom range still applies
+        let config = config.with_custom_range(10u8..=20);
+        let generator = IntGenerator::<u8>::full_range().with_edge_bias(1.0);
+        for _ in 0..100 {
+            let value = generator.generate(&mut rng, &config);
+            assert!([10, 11, 19, 20].contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_float_generator_edge_bias() {
+        let generator = FloatGenerator::new(0.0f64, 10.0).with_edge_bias(1.0);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let mut seen_nan = false;
+        for _ in 0..500 {
+            let value = generator.generate(&mut rng, &config);
+            seen_nan |= value.is_nan();
+            assert!(value.is_nan() || value.is_infinite() || (0.0..=10.0).contains(&value));
+        }
+        assert!(seen_nan);
+
+        // Zero bias stays uniform within the range
+        let generator = FloatGenerator::new(0.0f32, 10.0).with_edge_bias(0.0);
+        for _ in 0..100 {
+            assert!((0.0..=10.0).contains(&generator.generate(&mut rng, &config)));
+        }
+    }
+
+    #[test]
+    fn test_float_generator_special_values() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        // Excluded by default
+        let generator = FloatGenerator::new(-10.0f64, 10.0);
+        for _ in 0..500 {
+            let value = generator.generate(&mut rng, &config);
+            assert!(value.is_normal() || value.to_bits() == 0.0f64.to_bits());
+        }
+
+        let generator = generator.with_special_values(true);
+        let (mut seen_nan, mut seen_inf, mut seen_subnormal) = (false, false, false);
+        for _ in 0..2000 {
+            let value = generator.generate(&mut rng, &config);
+            seen_nan |= value.is_nan();
+            seen_inf |= value.is_infinite();
+            seen_subnormal |= value.is_subnormal();
+        }
+        assert!(seen_nan && seen_inf && seen_subnormal);
+
+        // Non-finite values and -0.0 shrink to zero
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -0.0] {
+            let shrinks: Vec<f64> = generator.shrink(&value).collect();
+            assert_eq!(shrinks.len(), 1);
+            assert_eq!(shrinks[0].to_bits(), 0.0f64.to_bits());
+        }
+        assert_eq!(generator.shrink(&0.0).count(), 0);
+
+        // Or to the range end nearest to zero
+        let generator = FloatGenerator::new(5.0f32, 10.0).with_special_values(true);
+        assert_eq!(generator.shrink(&f32::NAN).collect::<Vec<_>>(), vec![5.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "edge bias must be a probability")]
+    fn test_edge_bias_out_of_range() {
+        IntGenerator::new(0i32, 10).with_edge_bias(1.5);
+    }
+
+    #[test]
+    fn test_char_generator_any_scalar() {
+        let generator = CharGenerator::any_scalar();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        // Most of the code space lies beyond the BMP
+        let astral = (0..200)
+            .filter(|_| generator.generate(&mut rng, &config) as u32 > 0xFFFF)
+            .count();
+        assert!(astral > 100);
+
+        assert_eq!(generator.shrink(&'😀').next(), Some('a'));
+    }
+
+    #[test]
+    fn test_char_generator_range_spanning_surrogates() {
+        let generator = CharGenerator::with_ranges(vec![('\u{D7FF}', '\u{E000}')]);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..50 {
+            let value = generator.generate(&mut rng, &config);
+            assert!(value == '\u{D7FF}' || value == '\u{E000}');
+        }
+    }
+
     #[test]
     fn test_char_generator() {
         let generator = CharGenerator::ascii_alphanumeric();
@@ -628,6 +1223,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_char_generator_with_frequencies() {
+        let generator =
+            CharGenerator::with_frequencies(HashMap::from([('x', 9.0), ('y', 1.0), ('z', 0.0)]));
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let mut x_count = 0;
+        for _ in 0..1000 {
+            let value = generator.generate(&mut rng, &config);
+            assert!(value == 'x' || value == 'y');
+            if value == 'x' {
+                x_count += 1;
+            }
+        }
+        assert!(x_count > 800, "'x' should dominate, got {}", x_count);
+    }
+
+    #[test]
+    fn test_char_generator_english_letter_frequency() {
+        let generator = CharGenerator::english_letter_frequency();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let sample: Vec<char> = (0..5000)
+            .map(|_| generator.generate(&mut rng, &config))
+            .collect();
+        assert!(sample.iter().all(|c| *c == ' ' || c.is_ascii_lowercase()));
+        let count = |target: char| sample.iter().filter(|c| **c == target).count();
+        assert!(count('e') > count('z') * 10);
+
+        // Shrinking still heads for 'a' and space
+        let shrinks: Vec<char> = generator.shrink(&'q').collect();
+        assert_eq!(shrinks, vec!['a', ' ']);
+    }
+
     #[test]
     fn test_string_generator() {
         let generator = StringGenerator::ascii_alphanumeric(2, 10);
@@ -647,6 +1278,34 @@ mod tests {
         assert!(!shrinks.is_empty());
     }
 
+    #[test]
+    fn test_string_generator_prefix_suffix() {
+        let generator = StringGenerator::ascii_alphanumeric(9, 20)
+            .with_prefix("SELECT ")
+            .with_suffix(";");
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let value = generator.generate(&mut rng, &config);
+            assert!(value.starts_with("SELECT ") && value.ends_with(';'));
+            assert!(value.len() >= 9 && value.len() <= 20);
+        }
+
+        // Only the body shrinks; the body must keep at least one character
+        let shrinks: Vec<_> = generator.shrink(&"SELECT Foo;".to_string()).collect();
+        assert!(!shrinks.is_empty());
+        assert!(
+            shrinks
+                .iter()
+                .all(|s| s.starts_with("SELECT ") && s.ends_with(';') && s.len() >= 9)
+        );
+        assert!(shrinks.contains(&"SELECT a;".to_string()));
+
+        // Values without the affixes are left alone
+        assert_eq!(generator.shrink(&"DROP x;".to_string()).count(), 0);
+    }
+
     #[test]
     fn test_vec_generator() {
         let element_gen = IntGenerator::new(1, 100);
@@ -682,6 +1341,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_collection_size_factor() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig {
+            size_hint: 4,
+            ..GeneratorConfig::default()
+        };
+
+        // The explicit maximum still applies on top of the scaled size hint
+        let generator = VecGenerator::new(IntGenerator::new(0, 9), 0, 10).with_size_factor(3.0);
+        let scaled = VecGenerator::scaled(IntGenerator::new(0, 9), 16, 4.0);
+        let half = HashMapGenerator::scaled(IntGenerator::new(0, 1000), BoolGenerator, 0, 0.5);
+        for _ in 0..50 {
+            assert!(generator.generate(&mut rng, &config).len() <= 10);
+            assert_eq!(scaled.generate(&mut rng, &config).len(), 16);
+            assert!(half.generate(&mut rng, &config).len() <= 2);
+        }
+
+        // Without a maximum, length follows the size hint as it grows
+        let generator = VecGenerator::scaled(IntGenerator::new(0, 9), 0, 2.0);
+        let large = GeneratorConfig {
+            size_hint: 100,
+            ..GeneratorConfig::default()
+        };
+        assert!((0..50).any(|_| generator.generate(&mut rng, &large).len() > 100));
+    }
+
+    #[test]
+    #[should_panic(expected = "size factor must be finite and non-negative")]
+    fn test_negative_size_factor() {
+        VecGenerator::new(IntGenerator::new(0, 9), 0, 10).with_size_factor(-1.0);
+    }
+
     #[test]
     fn test_hashmap_structural_shrinking() {
         // Test that HashMap shrinks by removing entries
@@ -1025,175 +1717,146 @@ impl Generator<bool> for BoolStrategy {
     }
 }
 
-// Individual implementations for each integer type to avoid macro conflicts
+macro_rules! impl_arbitrary_int {
+    ($($t:ty => $strategy:ident),* $(,)?) => {
+        $(
+            impl Arbitrary for $t {
+                type Strategy = $strategy;
+                type Parameters = ($t, $t);
 
-impl Arbitrary for i32 {
-    type Strategy = I32Strategy;
-    type Parameters = (i32, i32);
+                fn arbitrary() -> Self::Strategy {
+                    $strategy::full_range()
+                }
 
-    fn arbitrary() -> Self::Strategy {
-        I32Strategy::full_range()
-    }
+                fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+                    $strategy::new(args.0, args.1)
+                }
+            }
 
-    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
-        I32Strategy::new(args.0, args.1)
-    }
-}
+            #[doc = concat!("Strategy for `", stringify!($t), "` values within an inclusive range")]
+            #[derive(Debug, Clone)]
+            pub struct $strategy {
+                generator: IntGenerator<$t>,
+            }
 
-#[derive(Debug, Clone)]
-pub struct I32Strategy {
-    generator: IntGenerator<i32>,
-}
+            impl $strategy {
+                pub fn new(min: $t, max: $t) -> Self {
+                    Self {
+                        generator: IntGenerator::new(min, max),
+                    }
+                }
 
-impl I32Strategy {
-    pub fn new(min: i32, max: i32) -> Self {
-        Self {
-            generator: IntGenerator::new(min, max),
-        }
-    }
+                pub fn full_range() -> Self {
+                    Self {
+                        generator: IntGenerator::<$t>::full_range(),
+                    }
+                }
+            }
 
-    pub fn full_range() -> Self {
-        Self {
-            generator: IntGenerator::<i32>::full_range(),
-        }
-    }
-}
+            impl Strategy for $strategy {
+                type Value = $t;
 
-impl Strategy for I32Strategy {
-    type Value = i32;
+                fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> $t {
+                    self.generator.generate(rng, config)
+                }
 
-    fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> i32 {
-        self.generator.generate(rng, config)
-    }
+                fn shrink(&self, value: &$t) -> Box<dyn Iterator<Item = $t>> {
+                    self.generator.shrink(value)
+                }
+            }
 
-    fn shrink(&self, value: &i32) -> Box<dyn Iterator<Item = i32>> {
-        self.generator.shrink(value)
-    }
+            impl Generator<$t> for $strategy {
+                fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> $t {
+                    self.generator.generate(rng, config)
+                }
+
+                fn shrink(&self, value: &$t) -> Box<dyn Iterator<Item = $t>> {
+                    self.generator.shrink(value)
+                }
+            }
+        )*
+    };
 }
 
-impl Generator<i32> for I32Strategy {
-    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> i32 {
-        self.generator.generate(rng, config)
-    }
-
-    fn shrink(&self, value: &i32) -> Box<dyn Iterator<Item = i32>> {
-        self.generator.shrink(value)
-    }
-}
-
-impl Arbitrary for u32 {
-    type Strategy = U32Strategy;
-    type Parameters = (u32, u32);
-
-    fn arbitrary() -> Self::Strategy {
-        U32Strategy::full_range()
-    }
-
-    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
-        U32Strategy::new(args.0, args.1)
-    }
-}
-
-#[derive(Debug, Clone)]
-pub struct U32Strategy {
-    generator: IntGenerator<u32>,
-}
-
-impl U32Strategy {
-    pub fn new(min: u32, max: u32) -> Self {
-        Self {
-            generator: IntGenerator::new(min, max),
-        }
-    }
-
-    pub fn full_range() -> Self {
-        Self {
-            generator: IntGenerator::<u32>::full_range(),
-        }
-    }
-}
-
-impl Strategy for U32Strategy {
-    type Value = u32;
-
-    fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> u32 {
-        self.generator.generate(rng, config)
-    }
-
-    fn shrink(&self, value: &u32) -> Box<dyn Iterator<Item = u32>> {
-        self.generator.shrink(value)
-    }
-}
-
-impl Generator<u32> for U32Strategy {
-    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> u32 {
-        self.generator.generate(rng, config)
-    }
-
-    fn shrink(&self, value: &u32) -> Box<dyn Iterator<Item = u32>> {
-        self.generator.shrink(value)
-    }
-}
-
-// Note: Only implementing i32 and u32 for now to avoid macro complexity
+impl_arbitrary_int!(
+    i8 => I8Strategy,
+    i16 => I16Strategy,
+    i32 => I32Strategy,
+    i64 => I64Strategy,
+    i128 => I128Strategy,
+    isize => IsizeStrategy,
+    u8 => U8Strategy,
+    u16 => U16Strategy,
+    u32 => U32Strategy,
+    u64 => U64Strategy,
+    u128 => U128Strategy,
+    usize => UsizeStrategy,
+);
 
 // Float implementations
 
-impl Arbitrary for f64 {
-    type Strategy = F64Strategy;
-    type Parameters = (f64, f64);
+macro_rules! impl_arbitrary_float {
+    ($($t:ty => $strategy:ident),* $(,)?) => {
+        $(
+            impl Arbitrary for $t {
+                type Strategy = $strategy;
+                type Parameters = ($t, $t);
 
-    fn arbitrary() -> Self::Strategy {
-        F64Strategy::reasonable_range()
-    }
+                fn arbitrary() -> Self::Strategy {
+                    $strategy::reasonable_range()
+                }
 
-    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
-        F64Strategy::new(args.0, args.1)
-    }
-}
+                fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+                    $strategy::new(args.0, args.1)
+                }
+            }
 
-#[derive(Debug, Clone)]
-pub struct F64Strategy {
-    generator: FloatGenerator<f64>,
-}
+            #[doc = concat!("Strategy for `", stringify!($t), "` values within an inclusive range")]
+            #[derive(Debug, Clone)]
+            pub struct $strategy {
+                generator: FloatGenerator<$t>,
+            }
 
-impl F64Strategy {
-    pub fn new(min: f64, max: f64) -> Self {
-        Self {
-            generator: FloatGenerator::new(min, max),
-        }
-    }
+            impl $strategy {
+                pub fn new(min: $t, max: $t) -> Self {
+                    Self {
+                        generator: FloatGenerator::new(min, max),
+                    }
+                }
 
-    pub fn reasonable_range() -> Self {
-        Self {
-            generator: FloatGenerator::<f64>::reasonable_range(),
-        }
-    }
-}
+                pub fn reasonable_range() -> Self {
+                    Self {
+                        generator: FloatGenerator::<$t>::reasonable_range(),
+                    }
+                }
+            }
 
-impl Strategy for F64Strategy {
-    type Value = f64;
+            impl Strategy for $strategy {
+                type Value = $t;
 
-    fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> f64 {
-        self.generator.generate(rng, config)
-    }
+                fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> $t {
+                    self.generator.generate(rng, config)
+                }
 
-    fn shrink(&self, value: &f64) -> Box<dyn Iterator<Item = f64>> {
-        self.generator.shrink(value)
-    }
-}
+                fn shrink(&self, value: &$t) -> Box<dyn Iterator<Item = $t>> {
+                    self.generator.shrink(value)
+                }
+            }
 
-impl Generator<f64> for F64Strategy {
-    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> f64 {
-        self.generator.generate(rng, config)
-    }
+            impl Generator<$t> for $strategy {
+                fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> $t {
+                    self.generator.generate(rng, config)
+                }
 
-    fn shrink(&self, value: &f64) -> Box<dyn Iterator<Item = f64>> {
-        self.generator.shrink(value)
-    }
+                fn shrink(&self, value: &$t) -> Box<dyn Iterator<Item = $t>> {
+                    self.generator.shrink(value)
+                }
+            }
+        )*
+    };
 }
 
-// Note: Only implementing f64 for now
+impl_arbitrary_float!(f32 => F32Strategy, f64 => F64Strategy);
 
 impl Arbitrary for char {
     type Strategy = CharStrategy;
@@ -1324,12 +1987,16 @@ impl Generator<String> for StringStrategy {
 }
 
 // Vec implementation
+//
+// The default length range is `0..=config.size_hint`; `arbitrary_with` takes
+// explicit `(min_length, max_length)` bounds, with the maximum still capped by
+// the size hint.
 impl<T: Arbitrary + Clone + 'static> Arbitrary for Vec<T> {
     type Strategy = VecStrategy<T>;
     type Parameters = (usize, usize);
 
     fn arbitrary() -> Self::Strategy {
-        VecStrategy::new(0, 10)
+        VecStrategy::new(0, usize::MAX)
     }
 
     fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
@@ -1352,13 +2019,18 @@ impl<T> VecStrategy<T> {
             _phantom: std::marker::PhantomData,
         }
     }
+
+    /// Largest length to generate, never below `min_length`
+    fn max_length_for(&self, config: &GeneratorConfig) -> usize {
+        self.max_length.min(config.size_hint).max(self.min_length)
+    }
 }
 
 impl<T: Arbitrary + Clone + 'static> Strategy for VecStrategy<T> {
     type Value = Vec<T>;
 
     fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> Vec<T> {
-        let length = rng.gen_range(self.min_length..=self.max_length.min(config.size_hint));
+        let length = rng.gen_range(self.min_length..=self.max_length_for(config));
         (0..length)
             .map(|_| {
                 let strategy = T::arbitrary();
@@ -1390,17 +2062,8 @@ impl<T: Arbitrary + Clone + 'static> Strategy for VecStrategy<T> {
 
 impl<T: Arbitrary + Clone + 'static> Generator<Vec<T>> for VecStrategy<T> {
     fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> Vec<T> {
-        use rand::Rng;
-        let length = rng.gen_range(self.min_length..=self.max_length.min(config.size_hint));
-        (0..length)
-            .map(|_| {
-                let strategy = T::arbitrary();
-                // We need to create a concrete RNG type to call strategy.generate
-                // For now, let's use a simple approach with thread_rng
-                let mut thread_rng = rand::thread_rng();
-                strategy.generate(&mut thread_rng, config)
-            })
-            .collect()
+        let mut rng = rng;
+        Strategy::generate(self, &mut rng, config)
     }
 
     fn shrink(&self, value: &Vec<T>) -> Box<dyn Iterator<Item = Vec<T>>> {
@@ -1494,14 +2157,9 @@ impl<A: Arbitrary + Clone + 'static, B: Arbitrary + Clone + 'static> Strategy
 impl<A: Arbitrary + Clone + 'static, B: Arbitrary + Clone + 'static> Generator<(A, B)>
     for TupleStrategy2<A, B>
 {
-    fn generate(&self, _rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> (A, B) {
-        let a_strategy = A::arbitrary();
-        let b_strategy = B::arbitrary();
-        // We need to create concrete RNG types to call strategy.generate
-        let mut thread_rng = rand::thread_rng();
-        let a_value = a_strategy.generate(&mut thread_rng, config);
-        let b_value = b_strategy.generate(&mut thread_rng, config);
-        (a_value, b_value)
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> (A, B) {
+        let mut rng = rng;
+        Strategy::generate(self, &mut rng, config)
     }
 
     fn shrink(&self, value: &(A, B)) -> Box<dyn Iterator<Item = (A, B)>> {
@@ -1607,16 +2265,9 @@ impl<A: Arbitrary + Clone + 'static, B: Arbitrary + Clone + 'static, C: Arbitrar
 impl<A: Arbitrary + Clone + 'static, B: Arbitrary + Clone + 'static, C: Arbitrary + Clone + 'static>
     Generator<(A, B, C)> for TupleStrategy3<A, B, C>
 {
-    fn generate(&self, _rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> (A, B, C) {
-        let a_strategy = A::arbitrary();
-        let b_strategy = B::arbitrary();
-        let c_strategy = C::arbitrary();
-        // We need to create concrete RNG types to call strategy.generate
-        let mut thread_rng = rand::thread_rng();
-        let a_value = a_strategy.generate(&mut thread_rng, config);
-        let b_value = b_strategy.generate(&mut thread_rng, config);
-        let c_value = c_strategy.generate(&mut thread_rng, config);
-        (a_value, b_value, c_value)
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> (A, B, C) {
+        let mut rng = rng;
+        Strategy::generate(self, &mut rng, config)
     }
 
     fn shrink(&self, value: &(A, B, C)) -> Box<dyn Iterator<Item = (A, B, C)>> {
@@ -1828,6 +2479,11 @@ where
 // ============================================================================
 
 /// Generator for `Option<T>`
+///
+/// Shrinking proposes `None` first, since it is structurally simpler than any
+/// `Some`, followed by `Some` of each shrink of the inner value. `None` is only
+/// proposed when it can be generated, i.e. when the probability of `Some` is
+/// below 1.0.
 pub struct OptionGenerator<T, G> {
     inner_gen: G,
     some_probability: f64,
@@ -1873,7 +2529,11 @@ where
         match value {
             None => Box::new(std::iter::empty()),
             Some(v) => {
-                let mut shrinks = vec![None]; // Always try None first
+                let mut shrinks = Vec::new();
+                // Try None first whenever it is a valid value
+                if self.some_probability < 1.0 {
+                    shrinks.push(None);
+                }
                 for shrunk in self.inner_gen.shrink(v) {
                     shrinks.push(Some(shrunk));
                 }
@@ -1883,176 +2543,715 @@ where
     }
 }
 
-// Tuple generator implementations to support property_test macro with multiple parameters
-impl<A, B, GA, GB> Generator<(A, B)> for (GA, GB)
+impl<T, S> Strategy for OptionGenerator<T, S>
 where
-    GA: Generator<A>,
-    GB: Generator<B>,
-    A: Clone + std::fmt::Debug + PartialEq + 'static,
-    B: Clone + std::fmt::Debug + PartialEq + 'static,
+    T: Clone + 'static,
+    S: Strategy<Value = T>,
 {
-    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> (A, B) {
-        (self.0.generate(rng, config), self.1.generate(rng, config))
-    }
-
-    fn shrink(&self, value: &(A, B)) -> Box<dyn Iterator<Item = (A, B)>> {
-        // Simple shrinking: shrink first element, then second
-        let mut shrinks = Vec::new();
+    type Value = Option<T>;
 
-        // Shrink first element
-        for a_shrunk in self.0.shrink(&value.0) {
-            shrinks.push((a_shrunk, value.1.clone()));
+    fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> Option<T> {
+        let random_value: f64 = rng.r#gen();
+        if random_value < self.some_probability {
+            Some(self.inner_gen.generate(rng, config))
+        } else {
+            None
         }
+    }
 
-        // Shrink second element
-        for b_shrunk in self.1.shrink(&value.1) {
-            shrinks.push((value.0.clone(), b_shrunk));
+    fn shrink(&self, value: &Option<T>) -> Box<dyn Iterator<Item = Option<T>>> {
+        match value {
+            None => Box::new(std::iter::empty()),
+            Some(v) => {
+                let mut shrinks = Vec::new();
+                if self.some_probability < 1.0 {
+                    shrinks.push(None);
+                }
+                for shrunk in self.inner_gen.shrink(v) {
+                    shrinks.push(Some(shrunk));
+                }
+                Box::new(shrinks.into_iter())
+            }
         }
-
-        Box::new(shrinks.into_iter())
     }
 }
 
-impl<A, B, C, GA, GB, GC> Generator<(A, B, C)> for (GA, GB, GC)
-where
-    GA: Generator<A>,
-    GB: Generator<B>,
-    GC: Generator<C>,
-    A: Clone + std::fmt::Debug + PartialEq + 'static,
-    B: Clone + std::fmt::Debug + PartialEq + 'static,
-    C: Clone + std::fmt::Debug + PartialEq + 'static,
-{
-    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> (A, B, C) {
-        (
-            self.0.generate(rng, config),
-            self.1.generate(rng, config),
-            self.2.generate(rng, config),
-        )
-    }
-
-    fn shrink(&self, value: &(A, B, C)) -> Box<dyn Iterator<Item = (A, B, C)>> {
-        let mut shrinks = Vec::new();
-
-        for a_shrunk in self.0.shrink(&value.0) {
-            shrinks.push((a_shrunk, value.1.clone(), value.2.clone()));
-        }
-
-        for b_shrunk in self.1.shrink(&value.1) {
-            shrinks.push((value.0.clone(), b_shrunk, value.2.clone()));
-        }
+/// `Option<T>` is `Some` half of the time; parameters are forwarded to the
+/// inner type, so range and length bounds apply to the `Some` payload.
+impl<T: Arbitrary + Clone + 'static> Arbitrary for Option<T> {
+    type Strategy = OptionGenerator<T, T::Strategy>;
+    type Parameters = T::Parameters;
 
-        for c_shrunk in self.2.shrink(&value.2) {
-            shrinks.push((value.0.clone(), value.1.clone(), c_shrunk));
-        }
+    fn arbitrary() -> Self::Strategy {
+        OptionGenerator::new(T::arbitrary())
+    }
 
-        Box::new(shrinks.into_iter())
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        OptionGenerator::new(T::arbitrary_with(args))
     }
 }
 
 // ============================================================================
-// HashSet Generator
+// Duration Generator
 // ============================================================================
 
-/// Generator for HashSet collections
+/// Generator for `std::time::Duration` values within a range
+///
+/// Values are uniform over the range at nanosecond resolution. Shrinking moves
+/// toward the lower bound by repeated halving, and also tries dropping the
+/// sub-second part.
 #[derive(Debug, Clone)]
-pub struct HashSetGenerator<T, G> {
-    element_generator: G,
-    min_size: usize,
-    max_size: usize,
-    _phantom: std::marker::PhantomData<T>,
+pub struct DurationGenerator {
+    min: Duration,
+    max: Duration,
 }
 
-impl<T, G> HashSetGenerator<T, G>
-where
-    T: std::hash::Hash + Eq + Clone,
-    G: Generator<T>,
-{
-    /// Create a new HashSet generator
-    pub fn new(element_generator: G, min_size: usize, max_size: usize) -> Self {
-        Self {
-            element_generator,
-            min_size,
-            max_size,
-            _phantom: std::marker::PhantomData,
-        }
+impl DurationGenerator {
+    /// Create a new duration generator with the given inclusive bounds
+    pub fn new(min: Duration, max: Duration) -> Self {
+        Self { min, max }
     }
-}
 
-impl<T, G> Generator<HashSet<T>> for HashSetGenerator<T, G>
-where
-    T: std::hash::Hash + Eq + Clone + 'static,
-    G: Generator<T> + Clone + 'static,
-{
-    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> HashSet<T> {
-        use rand::Rng;
-        let size = rng.r#gen_range(self.min_size..=self.max_size);
-        let mut set = HashSet::new();
+    /// Create a duration generator from zero up to `max`
+    pub fn up_to(max: Duration) -> Self {
+        Self::new(Duration::ZERO, max)
+    }
 
-        // Try to generate unique elements, but don't loop forever
-        let mut attempts = 0;
-        let max_attempts = size * 10;
+    fn from_nanos(nanos: u128) -> Duration {
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+        Duration::new(
+            (nanos / NANOS_PER_SEC) as u64,
+            (nanos % NANOS_PER_SEC) as u32,
+        )
+    }
+}
 
-        while set.len() < size && attempts < max_attempts {
-            let element = self.element_generator.generate(rng, config);
-            set.insert(element);
-            attempts += 1;
-        }
+impl Default for DurationGenerator {
+    /// Durations from zero up to one hour
+    fn default() -> Self {
+        Self::up_to(Duration::from_secs(60 * 60))
+    }
+}
 
-        set
+impl Generator<Duration> for DurationGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> Duration {
+        use rand::Rng;
+        Self::from_nanos(rng.r#gen_range(self.min.as_nanos()..=self.max.as_nanos()))
     }
 
-    fn shrink(&self, value: &HashSet<T>) -> Box<dyn Iterator<Item = HashSet<T>>> {
+    fn shrink(&self, value: &Duration) -> Box<dyn Iterator<Item = Duration>> {
+        let value = *value;
         let mut shrinks = Vec::new();
 
-        // Shrink to empty set
-        if !value.is_empty() {
-            shrinks.push(HashSet::new());
+        if value <= self.min {
+            return Box::new(shrinks.into_iter());
         }
 
-        // Shrink to smaller sets by removing elements
-        if value.len() > 1 {
-            for elem in value.iter().take(value.len() / 2) {
-                let mut smaller = value.clone();
-                smaller.remove(elem);
-                shrinks.push(smaller);
-            }
+        // Shrink towards the lower bound by halving the distance
+        let min = self.min.as_nanos();
+        let mut distance = value.as_nanos() - min;
+        shrinks.push(self.min);
+        while distance > 1 {
+            distance /= 2;
+            shrinks.push(Self::from_nanos(min + distance));
         }
 
-        // Shrink individual elements
-        for elem in value {
-            for shrunk_elem in self.element_generator.shrink(elem) {
-                let mut shrunk_set = value.clone();
-                shrunk_set.remove(elem);
-                shrunk_set.insert(shrunk_elem);
-                if shrunk_set != *value {
-                    shrinks.push(shrunk_set);
-                }
-            }
+        // Try dropping the sub-second part
+        let whole_secs = Duration::from_secs(value.as_secs());
+        if whole_secs > self.min && whole_secs < value && !shrinks.contains(&whole_secs) {
+            shrinks.push(whole_secs);
         }
 
         Box::new(shrinks.into_iter())
     }
 }
 
+impl Strategy for DurationGenerator {
+    type Value = Duration;
+
+    fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> Duration {
+        Generator::generate(self, rng, config)
+    }
+
+    fn shrink(&self, value: &Duration) -> Box<dyn Iterator<Item = Duration>> {
+        Generator::shrink(self, value)
+    }
+}
+
+impl Arbitrary for Duration {
+    type Strategy = DurationGenerator;
+    type Parameters = (Duration, Duration);
+
+    fn arbitrary() -> Self::Strategy {
+        DurationGenerator::default()
+    }
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        DurationGenerator::new(args.0, args.1)
+    }
+}
+
 // ============================================================================
-// BTreeMap Generator
+// Network Address Generators
 // ============================================================================
 
-/// Generator for BTreeMap collections
-#[derive(Debug, Clone)]
-pub struct BTreeMapGenerator<K, V, KG, VG> {
-    key_generator: KG,
-    value_generator: VG,
-    min_size: usize,
-    max_size: usize,
-    _phantom: std::marker::PhantomData<(K, V)>,
+/// Generator for `std::net::IpAddr` values
+///
+/// Addresses are uniform over the chosen families. Shrinking moves towards the
+/// unspecified address of the same family (`0.0.0.0` or `::`), then zeroes one
+/// octet or segment at a time. For address strings, see `IpAddressGenerator` in
+/// `protest-extras`.
+#[derive(Debug, Clone, Default)]
+pub enum IpAddrGenerator {
+    /// Generate IPv4 addresses
+    V4,
+    /// Generate IPv6 addresses
+    V6,
+    /// Generate both IPv4 and IPv6 addresses
+    #[default]
+    Both,
 }
 
-impl<K, V, KG, VG> BTreeMapGenerator<K, V, KG, VG>
-where
-    K: Ord + Clone,
-    V: Clone,
-    KG: Generator<K>,
+impl IpAddrGenerator {
+    /// Create a generator for IPv4 addresses only
+    pub fn ipv4() -> Self {
+        Self::V4
+    }
+
+    /// Create a generator for IPv6 addresses only
+    pub fn ipv6() -> Self {
+        Self::V6
+    }
+
+    /// Create a generator for both IPv4 and IPv6 addresses
+    pub fn both() -> Self {
+        Self::Both
+    }
+}
+
+impl Generator<IpAddr> for IpAddrGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, _config: &GeneratorConfig) -> IpAddr {
+        use rand::Rng;
+        let v4 = match self {
+            IpAddrGenerator::V4 => true,
+            IpAddrGenerator::V6 => false,
+            IpAddrGenerator::Both => rng.r#gen(),
+        };
+        if v4 {
+            IpAddr::V4(Ipv4Addr::from(rng.r#gen::<u32>()))
+        } else {
+            IpAddr::V6(Ipv6Addr::from(rng.r#gen::<u128>()))
+        }
+    }
+
+    fn shrink(&self, value: &IpAddr) -> Box<dyn Iterator<Item = IpAddr>> {
+        let shrinks: Vec<IpAddr> = match value {
+            IpAddr::V4(addr) => zero_parts(addr.octets())
+                .into_iter()
+                .map(|octets| IpAddr::V4(Ipv4Addr::from(octets)))
+                .collect(),
+            IpAddr::V6(addr) => zero_parts(addr.segments())
+                .into_iter()
+                .map(|segments| IpAddr::V6(Ipv6Addr::from(segments)))
+                .collect(),
+        };
+        Box::new(shrinks.into_iter())
+    }
+}
+
+/// All parts zeroed, then each non-zero part zeroed on its own
+fn zero_parts<T: Copy + Default + PartialEq, const N: usize>(parts: [T; N]) -> Vec<[T; N]> {
+    let zero = T::default();
+    let non_zero: Vec<usize> = (0..N).filter(|&i| parts[i] != zero).collect();
+    if non_zero.is_empty() {
+        return Vec::new();
+    }
+
+    let mut shrinks = vec![[zero; N]];
+    if non_zero.len() > 1 {
+        for i in non_zero {
+            let mut zeroed = parts;
+            zeroed[i] = zero;
+            shrinks.push(zeroed);
+        }
+    }
+    shrinks
+}
+
+impl Strategy for IpAddrGenerator {
+    type Value = IpAddr;
+
+    fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> IpAddr {
+        Generator::generate(self, rng, config)
+    }
+
+    fn shrink(&self, value: &IpAddr) -> Box<dyn Iterator<Item = IpAddr>> {
+        Generator::shrink(self, value)
+    }
+}
+
+impl Arbitrary for IpAddr {
+    type Strategy = IpAddrGenerator;
+    type Parameters = IpAddrGenerator;
+
+    fn arbitrary() -> Self::Strategy {
+        IpAddrGenerator::default()
+    }
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        args
+    }
+}
+
+/// Generator for `std::net::SocketAddr` values
+///
+/// Combines an [`IpAddrGenerator`] with a port range (all ports by default).
+/// Shrinking shrinks the address first, then moves the port towards the lower
+/// bound of the range.
+#[derive(Debug, Clone)]
+pub struct SocketAddrGenerator {
+    ip: IpAddrGenerator,
+    min_port: u16,
+    max_port: u16,
+}
+
+impl SocketAddrGenerator {
+    /// Create a socket address generator over all ports
+    pub fn new(ip: IpAddrGenerator) -> Self {
+        Self {
+            ip,
+            min_port: 0,
+            max_port: u16::MAX,
+        }
+    }
+
+    /// Restrict ports to an inclusive range
+    pub fn with_ports(mut self, min_port: u16, max_port: u16) -> Self {
+        self.min_port = min_port;
+        self.max_port = max_port;
+        self
+    }
+}
+
+impl Default for SocketAddrGenerator {
+    fn default() -> Self {
+        Self::new(IpAddrGenerator::default())
+    }
+}
+
+impl Generator<SocketAddr> for SocketAddrGenerator {
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> SocketAddr {
+        use rand::Rng;
+        let ip = Generator::generate(&self.ip, rng, config);
+        SocketAddr::new(ip, rng.r#gen_range(self.min_port..=self.max_port))
+    }
+
+    fn shrink(&self, value: &SocketAddr) -> Box<dyn Iterator<Item = SocketAddr>> {
+        let port = value.port();
+        let mut shrinks: Vec<SocketAddr> = Generator::shrink(&self.ip, &value.ip())
+            .map(|ip| SocketAddr::new(ip, port))
+            .collect();
+
+        if port > self.min_port {
+            shrinks.push(SocketAddr::new(value.ip(), self.min_port));
+            let half = self.min_port + (port - self.min_port) / 2;
+            if half > self.min_port {
+                shrinks.push(SocketAddr::new(value.ip(), half));
+            }
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
+impl Strategy for SocketAddrGenerator {
+    type Value = SocketAddr;
+
+    fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> SocketAddr {
+        Generator::generate(self, rng, config)
+    }
+
+    fn shrink(&self, value: &SocketAddr) -> Box<dyn Iterator<Item = SocketAddr>> {
+        Generator::shrink(self, value)
+    }
+}
+
+impl Arbitrary for SocketAddr {
+    type Strategy = SocketAddrGenerator;
+    type Parameters = (u16, u16);
+
+    fn arbitrary() -> Self::Strategy {
+        SocketAddrGenerator::default()
+    }
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        SocketAddrGenerator::default().with_ports(args.0, args.1)
+    }
+}
+
+// ============================================================================
+// Smart Pointer Generators
+// ============================================================================
+
+macro_rules! impl_pointer_generator {
+    ($($(#[$doc:meta])* $name:ident => $ptr:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone)]
+            pub struct $name<G> {
+                inner: G,
+            }
+
+            impl<G> $name<G> {
+                /// Wrap a generator (or strategy) for the pointee type
+                pub fn new(inner: G) -> Self {
+                    Self { inner }
+                }
+            }
+
+            impl<T, G> Generator<$ptr<T>> for $name<G>
+            where
+                T: 'static,
+                G: Generator<T>,
+            {
+                fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> $ptr<T> {
+                    $ptr::new(self.inner.generate(rng, &config.nested()))
+                }
+
+                fn shrink(&self, value: &$ptr<T>) -> Box<dyn Iterator<Item = $ptr<T>>> {
+                    Box::new(self.inner.shrink(value).map($ptr::new))
+                }
+            }
+
+            impl<S: Strategy> Strategy for $name<S> {
+                type Value = $ptr<S::Value>;
+
+                fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> Self::Value {
+                    $ptr::new(self.inner.generate(rng, &config.nested()))
+                }
+
+                fn shrink(&self, value: &Self::Value) -> Box<dyn Iterator<Item = Self::Value>> {
+                    Box::new(self.inner.shrink(value).map($ptr::new))
+                }
+            }
+
+            impl<T: Arbitrary + 'static> Arbitrary for $ptr<T> {
+                type Strategy = $name<T::Strategy>;
+                type Parameters = T::Parameters;
+
+                fn arbitrary() -> Self::Strategy {
+                    $name::new(T::arbitrary())
+                }
+
+                fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+                    $name::new(T::arbitrary_with(args))
+                }
+            }
+        )*
+    };
+}
+
+impl_pointer_generator!(
+    /// Generator for `Box<T>` that delegates to a generator for `T`
+    ///
+    /// The boxed value is generated one level deeper (see
+    /// [`GeneratorConfig::nested`]). Shrinking shrinks the boxed value and
+    /// re-boxes each candidate. Together with the `Arbitrary` impl for `Box<T>`
+    /// this allows deriving generators for recursive enums: once
+    /// `GeneratorConfig::max_depth` is used up, the derived generator only picks
+    /// variants that do not refer to the enum itself.
+    BoxGenerator => Box,
+    /// Generator for `Rc<T>` that delegates to a generator for `T`
+    ///
+    /// The shared value is generated one level deeper, like [`BoxGenerator`].
+    /// Shrinking shrinks the shared value and wraps each candidate in a new `Rc`.
+    RcGenerator => Rc,
+    /// Generator for `Arc<T>` that delegates to a generator for `T`
+    ///
+    /// The shared value is generated one level deeper, like [`BoxGenerator`].
+    /// Shrinking shrinks the shared value and wraps each candidate in a new `Arc`.
+    ArcGenerator => Arc,
+);
+
+/// Generator for `Cow<'static, str>` that delegates to a `String` generator
+///
+/// Values are always `Cow::Owned`; shrinking shrinks the string contents.
+#[derive(Debug, Clone)]
+pub struct CowStrGenerator<G> {
+    inner: G,
+}
+
+impl<G> CowStrGenerator<G> {
+    /// Wrap a `String` generator (or strategy)
+    pub fn new(inner: G) -> Self {
+        Self { inner }
+    }
+}
+
+impl<G: Generator<String>> Generator<Cow<'static, str>> for CowStrGenerator<G> {
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> Cow<'static, str> {
+        Cow::Owned(self.inner.generate(rng, config))
+    }
+
+    fn shrink(&self, value: &Cow<'static, str>) -> Box<dyn Iterator<Item = Cow<'static, str>>> {
+        Box::new(self.inner.shrink(&value.to_string()).map(Cow::Owned))
+    }
+}
+
+impl<S: Strategy<Value = String>> Strategy for CowStrGenerator<S> {
+    type Value = Cow<'static, str>;
+
+    fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> Self::Value {
+        Cow::Owned(self.inner.generate(rng, config))
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Box<dyn Iterator<Item = Self::Value>> {
+        Box::new(self.inner.shrink(&value.to_string()).map(Cow::Owned))
+    }
+}
+
+impl Arbitrary for Cow<'static, str> {
+    type Strategy = CowStrGenerator<StringStrategy>;
+    type Parameters = (usize, usize);
+
+    fn arbitrary() -> Self::Strategy {
+        CowStrGenerator::new(String::arbitrary())
+    }
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        CowStrGenerator::new(String::arbitrary_with(args))
+    }
+}
+
+// Tuple generator implementations to support property_test macro with multiple parameters
+impl<A, B, GA, GB> Generator<(A, B)> for (GA, GB)
+where
+    GA: Generator<A>,
+    GB: Generator<B>,
+    A: Clone + std::fmt::Debug + PartialEq + 'static,
+    B: Clone + std::fmt::Debug + PartialEq + 'static,
+{
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> (A, B) {
+        (self.0.generate(rng, config), self.1.generate(rng, config))
+    }
+
+    fn shrink(&self, value: &(A, B)) -> Box<dyn Iterator<Item = (A, B)>> {
+        // Simple shrinking: shrink first element, then second
+        let mut shrinks = Vec::new();
+
+        // Shrink first element
+        for a_shrunk in self.0.shrink(&value.0) {
+            shrinks.push((a_shrunk, value.1.clone()));
+        }
+
+        // Shrink second element
+        for b_shrunk in self.1.shrink(&value.1) {
+            shrinks.push((value.0.clone(), b_shrunk));
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
+impl<A, B, C, GA, GB, GC> Generator<(A, B, C)> for (GA, GB, GC)
+where
+    GA: Generator<A>,
+    GB: Generator<B>,
+    GC: Generator<C>,
+    A: Clone + std::fmt::Debug + PartialEq + 'static,
+    B: Clone + std::fmt::Debug + PartialEq + 'static,
+    C: Clone + std::fmt::Debug + PartialEq + 'static,
+{
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> (A, B, C) {
+        (
+            self.0.generate(rng, config),
+            self.1.generate(rng, config),
+            self.2.generate(rng, config),
+        )
+    }
+
+    fn shrink(&self, value: &(A, B, C)) -> Box<dyn Iterator<Item = (A, B, C)>> {
+        let mut shrinks = Vec::new();
+
+        for a_shrunk in self.0.shrink(&value.0) {
+            shrinks.push((a_shrunk, value.1.clone(), value.2.clone()));
+        }
+
+        for b_shrunk in self.1.shrink(&value.1) {
+            shrinks.push((value.0.clone(), b_shrunk, value.2.clone()));
+        }
+
+        for c_shrunk in self.2.shrink(&value.2) {
+            shrinks.push((value.0.clone(), value.1.clone(), c_shrunk));
+        }
+
+        Box::new(shrinks.into_iter())
+    }
+}
+
+// Larger tuples shrink one element at a time, in order, like the impls above
+macro_rules! impl_tuple_generator {
+    ($(($T:ident, $G:ident, $idx:tt)),+) => {
+        impl<$($T, $G),+> Generator<($($T,)+)> for ($($G,)+)
+        where
+            $(
+                $G: Generator<$T>,
+                $T: Clone + std::fmt::Debug + PartialEq + 'static,
+            )+
+        {
+            fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> ($($T,)+) {
+                ($(self.$idx.generate(rng, config),)+)
+            }
+
+            fn shrink(&self, value: &($($T,)+)) -> Box<dyn Iterator<Item = ($($T,)+)>> {
+                let mut shrinks = Vec::new();
+
+                $(
+                    for shrunk in self.$idx.shrink(&value.$idx) {
+                        let mut candidate = value.clone();
+                        candidate.$idx = shrunk;
+                        shrinks.push(candidate);
+                    }
+                )+
+
+                Box::new(shrinks.into_iter())
+            }
+        }
+    };
+}
+
+impl_tuple_generator!((A, GA, 0), (B, GB, 1), (C, GC, 2), (D, GD, 3));
+impl_tuple_generator!((A, GA, 0), (B, GB, 1), (C, GC, 2), (D, GD, 3), (E, GE, 4));
+impl_tuple_generator!(
+    (A, GA, 0),
+    (B, GB, 1),
+    (C, GC, 2),
+    (D, GD, 3),
+    (E, GE, 4),
+    (F, GF, 5)
+);
+impl_tuple_generator!(
+    (A, GA, 0),
+    (B, GB, 1),
+    (C, GC, 2),
+    (D, GD, 3),
+    (E, GE, 4),
+    (F, GF, 5),
+    (G, GG, 6)
+);
+impl_tuple_generator!(
+    (A, GA, 0),
+    (B, GB, 1),
+    (C, GC, 2),
+    (D, GD, 3),
+    (E, GE, 4),
+    (F, GF, 5),
+    (G, GG, 6),
+    (H, GH, 7)
+);
+
+// ============================================================================
+// HashSet Generator
+// ============================================================================
+
+/// Generator for `HashSet<T>` collections
+///
+/// Draws elements until the set reaches a target size between `min_size` and
+/// `max_size` (capped by the config's `size_hint`), giving up after
+/// `target_size * 10` draws if the element generator keeps producing
+/// duplicates, like [`HashMapGenerator`]. Shrinking follows the
+/// `Shrinkable for HashSet` order, shrinking elements with the element
+/// generator and never going below `min_size`.
+///
+/// # Example
+///
+/// ```
+/// use protest::{Generator, GeneratorConfig, HashSetGenerator, IntGenerator};
+/// use rand::thread_rng;
+///
+/// let generator = HashSetGenerator::new(IntGenerator::new(1, 100), 2, 10);
+/// let set = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert!(set.len() >= 2 && set.len() <= 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HashSetGenerator<T, G> {
+    element_generator: G,
+    min_size: usize,
+    max_size: usize,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T, G> HashSetGenerator<T, G>
+where
+    T: std::hash::Hash + Eq + Clone,
+    G: Generator<T>,
+{
+    /// Create a new HashSet generator
+    pub fn new(element_generator: G, min_size: usize, max_size: usize) -> Self {
+        Self {
+            element_generator,
+            min_size,
+            max_size,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, G> Generator<HashSet<T>> for HashSetGenerator<T, G>
+where
+    T: std::hash::Hash + Eq + Clone + 'static,
+    G: Generator<T>,
+{
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> HashSet<T> {
+        use rand::Rng;
+        let max_size = self.max_size.min(config.size_hint);
+        let target_size = if self.min_size >= max_size {
+            self.min_size
+        } else {
+            rng.r#gen_range(self.min_size..=max_size)
+        };
+
+        let mut set = HashSet::new();
+        let mut attempts = 0;
+
+        // Try to generate unique elements, but don't loop forever
+        while set.len() < target_size && attempts < target_size * 10 {
+            let element = self.element_generator.generate(rng, config);
+            set.insert(element);
+            attempts += 1;
+        }
+
+        set
+    }
+
+    fn shrink(&self, value: &HashSet<T>) -> Box<dyn Iterator<Item = HashSet<T>>> {
+        let min_size = self.min_size;
+        let shrinks =
+            crate::shrink::set_candidates(value, |elem| self.element_generator.shrink(elem));
+
+        Box::new(shrinks.into_iter().filter(move |set| set.len() >= min_size))
+    }
+}
+
+// ============================================================================
+// BTreeMap Generator
+// ============================================================================
+
+/// Generator for BTreeMap collections
+#[derive(Debug, Clone)]
+pub struct BTreeMapGenerator<K, V, KG, VG> {
+    key_generator: KG,
+    value_generator: VG,
+    min_size: usize,
+    max_size: usize,
+    _phantom: std::marker::PhantomData<(K, V)>,
+}
+
+impl<K, V, KG, VG> BTreeMapGenerator<K, V, KG, VG>
+where
+    K: Ord + Clone,
+    V: Clone,
+    KG: Generator<K>,
     VG: Generator<V>,
 {
     /// Create a new BTreeMap generator
@@ -2128,7 +3327,24 @@ where
 // BTreeSet Generator
 // ============================================================================
 
-/// Generator for BTreeSet collections
+/// Generator for `BTreeSet<T>` collections
+///
+/// Produces ordered sets of distinct elements, sized like [`HashSetGenerator`]:
+/// a target between `min_size` and `max_size` (capped by the config's
+/// `size_hint`), retrying duplicate draws up to `target_size * 10` times.
+/// Shrinking follows the `Shrinkable for BTreeSet` order, shrinking elements
+/// with the element generator and never going below `min_size`.
+///
+/// # Example
+///
+/// ```
+/// use protest::{BTreeSetGenerator, Generator, GeneratorConfig, IntGenerator};
+/// use rand::thread_rng;
+///
+/// let generator = BTreeSetGenerator::new(IntGenerator::new(1u64, 1000), 1, 20);
+/// let ids = generator.generate(&mut thread_rng(), &GeneratorConfig::default());
+/// assert!(!ids.is_empty() && ids.len() <= 20);
+/// ```
 #[derive(Debug, Clone)]
 pub struct BTreeSetGenerator<T, G> {
     element_generator: G,
@@ -2156,18 +3372,22 @@ where
 impl<T, G> Generator<BTreeSet<T>> for BTreeSetGenerator<T, G>
 where
     T: Ord + Clone + 'static,
-    G: Generator<T> + Clone + 'static,
+    G: Generator<T>,
 {
     fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> BTreeSet<T> {
         use rand::Rng;
-        let size = rng.r#gen_range(self.min_size..=self.max_size);
-        let mut set = BTreeSet::new();
+        let max_size = self.max_size.min(config.size_hint);
+        let target_size = if self.min_size >= max_size {
+            self.min_size
+        } else {
+            rng.r#gen_range(self.min_size..=max_size)
+        };
 
-        // Try to generate unique elements
+        let mut set = BTreeSet::new();
         let mut attempts = 0;
-        let max_attempts = size * 10;
 
-        while set.len() < size && attempts < max_attempts {
+        // Try to generate unique elements, but don't loop forever
+        while set.len() < target_size && attempts < target_size * 10 {
             let element = self.element_generator.generate(rng, config);
             set.insert(element);
             attempts += 1;
@@ -2177,36 +3397,11 @@ where
     }
 
     fn shrink(&self, value: &BTreeSet<T>) -> Box<dyn Iterator<Item = BTreeSet<T>>> {
-        let mut shrinks = Vec::new();
-
-        // Shrink to empty set
-        if !value.is_empty() {
-            shrinks.push(BTreeSet::new());
-        }
-
-        // Shrink to smaller sets
-        if value.len() > 1 {
-            let elems_to_remove: Vec<_> = value.iter().take(value.len() / 2).cloned().collect();
-            for elem in elems_to_remove {
-                let mut smaller = value.clone();
-                smaller.remove(&elem);
-                shrinks.push(smaller);
-            }
-        }
-
-        // Shrink individual elements
-        for elem in value {
-            for shrunk_elem in self.element_generator.shrink(elem) {
-                let mut shrunk_set = value.clone();
-                shrunk_set.remove(elem);
-                shrunk_set.insert(shrunk_elem.clone());
-                if shrunk_set != *value {
-                    shrinks.push(shrunk_set);
-                }
-            }
-        }
+        let min_size = self.min_size;
+        let shrinks =
+            crate::shrink::set_candidates(value, |elem| self.element_generator.shrink(elem));
 
-        Box::new(shrinks.into_iter())
+        Box::new(shrinks.into_iter().filter(move |set| set.len() >= min_size))
     }
 }
 
@@ -2215,11 +3410,20 @@ where
 // ============================================================================
 
 /// Generator for `Result<T, E>` values
+///
+/// Produces `Ok` with probability `ok_probability` and `Err` otherwise.
+/// Shrinking prefers `Ok` over `Err` by default (see
+/// [`prefer_err`](Self::prefer_err) for the reverse): a value of the
+/// non-preferred variant first shrinks to a fixed representative of the
+/// preferred one, drawn from its generator with a constant seed, and then to
+/// each shrink of its own payload. The preferred variant is only proposed when
+/// it can be generated, i.e. when its probability is above 0.0.
 #[derive(Debug, Clone)]
 pub struct ResultGenerator<T, E, TG, EG> {
     ok_generator: TG,
     err_generator: EG,
     ok_probability: f64,
+    prefer_ok: bool,
     _phantom: std::marker::PhantomData<(T, E)>,
 }
 
@@ -2232,12 +3436,7 @@ where
 {
     /// Create a new Result generator with 50/50 Ok/Err probability
     pub fn new(ok_generator: TG, err_generator: EG) -> Self {
-        Self {
-            ok_generator,
-            err_generator,
-            ok_probability: 0.5,
-            _phantom: std::marker::PhantomData,
-        }
+        Self::with_ok_probability(ok_generator, err_generator, 0.5)
     }
 
     /// Create a Result generator with custom Ok probability (0.0 to 1.0)
@@ -2246,17 +3445,35 @@ where
             ok_generator,
             err_generator,
             ok_probability: ok_probability.clamp(0.0, 1.0),
+            prefer_ok: true,
             _phantom: std::marker::PhantomData,
         }
     }
+
+    /// Shrink `Ok` values towards `Err` instead of the other way around
+    pub fn prefer_err(mut self) -> Self {
+        self.prefer_ok = false;
+        self
+    }
+}
+
+/// A fixed value from `generator`, used as the target when shrinking across variants
+fn representative<V, G: Generator<V>>(generator: &G) -> V {
+    use rand::SeedableRng;
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let config = GeneratorConfig {
+        size_hint: 0,
+        ..GeneratorConfig::default()
+    };
+    generator.generate(&mut rng, &config)
 }
 
 impl<T, E, TG, EG> Generator<Result<T, E>> for ResultGenerator<T, E, TG, EG>
 where
     T: Clone + 'static,
     E: Clone + 'static,
-    TG: Generator<T> + Clone + 'static,
-    EG: Generator<E> + Clone + 'static,
+    TG: Generator<T>,
+    EG: Generator<E>,
 {
     fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> Result<T, E> {
         use rand::Rng;
@@ -2268,21 +3485,24 @@ where
     }
 
     fn shrink(&self, value: &Result<T, E>) -> Box<dyn Iterator<Item = Result<T, E>>> {
+        let mut shrinks = Vec::new();
         match value {
             Ok(t) => {
-                let shrinks: Vec<_> = self.ok_generator.shrink(t).map(Ok).collect();
-                Box::new(shrinks.into_iter())
+                // Switch to Err first when that is the preferred variant
+                if !self.prefer_ok && self.ok_probability < 1.0 {
+                    shrinks.push(Err(representative(&self.err_generator)));
+                }
+                shrinks.extend(self.ok_generator.shrink(t).map(Ok));
             }
             Err(e) => {
-                // First try to shrink to Ok(default-ish value) if possible
-                let mut shrinks = vec![];
-
-                // Then shrink the error value
+                // Switch to Ok first when that is the preferred variant
+                if self.prefer_ok && self.ok_probability > 0.0 {
+                    shrinks.push(Ok(representative(&self.ok_generator)));
+                }
                 shrinks.extend(self.err_generator.shrink(e).map(Err));
-
-                Box::new(shrinks.into_iter())
             }
         }
+        Box::new(shrinks.into_iter())
     }
 }
 
@@ -2412,6 +3632,29 @@ mod new_generator_tests {
         }
     }
 
+    #[test]
+    fn test_btreeset_generator_bounds_and_shrinking() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+        let generator = BTreeSetGenerator::new(IntGenerator::new(1, 1000), 3, 6);
+
+        for _ in 0..20 {
+            let set = generator.generate(&mut rng, &config);
+            assert!(set.len() >= 3 && set.len() <= 6);
+        }
+
+        let set: BTreeSet<i32> = [100, 200, 300, 400].into_iter().collect();
+        let shrinks: Vec<_> = generator.shrink(&set).collect();
+        assert!(shrinks.iter().all(|s| s.len() >= 3));
+        assert!(shrinks.iter().any(|s| s.len() == 3));
+        assert!(
+            shrinks
+                .iter()
+                .any(|s| s.len() == 4 && !s.contains(&400) && s.contains(&100)),
+            "Should shrink individual elements"
+        );
+    }
+
     #[test]
     fn test_result_generator() {
         let mut rng = thread_rng();
@@ -2482,6 +3725,23 @@ mod new_generator_tests {
         assert!(shrinks.iter().any(|s| s.is_empty()));
     }
 
+    #[test]
+    fn test_hashset_generator_respects_min_size() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+        let generator = HashSetGenerator::new(IntGenerator::new(1, 100), 2, 10);
+
+        for _ in 0..20 {
+            let set = generator.generate(&mut rng, &config);
+            assert!(set.len() >= 2 && set.len() <= 10);
+        }
+
+        let set: HashSet<i32> = [10, 20, 30].into_iter().collect();
+        let shrinks: Vec<_> = generator.shrink(&set).collect();
+        assert!(!shrinks.is_empty());
+        assert!(shrinks.iter().all(|s| s.len() >= 2));
+    }
+
     #[test]
     fn test_result_shrinking() {
         let generator = ResultGenerator::new(
@@ -2495,4 +3755,332 @@ mod new_generator_tests {
         // Should shrink the Ok value towards 0
         assert!(!shrinks.is_empty());
     }
+
+    #[test]
+    fn test_result_shrinking_prefers_ok() {
+        let generator = ResultGenerator::new(
+            IntGenerator::new(0, 100),
+            StringGenerator::ascii_printable(0, 10),
+        );
+
+        let err_value: Result<i32, String> = Err("boom".to_string());
+        let shrinks: Vec<_> = generator.shrink(&err_value).collect();
+        assert!(matches!(shrinks.first(), Some(Ok(n)) if (0..=100).contains(n)));
+        assert!(shrinks[1..].iter().all(|s| s.is_err()));
+
+        // The representative is stable across calls
+        assert_eq!(shrinks[0], generator.shrink(&err_value).next().unwrap());
+
+        // Ok values only shrink their payload
+        assert!(generator.shrink(&Ok(50)).all(|s| s.is_ok()));
+
+        // Ok is never proposed when it cannot be generated
+        let errors_only = ResultGenerator::with_ok_probability(
+            IntGenerator::new(0, 100),
+            StringGenerator::ascii_printable(0, 10),
+            0.0,
+        );
+        assert!(errors_only.shrink(&err_value).all(|s| s.is_err()));
+    }
+
+    #[test]
+    fn test_result_shrinking_prefer_err() {
+        let generator = ResultGenerator::new(
+            IntGenerator::new(0, 100),
+            StringGenerator::ascii_printable(0, 10),
+        )
+        .prefer_err();
+
+        let shrinks: Vec<_> = generator.shrink(&Ok(50)).collect();
+        assert!(shrinks[0].is_err());
+        assert!(shrinks[1..].iter().all(|s| s.is_ok()));
+        assert!(
+            generator
+                .shrink(&Err("boom".to_string()))
+                .all(|s| s.is_err())
+        );
+    }
+
+    #[test]
+    fn test_option_shrinking_tries_none_first() {
+        let generator = OptionGenerator::new(IntGenerator::new(0, 100));
+
+        let shrinks: Vec<_> = generator.shrink(&Some(50)).collect();
+        assert_eq!(shrinks.first(), Some(&None));
+        assert!(shrinks[1..].iter().all(|s| s.is_some()));
+
+        // Nothing is simpler than None
+        assert_eq!(generator.shrink(&None).count(), 0);
+
+        // None is not proposed when it can never be generated
+        let always_some = OptionGenerator::with_probability(IntGenerator::new(0, 100), 1.0);
+        assert!(always_some.shrink(&Some(50)).all(|s| s.is_some()));
+    }
+
+    #[test]
+    fn test_option_shrinks_to_none_when_it_reproduces() {
+        let config = crate::TestConfig {
+            iterations: 20,
+            ..crate::TestConfig::default()
+        };
+
+        // Fails for every input, so the structurally simplest value should win
+        let result = crate::ergonomic::check_with_closure_config(
+            OptionGenerator::new(IntGenerator::new(0, 100)),
+            |_: Option<i32>| false,
+            config,
+        );
+        let failure = result.unwrap_err();
+        assert_eq!(failure.shrunk_input.unwrap_or(failure.original_input), None);
+    }
+
+    #[test]
+    fn test_pointer_generators() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let boxed = BoxGenerator::new(IntGenerator::new(1, 10));
+        let value: Box<i32> = boxed.generate(&mut rng, &config);
+        assert!((1..=10).contains(&*value));
+        let shrinks: Vec<Box<i32>> = boxed.shrink(&Box::new(8)).collect();
+        assert!(!shrinks.is_empty());
+        assert!(shrinks.iter().all(|s| **s < 8));
+
+        let rc = RcGenerator::new(IntGenerator::new(1, 10));
+        let shrinks: Vec<Rc<i32>> = rc.shrink(&Rc::new(8)).collect();
+        assert!(shrinks.iter().all(|s| **s < 8));
+
+        let arc = ArcGenerator::new(StringGenerator::ascii_printable(0, 5));
+        let value: Arc<String> = arc.generate(&mut rng, &config);
+        assert!(value.len() <= 5);
+    }
+
+    #[test]
+    fn test_pointer_arbitrary() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let strategy = <Box<i32> as Arbitrary>::arbitrary_with((0, 5));
+        let value = Strategy::generate(&strategy, &mut rng, &config);
+        assert!((0..=5).contains(&*value));
+
+        let strategy = <Arc<Vec<i32>> as Arbitrary>::arbitrary();
+        let value = Strategy::generate(&strategy, &mut rng, &config);
+        assert!(value.len() <= 10);
+
+        let strategy = <Cow<'static, str> as Arbitrary>::arbitrary_with((2, 4));
+        let value = Strategy::generate(&strategy, &mut rng, &config);
+        assert!((2..=4).contains(&value.len()));
+        let shrinks: Vec<_> = Strategy::shrink(&strategy, &value).collect();
+        assert!(shrinks.iter().all(|s| s.len() <= value.len()));
+    }
+
+    #[test]
+    fn test_option_arbitrary() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let strategy = <Option<i32> as Arbitrary>::arbitrary_with((1, 5));
+        let values: Vec<_> = (0..200)
+            .map(|_| Strategy::generate(&strategy, &mut rng, &config))
+            .collect();
+        assert!(values.iter().any(|v| v.is_none()));
+        assert!(values.iter().any(|v| v.is_some()));
+        assert!(values.iter().flatten().all(|v| (1..=5).contains(v)));
+
+        let shrinks: Vec<_> = Strategy::shrink(&strategy, &Some(4)).collect();
+        assert_eq!(shrinks.first(), Some(&None));
+        assert!(shrinks[1..].iter().all(|s| s.is_some()));
+    }
+
+    #[test]
+    fn test_vec_arbitrary_follows_size_hint() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig {
+            size_hint: 50,
+            ..GeneratorConfig::default()
+        };
+
+        let strategy = <Vec<u8> as Arbitrary>::arbitrary();
+        let max_len = (0..200)
+            .map(|_| Strategy::generate(&strategy, &mut rng, &config).len())
+            .max()
+            .unwrap();
+        assert!(max_len > 10 && max_len <= 50);
+
+        // A minimum above the size hint still generates valid lengths
+        let small = GeneratorConfig {
+            size_hint: 2,
+            ..GeneratorConfig::default()
+        };
+        let strategy = <Vec<u8> as Arbitrary>::arbitrary_with((5, 8));
+        let value = Strategy::generate(&strategy, &mut rng, &small);
+        assert_eq!(value.len(), 5);
+    }
+
+    #[test]
+    fn test_collection_generators_use_given_rng() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        fn sample<T, G: Generator<T>>(generator: &G, seed: u64) -> T {
+            let mut rng = StdRng::seed_from_u64(seed);
+            generator.generate(&mut rng, &GeneratorConfig::default())
+        }
+
+        let vec_gen = <Vec<i32> as Arbitrary>::arbitrary();
+        assert_eq!(sample(&vec_gen, 7), sample(&vec_gen, 7));
+
+        let pair_gen = <(i32, String) as Arbitrary>::arbitrary();
+        assert_eq!(sample(&pair_gen, 7), sample(&pair_gen, 7));
+
+        let triple_gen = <(u8, bool, Vec<u16>) as Arbitrary>::arbitrary();
+        assert_eq!(sample(&triple_gen, 7), sample(&triple_gen, 7));
+    }
+
+    #[test]
+    fn test_duration_generator() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let generator = DurationGenerator::new(Duration::from_millis(10), Duration::from_secs(2));
+        for _ in 0..50 {
+            let value: Duration = Generator::generate(&generator, &mut rng, &config);
+            assert!(value >= Duration::from_millis(10) && value <= Duration::from_secs(2));
+        }
+
+        let value = Duration::new(1, 500_000_000);
+        let shrinks: Vec<_> = Generator::shrink(&generator, &value).collect();
+        assert_eq!(shrinks[0], Duration::from_millis(10));
+        assert!(shrinks.contains(&Duration::from_secs(1)));
+        assert!(
+            shrinks
+                .iter()
+                .all(|s| *s >= Duration::from_millis(10) && *s < value)
+        );
+
+        assert_eq!(
+            Generator::shrink(&generator, &Duration::from_millis(10)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_duration_arbitrary() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let strategy = Duration::arbitrary();
+        let value = Strategy::generate(&strategy, &mut rng, &config);
+        assert!(value <= Duration::from_secs(3600));
+
+        let strategy = Duration::arbitrary_with((Duration::from_secs(1), Duration::from_secs(1)));
+        assert_eq!(
+            Strategy::generate(&strategy, &mut rng, &config),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn test_ip_addr_generator() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            assert!(Generator::generate(&IpAddrGenerator::ipv4(), &mut rng, &config).is_ipv4());
+            assert!(Generator::generate(&IpAddrGenerator::ipv6(), &mut rng, &config).is_ipv6());
+        }
+
+        let generator = IpAddrGenerator::both();
+        let value: IpAddr = "10.0.3.7".parse().unwrap();
+        let shrinks: Vec<IpAddr> = Generator::shrink(&generator, &value).collect();
+        assert_eq!(
+            shrinks,
+            ["0.0.0.0", "0.0.3.7", "10.0.0.7", "10.0.3.0"]
+                .iter()
+                .map(|s| s.parse::<IpAddr>().unwrap())
+                .collect::<Vec<_>>()
+        );
+
+        let value: IpAddr = "2001:db8::1".parse().unwrap();
+        let shrinks: Vec<IpAddr> = Generator::shrink(&generator, &value).collect();
+        assert_eq!(shrinks[0], "::".parse::<IpAddr>().unwrap());
+        assert!(shrinks.iter().all(|ip| ip.is_ipv6()));
+
+        let unspecified = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+        assert_eq!(Generator::shrink(&generator, &unspecified).count(), 0);
+    }
+
+    #[test]
+    fn test_socket_addr_generator() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let generator = SocketAddrGenerator::new(IpAddrGenerator::ipv4()).with_ports(1024, 2048);
+        for _ in 0..20 {
+            let value: SocketAddr = Generator::generate(&generator, &mut rng, &config);
+            assert!(value.is_ipv4() && (1024..=2048).contains(&value.port()));
+        }
+
+        let strategy = SocketAddr::arbitrary();
+        let value: SocketAddr = "1.2.3.4:8080".parse().unwrap();
+        let shrinks: Vec<SocketAddr> = Strategy::shrink(&strategy, &value).collect();
+        assert_eq!(shrinks[0], "0.0.0.0:8080".parse().unwrap());
+        assert!(shrinks.contains(&"1.2.3.4:0".parse().unwrap()));
+        assert!(shrinks.contains(&"1.2.3.4:4040".parse().unwrap()));
+
+        let minimal: SocketAddr = "0.0.0.0:0".parse().unwrap();
+        assert_eq!(Strategy::shrink(&strategy, &minimal).count(), 0);
+
+        let strategy = IpAddr::arbitrary_with(IpAddrGenerator::ipv6());
+        assert!(Strategy::generate(&strategy, &mut rng, &config).is_ipv6());
+    }
+
+    #[test]
+    fn test_numeric_arbitrary_with_bounds() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        macro_rules! check_bounds {
+            ($($t:ty => ($min:expr, $max:expr)),*) => {
+                $(
+                    let strategy = <$t as Arbitrary>::arbitrary_with(($min, $max));
+                    for _ in 0..20 {
+                        let value: $t = Strategy::generate(&strategy, &mut rng, &config);
+                        assert!(($min..=$max).contains(&value), "{} out of range", value);
+                    }
+                    let shrinks: Vec<$t> = Strategy::shrink(&strategy, &$max).collect();
+                    assert!(shrinks.iter().all(|s| ($min..=$max).contains(s)));
+                )*
+            };
+        }
+
+        check_bounds!(
+            i8 => (-5, 5),
+            i16 => (-300, 300),
+            i64 => (-1_000_000_000_000, 1_000_000_000_000),
+            i128 => (-1, 1),
+            isize => (-10, 10),
+            u8 => (200, 255),
+            u16 => (1, 1000),
+            u64 => (u64::MAX - 10, u64::MAX),
+            u128 => (0, 3),
+            usize => (5, 50),
+            f32 => (-1.5, 1.5)
+        );
+    }
+
+    #[test]
+    fn test_numeric_arbitrary_defaults() {
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        // Full-range integers must not panic at the type's bounds
+        let _: u64 = Strategy::generate(&u64::arbitrary(), &mut rng, &config);
+        let _: i128 = Strategy::generate(&i128::arbitrary(), &mut rng, &config);
+        let _: usize = Generator::generate(&usize::arbitrary(), &mut rng, &config);
+
+        let value: f32 = Strategy::generate(&f32::arbitrary(), &mut rng, &config);
+        assert!((-1000.0..=1000.0).contains(&value));
+    }
 }
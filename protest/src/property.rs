@@ -10,6 +10,128 @@ pub trait Property<T> {
     fn test(&self, input: T) -> Result<Self::Output, PropertyError>;
 }
 
+/// Panic payload [`assume`] unwinds with
+struct Rejection;
+
+/// Discard the current input unless `condition` holds
+///
+/// Inside a property, sync or async, a false condition skips this input: the
+/// runner generates a fresh one without counting a failure, like QuickCheck's
+/// `==>`. A run fails with [`PropertyError::TooManyRejections`] once more than
+/// [`TestConfig::max_rejections`](crate::TestConfig::max_rejections) inputs are
+/// rejected. Rejected inputs are also never accepted while shrinking.
+///
+/// ```rust
+/// use protest::{assume, property_test};
+///
+/// #[property_test]
+/// fn max_is_an_element(v: Vec<i32>) {
+///     assume(!v.is_empty());
+///     let max = v.iter().max().unwrap();
+///     assert!(v.contains(max));
+/// }
+/// ```
+pub fn assume(condition: bool) {
+    if !condition {
+        // `resume_unwind` skips the panic hook, so rejections print nothing
+        std::panic::resume_unwind(Box::new(Rejection));
+    }
+}
+
+/// Whether a panic payload came from [`assume`]
+pub(crate) fn is_rejection(payload: &(dyn std::any::Any + Send)) -> bool {
+    payload.is::<Rejection>()
+}
+
+/// Run `test`, turning an [`assume`] rejection into [`PropertyError::InputRejected`]
+///
+/// Other panics continue to unwind.
+pub(crate) fn catch_rejection<R>(
+    test: impl FnOnce() -> Result<R, PropertyError>,
+) -> Result<R, PropertyError> {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(test)) {
+        Ok(result) => result,
+        Err(payload) if is_rejection(&*payload) => Err(PropertyError::InputRejected),
+        Err(payload) => std::panic::resume_unwind(payload),
+    }
+}
+
+/// Await `test`, turning an [`assume`] rejection into [`PropertyError::InputRejected`]
+///
+/// The async counterpart of [`catch_rejection`]; other panics continue to unwind.
+pub(crate) async fn catch_rejection_async<R>(
+    test: impl std::future::Future<Output = Result<R, PropertyError>>,
+) -> Result<R, PropertyError> {
+    let mut test = std::pin::pin!(test);
+    std::future::poll_fn(|cx| {
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| test.as_mut().poll(cx))) {
+            Ok(poll) => poll,
+            Err(payload) if is_rejection(&*payload) => {
+                std::task::Poll::Ready(Err(PropertyError::InputRejected))
+            }
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    })
+    .await
+}
+
+thread_local! {
+    /// Labels [`classify`] recorded for the input under test, if recording
+    static LABELS: std::cell::RefCell<Option<Vec<String>>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Label the current input when `condition` holds
+///
+/// Inside a synchronous property, labels count the inputs of each kind a run
+/// tests, like QuickCheck's `classify`. With a
+/// [`CoverageThresholdsBuilder::label_threshold`](crate::CoverageThresholdsBuilder::label_threshold)
+/// set, the run fails with [`PropertyError::InsufficientCoverage`] when a label
+/// covers too few inputs, catching generators that silently miss a case.
+///
+/// ```rust
+/// use protest::{CoverageThresholdsBuilder, IntGenerator, Property, PropertyError,
+///     PropertyTestBuilder, classify};
+///
+/// struct AbsIsPositive;
+/// impl Property<i32> for AbsIsPositive {
+///     type Output = ();
+///     fn test(&self, x: i32) -> Result<(), PropertyError> {
+///         classify(x == 0, "zero");
+///         assert!(x.abs() >= 0);
+///         Ok(())
+///     }
+/// }
+///
+/// // Zero is 1 in 21 values, so at least 1% of inputs should hit it
+/// let thresholds = CoverageThresholdsBuilder::new()
+///     .label_threshold("zero", 0.01)
+///     .build();
+/// let result = PropertyTestBuilder::new()
+///     .iterations(1000)
+///     .coverage_thresholds(thresholds)
+///     .run(IntGenerator::new(-10, 10), AbsIsPositive);
+/// assert!(result.is_ok());
+/// ```
+pub fn classify(condition: bool, label: impl Into<String>) {
+    if condition {
+        LABELS.with(|labels| {
+            if let Some(labels) = labels.borrow_mut().as_mut() {
+                labels.push(label.into());
+            }
+        });
+    }
+}
+
+/// Run `test`, collecting the distinct labels [`classify`] recorded during it
+pub(crate) fn collect_labels<R>(test: impl FnOnce() -> R) -> (R, Vec<String>) {
+    LABELS.with(|labels| *labels.borrow_mut() = Some(Vec::new()));
+    let result = test();
+    let mut labels = LABELS.with(|labels| labels.borrow_mut().take().unwrap_or_default());
+    labels.sort();
+    labels.dedup();
+    (result, labels)
+}
+
 /// Async property trait for asynchronous testing
 pub trait AsyncProperty<T> {
     type Output;
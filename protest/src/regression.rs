@@ -2,8 +2,16 @@
 //!
 //! This module provides functionality to convert saved test failures into
 //! permanent regression test code that can be committed to version control.
-
-use crate::persistence::{FailureCase, FailureSnapshot};
+//!
+//! Generated tests call a property function you point the generator at with
+//! [`RegressionConfig::with_property`]. The function takes the input by value and
+//! returns `Result<(), PropertyError>`. Seed-based tests replay the failing run
+//! with `.seed(n)` through the generator set with
+//! [`RegressionConfig::with_generator`], which must be the one the original test
+//! used for the seed to regenerate the same input. Value-based tests call the
+//! property on the recorded minimal input directly.
+
+use crate::persistence::{FailureCase, FailureSnapshot, ITERATIONS_KEY, MINIMAL_INPUT_KEY};
 use std::fs;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
@@ -27,9 +35,27 @@ pub struct RegressionConfig {
     pub seed_based: bool,
 
     /// Generate value-based tests (test specific input value)
+    ///
+    /// The recorded input is embedded using its `Debug` output, so this only
+    /// compiles for types whose `Debug` output is a valid Rust expression.
     pub value_based: bool,
+
+    /// Path of the property function generated tests call
+    ///
+    /// `{test}` is replaced with the sanitized test name, so one template can
+    /// cover every test, e.g. `my_crate::properties::{test}`.
+    pub property: Option<String>,
+
+    /// Expression building the generator seed-based tests replay with
+    ///
+    /// Must match the generator of the original test, and `{test}` is
+    /// replaced like in `property`. Seed-based tests are refused without one.
+    pub generator: Option<String>,
 }
 
+/// Iteration count replayed when the saved failure does not record one
+const DEFAULT_ITERATIONS: usize = 100;
+
 impl Default for RegressionConfig {
     fn default() -> Self {
         Self {
@@ -39,6 +65,8 @@ impl Default for RegressionConfig {
             include_error_comment: true,
             seed_based: true,
             value_based: false,
+            property: None,
+            generator: None,
         }
     }
 }
@@ -70,6 +98,16 @@ impl RegressionConfig {
         self.value_based = enabled;
         self
     }
+
+    pub fn with_property(mut self, path: String) -> Self {
+        self.property = Some(path);
+        self
+    }
+
+    pub fn with_generator(mut self, expr: String) -> Self {
+        self.generator = Some(expr);
+        self
+    }
 }
 
 /// Generator for regression test code
@@ -88,6 +126,8 @@ impl RegressionGenerator {
         snapshot: &FailureSnapshot,
         test_name: &str,
     ) -> io::Result<PathBuf> {
+        let property = self.property_for(test_name)?;
+        let generator = self.generator_for(test_name)?;
         let failures = snapshot.load_failures(test_name)?;
 
         if failures.is_empty() {
@@ -112,7 +152,7 @@ impl RegressionGenerator {
         // Generate tests for each failure
         for (idx, failure) in failures.iter().enumerate() {
             writeln!(file)?;
-            self.write_test(&mut file, test_name, idx, failure)?;
+            self.write_test(&mut file, test_name, &property, &generator, idx, failure)?;
         }
 
         Ok(file_path)
@@ -137,6 +177,35 @@ impl RegressionGenerator {
         Ok(generated_files)
     }
 
+    /// Resolve the configured property path for `test_name`
+    fn property_for(&self, test_name: &str) -> io::Result<String> {
+        let template = self.config.property.as_deref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "No property function configured; set RegressionConfig::with_property",
+            )
+        })?;
+        Ok(template.replace("{test}", &sanitize_name(test_name)))
+    }
+
+    /// Resolve the configured generator for `test_name`, if seed-based tests need one
+    ///
+    /// A seed only reproduces the failure through the generator the original
+    /// test used, so this refuses to guess one.
+    fn generator_for(&self, test_name: &str) -> io::Result<String> {
+        if !self.config.seed_based {
+            return Ok(String::new());
+        }
+        let template = self.config.generator.as_deref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "No generator configured for seed-based tests; set \
+                 RegressionConfig::with_generator to the test's generator",
+            )
+        })?;
+        Ok(template.replace("{test}", &sanitize_name(test_name)))
+    }
+
     fn write_header(
         &self,
         file: &mut fs::File,
@@ -160,6 +229,25 @@ impl RegressionGenerator {
         writeln!(file)?;
         writeln!(file, "use protest::*;")?;
         writeln!(file)?;
+        writeln!(
+            file,
+            "/// Adapts a property function to the `Property` trait"
+        )?;
+        writeln!(file, "struct RegressionProperty<F>(F);")?;
+        writeln!(file)?;
+        writeln!(file, "impl<T, F> Property<T> for RegressionProperty<F>")?;
+        writeln!(file, "where")?;
+        writeln!(file, "    F: Fn(T) -> Result<(), PropertyError>,")?;
+        writeln!(file, "{{")?;
+        writeln!(file, "    type Output = ();")?;
+        writeln!(file)?;
+        writeln!(
+            file,
+            "    fn test(&self, input: T) -> Result<(), PropertyError> {{"
+        )?;
+        writeln!(file, "        (self.0)(input)")?;
+        writeln!(file, "    }}")?;
+        writeln!(file, "}}")?;
 
         Ok(())
     }
@@ -168,6 +256,8 @@ impl RegressionGenerator {
         &self,
         file: &mut fs::File,
         test_name: &str,
+        property: &str,
+        generator: &str,
         _idx: usize,
         failure: &FailureCase,
     ) -> io::Result<()> {
@@ -177,64 +267,132 @@ impl RegressionGenerator {
             failure.seed
         );
 
-        // Write comment with context
-        if self.config.include_error_comment {
-            writeln!(
-                file,
-                "/// Regression test for failure with seed {}",
-                failure.seed
-            )?;
-            writeln!(file, "///")?;
-            writeln!(file, "/// Original error: {}", failure.error_message)?;
-            writeln!(file, "/// Input: {}", failure.input)?;
-            writeln!(file, "/// Shrink steps: {}", failure.shrink_steps)?;
-
-            if self.config.include_timestamp {
-                use chrono::{DateTime, Utc};
-                let datetime = DateTime::<Utc>::from(failure.timestamp);
-                writeln!(
-                    file,
-                    "/// Discovered: {}",
-                    datetime.format("%Y-%m-%d %H:%M:%S UTC")
-                )?;
+        if self.config.seed_based {
+            self.write_doc_comment(file, failure)?;
+            self.write_seed_test(file, &test_fn_name, property, generator, failure)?;
+        }
+
+        if self.config.value_based {
+            if self.config.seed_based {
+                writeln!(file)?;
             }
+            self.write_doc_comment(file, failure)?;
+            self.write_value_test(file, &test_fn_name, property, failure)?;
         }
 
-        writeln!(file, "#[test]")?;
-        writeln!(file, "fn {}() {{", test_fn_name)?;
+        Ok(())
+    }
 
-        if self.config.seed_based {
-            // Generate seed-based test (replay with exact seed)
-            writeln!(
-                file,
-                "    // This test replays the failure using the original seed"
-            )?;
-            writeln!(file, "    // If the bug is fixed, this test should pass")?;
-            writeln!(file, "    //")?;
-            writeln!(file, "    // TODO: Replace with your actual test setup")?;
-            writeln!(file, "    // Example:")?;
-            writeln!(file, "    // PropertyTestBuilder::new()")?;
-            writeln!(file, "    //     .iterations(1)")?;
-            writeln!(file, "    //     .seed({})", failure.seed)?;
-            writeln!(file, "    //     .run(your_generator, your_property)")?;
-            writeln!(
-                file,
-                "    //     .expect(\"Regression: failure should not reoccur\");"
-            )?;
-            writeln!(file)?;
+    fn write_doc_comment(&self, file: &mut fs::File, failure: &FailureCase) -> io::Result<()> {
+        if !self.config.include_error_comment {
+            return Ok(());
+        }
+
+        writeln!(
+            file,
+            "/// Regression test for failure with seed {}",
+            failure.seed
+        )?;
+        writeln!(file, "///")?;
+        write_doc_lines(file, "Original error: ", &failure.error_message)?;
+        write_doc_lines(file, "Input: ", &failure.input)?;
+        if let Some(minimal) = failure.metadata.get(MINIMAL_INPUT_KEY) {
+            write_doc_lines(file, "Minimal input: ", minimal)?;
+        }
+        writeln!(file, "/// Shrink steps: {}", failure.shrink_steps)?;
+
+        if self.config.include_timestamp {
+            use chrono::{DateTime, Utc};
+            let datetime = DateTime::<Utc>::from(failure.timestamp);
             writeln!(
                 file,
-                "    panic!(\"TODO: Implement regression test for seed {}\");",
-                failure.seed
+                "/// Discovered: {}",
+                datetime.format("%Y-%m-%d %H:%M:%S UTC")
             )?;
         }
 
+        Ok(())
+    }
+
+    /// Replay the failing run from its seed; passes once the bug is fixed
+    fn write_seed_test(
+        &self,
+        file: &mut fs::File,
+        test_fn_name: &str,
+        property: &str,
+        generator: &str,
+        failure: &FailureCase,
+    ) -> io::Result<()> {
+        let iterations = failure
+            .metadata
+            .get(ITERATIONS_KEY)
+            .and_then(|n| n.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_ITERATIONS);
+
+        writeln!(file, "#[test]")?;
+        writeln!(file, "fn {}() {{", test_fn_name)?;
+        writeln!(file, "    let result = PropertyTestBuilder::new()")?;
+        writeln!(file, "        .iterations({})", iterations)?;
+        writeln!(file, "        .seed({})", failure.seed)?;
+        writeln!(file, "        .run(")?;
+        writeln!(file, "            {},", generator)?;
+        writeln!(file, "            RegressionProperty({}),", property)?;
+        writeln!(file, "        );")?;
+        writeln!(file)?;
+        writeln!(file, "    if let Err(failure) = result {{")?;
+        writeln!(
+            file,
+            "        panic!(\"Regression: failure with seed {} reoccurred\\n{{}}\", failure);",
+            failure.seed
+        )?;
+        writeln!(file, "    }}")?;
+        writeln!(file, "}}")?;
+
+        Ok(())
+    }
+
+    /// Call the property on the recorded minimal input directly
+    fn write_value_test(
+        &self,
+        file: &mut fs::File,
+        test_fn_name: &str,
+        property: &str,
+        failure: &FailureCase,
+    ) -> io::Result<()> {
+        let input = failure
+            .metadata
+            .get(MINIMAL_INPUT_KEY)
+            .unwrap_or(&failure.input);
+
+        writeln!(file, "#[test]")?;
+        writeln!(file, "fn {}_input() {{", test_fn_name)?;
+        writeln!(file, "    let input = {};", input)?;
+        writeln!(file)?;
+        writeln!(file, "    if let Err(error) = {}(input) {{", property)?;
+        writeln!(
+            file,
+            "        panic!(\"Regression: recorded input still fails: {{}}\", error);"
+        )?;
+        writeln!(file, "    }}")?;
         writeln!(file, "}}")?;
 
         Ok(())
     }
 }
 
+/// Write `text` as `///` lines, the first starting with `label`
+///
+/// Every line of multi-line text (such as an `assert_eq!` message) gets its own
+/// `///` prefix so the generated file still compiles.
+fn write_doc_lines(file: &mut fs::File, label: &str, text: &str) -> io::Result<()> {
+    let mut lines = text.lines();
+    writeln!(file, "/// {}{}", label, lines.next().unwrap_or_default())?;
+    for line in lines {
+        writeln!(file, "/// {}", line)?;
+    }
+    Ok(())
+}
+
 /// Sanitize a test name for use in Rust identifiers
 fn sanitize_name(name: &str) -> String {
     name.chars()
@@ -278,7 +436,9 @@ mod tests {
         snapshot.save_failure("my_test", &failure).unwrap();
 
         // Generate regression test
-        let config = RegressionConfig::new(&output_dir);
+        let config = RegressionConfig::new(&output_dir)
+            .with_property("properties::{test}".to_string())
+            .with_generator("generators::{test}()".to_string());
         let generator = RegressionGenerator::new(config);
 
         let generated_file = generator.generate_for_test(&snapshot, "my_test").unwrap();
@@ -289,6 +449,104 @@ mod tests {
         assert!(content.contains("regression_my_test_seed_12345"));
         assert!(content.contains("Value too large"));
         assert!(content.contains(".seed(12345)"));
+        assert!(content.contains("RegressionProperty(properties::my_test)"));
+        assert!(content.contains("generators::my_test(),"));
+        assert!(!content.contains("TODO"));
+    }
+
+    #[test]
+    fn test_multiline_error_stays_in_doc_comment() {
+        let temp_dir = TempDir::new().unwrap();
+        let snapshot = FailureSnapshot::new(temp_dir.path().join("failures")).unwrap();
+        let failure = FailureCase::new(
+            9,
+            "Point {\n    x: 1,\n}".to_string(),
+            "assertion `left == right` failed\n  left: 1\n right: 2".to_string(),
+            0,
+        );
+        snapshot.save_failure("multiline", &failure).unwrap();
+
+        let config = RegressionConfig::new(temp_dir.path().join("output"))
+            .with_property("check".to_string())
+            .with_generator("gen()".to_string());
+        let file = RegressionGenerator::new(config)
+            .generate_for_test(&snapshot, "multiline")
+            .unwrap();
+        let content = fs::read_to_string(file).unwrap();
+
+        assert!(content.contains(
+            "/// Original error: assertion `left == right` failed\n///   left: 1\n///  right: 2\n"
+        ));
+        assert!(content.contains("/// Input: Point {\n///     x: 1,\n/// }\n"));
+        // Everything before the test attribute is header code or doc comments
+        let doc = &content
+            [content.find("/// Regression test").unwrap()..content.find("#[test]").unwrap()];
+        assert!(doc.lines().all(|line| line.starts_with("///")));
+    }
+
+    #[test]
+    fn test_seed_based_requires_generator() {
+        let temp_dir = TempDir::new().unwrap();
+        let snapshot = FailureSnapshot::new(temp_dir.path().join("failures")).unwrap();
+        snapshot
+            .save_failure("my_test", &FailureCase::new(1, "0".into(), "e".into(), 0))
+            .unwrap();
+
+        let config = RegressionConfig::new(temp_dir.path()).with_property("check".to_string());
+        let error = RegressionGenerator::new(config.clone())
+            .generate_for_test(&snapshot, "my_test")
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+        // Value-based tests alone do not replay a seed, so need no generator
+        let file = RegressionGenerator::new(config.seed_based(false).value_based(true))
+            .generate_for_test(&snapshot, "my_test")
+            .unwrap();
+        let content = fs::read_to_string(file).unwrap();
+        assert!(!content.contains(".seed("));
+        assert!(content.contains("check(input)"));
+    }
+
+    #[test]
+    fn test_generate_value_based_regression() {
+        let temp_dir = TempDir::new().unwrap();
+        let failures_dir = temp_dir.path().join("failures");
+        let output_dir = temp_dir.path().join("output");
+
+        let snapshot = FailureSnapshot::new(&failures_dir).unwrap();
+        let failure = FailureCase::new(7, "1234".to_string(), "too big".to_string(), 3)
+            .with_metadata(MINIMAL_INPUT_KEY.to_string(), "101".to_string())
+            .with_metadata(ITERATIONS_KEY.to_string(), "250".to_string());
+        snapshot.save_failure("bounded", &failure).unwrap();
+
+        let config = RegressionConfig::new(&output_dir)
+            .value_based(true)
+            .with_property("check_bounded".to_string())
+            .with_generator("range(0u32, 5000)".to_string());
+        let generator = RegressionGenerator::new(config);
+
+        let generated_file = generator.generate_for_test(&snapshot, "bounded").unwrap();
+        let content = fs::read_to_string(&generated_file).unwrap();
+        assert!(content.contains(".iterations(250)"));
+        assert!(content.contains("range(0u32, 5000),"));
+        assert!(content.contains("fn regression_bounded_seed_7_input()"));
+        assert!(content.contains("let input = 101;"));
+        assert!(content.contains("check_bounded(input)"));
+    }
+
+    #[test]
+    fn test_generate_requires_property() {
+        let temp_dir = TempDir::new().unwrap();
+        let snapshot = FailureSnapshot::new(temp_dir.path().join("failures")).unwrap();
+        snapshot
+            .save_failure("my_test", &FailureCase::new(1, "0".into(), "e".into(), 0))
+            .unwrap();
+
+        let generator = RegressionGenerator::new(RegressionConfig::new(temp_dir.path()));
+        let error = generator
+            .generate_for_test(&snapshot, "my_test")
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
     }
 
     #[test]
@@ -313,7 +571,9 @@ mod tests {
             )
             .unwrap();
 
-        let config = RegressionConfig::new(&output_dir);
+        let config = RegressionConfig::new(&output_dir)
+            .with_property("properties::{test}".to_string())
+            .with_generator("generators::{test}()".to_string());
         let generator = RegressionGenerator::new(config);
 
         let files = generator.generate_all(&snapshot).unwrap();
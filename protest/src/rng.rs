@@ -88,6 +88,32 @@ impl<P: RngProvider> RngManager<P> {
     }
 }
 
+impl<P: RngProvider + Clone> RngManager<P> {
+    /// Fork child stream `stream` from this manager
+    ///
+    /// The child is seeded with [`split_seed`] of this manager's seed, so the same
+    /// parent seed and stream index always give the same child, independently of
+    /// any other stream. An unseeded manager forks unseeded children.
+    pub fn split(&self, stream: u64) -> Self {
+        Self {
+            provider: self.provider.clone(),
+            seed: self.seed.map(|seed| split_seed(seed, stream)),
+            thread_rng: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+/// Derive the seed of child stream `stream` from a parent seed
+///
+/// Mixes the pair with the SplitMix64 finalizer, so neighbouring parent seeds and
+/// stream indices give unrelated seeds instead of overlapping sequences.
+pub fn split_seed(seed: u64, stream: u64) -> u64 {
+    let mut z = seed.wrapping_add(stream.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 impl<P: RngProvider + Clone> Clone for RngManager<P> {
     fn clone(&self) -> Self {
         Self {
@@ -198,6 +224,114 @@ impl<R: rand::RngCore + Clone> Clone for RngWrapper<R> {
     }
 }
 
+/// An RNG that records every byte it hands out, for later replay or diffing
+///
+/// Integers are recorded little-endian, so the recording is a flat byte stream
+/// that a [`ReplayRng`] feeds back in the same order.
+#[derive(Debug, Clone)]
+pub struct RecordingRng<R> {
+    inner: R,
+    draws: Vec<u8>,
+}
+
+impl<R> RecordingRng<R> {
+    /// Wrap an RNG, starting with an empty recording
+    pub fn new(rng: R) -> Self {
+        Self {
+            inner: rng,
+            draws: Vec::new(),
+        }
+    }
+
+    /// The bytes drawn so far, in order
+    pub fn draws(&self) -> &[u8] {
+        &self.draws
+    }
+
+    /// Consume the wrapper, returning the recorded bytes
+    pub fn into_draws(self) -> Vec<u8> {
+        self.draws
+    }
+}
+
+impl<R: rand::RngCore> rand::RngCore for RecordingRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        let value = self.inner.next_u32();
+        self.draws.extend_from_slice(&value.to_le_bytes());
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = self.inner.next_u64();
+        self.draws.extend_from_slice(&value.to_le_bytes());
+        value
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.inner.fill_bytes(dest);
+        self.draws.extend_from_slice(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.inner.try_fill_bytes(dest)?;
+        self.draws.extend_from_slice(dest);
+        Ok(())
+    }
+}
+
+/// An RNG that replays bytes captured by a [`RecordingRng`]
+///
+/// Once the recording runs out, every further byte is zero. Replaying through
+/// a generator that draws more than it did while recording therefore yields a
+/// different value, and one that retries until the RNG produces something
+/// new, such as a filter, may never finish.
+#[derive(Debug, Clone)]
+pub struct ReplayRng {
+    draws: Vec<u8>,
+    position: usize,
+}
+
+impl ReplayRng {
+    /// Replay the given bytes from the start
+    pub fn new(draws: impl Into<Vec<u8>>) -> Self {
+        Self {
+            draws: draws.into(),
+            position: 0,
+        }
+    }
+
+    /// Number of recorded bytes not yet replayed
+    pub fn remaining(&self) -> usize {
+        self.draws.len().saturating_sub(self.position)
+    }
+}
+
+impl rand::RngCore for ReplayRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            *byte = self.draws.get(self.position).copied().unwrap_or(0);
+            self.position += 1;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -246,6 +380,29 @@ mod tests {
         assert_eq!(seeded_value, seeded_value2);
     }
 
+    #[test]
+    fn test_rng_manager_split() {
+        let manager = RngManager::with_seed(DefaultRngProvider, 42);
+
+        let first = manager.split(0);
+        let again = manager.split(0);
+        let second = manager.split(1);
+
+        assert_eq!(first.seed(), again.seed());
+        assert_ne!(first.seed(), second.seed());
+        assert_ne!(first.seed(), Some(42));
+        assert_eq!(
+            first.get_rng().next_u64(),
+            manager.split(0).get_rng().next_u64()
+        );
+
+        // Neighbouring parents do not share streams shifted by one
+        assert_ne!(split_seed(42, 1), split_seed(43, 0));
+
+        let unseeded = RngManager::new(DefaultRngProvider);
+        assert_eq!(unseeded.split(3).seed(), None);
+    }
+
     #[test]
     fn test_rng_manager_with_seed() {
         let provider = DefaultRngProvider;
@@ -349,4 +506,25 @@ mod tests {
         manager.set_seed(None);
         assert_eq!(manager.seed(), None);
     }
+
+    #[test]
+    fn test_recording_and_replay_rng() {
+        let mut recording = RecordingRng::new(create_seeded_rng(7));
+        let a = recording.next_u32();
+        let b = recording.next_u64();
+        let mut bytes = [0; 3];
+        recording.fill_bytes(&mut bytes);
+        assert_eq!(recording.draws().len(), 4 + 8 + 3);
+
+        let mut replay = ReplayRng::new(recording.into_draws());
+        assert_eq!(replay.next_u32(), a);
+        assert_eq!(replay.next_u64(), b);
+        let mut replayed = [0; 3];
+        replay.fill_bytes(&mut replayed);
+        assert_eq!(replayed, bytes);
+
+        // Exhausted recordings read as zeros
+        assert_eq!(replay.remaining(), 0);
+        assert_eq!(replay.next_u64(), 0);
+    }
 }
@@ -57,6 +57,35 @@ impl<T> ShrinkResult<T> {
     }
 }
 
+/// Shortest shrink budget derived from a budget multiplier
+///
+/// Keeps very fast properties, whose check time is close to timer resolution,
+/// from getting no time to shrink at all.
+pub const MIN_SHRINK_BUDGET: Duration = Duration::from_millis(100);
+
+/// Shrink budget of `multiplier` times `check_time`, but at least [`MIN_SHRINK_BUDGET`]
+///
+/// The multiplier fields are public, so an unchecked value can reach this
+/// point: a multiplier that is not positive, or is NaN, gets the minimum budget
+/// rather than an unbounded one.
+fn proportional_budget(multiplier: f64, check_time: Duration) -> Duration {
+    if multiplier.is_nan() || multiplier <= 0.0 {
+        return MIN_SHRINK_BUDGET;
+    }
+    Duration::try_from_secs_f64(check_time.as_secs_f64() * multiplier)
+        .unwrap_or(Duration::MAX)
+        .max(MIN_SHRINK_BUDGET)
+}
+
+/// Panic unless `multiplier` is usable as a shrink budget multiplier
+pub(crate) fn checked_budget_multiplier(multiplier: f64) -> f64 {
+    assert!(
+        multiplier.is_finite() && multiplier > 0.0,
+        "shrink budget multiplier must be finite and positive"
+    );
+    multiplier
+}
+
 /// Configuration for shrinking behavior
 #[derive(Debug, Clone)]
 pub struct ShrinkConfig {
@@ -66,6 +95,8 @@ pub struct ShrinkConfig {
     pub timeout: Duration,
     /// Whether to enable verbose shrinking output
     pub verbose: bool,
+    /// Budget as a multiple of the original failing check's duration; replaces `timeout` when set
+    pub budget_multiplier: Option<f64>,
 }
 
 impl Default for ShrinkConfig {
@@ -74,6 +105,7 @@ impl Default for ShrinkConfig {
             max_iterations: 1000,
             timeout: Duration::from_secs(10),
             verbose: false,
+            budget_multiplier: None,
         }
     }
 }
@@ -85,6 +117,7 @@ impl ShrinkConfig {
             max_iterations,
             timeout,
             verbose,
+            budget_multiplier: None,
         }
     }
 
@@ -96,6 +129,32 @@ impl ShrinkConfig {
         }
     }
 
+    /// Create a shrink configuration whose budget scales with the failing check
+    ///
+    /// Shrinking may run for `multiplier` times as long as the original
+    /// failing check took (but at least [`MIN_SHRINK_BUDGET`]), instead of the
+    /// flat `timeout`. Slow properties get more time and fast ones less.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `multiplier` is not finite and positive.
+    pub fn budget_multiplier(multiplier: f64) -> Self {
+        Self {
+            budget_multiplier: Some(checked_budget_multiplier(multiplier)),
+            ..Default::default()
+        }
+    }
+
+    /// Time allowed for shrinking when the original failing check took `check_time`
+    ///
+    /// This is `timeout` unless `budget_multiplier` is set.
+    pub fn budget_for(&self, check_time: Duration) -> Duration {
+        match self.budget_multiplier {
+            Some(multiplier) => proportional_budget(multiplier, check_time),
+            None => self.timeout,
+        }
+    }
+
     /// Create a shrink configuration with custom max iterations
     pub fn with_max_iterations(max_iterations: usize) -> Self {
         Self {
@@ -145,10 +204,11 @@ impl ShrinkEngine {
             // Original value doesn't fail, no shrinking needed
             return ShrinkResult::new(value, current, 0, start_time.elapsed(), true);
         }
+        let budget = self.config.budget_for(start_time.elapsed());
 
         while shrink_steps < self.config.max_iterations {
             // Check timeout
-            if start_time.elapsed() >= self.config.timeout {
+            if start_time.elapsed() >= budget {
                 if self.config.verbose {
                     eprintln!("Shrinking timed out after {} steps", shrink_steps);
                 }
@@ -220,10 +280,11 @@ impl ShrinkEngine {
         if property(&current).is_ok() {
             return ShrinkResult::new(value, current, 0, start_time.elapsed(), true);
         }
+        let budget = self.config.budget_for(start_time.elapsed());
 
         while shrink_steps < self.config.max_iterations {
             // Check timeout
-            if start_time.elapsed() >= self.config.timeout {
+            if start_time.elapsed() >= budget {
                 if self.config.verbose {
                     eprintln!("Shrinking timed out after {} steps", shrink_steps);
                 }
@@ -273,6 +334,27 @@ impl ShrinkEngine {
             shrink_steps < self.config.max_iterations,
         )
     }
+
+    /// Shrink a collection with a [`StreamingShrinkStrategy`]
+    ///
+    /// Candidates are pulled from the strategy one at a time and never
+    /// collected, which keeps memory bounded for very large failing inputs.
+    ///
+    /// [`StreamingShrinkStrategy`]: crate::StreamingShrinkStrategy
+    pub fn shrink_streaming<T, F>(
+        &self,
+        value: Vec<T>,
+        property: F,
+        strategy: &crate::StreamingShrinkStrategy<T>,
+    ) -> ShrinkResult<Vec<T>>
+    where
+        T: Clone + 'static,
+        F: Fn(&Vec<T>) -> Result<(), PropertyError>,
+    {
+        self.shrink_with_strategy(value, property, |current| {
+            Box::new(strategy.candidates(current))
+        })
+    }
 }
 
 impl Default for ShrinkEngine {
@@ -316,10 +398,11 @@ impl AsyncShrinkEngine {
             // Original value doesn't fail, no shrinking needed
             return ShrinkResult::new(value, current, 0, start_time.elapsed(), true);
         }
+        let budget = self.config.budget_for(start_time.elapsed());
 
         while shrink_steps < self.config.max_iterations {
             // Check timeout
-            if start_time.elapsed() >= self.config.timeout {
+            if start_time.elapsed() >= budget {
                 if self.config.verbose {
                     eprintln!("Async shrinking timed out after {} steps", shrink_steps);
                 }
@@ -396,10 +479,11 @@ impl AsyncShrinkEngine {
         if property(current.clone()).await.is_ok() {
             return ShrinkResult::new(value, current, 0, start_time.elapsed(), true);
         }
+        let budget = self.config.budget_for(start_time.elapsed());
 
         while shrink_steps < self.config.max_iterations {
             // Check timeout
-            if start_time.elapsed() >= self.config.timeout {
+            if start_time.elapsed() >= budget {
                 if self.config.verbose {
                     eprintln!(
                         "Async shrinking with strategy timed out after {} steps",
@@ -480,10 +564,11 @@ impl AsyncShrinkEngine {
         if property(current.clone()).await.is_ok() {
             return ShrinkResult::new(value, current, 0, start_time.elapsed(), true);
         }
+        let budget = self.config.budget_for(start_time.elapsed());
 
         while shrink_steps < self.config.max_iterations {
             // Check timeout (use the more restrictive of the two timeouts)
-            let effective_timeout = std::cmp::min(self.config.timeout, timeout);
+            let effective_timeout = std::cmp::min(budget, timeout);
             if start_time.elapsed() >= effective_timeout {
                 if self.config.verbose {
                     eprintln!("Async shrinking timed out after {} steps", shrink_steps);
@@ -608,32 +693,13 @@ pub mod strategies {
     }
 
     /// Collection shrinking - try removing elements
+    ///
+    /// Candidates are built lazily, one per `next()` call.
     pub fn collection_shrink<T>(collection: &[T]) -> Box<dyn Iterator<Item = Vec<T>> + '_>
     where
         T: Clone,
     {
-        let mut candidates = Vec::new();
-
-        // Try empty collection first
-        if !collection.is_empty() {
-            candidates.push(Vec::new());
-        }
-
-        // Try removing each element
-        for i in 0..collection.len() {
-            let mut shrunk = collection.to_vec();
-            shrunk.remove(i);
-            candidates.push(shrunk);
-        }
-
-        // Try removing half the elements
-        if collection.len() > 2 {
-            let mid = collection.len() / 2;
-            candidates.push(collection[..mid].to_vec());
-            candidates.push(collection[mid..].to_vec());
-        }
-
-        Box::new(candidates.into_iter())
+        Box::new(super::removal_candidates(collection))
     }
 
     /// String shrinking - try shorter strings
@@ -1117,18 +1183,89 @@ impl_shrinkable_float!(f32, f64);
 // Implement Shrinkable for Vec<T> where T: Shrinkable to handle element shrinking
 impl<T: Shrinkable + Clone + 'static> Shrinkable for Vec<T> {
     fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-        let mut candidates: Vec<Vec<T>> = strategies::collection_shrink(self).collect();
+        // Candidates are produced lazily from a single shared copy of the vector
+        let original: std::rc::Rc<[T]> = self.as_slice().into();
+        let removals = removal_candidates(std::rc::Rc::clone(&original));
 
         // Also try shrinking individual elements
-        for i in 0..self.len() {
-            for shrunk_element in self[i].shrink() {
-                let mut shrunk_vec = self.clone();
+        let element_shrinks = (0..original.len()).flat_map(move |i| {
+            let original = std::rc::Rc::clone(&original);
+            original[i].shrink().map(move |shrunk_element| {
+                let mut shrunk_vec = original.to_vec();
                 shrunk_vec[i] = shrunk_element;
-                candidates.push(shrunk_vec);
-            }
+                shrunk_vec
+            })
+        });
+
+        Box::new(removals.chain(element_shrinks))
+    }
+}
+
+/// Lazily yield the removal candidates of a collection: empty, each element
+/// removed in turn, then each half
+fn removal_candidates<T, C>(collection: C) -> impl Iterator<Item = Vec<T>>
+where
+    T: Clone,
+    C: std::ops::Deref<Target = [T]> + Clone,
+{
+    let len = collection.len();
+    let mid = len / 2;
+
+    let empty = (len > 0).then(Vec::new);
+
+    let removals = {
+        let collection = collection.clone();
+        (0..len).map(move |i| {
+            let mut shrunk = Vec::with_capacity(len - 1);
+            shrunk.extend_from_slice(&collection[..i]);
+            shrunk.extend_from_slice(&collection[i + 1..]);
+            shrunk
+        })
+    };
+
+    let halves = (0..if len > 2 { 2 } else { 0 }).map(move |half| {
+        if half == 0 {
+            collection[..mid].to_vec()
+        } else {
+            collection[mid..].to_vec()
         }
+    });
 
-        Box::new(candidates.into_iter())
+    empty.into_iter().chain(removals).chain(halves)
+}
+
+// Implement Shrinkable for Duration, shrinking toward Duration::ZERO
+impl Shrinkable for std::time::Duration {
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        crate::generator::Generator::shrink(
+            &crate::primitives::DurationGenerator::up_to(std::time::Duration::MAX),
+            self,
+        )
+    }
+}
+
+// Implement Shrinkable for smart pointers by shrinking the pointee and re-wrapping
+impl<T: Shrinkable + 'static> Shrinkable for Box<T> {
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new((**self).shrink().map(Box::new))
+    }
+}
+
+impl<T: Shrinkable + 'static> Shrinkable for std::rc::Rc<T> {
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new((**self).shrink().map(std::rc::Rc::new))
+    }
+}
+
+impl<T: Shrinkable + 'static> Shrinkable for std::sync::Arc<T> {
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new((**self).shrink().map(std::sync::Arc::new))
+    }
+}
+
+impl Shrinkable for std::borrow::Cow<'static, str> {
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.to_string().shrink().map(std::borrow::Cow::Owned))
     }
 }
 
@@ -1321,6 +1458,43 @@ where
     }
 }
 
+// Tuples of 6 to 8 elements shrink the same way, one element at a time
+macro_rules! impl_shrinkable_tuple {
+    ($(($T:ident, $idx:tt)),+) => {
+        impl<$($T),+> Shrinkable for ($($T,)+)
+        where
+            $($T: Shrinkable + Clone + 'static,)+
+        {
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                let mut candidates = Vec::new();
+
+                $(
+                    for shrunk in self.$idx.shrink() {
+                        let mut candidate = self.clone();
+                        candidate.$idx = shrunk;
+                        candidates.push(candidate);
+                    }
+                )+
+
+                Box::new(candidates.into_iter())
+            }
+        }
+    };
+}
+
+impl_shrinkable_tuple!((A, 0), (B, 1), (C, 2), (D, 3), (E, 4), (F, 5));
+impl_shrinkable_tuple!((A, 0), (B, 1), (C, 2), (D, 3), (E, 4), (F, 5), (G, 6));
+impl_shrinkable_tuple!(
+    (A, 0),
+    (B, 1),
+    (C, 2),
+    (D, 3),
+    (E, 4),
+    (F, 5),
+    (G, 6),
+    (H, 7)
+);
+
 // Implement Shrinkable for HashMap
 impl<K, V> Shrinkable for std::collections::HashMap<K, V>
 where
@@ -1465,47 +1639,7 @@ where
     T: Shrinkable + Clone + std::hash::Hash + Eq + 'static,
 {
     fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-        let mut candidates = Vec::new();
-
-        // Try empty set first
-        if !self.is_empty() {
-            candidates.push(std::collections::HashSet::new());
-        }
-
-        // Try removing each element
-        for item in self.iter() {
-            let mut shrunk = self.clone();
-            shrunk.remove(item);
-            candidates.push(shrunk);
-        }
-
-        // Try shrinking individual elements
-        for item in self.iter() {
-            for shrunk_item in item.shrink() {
-                if !self.contains(&shrunk_item) {
-                    let mut shrunk_set = self.clone();
-                    shrunk_set.remove(item);
-                    shrunk_set.insert(shrunk_item);
-                    candidates.push(shrunk_set);
-                }
-            }
-        }
-
-        // Try removing half the elements
-        if self.len() > 2 {
-            let items: Vec<_> = self.iter().cloned().collect();
-            let mid = items.len() / 2;
-
-            // First half
-            let first_half: std::collections::HashSet<_> = items[..mid].iter().cloned().collect();
-            candidates.push(first_half);
-
-            // Second half
-            let second_half: std::collections::HashSet<_> = items[mid..].iter().cloned().collect();
-            candidates.push(second_half);
-        }
-
-        Box::new(candidates.into_iter())
+        Box::new(set_candidates(self, |item| item.shrink()).into_iter())
     }
 }
 
@@ -1515,48 +1649,59 @@ where
     T: Shrinkable + Clone + Ord + 'static,
 {
     fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-        let mut candidates = Vec::new();
-
-        // Try empty set first
-        if !self.is_empty() {
-            candidates.push(std::collections::BTreeSet::new());
-        }
-
-        // Try removing each element
-        for item in self.iter() {
-            let mut shrunk = self.clone();
-            shrunk.remove(item);
-            candidates.push(shrunk);
-        }
+        Box::new(set_candidates(self, |item| item.shrink()).into_iter())
+    }
+}
 
-        // Try shrinking individual elements
-        for item in self.iter() {
-            for shrunk_item in item.shrink() {
-                if !self.contains(&shrunk_item) {
-                    let mut shrunk_set = self.clone();
-                    shrunk_set.remove(item);
-                    shrunk_set.insert(shrunk_item);
-                    candidates.push(shrunk_set);
-                }
-            }
-        }
+/// Shrink candidates for a set: empty, each element removed, each element
+/// shrunk with `shrink_element` (skipping shrinks that collide), then each half
+///
+/// Shared by `HashSet` and `BTreeSet`, which only need to be iterable by
+/// reference and collectable from their elements.
+pub(crate) fn set_candidates<S, T, F>(set: &S, shrink_element: F) -> Vec<S>
+where
+    for<'a> &'a S: IntoIterator<Item = &'a T>,
+    S: FromIterator<T>,
+    T: Clone + PartialEq + 'static,
+    F: Fn(&T) -> Box<dyn Iterator<Item = T>>,
+{
+    let items: Vec<T> = set.into_iter().cloned().collect();
+    let without = |index: usize| {
+        items
+            .iter()
+            .enumerate()
+            .filter(move |&(i, _)| i != index)
+            .map(|(_, item)| item.clone())
+    };
+    let mut candidates = Vec::new();
 
-        // Try removing half the elements
-        if self.len() > 2 {
-            let items: Vec<_> = self.iter().cloned().collect();
-            let mid = items.len() / 2;
+    // Try empty set first
+    if !items.is_empty() {
+        candidates.push(std::iter::empty().collect());
+    }
 
-            // First half
-            let first_half: std::collections::BTreeSet<_> = items[..mid].iter().cloned().collect();
-            candidates.push(first_half);
+    // Try removing each element
+    for index in 0..items.len() {
+        candidates.push(without(index).collect());
+    }
 
-            // Second half
-            let second_half: std::collections::BTreeSet<_> = items[mid..].iter().cloned().collect();
-            candidates.push(second_half);
+    // Try shrinking individual elements
+    for (index, item) in items.iter().enumerate() {
+        for shrunk_item in shrink_element(item) {
+            if !items.contains(&shrunk_item) {
+                candidates.push(without(index).chain(std::iter::once(shrunk_item)).collect());
+            }
         }
+    }
 
-        Box::new(candidates.into_iter())
+    // Try removing half the elements
+    if items.len() > 2 {
+        let mid = items.len() / 2;
+        candidates.push(items[..mid].iter().cloned().collect());
+        candidates.push(items[mid..].iter().cloned().collect());
     }
+
+    candidates
 }
 
 #[cfg(test)]
@@ -1609,6 +1754,58 @@ mod tests {
         assert!(config.verbose);
     }
 
+    #[test]
+    fn test_shrink_config_budget_multiplier() {
+        let config = ShrinkConfig::default();
+        assert_eq!(config.budget_for(Duration::from_secs(3)), config.timeout);
+
+        let config = ShrinkConfig::budget_multiplier(10.0);
+        assert_eq!(config.budget_multiplier, Some(10.0));
+        assert_eq!(
+            config.budget_for(Duration::from_secs(2)),
+            Duration::from_secs(20)
+        );
+        // Fast checks still get the minimum budget
+        assert_eq!(
+            config.budget_for(Duration::from_micros(5)),
+            MIN_SHRINK_BUDGET
+        );
+
+        // Unchecked values set through the public field never give an unbounded budget
+        let mut config = ShrinkConfig::default();
+        for multiplier in [-1.0, 0.0, f64::NAN, f64::NEG_INFINITY] {
+            config.budget_multiplier = Some(multiplier);
+            assert_eq!(config.budget_for(Duration::from_secs(2)), MIN_SHRINK_BUDGET);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "shrink budget multiplier must be finite and positive")]
+    fn test_shrink_config_rejects_zero_budget_multiplier() {
+        ShrinkConfig::budget_multiplier(0.0);
+    }
+
+    #[test]
+    fn test_shrink_engine_budget_scales_with_check_time() {
+        let engine = ShrinkEngine::with_config(ShrinkConfig::budget_multiplier(1.0));
+
+        // Every check takes 20ms, so the budget is the 100ms minimum, well
+        // short of the ~20 rounds it takes to shrink to 1
+        let property = |x: &i32| {
+            std::thread::sleep(Duration::from_millis(20));
+            if *x > 0 {
+                Err(PropertyError::property_failed("positive"))
+            } else {
+                Ok(())
+            }
+        };
+
+        let result = engine.shrink(1_000_000, property);
+
+        assert!(!result.completed);
+        assert!(result.shrink_duration < Duration::from_secs(1));
+    }
+
     #[test]
     fn test_shrink_engine_creation() {
         let engine = ShrinkEngine::new();
@@ -1806,6 +2003,17 @@ mod tests {
         assert!(shrunk.contains(&vec![])); // Should try empty collection
     }
 
+    #[test]
+    fn test_vec_shrink_is_lazy_for_large_inputs() {
+        // Materializing every candidate would need ~10 GB here
+        let original = vec![1u8; 100_000];
+        let first: Vec<Vec<u8>> = original.shrink().take(3).collect();
+
+        assert_eq!(first[0], Vec::<u8>::new());
+        assert_eq!(first[1].len(), 99_999);
+        assert_eq!(first[2].len(), 99_999);
+    }
+
     #[test]
     fn test_shrinkable_trait_for_integers() {
         let value = 50;
@@ -2009,6 +2217,38 @@ mod tests {
         assert!(shrunk.is_empty());
     }
 
+    #[test]
+    fn test_duration_shrinking() {
+        let shrunk: Vec<Duration> = Duration::from_millis(1500).shrink().collect();
+        assert_eq!(shrunk[0], Duration::ZERO);
+        assert!(shrunk.contains(&Duration::from_millis(750)));
+        assert!(shrunk.contains(&Duration::from_secs(1)));
+        assert!(shrunk.iter().all(|d| *d < Duration::from_millis(1500)));
+
+        assert_eq!(Duration::ZERO.shrink().count(), 0);
+    }
+
+    #[test]
+    fn test_smart_pointer_shrinking() {
+        let shrunk: Vec<Box<i32>> = Box::new(100i32).shrink().collect();
+        assert_eq!(
+            shrunk.iter().map(|b| **b).collect::<Vec<_>>(),
+            100i32.shrink().collect::<Vec<_>>()
+        );
+
+        let shrunk: Vec<std::rc::Rc<i32>> = std::rc::Rc::new(100i32).shrink().collect();
+        assert!(shrunk.iter().any(|rc| **rc == 0));
+
+        let shrunk: Vec<std::sync::Arc<Vec<i32>>> =
+            std::sync::Arc::new(vec![1, 2, 3]).shrink().collect();
+        assert!(shrunk.iter().any(|arc| arc.is_empty()));
+
+        let cow: std::borrow::Cow<'static, str> = std::borrow::Cow::Borrowed("hello");
+        let shrunk: Vec<_> = cow.shrink().collect();
+        assert!(!shrunk.is_empty());
+        assert!(shrunk.iter().all(|s| s.len() < 5));
+    }
+
     #[test]
     fn test_tuple_shrinking() {
         let original = (100i32, true);
@@ -2198,6 +2438,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_eight_tuple_shrinking() {
+        let original = (1i32, 2u8, 3i64, true, 5i32, 6u16, 7i8, 8u32);
+        let shrunk: Vec<_> = original.shrink().collect();
+
+        // Each candidate changes exactly one element
+        assert!(!shrunk.is_empty());
+        assert!(shrunk.iter().any(|t| t.0 == 0 && t.1 == 2 && t.7 == 8));
+        assert!(shrunk.iter().any(|t| !t.3 && t.0 == 1 && t.7 == 8));
+        assert!(shrunk.iter().any(|t| t.7 == 0 && t.0 == 1 && t.6 == 7));
+    }
+
     #[test]
     fn test_five_tuple_shrinking() {
         let original = (1i32, 2i32, 3i32, 4i32, 5i32);
@@ -2669,6 +2921,7 @@ mod tests {
             max_iterations: 1000,
             timeout: Duration::from_millis(10), // Very short timeout
             verbose: false,
+            budget_multiplier: None,
         };
         let engine = AsyncShrinkEngine::with_config(config);
         let original = 1000;
@@ -2,6 +2,7 @@
 
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
+use std::fmt;
 use std::time::{Duration, Instant};
 
 use crate::config::{
@@ -20,6 +21,8 @@ pub struct StatisticsCollector {
     generation_times: Vec<Duration>,
     /// Memory tracking (simplified)
     memory_tracker: MemoryTracker,
+    /// Tallies of values passed to `record`, by bucket key
+    histogram: HashMap<String, usize>,
 }
 
 /// Memory tracking helper (simplified implementation)
@@ -38,6 +41,7 @@ impl StatisticsCollector {
             start_time: None,
             generation_times: Vec::new(),
             memory_tracker: MemoryTracker::new(),
+            histogram: HashMap::new(),
         }
     }
 
@@ -49,6 +53,7 @@ impl StatisticsCollector {
             start_time: None,
             generation_times: Vec::new(),
             memory_tracker: MemoryTracker::new(),
+            histogram: HashMap::new(),
         }
     }
 
@@ -240,6 +245,43 @@ impl StatisticsCollector {
         coverage.record_size(size);
     }
 
+    /// Tally a value into the histogram bucket named by `key`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use protest::StatisticsCollector;
+    ///
+    /// let mut collector = StatisticsCollector::new();
+    /// for value in [1, 5, 12, 7, 30] {
+    ///     collector.record(&value, |v| if *v < 10 { "small" } else { "large" });
+    /// }
+    ///
+    /// let histogram = collector.histogram();
+    /// assert_eq!(histogram.count("small"), 3);
+    /// println!("{}", histogram);
+    /// ```
+    pub fn record<T: ?Sized, K: fmt::Display>(&mut self, value: &T, key: impl FnOnce(&T) -> K) {
+        if !self.enabled {
+            return;
+        }
+
+        *self.histogram.entry(key(value).to_string()).or_insert(0) += 1;
+    }
+
+    /// Distribution of the values passed to [`StatisticsCollector::record`]
+    pub fn histogram(&self) -> Histogram {
+        let mut buckets: Vec<(String, usize)> = self
+            .histogram
+            .iter()
+            .map(|(key, count)| (key.clone(), *count))
+            .collect();
+        // Most frequent first, ties by key so output is stable
+        buckets.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        Histogram { buckets }
+    }
+
     /// Add custom coverage tracking
     pub fn add_custom_coverage(
         &mut self,
@@ -299,6 +341,7 @@ impl StatisticsCollector {
         self.stats = GenerationStats::default();
         self.generation_times.clear();
         self.memory_tracker.reset();
+        self.histogram.clear();
     }
 
     /// Generate a detailed analysis report
@@ -581,6 +624,78 @@ impl MemoryTracker {
     }
 }
 
+/// Width of the longest bar in a printed [`Histogram`]
+const HISTOGRAM_BAR_WIDTH: usize = 40;
+
+/// Counts of recorded values per bucket, from [`StatisticsCollector::histogram`]
+///
+/// Displays as a text bar chart, most frequent bucket first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    buckets: Vec<(String, usize)>,
+}
+
+impl Histogram {
+    /// Buckets and their counts, most frequent first
+    pub fn buckets(&self) -> &[(String, usize)] {
+        &self.buckets
+    }
+
+    /// Total number of recorded values
+    pub fn total(&self) -> usize {
+        self.buckets.iter().map(|(_, count)| count).sum()
+    }
+
+    /// Number of values recorded in `key`
+    pub fn count(&self, key: &str) -> usize {
+        self.buckets
+            .iter()
+            .find(|(bucket, _)| bucket == key)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Percentage (0-100) of recorded values that fell in `key`
+    pub fn percentage(&self, key: &str) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            0.0
+        } else {
+            self.count(key) as f64 / total as f64 * 100.0
+        }
+    }
+}
+
+impl fmt::Display for Histogram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.total();
+        if total == 0 {
+            return writeln!(f, "No values recorded");
+        }
+
+        let key_width = self
+            .buckets
+            .iter()
+            .map(|(key, _)| key.chars().count())
+            .max()
+            .unwrap_or(0);
+        let max_count = self.buckets[0].1;
+
+        for (key, count) in &self.buckets {
+            let bar = "█".repeat(count * HISTOGRAM_BAR_WIDTH / max_count);
+            writeln!(
+                f,
+                "{:<key_width$} | {:<bar_width$} {:>5.1}% ({})",
+                key,
+                bar,
+                *count as f64 / total as f64 * 100.0,
+                count,
+                bar_width = HISTOGRAM_BAR_WIDTH,
+            )?;
+        }
+        Ok(())
+    }
+}
+
 /// Builder for creating coverage thresholds
 pub struct CoverageThresholdsBuilder {
     thresholds: CoverageThresholds,
@@ -594,6 +709,7 @@ impl CoverageThresholdsBuilder {
                 numeric_thresholds: HashMap::new(),
                 require_full_boolean_coverage: false,
                 enum_thresholds: HashMap::new(),
+                label_thresholds: HashMap::new(),
             },
         }
     }
@@ -633,6 +749,18 @@ impl CoverageThresholdsBuilder {
         self
     }
 
+    /// Require a [`crate::classify`] label on at least `min_coverage` (0.0 to 1.0)
+    /// of the inputs a run tests
+    ///
+    /// Enforced by the runner when the thresholds are set with
+    /// [`PropertyTestBuilder::coverage_thresholds`](crate::PropertyTestBuilder::coverage_thresholds).
+    pub fn label_threshold(mut self, label: &str, min_coverage: f64) -> Self {
+        self.thresholds
+            .label_thresholds
+            .insert(label.to_string(), min_coverage);
+        self
+    }
+
     /// Build the thresholds
     pub fn build(self) -> CoverageThresholds {
         self.thresholds
@@ -838,6 +966,50 @@ mod tests {
         assert!(report.contains("Coverage Pattern Analysis"));
     }
 
+    #[test]
+    fn test_histogram() {
+        let mut collector = StatisticsCollector::new();
+        for i in 0..100 {
+            let bucket = match i % 10 {
+                0..=6 => "common",
+                7 | 8 => "rare",
+                _ => "edge",
+            };
+            collector.record(&bucket, |b| *b);
+        }
+
+        let histogram = collector.histogram();
+        assert_eq!(histogram.total(), 100);
+        assert_eq!(
+            histogram.buckets(),
+            &[
+                ("common".to_string(), 70),
+                ("rare".to_string(), 20),
+                ("edge".to_string(), 10)
+            ]
+        );
+        assert_eq!(histogram.percentage("rare"), 20.0);
+        assert_eq!(histogram.count("missing"), 0);
+
+        let printed = histogram.to_string();
+        let lines: Vec<_> = printed.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("common | "));
+        assert!(lines[0].ends_with(" 70.0% (70)"));
+        assert_eq!(lines[0].matches('█').count(), HISTOGRAM_BAR_WIDTH);
+        assert_eq!(lines[2].matches('█').count(), HISTOGRAM_BAR_WIDTH / 7);
+
+        collector.reset();
+        assert_eq!(collector.histogram().to_string(), "No values recorded\n");
+    }
+
+    #[test]
+    fn test_histogram_disabled() {
+        let mut collector = StatisticsCollector::disabled();
+        collector.record(&3, |v| v % 2);
+        assert_eq!(collector.histogram().total(), 0);
+    }
+
     #[test]
     fn test_collector_reset() {
         let mut collector = StatisticsCollector::new();
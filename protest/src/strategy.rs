@@ -1,8 +1,12 @@
 //! Strategy-based generation system for composable test data creation.
 
 use crate::config::GeneratorConfig;
+use crate::error::PropertyError;
 use crate::generator::Generator;
 
+/// Values [`Strategy::filter`] and [`Strategy::filter_map`] try before giving up
+pub const DEFAULT_FILTER_ATTEMPTS: usize = 1000;
+
 /// A strategy for generating values of a specific type
 ///
 /// Strategies are composable and can be combined to create more complex generation patterns.
@@ -30,7 +34,44 @@ pub trait Strategy {
         }
     }
 
+    /// Map this strategy, shrinking through an inverse mapping
+    ///
+    /// Unlike [`Strategy::map`], values are plain `U` rather than
+    /// [`MappedValue`]s. To shrink a value, `unmap` recovers its source value,
+    /// which is shrunk by this strategy and mapped forward again. `unmap` should
+    /// invert `f`, as with wrapping and unwrapping a newtype.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use protest::{Strategy, range};
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct NonNegative(i32);
+    ///
+    /// let strategy = range(0, 1000).map_with_shrink(NonNegative, |n: &NonNegative| n.0);
+    /// assert!(strategy.shrink(&NonNegative(500)).all(|n| n.0 < 500));
+    /// ```
+    fn map_with_shrink<F, G, U>(self, f: F, unmap: G) -> MapWithShrink<Self, F, G>
+    where
+        Self: Sized,
+        F: Fn(Self::Value) -> U,
+        G: Fn(&U) -> Self::Value,
+        U: 'static,
+    {
+        MapWithShrink {
+            strategy: self,
+            mapper: f,
+            unmapper: unmap,
+        }
+    }
+
     /// Filter values produced by this strategy
+    ///
+    /// Generation gives up after [`DEFAULT_FILTER_ATTEMPTS`] rejected values (see
+    /// [`Filter::with_max_attempts`]) and panics with
+    /// [`PropertyError::FilterExhausted`]. There is no input to report, so the
+    /// test runner lets that panic end the run.
     fn filter<F>(self, predicate: F) -> Filter<Self, F>
     where
         Self: Sized,
@@ -39,6 +80,23 @@ pub trait Strategy {
         Filter {
             strategy: self,
             predicate,
+            max_attempts: DEFAULT_FILTER_ATTEMPTS,
+        }
+    }
+
+    /// Filter and transform values in one step, keeping those mapped to `Some`
+    ///
+    /// Gives up like [`Strategy::filter`] (see [`FilterMap::with_max_attempts`]).
+    fn filter_map<F, U>(self, f: F) -> FilterMap<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Value) -> Option<U>,
+        U: 'static,
+    {
+        FilterMap {
+            strategy: self,
+            mapper: f,
+            max_attempts: DEFAULT_FILTER_ATTEMPTS,
         }
     }
 
@@ -67,6 +125,56 @@ pub trait Strategy {
             flat_mapper: f,
         }
     }
+
+    /// Keep generated values fixed during shrinking
+    ///
+    /// Useful for values that are meaningless when altered, such as random
+    /// identifiers: the wrapped value keeps its generated form while sibling
+    /// values in a `zip` or tuple still shrink.
+    fn no_shrink(self) -> NoShrink<Self>
+    where
+        Self: Sized,
+    {
+        NoShrink::new(self)
+    }
+}
+
+/// A strategy or generator whose values never shrink
+///
+/// Created by [`Strategy::no_shrink`], or with [`NoShrink::new`] to wrap any
+/// [`Generator`], e.g. in a `#[generator(...)]` field attribute.
+#[derive(Debug, Clone)]
+pub struct NoShrink<S> {
+    inner: S,
+}
+
+impl<S> NoShrink<S> {
+    /// Wrap a strategy or generator so its values are never shrunk
+    pub fn new(inner: S) -> Self {
+        Self { inner }
+    }
+}
+
+impl<S: Strategy> Strategy for NoShrink<S> {
+    type Value = S::Value;
+
+    fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> Self::Value {
+        self.inner.generate(rng, config)
+    }
+
+    fn shrink(&self, _value: &Self::Value) -> Box<dyn Iterator<Item = Self::Value>> {
+        Box::new(std::iter::empty())
+    }
+}
+
+impl<T: 'static, G: Generator<T>> Generator<T> for NoShrink<G> {
+    fn generate(&self, rng: &mut dyn rand::RngCore, config: &GeneratorConfig) -> T {
+        self.inner.generate(rng, config)
+    }
+
+    fn shrink(&self, _value: &T) -> Box<dyn Iterator<Item = T>> {
+        Box::new(std::iter::empty())
+    }
 }
 
 /// A strategy that maps values from one type to another
@@ -197,10 +305,52 @@ where
     }
 }
 
+/// A strategy that maps values and shrinks them through an inverse mapping
+///
+/// Created by [`Strategy::map_with_shrink`].
+pub struct MapWithShrink<S, F, G> {
+    strategy: S,
+    mapper: F,
+    unmapper: G,
+}
+
+impl<S, F, G, U> Strategy for MapWithShrink<S, F, G>
+where
+    S: Strategy,
+    F: Fn(S::Value) -> U + Clone + 'static,
+    G: Fn(&U) -> S::Value,
+    U: 'static,
+{
+    type Value = U;
+
+    fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> Self::Value {
+        (self.mapper)(self.strategy.generate(rng, config))
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Box<dyn Iterator<Item = Self::Value>> {
+        let input = (self.unmapper)(value);
+        Box::new(self.strategy.shrink(&input).map(self.mapper.clone()))
+    }
+}
+
 /// A strategy that filters values based on a predicate
 pub struct Filter<S, F> {
     strategy: S,
     predicate: F,
+    max_attempts: usize,
+}
+
+impl<S, F> Filter<S, F> {
+    /// Set how many values to try before giving up
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+}
+
+/// Give up on a filtering strategy after `attempts` rejected values
+fn filter_exhausted(attempts: usize) -> ! {
+    std::panic::panic_any(PropertyError::FilterExhausted { attempts })
 }
 
 impl<S, F> Strategy for Filter<S, F>
@@ -214,13 +364,13 @@ where
     fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> Self::Value {
         // Try to generate a value that passes the filter
         // Limit attempts to avoid infinite loops
-        for _ in 0..1000 {
+        for _ in 0..self.max_attempts {
             let value = self.strategy.generate(rng, config);
             if (self.predicate)(&value) {
                 return value;
             }
         }
-        panic!("Filter strategy failed to generate a valid value after 1000 attempts");
+        filter_exhausted(self.max_attempts)
     }
 
     fn shrink(&self, value: &Self::Value) -> Box<dyn Iterator<Item = Self::Value>> {
@@ -232,6 +382,53 @@ where
     }
 }
 
+/// A strategy that filters and transforms values in one step
+pub struct FilterMap<S, F> {
+    strategy: S,
+    mapper: F,
+    max_attempts: usize,
+}
+
+impl<S, F> FilterMap<S, F> {
+    /// Set how many values to try before giving up
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+}
+
+impl<S, F, U> Strategy for FilterMap<S, F>
+where
+    S: Strategy,
+    F: Fn(S::Value) -> Option<U> + Clone + 'static,
+    U: 'static,
+    S::Value: Clone,
+{
+    // Like `Map`, keep the input so shrinking can work on it
+    type Value = MappedValue<S::Value, U>;
+
+    fn generate<R: rand::Rng>(&self, rng: &mut R, config: &GeneratorConfig) -> Self::Value {
+        for _ in 0..self.max_attempts {
+            let input = self.strategy.generate(rng, config);
+            if let Some(output) = (self.mapper)(input.clone()) {
+                return MappedValue { input, output };
+            }
+        }
+        filter_exhausted(self.max_attempts)
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Box<dyn Iterator<Item = Self::Value>> {
+        // Shrink the input, keeping only shrinks that still map to a value
+        let shrunk_inputs = self.strategy.shrink(&value.input);
+        let mapper = self.mapper.clone();
+
+        Box::new(shrunk_inputs.filter_map(move |input| {
+            let output = mapper(input.clone())?;
+            Some(MappedValue { input, output })
+        }))
+    }
+}
+
 /// A strategy that combines two strategies to produce tuples
 pub struct Zip<L, R> {
     left: L,
@@ -995,6 +1192,21 @@ mod tests {
         assert!(shrinks.is_empty());
     }
 
+    #[test]
+    fn test_no_shrink_strategy() {
+        let strategy = range(1, 100).no_shrink().zip(range(1, 100));
+
+        // The left side stays fixed while the right side still shrinks
+        let shrinks: Vec<_> = Strategy::shrink(&strategy, &(50, 50)).collect();
+        assert!(!shrinks.is_empty());
+        assert!(shrinks.iter().all(|(left, _)| *left == 50));
+
+        let generator = NoShrink::new(crate::primitives::IntGenerator::new(1, 100));
+        let value = Generator::generate(&generator, &mut thread_rng(), &GeneratorConfig::default());
+        assert!((1..=100).contains(&value));
+        assert_eq!(Generator::shrink(&generator, &value).count(), 0);
+    }
+
     #[test]
     fn test_one_of_strategy() {
         let values = vec![1, 2, 3, 4, 5];
@@ -1080,6 +1292,27 @@ mod tests {
         // Range now implements shrinking - tests verify this in test_range_shrinking_*
     }
 
+    #[test]
+    fn test_map_with_shrink() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct NonNegative(i32);
+
+        let strategy = range(0, 1000).map_with_shrink(NonNegative, |n: &NonNegative| n.0);
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let value = strategy.generate(&mut rng, &config);
+        assert!((0..=1000).contains(&value.0));
+
+        // Shrinks are the inner value's shrinks, re-wrapped
+        let expected: Vec<_> = Strategy::shrink(&range(0, 1000), &500)
+            .map(NonNegative)
+            .collect();
+        let shrinks: Vec<_> = strategy.shrink(&NonNegative(500)).collect();
+        assert!(!shrinks.is_empty());
+        assert_eq!(shrinks, expected);
+    }
+
     #[test]
     fn test_filter_shrinking() {
         // Test that filter only yields shrinks that pass the predicate
@@ -1097,6 +1330,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_filter_reports_exhaustion() {
+        let strategy = range(0, 10).filter(|x| *x > 100).with_max_attempts(20);
+        let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            strategy.generate(&mut thread_rng(), &GeneratorConfig::default())
+        }))
+        .expect_err("no value passes the predicate");
+
+        assert!(matches!(
+            payload.downcast_ref::<PropertyError>(),
+            Some(PropertyError::FilterExhausted { attempts: 20 })
+        ));
+    }
+
+    #[test]
+    fn test_filter_map() {
+        let strategy = range(0, 100).filter_map(|x| (x % 3 == 0).then_some(x / 3));
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let value = strategy.generate(&mut rng, &config);
+            assert_eq!(value.input % 3, 0);
+            assert_eq!(value.output, value.input / 3);
+
+            for shrink in Strategy::shrink(&strategy, &value) {
+                assert_eq!(shrink.input % 3, 0);
+                assert_eq!(shrink.output, shrink.input / 3);
+            }
+        }
+    }
+
     #[test]
     fn test_zip_shrinking() {
         // Test that zip shrinks both sides independently
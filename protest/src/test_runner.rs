@@ -5,6 +5,8 @@
 
 use crate::{PropertyResult, TestFailure, TestSuccess};
 use std::fmt;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::time::Duration;
 
 /// Test runner integration utilities
@@ -160,6 +162,7 @@ pub enum TestResult {
         shrink_steps: usize,
         seed: Option<u64>,
         duration: Duration,
+        shrink_duration: Duration,
     },
     /// Test was skipped
     Skipped { reason: String },
@@ -184,6 +187,7 @@ impl TestResult {
                 shrink_steps: failure.shrink_steps,
                 seed: failure.config.seed,
                 duration: failure.test_duration,
+                shrink_duration: failure.shrink_duration,
             },
         }
     }
@@ -243,6 +247,7 @@ impl fmt::Display for TestResult {
                 shrink_steps,
                 seed,
                 duration,
+                ..
             } => {
                 write!(f, "FAILED: {}", error)?;
                 write!(f, "\n  Original input: {}", original_input)?;
@@ -314,8 +319,47 @@ impl TestOutputFormatter for DefaultFormatter {
 }
 
 /// Verbose test output formatter with detailed information
+///
+/// Failures that were shrunk also get a line diff between the original and
+/// minimal inputs. Both `Debug` representations are broken into one field or
+/// element per line first, so changes inside large structs stand out. Removed
+/// lines are prefixed with `-` and added lines with `+`, coloured red and
+/// green when stderr is a terminal and the `NO_COLOR` environment variable is
+/// not set. Inputs that differ over too many lines to diff quickly get a note
+/// instead of a diff.
 pub struct VerboseFormatter;
 
+impl VerboseFormatter {
+    /// Render a line diff from `original` to `minimal` Debug output
+    fn render_diff(original: &str, minimal: &str, color: bool) -> String {
+        let original = pretty_debug_lines(original);
+        let minimal = pretty_debug_lines(minimal);
+        let mut output = String::from("\n  Diff (- original, + minimal):");
+
+        let Some(diff) = diff_lines(&original, &minimal) else {
+            output.push_str(
+                "\n  (inputs too large to diff; compare the original and minimal inputs above)",
+            );
+            return output;
+        };
+        for line in diff {
+            let (marker, text, ansi) = match line {
+                DiffLine::Same(text) => (' ', text, None),
+                DiffLine::Removed(text) => ('-', text, Some("\x1b[31m")),
+                DiffLine::Added(text) => ('+', text, Some("\x1b[32m")),
+            };
+            match ansi {
+                Some(code) if color => {
+                    output.push_str(&format!("\n  {}{} {}\x1b[0m", code, marker, text))
+                }
+                _ => output.push_str(&format!("\n  {} {}", marker, text)),
+            }
+        }
+
+        output
+    }
+}
+
 impl TestOutputFormatter for VerboseFormatter {
     fn format_test_start(&self, test_name: &str) -> String {
         format!("Running property test: {}", test_name)
@@ -326,7 +370,18 @@ impl TestOutputFormatter for VerboseFormatter {
     }
 
     fn format_test_failure(&self, test_name: &str, result: &TestResult) -> String {
-        format!("✗ {} {}", test_name, result)
+        let mut output = format!("✗ {} {}", test_name, result);
+        if let TestResult::Failed {
+            original_input,
+            shrunk_input: Some(shrunk),
+            ..
+        } = result
+            && shrunk != original_input
+        {
+            let color = std::env::var_os("NO_COLOR").is_none() && std::io::stderr().is_terminal();
+            output.push_str(&Self::render_diff(original_input, shrunk, color));
+        }
+        output
     }
 
     fn format_test_skipped(&self, test_name: &str, result: &TestResult) -> String {
@@ -334,14 +389,222 @@ impl TestOutputFormatter for VerboseFormatter {
     }
 }
 
+/// A single line of a diff between two Debug representations
+#[derive(Debug, PartialEq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Split compact `Debug` output into indented lines, one field or element each
+///
+/// Breaks after opening brackets and commas and before closing brackets,
+/// leaving string and char literals untouched.
+fn pretty_debug_lines(debug: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut chars = debug.chars().peekable();
+
+    let flush = |current: &mut String, lines: &mut Vec<String>, depth: usize| {
+        let trimmed = current.trim();
+        if !trimmed.is_empty() {
+            lines.push(format!("{}{}", "    ".repeat(depth), trimmed));
+        }
+        current.clear();
+    };
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' | '\'' => {
+                current.push(c);
+                while let Some(inner) = chars.next() {
+                    current.push(inner);
+                    if inner == '\\' {
+                        if let Some(escaped) = chars.next() {
+                            current.push(escaped);
+                        }
+                    } else if inner == c {
+                        break;
+                    }
+                }
+            }
+            '{' | '[' | '(' => {
+                let closer = match c {
+                    '{' => '}',
+                    '[' => ']',
+                    _ => ')',
+                };
+                while chars.peek() == Some(&' ') {
+                    chars.next();
+                }
+                if chars.peek() == Some(&closer) {
+                    chars.next();
+                    current.push(c);
+                    current.push(closer);
+                } else {
+                    current.push(c);
+                    flush(&mut current, &mut lines, depth);
+                    depth += 1;
+                }
+            }
+            '}' | ']' | ')' => {
+                flush(&mut current, &mut lines, depth);
+                depth = depth.saturating_sub(1);
+                current.push(c);
+            }
+            ',' => {
+                current.push(c);
+                flush(&mut current, &mut lines, depth);
+            }
+            _ => current.push(c),
+        }
+    }
+    flush(&mut current, &mut lines, depth);
+
+    lines
+}
+
+/// Most line comparisons [`diff_lines`] will make before giving up
+const MAX_DIFF_COMPARISONS: usize = 1 << 22;
+
+/// Compute a line diff along a longest common subsequence
+///
+/// Uses Hirschberg's algorithm, which keeps only two rows of the LCS table at a
+/// time, so memory is O(n + m). Time is still O(n * m) after trimming the
+/// common prefix and suffix, so `None` is returned when that would exceed
+/// [`MAX_DIFF_COMPARISONS`].
+fn diff_lines<'a>(original: &'a [String], minimal: &'a [String]) -> Option<Vec<DiffLine<'a>>> {
+    let (prefix, suffix) = common_ends(original, minimal);
+    let changed =
+        (original.len() - prefix - suffix).saturating_mul(minimal.len() - prefix - suffix);
+    if changed > MAX_DIFF_COMPARISONS {
+        return None;
+    }
+
+    let mut diff = Vec::with_capacity(original.len().max(minimal.len()));
+    diff_into(original, minimal, &mut diff);
+    Some(diff)
+}
+
+/// Lengths of the common prefix and the common suffix, without overlap
+fn common_ends(original: &[String], minimal: &[String]) -> (usize, usize) {
+    let prefix = original
+        .iter()
+        .zip(minimal)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = original[prefix..]
+        .iter()
+        .rev()
+        .zip(minimal[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    (prefix, suffix)
+}
+
+/// Append the diff of `original` against `minimal` to `diff`
+fn diff_into<'a>(original: &'a [String], minimal: &'a [String], diff: &mut Vec<DiffLine<'a>>) {
+    // A common prefix and suffix are always part of some longest subsequence
+    let (prefix, suffix) = common_ends(original, minimal);
+    diff.extend(original[..prefix].iter().map(|l| DiffLine::Same(l)));
+    let (original, common_suffix) = original[prefix..].split_at(original.len() - prefix - suffix);
+    let minimal = &minimal[prefix..minimal.len() - suffix];
+
+    match original {
+        [] => diff.extend(minimal.iter().map(|l| DiffLine::Added(l))),
+        _ if minimal.is_empty() => diff.extend(original.iter().map(|l| DiffLine::Removed(l))),
+        [line] => match minimal.iter().position(|l| l == line) {
+            Some(k) => {
+                diff.extend(minimal[..k].iter().map(|l| DiffLine::Added(l)));
+                diff.push(DiffLine::Same(line));
+                diff.extend(minimal[k + 1..].iter().map(|l| DiffLine::Added(l)));
+            }
+            None => {
+                diff.push(DiffLine::Removed(line));
+                diff.extend(minimal.iter().map(|l| DiffLine::Added(l)));
+            }
+        },
+        _ => {
+            // Split `original` in half and find where the best subsequence
+            // crosses `minimal`, then diff each side on its own
+            let mid = original.len() / 2;
+            let forward = lcs_lengths(original[..mid].iter(), minimal.iter());
+            let backward = lcs_lengths(original[mid..].iter().rev(), minimal.iter().rev());
+            let m = minimal.len();
+            let split = (0..=m)
+                .rev()
+                .max_by_key(|&k| forward[k] + backward[m - k])
+                .unwrap_or(0);
+            diff_into(&original[..mid], &minimal[..split], diff);
+            diff_into(&original[mid..], &minimal[split..], diff);
+        }
+    }
+
+    diff.extend(common_suffix.iter().map(|l| DiffLine::Same(l)));
+}
+
+/// Last row of the LCS table: entry `j` is the LCS length of `original` and the
+/// first `j` lines of `minimal`
+fn lcs_lengths<'a>(
+    original: impl Iterator<Item = &'a String>,
+    minimal: impl Iterator<Item = &'a String> + Clone,
+) -> Vec<usize> {
+    let m = minimal.clone().count();
+    let mut previous = vec![0usize; m + 1];
+    let mut current = vec![0usize; m + 1];
+    for line in original {
+        for (j, other) in minimal.clone().enumerate() {
+            current[j + 1] = if line == other {
+                previous[j] + 1
+            } else {
+                previous[j + 1].max(current[j])
+            };
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous
+}
+
 /// JSON test output formatter for machine-readable results
+///
+/// Every event is a single-line JSON object. Passing tests report `passed`,
+/// `iterations`, `duration_ms` and `seed`; failing tests additionally carry a
+/// `shrinking` object with `original_input`, `minimal_input`, `shrink_steps`
+/// and `shrink_duration_ms`. Missing values are emitted as `null` so the
+/// schema stays stable across results.
 pub struct JsonFormatter;
 
+/// Escape a string for embedding inside a JSON string literal
+fn json_escape(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Render an optional number as a JSON value
+fn json_optional_number(value: Option<u64>) -> String {
+    value
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "null".to_string())
+}
+
 impl TestOutputFormatter for JsonFormatter {
     fn format_test_start(&self, test_name: &str) -> String {
         format!(
             r#"{{"event":"started","name":"{}","type":"property_test"}}"#,
-            test_name
+            json_escape(test_name)
         )
     }
 
@@ -352,20 +615,17 @@ impl TestOutputFormatter for JsonFormatter {
                 duration,
                 seed,
             } => {
-                let seed_json = seed
-                    .map(|s| format!(r#","seed":{}"#, s))
-                    .unwrap_or_default();
                 format!(
-                    r#"{{"event":"ok","name":"{}","type":"property_test","iterations":{},"duration_ms":{}{}}}"#,
-                    test_name,
+                    r#"{{"event":"ok","name":"{}","type":"property_test","passed":true,"iterations":{},"duration_ms":{},"seed":{}}}"#,
+                    json_escape(test_name),
                     iterations,
                     duration.as_millis(),
-                    seed_json
+                    json_optional_number(*seed)
                 )
             }
             _ => format!(
-                r#"{{"event":"ok","name":"{}","type":"property_test"}}"#,
-                test_name
+                r#"{{"event":"ok","name":"{}","type":"property_test","passed":true}}"#,
+                json_escape(test_name)
             ),
         }
     }
@@ -379,28 +639,28 @@ impl TestOutputFormatter for JsonFormatter {
                 shrink_steps,
                 seed,
                 duration,
+                shrink_duration,
             } => {
-                let seed_json = seed
-                    .map(|s| format!(r#","seed":{}"#, s))
-                    .unwrap_or_default();
-                let shrunk_json = shrunk_input
+                let minimal_input = shrunk_input
                     .as_ref()
-                    .map(|s| format!(r#","shrunk_input":"{}","shrink_steps":{}"#, s, shrink_steps))
-                    .unwrap_or_default();
+                    .map(|s| format!(r#""{}""#, json_escape(s)))
+                    .unwrap_or_else(|| "null".to_string());
 
                 format!(
-                    r#"{{"event":"failed","name":"{}","type":"property_test","error":"{}","original_input":"{}","duration_ms":{}{}{}}}"#,
-                    test_name,
-                    error.replace('"', r#"\""#),
-                    original_input.replace('"', r#"\""#),
+                    r#"{{"event":"failed","name":"{}","type":"property_test","passed":false,"error":"{}","duration_ms":{},"seed":{},"shrinking":{{"original_input":"{}","minimal_input":{},"shrink_steps":{},"shrink_duration_ms":{}}}}}"#,
+                    json_escape(test_name),
+                    json_escape(error),
                     duration.as_millis(),
-                    seed_json,
-                    shrunk_json
+                    json_optional_number(*seed),
+                    json_escape(original_input),
+                    minimal_input,
+                    shrink_steps,
+                    shrink_duration.as_millis()
                 )
             }
             _ => format!(
-                r#"{{"event":"failed","name":"{}","type":"property_test"}}"#,
-                test_name
+                r#"{{"event":"failed","name":"{}","type":"property_test","passed":false}}"#,
+                json_escape(test_name)
             ),
         }
     }
@@ -410,18 +670,246 @@ impl TestOutputFormatter for JsonFormatter {
             TestResult::Skipped { reason } => {
                 format!(
                     r#"{{"event":"ignored","name":"{}","type":"property_test","reason":"{}"}}"#,
-                    test_name,
-                    reason.replace('"', r#"\""#)
+                    json_escape(test_name),
+                    json_escape(reason)
                 )
             }
             _ => format!(
                 r#"{{"event":"ignored","name":"{}","type":"property_test"}}"#,
-                test_name
+                json_escape(test_name)
             ),
         }
     }
 }
 
+/// Test Anything Protocol (TAP version 13) output formatter
+///
+/// Results are numbered in the order they are formatted. When constructed
+/// with [`TapFormatter::with_plan`] the `1..N` plan is emitted before the
+/// first test; otherwise call [`TapFormatter::plan`] once all tests have run
+/// to get a trailing plan. Failures carry a YAML diagnostic block whose
+/// string values are double-quoted, so arbitrary `Debug` output stays valid
+/// YAML.
+#[derive(Debug, Default)]
+pub struct TapFormatter {
+    planned: Option<usize>,
+    plan_emitted: AtomicBool,
+    count: AtomicUsize,
+}
+
+impl TapFormatter {
+    /// Create a formatter that emits no leading plan
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a formatter that emits a `1..planned` plan before the first test
+    pub fn with_plan(planned: usize) -> Self {
+        Self {
+            planned: Some(planned),
+            ..Self::default()
+        }
+    }
+
+    /// The `1..N` plan line covering every result formatted so far
+    pub fn plan(&self) -> String {
+        format!("1..{}", self.count.load(Ordering::SeqCst))
+    }
+
+    fn next_test_line(&self, status: &str, test_name: &str) -> String {
+        let number = self.count.fetch_add(1, Ordering::SeqCst) + 1;
+        format!(
+            "{} {} - {}",
+            status,
+            number,
+            test_name.replace('\\', "\\\\").replace('#', "\\#")
+        )
+    }
+}
+
+impl TestOutputFormatter for TapFormatter {
+    fn format_test_start(&self, _test_name: &str) -> String {
+        match self.planned {
+            Some(planned) if !self.plan_emitted.swap(true, Ordering::SeqCst) => {
+                format!("TAP version 13\n1..{}\n", planned)
+            }
+            _ => String::new(),
+        }
+    }
+
+    fn format_test_success(&self, test_name: &str, _result: &TestResult) -> String {
+        self.next_test_line("ok", test_name)
+    }
+
+    fn format_test_failure(&self, test_name: &str, result: &TestResult) -> String {
+        let mut output = self.next_test_line("not ok", test_name);
+        if let TestResult::Failed {
+            error,
+            original_input,
+            shrunk_input,
+            shrink_steps,
+            seed,
+            duration,
+            ..
+        } = result
+        {
+            // JSON string literals are valid YAML double-quoted scalars
+            let minimal_input = shrunk_input
+                .as_ref()
+                .map(|s| format!(r#""{}""#, json_escape(s)))
+                .unwrap_or_else(|| "null".to_string());
+            output.push_str("\n  ---");
+            output.push_str(&format!("\n  message: \"{}\"", json_escape(error)));
+            output.push_str(&format!("\n  seed: {}", json_optional_number(*seed)));
+            output.push_str(&format!(
+                "\n  original_input: \"{}\"",
+                json_escape(original_input)
+            ));
+            output.push_str(&format!("\n  minimal_input: {}", minimal_input));
+            output.push_str(&format!("\n  shrink_steps: {}", shrink_steps));
+            output.push_str(&format!("\n  duration_ms: {}", duration.as_millis()));
+            output.push_str("\n  ...");
+        }
+        output
+    }
+
+    fn format_test_skipped(&self, test_name: &str, result: &TestResult) -> String {
+        let mut output = self.next_test_line("ok", test_name);
+        output.push_str(" # SKIP");
+        if let TestResult::Skipped { reason } = result {
+            output.push(' ');
+            output.push_str(&reason.replace('\n', " "));
+        }
+        output
+    }
+}
+
+/// JUnit XML test output formatter for CI systems such as Jenkins and GitLab
+///
+/// Each result is emitted as a self-contained `<testsuite>` holding a single
+/// `<testcase>`. Failures carry the error, minimal counterexample and seed in
+/// the `<failure>` message, with the full report in its body. Use
+/// [`JUnitFormatter::format_suite`] to aggregate several results into one
+/// report file.
+pub struct JUnitFormatter;
+
+impl JUnitFormatter {
+    /// Render several test results as a complete JUnit XML document
+    pub fn format_suite(&self, suite_name: &str, results: &[(&str, &TestResult)]) -> String {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}",
+            Self::render_suite(suite_name, results)
+        )
+    }
+
+    fn render_suite(suite_name: &str, results: &[(&str, &TestResult)]) -> String {
+        let failures = results.iter().filter(|(_, r)| r.is_failed()).count();
+        let skipped = results.iter().filter(|(_, r)| r.is_skipped()).count();
+        let time: Duration = results.iter().filter_map(|(_, r)| r.duration()).sum();
+
+        let mut output = format!(
+            r#"<testsuite name="{}" tests="{}" failures="{}" errors="0" skipped="{}" time="{:.3}">"#,
+            xml_escape(suite_name),
+            results.len(),
+            failures,
+            skipped,
+            time.as_secs_f64()
+        );
+        for (test_name, result) in results {
+            output.push_str(&Self::render_testcase(test_name, result));
+        }
+        output.push_str("</testsuite>");
+        output
+    }
+
+    fn render_testcase(test_name: &str, result: &TestResult) -> String {
+        let time = result.duration().unwrap_or_default().as_secs_f64();
+        let open = format!(
+            r#"<testcase name="{}" classname="protest" time="{:.3}">"#,
+            xml_escape(test_name),
+            time
+        );
+
+        let body = match result {
+            TestResult::Passed {
+                iterations, seed, ..
+            } => {
+                let seed = seed.map(|s| s.to_string()).unwrap_or_else(|| "none".into());
+                format!(
+                    "<system-out>{}</system-out>",
+                    xml_escape(&format!("iterations: {}, seed: {}", iterations, seed))
+                )
+            }
+            TestResult::Failed {
+                error,
+                original_input,
+                shrunk_input,
+                seed,
+                ..
+            } => {
+                let minimal = shrunk_input.as_ref().unwrap_or(original_input);
+                let seed = seed.map(|s| s.to_string()).unwrap_or_else(|| "none".into());
+                let message = format!(
+                    "{}; minimal counterexample: {}; seed: {}",
+                    error, minimal, seed
+                );
+                format!(
+                    r#"<failure message="{}" type="PropertyFailure">{}</failure>"#,
+                    xml_escape(&message),
+                    xml_escape(&result.to_string())
+                )
+            }
+            TestResult::Skipped { reason } => {
+                format!(r#"<skipped message="{}"/>"#, xml_escape(reason))
+            }
+        };
+
+        format!("{}{}</testcase>", open, body)
+    }
+}
+
+impl TestOutputFormatter for JUnitFormatter {
+    fn format_test_start(&self, _test_name: &str) -> String {
+        String::new()
+    }
+
+    fn format_test_success(&self, test_name: &str, result: &TestResult) -> String {
+        Self::render_suite(test_name, &[(test_name, result)])
+    }
+
+    fn format_test_failure(&self, test_name: &str, result: &TestResult) -> String {
+        Self::render_suite(test_name, &[(test_name, result)])
+    }
+
+    fn format_test_skipped(&self, test_name: &str, result: &TestResult) -> String {
+        Self::render_suite(test_name, &[(test_name, result)])
+    }
+}
+
+/// Escape a string for use in XML text and attribute values
+///
+/// Characters XML 1.0 does not allow, even as character references, such as
+/// most C0 controls, are replaced with U+FFFD.
+fn xml_escape(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\t' | '\r' => escaped.push(c),
+            c if (c as u32) < 0x20 || c == '\u{FFFE}' || c == '\u{FFFF}' => {
+                escaped.push(char::REPLACEMENT_CHARACTER)
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 /// Test execution context for custom test runners
 pub struct TestContext {
     pub test_name: String,
@@ -458,6 +946,26 @@ impl TestContext {
         }
     }
 
+    /// Create a test context with JUnit XML output
+    pub fn with_junit_output(test_name: String) -> Self {
+        Self {
+            test_name,
+            formatter: Box::new(JUnitFormatter),
+            capture_output: false,
+            verbose: false,
+        }
+    }
+
+    /// Create a test context with TAP output
+    pub fn with_tap_output(test_name: String) -> Self {
+        Self {
+            test_name,
+            formatter: Box::new(TapFormatter::with_plan(1)),
+            capture_output: false,
+            verbose: false,
+        }
+    }
+
     /// Create a test context with custom formatter
     pub fn with_formatter(test_name: String, formatter: Box<dyn TestOutputFormatter>) -> Self {
         Self {
@@ -597,6 +1105,7 @@ mod tests {
             shrink_steps: 3,
             seed: Some(123),
             duration: Duration::from_millis(200),
+            shrink_duration: Duration::from_millis(20),
         };
 
         let failure = formatter.format_test_failure("my_test", &result);
@@ -605,6 +1114,139 @@ mod tests {
         assert!(failure.contains("FAILED"));
     }
 
+    #[test]
+    fn test_pretty_debug_lines_splits_fields() {
+        let lines = pretty_debug_lines(r#"Config { name: "a, {b}", ports: [80, 443], extra: [] }"#);
+        assert_eq!(
+            lines,
+            vec![
+                "Config {",
+                r#"    name: "a, {b}","#,
+                "    ports: [",
+                "        80,",
+                "        443",
+                "    ],",
+                "    extra: []",
+                "}",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verbose_formatter_diff_highlights_changed_fields() {
+        let diff = VerboseFormatter::render_diff(
+            r#"Config { name: "server", port: 8080, debug: true }"#,
+            r#"Config { name: "", port: 8080, debug: true }"#,
+            false,
+        );
+        let expected = [
+            "",
+            "  Diff (- original, + minimal):",
+            "    Config {",
+            r#"  -     name: "server","#,
+            r#"  +     name: "","#,
+            "        port: 8080,",
+            "        debug: true",
+            "    }",
+        ]
+        .join("\n");
+        assert_eq!(diff, expected);
+
+        let colored = VerboseFormatter::render_diff("1", "0", true);
+        assert!(colored.contains("\x1b[31m- 1\x1b[0m"));
+        assert!(colored.contains("\x1b[32m+ 0\x1b[0m"));
+    }
+
+    #[test]
+    fn test_diff_lines_finds_a_longest_common_subsequence() {
+        // Small alphabets give many repeated lines and competing alignments
+        let mut state = 0x2545_f491_u64;
+        let mut next = move |bound: u64| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) % bound
+        };
+
+        for _ in 0..200 {
+            let original: Vec<String> = (0..next(12)).map(|_| next(4).to_string()).collect();
+            let minimal: Vec<String> = (0..next(12)).map(|_| next(4).to_string()).collect();
+            let diff = diff_lines(&original, &minimal).unwrap();
+
+            let left: Vec<&str> = diff
+                .iter()
+                .filter_map(|line| match line {
+                    DiffLine::Same(l) | DiffLine::Removed(l) => Some(*l),
+                    DiffLine::Added(_) => None,
+                })
+                .collect();
+            let right: Vec<&str> = diff
+                .iter()
+                .filter_map(|line| match line {
+                    DiffLine::Same(l) | DiffLine::Added(l) => Some(*l),
+                    DiffLine::Removed(_) => None,
+                })
+                .collect();
+            assert_eq!(left, original);
+            assert_eq!(right, minimal);
+
+            let same = diff
+                .iter()
+                .filter(|line| matches!(line, DiffLine::Same(_)))
+                .count();
+            let lcs = lcs_lengths(original.iter(), minimal.iter())[minimal.len()];
+            assert_eq!(same, lcs, "{:?} vs {:?}", original, minimal);
+        }
+    }
+
+    #[test]
+    fn test_diff_lines_handles_large_values() {
+        // Long shared runs around the changes are trimmed before diffing
+        let original: Vec<String> = (0..50_000).map(|i| format!("{},", i)).collect();
+        let mut minimal = original.clone();
+        minimal[20_000] = "changed,".to_string();
+        minimal.remove(20_500);
+
+        let diff = diff_lines(&original, &minimal).unwrap();
+        let changed = diff
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Same(_)))
+            .count();
+        assert_eq!(changed, 3);
+
+        // Values that differ throughout are not diffed at all
+        let minimal: Vec<String> = (0..50_000).map(|i| format!("{};", i)).collect();
+        assert!(diff_lines(&original, &minimal).is_none());
+
+        let rendered = VerboseFormatter::render_diff(
+            &format!("{:?}", (0..5_000).collect::<Vec<_>>()),
+            &format!("{:?}", (1..5_001).rev().collect::<Vec<_>>()),
+            false,
+        );
+        assert!(rendered.ends_with(
+            "(inputs too large to diff; compare the original and minimal inputs above)"
+        ));
+    }
+
+    #[test]
+    fn test_verbose_formatter_failure_includes_diff() {
+        let formatter = VerboseFormatter;
+        let result = TestResult::Failed {
+            error: "Property failed".to_string(),
+            original_input: "[5, 9, 2]".to_string(),
+            shrunk_input: Some("[9]".to_string()),
+            shrink_steps: 2,
+            seed: None,
+            duration: Duration::from_millis(1),
+            shrink_duration: Duration::from_millis(1),
+        };
+
+        let failure = formatter.format_test_failure("my_test", &result);
+        assert!(failure.contains("Diff (- original, + minimal):"));
+        assert!(failure.contains("5,"));
+        assert!(failure.contains("    9"));
+    }
+
     #[test]
     fn test_json_formatter() {
         let formatter = JsonFormatter;
@@ -617,10 +1259,194 @@ mod tests {
         let success = formatter.format_test_success("my_test", &result);
         assert!(success.contains(r#""event":"ok""#));
         assert!(success.contains(r#""name":"my_test""#));
+        assert!(success.contains(r#""passed":true"#));
         assert!(success.contains(r#""iterations":100"#));
         assert!(success.contains(r#""seed":42"#));
     }
 
+    #[test]
+    fn test_json_formatter_failure_includes_shrink_details() {
+        let formatter = JsonFormatter;
+        let result = TestResult::Failed {
+            error: "Property failed".to_string(),
+            original_input: "[42, 17]".to_string(),
+            shrunk_input: Some("[1]".to_string()),
+            shrink_steps: 7,
+            seed: Some(123),
+            duration: Duration::from_millis(200),
+            shrink_duration: Duration::from_millis(35),
+        };
+
+        let failure = formatter.format_test_failure("my_test", &result);
+        assert!(failure.contains(r#""event":"failed""#));
+        assert!(failure.contains(r#""passed":false"#));
+        assert!(failure.contains(r#""seed":123"#));
+        assert!(failure.contains(r#""duration_ms":200"#));
+        assert!(failure.contains(r#""original_input":"[42, 17]""#));
+        assert!(failure.contains(r#""minimal_input":"[1]""#));
+        assert!(failure.contains(r#""shrink_steps":7"#));
+        assert!(failure.contains(r#""shrink_duration_ms":35"#));
+    }
+
+    #[test]
+    fn test_json_formatter_uses_null_for_missing_values() {
+        let formatter = JsonFormatter;
+        let result = TestResult::Failed {
+            error: "Property failed".to_string(),
+            original_input: "0".to_string(),
+            shrunk_input: None,
+            shrink_steps: 0,
+            seed: None,
+            duration: Duration::from_millis(1),
+            shrink_duration: Duration::from_millis(0),
+        };
+
+        let failure = formatter.format_test_failure("my_test", &result);
+        assert!(failure.contains(r#""seed":null"#));
+        assert!(failure.contains(r#""minimal_input":null"#));
+    }
+
+    #[test]
+    fn test_json_formatter_escapes_debug_output() {
+        let formatter = JsonFormatter;
+        let result = TestResult::Failed {
+            error: "bad\nvalue".to_string(),
+            original_input: r#""quoted\path""#.to_string(),
+            shrunk_input: Some("\"\"".to_string()),
+            shrink_steps: 1,
+            seed: Some(1),
+            duration: Duration::from_millis(1),
+            shrink_duration: Duration::from_millis(1),
+        };
+
+        let failure = formatter.format_test_failure("my_test", &result);
+        assert!(failure.contains(r#""error":"bad\nvalue""#));
+        assert!(failure.contains(r#""original_input":"\"quoted\\path\"""#));
+        assert!(failure.contains(r#""minimal_input":"\"\"""#));
+        assert!(!failure.contains('\n'));
+    }
+
+    #[test]
+    fn test_tap_formatter_numbers_results() {
+        let formatter = TapFormatter::with_plan(3);
+        let passed = TestResult::Passed {
+            iterations: 100,
+            duration: Duration::from_millis(5),
+            seed: Some(1),
+        };
+        let skipped = TestResult::Skipped {
+            reason: "not on CI".to_string(),
+        };
+
+        assert_eq!(formatter.format_test_start("a"), "TAP version 13\n1..3\n");
+        assert_eq!(formatter.format_test_success("a", &passed), "ok 1 - a");
+        assert_eq!(formatter.format_test_start("b"), "");
+        assert_eq!(
+            formatter.format_test_skipped("b # c", &skipped),
+            "ok 2 - b \\# c # SKIP not on CI"
+        );
+        assert_eq!(formatter.plan(), "1..2");
+    }
+
+    #[test]
+    fn test_tap_formatter_failure_yaml_block() {
+        let formatter = TapFormatter::new();
+        let result = TestResult::Failed {
+            error: "Property failed: x: 1".to_string(),
+            original_input: "Config { name: \"a\\nb\" }".to_string(),
+            shrunk_input: Some("Config { name: \"\" }".to_string()),
+            shrink_steps: 2,
+            seed: Some(99),
+            duration: Duration::from_millis(12),
+            shrink_duration: Duration::from_millis(3),
+        };
+
+        assert_eq!(formatter.format_test_start("t"), "");
+        let output = formatter.format_test_failure("t", &result);
+        let expected = [
+            "not ok 1 - t",
+            "  ---",
+            r#"  message: "Property failed: x: 1""#,
+            "  seed: 99",
+            r#"  original_input: "Config { name: \"a\\nb\" }""#,
+            r#"  minimal_input: "Config { name: \"\" }""#,
+            "  shrink_steps: 2",
+            "  duration_ms: 12",
+            "  ...",
+        ]
+        .join("\n");
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_junit_formatter_success() {
+        let formatter = JUnitFormatter;
+        let result = TestResult::Passed {
+            iterations: 100,
+            duration: Duration::from_millis(1500),
+            seed: Some(42),
+        };
+
+        assert_eq!(formatter.format_test_start("my_test"), "");
+        let xml = formatter.format_test_success("my_test", &result);
+        assert!(xml.starts_with(r#"<testsuite name="my_test" tests="1" failures="0""#));
+        assert!(xml.contains(r#"<testcase name="my_test" classname="protest" time="1.500">"#));
+        assert!(xml.contains("iterations: 100, seed: 42"));
+        assert!(xml.ends_with("</testcase></testsuite>"));
+    }
+
+    #[test]
+    fn test_junit_formatter_failure_reports_counterexample_and_seed() {
+        let formatter = JUnitFormatter;
+        let result = TestResult::Failed {
+            error: "x < 10".to_string(),
+            original_input: "\"long & winding\"".to_string(),
+            shrunk_input: Some("\"a\"".to_string()),
+            shrink_steps: 4,
+            seed: Some(7),
+            duration: Duration::from_millis(20),
+            shrink_duration: Duration::from_millis(5),
+        };
+
+        let xml = formatter.format_test_failure("my_test", &result);
+        assert!(xml.contains(r#"failures="1""#));
+        assert!(xml.contains(
+            r#"<failure message="x &lt; 10; minimal counterexample: &quot;a&quot;; seed: 7" type="PropertyFailure">"#
+        ));
+        assert!(xml.contains("&quot;long &amp; winding&quot;"));
+        assert!(!xml.contains("x < 10"));
+    }
+
+    #[test]
+    fn test_xml_escape_replaces_characters_xml_forbids() {
+        assert_eq!(
+            xml_escape("a\u{0}b\u{1b}c\u{FFFF}"),
+            "a\u{FFFD}b\u{FFFD}c\u{FFFD}"
+        );
+        assert_eq!(xml_escape("tab\tline\n"), "tab\tline&#10;");
+    }
+
+    #[test]
+    fn test_junit_formatter_suite_aggregates_results() {
+        let formatter = JUnitFormatter;
+        let passed = TestResult::Passed {
+            iterations: 10,
+            duration: Duration::from_millis(100),
+            seed: None,
+        };
+        let skipped = TestResult::Skipped {
+            reason: "slow".to_string(),
+        };
+
+        let xml = formatter.format_suite("props", &[("a", &passed), ("b", &skipped)]);
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        assert!(xml.contains(
+            r#"<testsuite name="props" tests="2" failures="0" errors="0" skipped="1" time="0.100">"#
+        ));
+        assert!(xml.contains(r#"<skipped message="slow"/>"#));
+        assert_eq!(xml.matches("<testcase ").count(), 2);
+    }
+
     #[test]
     fn test_test_context_creation() {
         let context = TestContext::new("test_name".to_string());
@@ -629,5 +1455,12 @@ mod tests {
         let json_context = TestContext::with_json_output("json_test".to_string());
         assert_eq!(json_context.test_name, "json_test");
         assert!(!json_context.verbose);
+
+        let junit_context = TestContext::with_junit_output("junit_test".to_string());
+        assert_eq!(junit_context.test_name, "junit_test");
+        assert!(!junit_context.capture_output);
+
+        let tap_context = TestContext::with_tap_output("tap_test".to_string());
+        assert_eq!(tap_context.test_name, "tap_test");
     }
 }
@@ -41,6 +41,28 @@ mod coverage_tests {
         assert_eq!(size, 3);
     }
 
+    #[test]
+    fn test_coverage_corpus_keeps_admitting_new_paths_past_100() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = CoverageCorpusConfig::new(temp_dir.path());
+        let mut corpus = CoverageCorpus::new(config).unwrap();
+
+        for i in 0..250u64 {
+            assert!(corpus.try_add(&i, i).unwrap(), "path {} was rejected", i);
+        }
+        assert!(!corpus.try_add(&0u64, 0).unwrap());
+        assert_eq!(corpus.corpus_size().unwrap(), 250);
+
+        // An explicit 1% threshold still caps admission at 100 known paths
+        let temp_dir = TempDir::new().unwrap();
+        let config = CoverageCorpusConfig::new(temp_dir.path()).with_min_coverage(1.0);
+        let mut corpus = CoverageCorpus::new(config).unwrap();
+        let added = (0..250u64)
+            .filter(|&i| corpus.try_add(&i, i).unwrap())
+            .count();
+        assert_eq!(added, 101);
+    }
+
     #[test]
     fn test_coverage_corpus_stats() {
         let temp_dir = TempDir::new().unwrap();
@@ -56,6 +78,22 @@ mod coverage_tests {
         assert_eq!(stats.corpus_size, 2);
     }
 
+    #[test]
+    fn test_coverage_stats_track_path_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = CoverageCorpusConfig::new(temp_dir.path());
+
+        let mut corpus = CoverageCorpus::new(config).unwrap();
+
+        corpus.try_add(&1, 10).unwrap();
+        corpus.try_add(&2, 10).unwrap();
+        corpus.try_add(&3, 20).unwrap();
+
+        let stats = corpus.stats();
+        assert_eq!(stats.path_history, vec![1, 1, 2]);
+        assert!(stats.report().contains("Unique paths: 2"));
+    }
+
     #[test]
     fn test_path_hash_consistency() {
         // Same values should produce same hash
@@ -118,4 +156,65 @@ mod coverage_tests {
         assert_eq!(by_coverage[1].1, 2); // input_b has 2 paths
         assert_eq!(by_coverage[2].1, 1); // input_c has 1 path
     }
+
+    #[test]
+    fn test_coverage_corpus_rejects_known_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut corpus = CoverageCorpus::new(CoverageCorpusConfig::new(temp_dir.path())).unwrap();
+
+        assert!(corpus.try_add(&1, 100).unwrap());
+        assert!(!corpus.try_add(&2, 100).unwrap());
+        assert_eq!(corpus.corpus_size().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_coverage_guide_mutates_from_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let corpus = CoverageCorpus::new(CoverageCorpusConfig::new(temp_dir.path())).unwrap();
+        let mut guide = CoverageGuide::new(corpus).with_mutation_probability(1.0);
+        let generator = IntGenerator::new(0, 1_000_000);
+
+        assert!(guide.observe(&500_000, 1).unwrap());
+        assert!(!guide.observe(&12, 1).unwrap());
+        assert_eq!(guide.entries(), &[500_000]);
+
+        let mut rng = create_seeded_rng(3);
+        let config = GeneratorConfig::default();
+        for _ in 0..20 {
+            let input = guide.next_input(&generator, &mut rng, &config);
+            assert!(input < 500_000);
+        }
+    }
+
+    struct MagnitudeProperty;
+    impl Property<i32> for MagnitudeProperty {
+        type Output = ();
+        fn test(&self, x: i32) -> Result<(), PropertyError> {
+            match x {
+                0..=9 => protest::coverage::record("tiny"),
+                10..=99 => protest::coverage::record("small"),
+                _ => protest::coverage::record("large"),
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_builder_coverage_guided_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = CoverageCorpusConfig::new(temp_dir.path()).with_min_coverage(0.0);
+        let corpus = CoverageCorpus::new(config.clone()).unwrap();
+
+        let result = PropertyTestBuilder::new()
+            .iterations(200)
+            .seed(11)
+            .coverage_guided(corpus)
+            .run(IntGenerator::new(0, 1000), MagnitudeProperty);
+        assert!(result.is_ok());
+
+        // One corpus entry per distinct path reached, never more
+        let mut reopened = CoverageCorpus::new(config).unwrap();
+        let size = reopened.corpus_size().unwrap();
+        assert!((1..=3).contains(&size));
+    }
 }
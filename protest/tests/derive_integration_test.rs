@@ -33,10 +33,127 @@ struct GenericStruct<T, U> {
 #[derive(Debug, Clone, PartialEq, protest::Generator)]
 struct TupleStruct(u32, String);
 
+// Test derivation with smart pointer fields
+#[derive(Debug, Clone, PartialEq, protest::Generator)]
+struct PointerStruct {
+    boxed: Box<u32>,
+    shared: std::sync::Arc<String>,
+    local: std::rc::Rc<bool>,
+    label: std::borrow::Cow<'static, str>,
+}
+
+// Test recursive enum derivation through Box
+#[derive(Debug, Clone, PartialEq, protest::Generator)]
+enum Tree {
+    Leaf(i32),
+    Node(Box<Tree>, Box<Tree>),
+}
+
+impl Tree {
+    fn depth(&self) -> usize {
+        match self {
+            Tree::Leaf(_) => 1,
+            Tree::Node(left, right) => 1 + left.depth().max(right.depth()),
+        }
+    }
+}
+
+// Test derivation with a Duration field
+#[derive(Debug, Clone, PartialEq, protest::Generator)]
+struct TimedStruct {
+    name: String,
+    timeout: std::time::Duration,
+}
+
+// Test derivation with every numeric primitive
+#[derive(Debug, Clone, PartialEq, protest::Generator)]
+struct NumericStruct {
+    small: i8,
+    short: i16,
+    long: i64,
+    huge: i128,
+    index: isize,
+    byte: u8,
+    port: u16,
+    count: u64,
+    id: u128,
+    len: usize,
+    ratio: f32,
+    #[generator(range = "10..20")]
+    bounded: u64,
+}
+
+// Test derivation with Option, Vec, String and tuple fields
+#[derive(Debug, Clone, PartialEq, protest::Generator)]
+struct CompositeStruct {
+    nickname: Option<String>,
+    #[generator(range = "1..5")]
+    retries: Option<u32>,
+    tags: Vec<String>,
+    #[generator(length = "2..4")]
+    scores: Vec<u8>,
+    point: (i32, i32),
+    entry: (u8, String, bool),
+}
+
+// Test derivation with fields that are pinned rather than generated
+#[derive(Debug, Clone, PartialEq, protest::Generator)]
+struct PinnedStruct {
+    id: u32,
+    #[generator(default)]
+    cache: Vec<u8>,
+    #[generator(skip)]
+    label: Option<String>,
+    #[generator(value = "String::from(\"v1\")")]
+    schema: String,
+    #[generator(value = "MAX_RETRIES * 2")]
+    retries: u32,
+    #[generator(constant = "2")]
+    version: u16,
+}
+
+const MAX_RETRIES: u32 = 3;
+
+// Test weighted enum variant selection
+#[derive(Debug, Clone, PartialEq, protest::Generator)]
+enum WeightedStatus {
+    #[generator(weight = 20)]
+    Active,
+    Error,
+    #[allow(dead_code)] // weight 0: never generated
+    #[generator(weight = 0)]
+    Retired,
+}
+
+// Test enum shrinking towards simpler variants
+#[derive(Debug, Clone, PartialEq, protest::Generator)]
+enum Shape {
+    Polygon {
+        sides: u8,
+        closed: bool,
+    },
+    Circle(u32),
+    Point,
+    Square(u32),
+    Empty,
+    #[allow(dead_code)] // weight 0: never generated or proposed
+    #[generator(weight = 0)]
+    Invalid,
+}
+
 // Test unit struct derivation
 #[derive(Debug, Clone, PartialEq, protest::Generator)]
 struct UnitStruct;
 
+// Test nesting derived types inside another derived type
+#[derive(Debug, Clone, PartialEq, protest::Generator)]
+struct NestedStruct {
+    simple: SimpleStruct,
+    many: Vec<TupleStruct>,
+    maybe: Option<SimpleEnum>,
+    generic: GenericStruct<SimpleStruct, u8>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,9 +253,256 @@ mod tests {
         let config = GeneratorConfig::default();
 
         let instance = generator.generate(&mut rng, &config);
+        for shrunk in generator.shrink(&instance) {
+            // Each candidate shrinks exactly one field
+            let changed = [
+                shrunk.id != instance.id,
+                shrunk.name != instance.name,
+                shrunk.active != instance.active,
+            ];
+            assert_eq!(changed.iter().filter(|&&c| c).count(), 1);
+        }
+
+        let instance = SimpleStruct {
+            id: 100,
+            name: "hello".to_string(),
+            active: true,
+        };
+        let shrinks: Vec<_> = generator.shrink(&instance).collect();
+        assert!(!shrinks.is_empty());
+        assert!(shrinks.iter().any(|shrunk| shrunk.id < 100));
+        assert!(shrinks.iter().any(|shrunk| shrunk.name.len() < 5));
+        assert!(shrinks.iter().any(|shrunk| !shrunk.active));
+
+        // Fields already at their minimum have nothing left to shrink
+        let minimal = SimpleStruct {
+            id: 0,
+            name: String::new(),
+            active: false,
+        };
+        assert_eq!(generator.shrink(&minimal).count(), 0);
+    }
+
+    #[test]
+    fn test_pointer_struct_derivation() {
+        let generator = PointerStructGenerator::default();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..10 {
+            let instance = generator.generate(&mut rng, &config);
+            assert!(instance.shared.len() <= 20);
+            assert!(instance.label.len() <= 20);
+        }
+    }
+
+    #[test]
+    fn test_duration_field_derivation() {
+        let generator = TimedStructGenerator::default();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..10 {
+            let instance = generator.generate(&mut rng, &config);
+            assert!(instance.timeout <= std::time::Duration::from_secs(3600));
+        }
+    }
+
+    #[test]
+    fn test_default_registry_overrides_field_generation() {
+        use protest::{ConstantGenerator, GeneratorRegistry};
+
+        GeneratorRegistry::register_default::<String, _>(ConstantGenerator::new(
+            "registered".to_string(),
+        ));
+
+        let generator = SimpleStructGenerator::default();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+        for _ in 0..10 {
+            let instance = generator.generate(&mut rng, &config);
+            assert_eq!(instance.name, "registered");
+        }
+
+        // Tuple struct fields consult the registry too
+        let tuple = TupleStructGenerator::default().generate(&mut rng, &config);
+        assert_eq!(tuple.1, "registered");
+
+        assert!(GeneratorRegistry::remove_default::<String>());
+    }
+
+    #[test]
+    fn test_numeric_struct_derivation() {
+        let generator = NumericStructGenerator::default();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..10 {
+            let instance = generator.generate(&mut rng, &config);
+            assert!((10..=20).contains(&instance.bounded));
+            assert!((-1000.0..=1000.0).contains(&instance.ratio));
+        }
+    }
+
+    #[test]
+    fn test_composite_struct_derivation() {
+        let generator = CompositeStructGenerator::default();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..20 {
+            let instance = generator.generate(&mut rng, &config);
+            assert!(instance.retries.is_none_or(|r| (1..=5).contains(&r)));
+            assert!(instance.tags.len() <= config.size_hint);
+            assert!((2..=4).contains(&instance.scores.len()));
+        }
+    }
+
+    #[test]
+    fn test_recursive_enum_is_bounded_by_max_depth() {
+        let generator = TreeGenerator::default();
+        let mut rng = thread_rng();
+
+        for max_depth in [1, 2, 5] {
+            let config = GeneratorConfig {
+                max_depth,
+                ..GeneratorConfig::default()
+            };
+            for _ in 0..200 {
+                let tree = generator.generate(&mut rng, &config);
+                assert!(tree.depth() <= max_depth, "{:?} is too deep", tree);
+            }
+        }
+
+        // Depth 1 leaves no room for the recursive variant
+        let config = GeneratorConfig {
+            max_depth: 1,
+            ..GeneratorConfig::default()
+        };
+        assert!(matches!(
+            generator.generate(&mut rng, &config),
+            Tree::Leaf(_)
+        ));
+    }
+
+    #[test]
+    fn test_pinned_fields_derivation() {
+        let generator = PinnedStructGenerator::default();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..10 {
+            let instance = generator.generate(&mut rng, &config);
+            assert!(instance.cache.is_empty());
+            assert_eq!(instance.label, None);
+            assert_eq!(instance.schema, "v1");
+            assert_eq!(instance.retries, 6);
+            assert_eq!(instance.version, 2);
+
+            for shrunk in generator.shrink(&instance) {
+                assert_eq!(shrunk.version, 2);
+            }
+        }
+
+        let instance = PinnedStruct {
+            id: 50,
+            cache: vec![1, 2, 3],
+            label: Some("label".to_string()),
+            schema: "v1".to_string(),
+            retries: 6,
+            version: 7,
+        };
         let shrinks: Vec<_> = generator.shrink(&instance).collect();
+        assert!(shrinks.iter().any(|shrunk| shrunk.id < 50));
+        // `value` fields shrink, while constant and default fields never do
+        assert!(shrinks.iter().any(|shrunk| shrunk.retries < 6));
+        for shrunk in &shrinks {
+            assert_eq!(shrunk.version, 7);
+            assert_eq!(shrunk.cache, vec![1, 2, 3]);
+            assert_eq!(shrunk.label.as_deref(), Some("label"));
+        }
+    }
+
+    #[test]
+    fn test_weighted_enum_derivation() {
+        let generator = WeightedStatusGenerator::default();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let mut active = 0;
+        let mut error = 0;
+        for _ in 0..2100 {
+            match generator.generate(&mut rng, &config) {
+                WeightedStatus::Active => active += 1,
+                WeightedStatus::Error => error += 1,
+                WeightedStatus::Retired => panic!("zero-weight variant was generated"),
+            }
+        }
+
+        // Expected ratio is 20:1, so roughly 2000 vs 100
+        assert!(error > 0);
+        assert!(active > error * 8);
+    }
+
+    #[test]
+    fn test_enum_shrinks_to_simpler_variants_first() {
+        let generator = ShapeGenerator::default();
+        let polygon = Shape::Polygon {
+            sides: 5,
+            closed: true,
+        };
+
+        // Unit variants, then tuple variants, each in declaration order
+        let shrinks: Vec<_> = generator.shrink(&polygon).collect();
+        assert_eq!(shrinks.len(), 4);
+        assert_eq!(shrinks[0], Shape::Point);
+        assert_eq!(shrinks[1], Shape::Empty);
+        assert!(matches!(shrinks[2], Shape::Circle(_)));
+        assert!(matches!(shrinks[3], Shape::Square(_)));
+
+        // Shrinking is deterministic
+        assert_eq!(shrinks, generator.shrink(&polygon).collect::<Vec<_>>());
+
+        let shrinks: Vec<_> = generator.shrink(&Shape::Square(7)).collect();
+        assert_eq!(shrinks.len(), 3);
+        assert!(matches!(shrinks[2], Shape::Circle(_)));
+
+        assert_eq!(
+            generator.shrink(&Shape::Empty).collect::<Vec<_>>(),
+            vec![Shape::Point]
+        );
+        assert_eq!(generator.shrink(&Shape::Point).count(), 0);
+    }
+
+    #[test]
+    fn test_nested_derived_types() {
+        let strategy = NestedStruct::arbitrary();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        for _ in 0..10 {
+            let instance = Strategy::generate(&strategy, &mut rng, &config);
+            assert!(instance.many.len() <= config.size_hint);
+        }
+    }
+
+    #[test]
+    fn test_derived_types_are_auto_generated() {
+        use protest::ergonomic::AutoGen;
+
+        let generator = <Vec<SimpleStruct> as AutoGen>::auto_generator();
+        let mut rng = thread_rng();
+        let config = GeneratorConfig::default();
+
+        let values = Generator::generate(&generator, &mut rng, &config);
+        assert!(values.len() <= 100);
 
-        // For now, shrinking returns empty iterator (basic implementation)
-        assert!(shrinks.is_empty());
+        let generator = NestedStruct::auto_generator();
+        let value = Generator::generate(&generator, &mut rng, &config);
+        let shrinks: Vec<_> = Generator::shrink(&generator, &value).collect();
+        assert_eq!(
+            shrinks,
+            NestedStruct::arbitrary().shrink(&value).collect::<Vec<_>>()
+        );
     }
 }
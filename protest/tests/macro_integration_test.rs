@@ -291,6 +291,7 @@ mod output_integration_tests {
             shrink_steps: 5,
             seed: Some(123),
             duration: Duration::from_millis(200),
+            shrink_duration: Duration::from_millis(50),
         };
 
         let failure_output = format!("{}", failure_result);
@@ -42,6 +42,7 @@ fn test_parallel_execution_basic() {
         enabled: true,
         num_threads: 2,
         batch_size: 5,
+        pregenerate_inputs: false,
     };
 
     let result = check_parallel(generator, property, config, parallel_config);
@@ -70,6 +71,7 @@ fn test_parallel_vs_sequential_performance() {
         enabled: true,
         num_threads: 4,
         batch_size: 10,
+        pregenerate_inputs: false,
     };
 
     let par_result = check_parallel(generator, par_property, config, parallel_config);
@@ -215,4 +215,136 @@ mod persistence_tests {
             "Fixed failure should have been cleaned up"
         );
     }
+
+    // Property that rejects every input through `assume`
+    struct RejectsEverything;
+    impl protest::Property<u32> for RejectsEverything {
+        type Output = ();
+        fn test(&self, _x: u32) -> Result<(), protest::PropertyError> {
+            protest::assume(false);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_replay_treats_rejected_input_as_not_replayed() {
+        let temp_dir = TempDir::new().unwrap();
+        let failure_dir = temp_dir.path().join("failures");
+        let persistence_cfg = PersistenceConfig::enabled().with_failure_dir(failure_dir.clone());
+
+        use protest::Arbitrary;
+        let result = PropertyTestBuilder::new()
+            .persistence_config(persistence_cfg.clone())
+            .test_name("rejected_replay_test")
+            .iterations(10)
+            .seed(12345)
+            .run(u32::arbitrary(), FailsOver50);
+        assert!(result.is_err());
+
+        // Replaying through a property that calls `assume` must not panic,
+        // and a rejected replay is neither still failing nor fixed
+        let result = PropertyTestBuilder::new()
+            .persistence_config(persistence_cfg)
+            .test_name("rejected_replay_test")
+            .iterations(10)
+            .run(u32::arbitrary(), RejectsEverything);
+        assert!(matches!(
+            result.unwrap_err().error,
+            PropertyError::TooManyRejections { .. }
+        ));
+
+        let snapshot = FailureSnapshot::new(&failure_dir).unwrap();
+        let failures = snapshot.load_failures("rejected_replay_test").unwrap();
+        assert!(
+            !failures.is_empty(),
+            "Rejected replay must not be cleaned up"
+        );
+    }
+
+    struct FailsOver1000;
+    impl protest::Property<u32> for FailsOver1000 {
+        type Output = ();
+        fn test(&self, x: u32) -> Result<(), protest::PropertyError> {
+            if x > 1000 {
+                Err(protest::PropertyError::property_failed("Value too large"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_reproduce_saved_failure() {
+        let temp_dir = TempDir::new().unwrap();
+        let failure_dir = temp_dir.path().join("failures");
+        let persistence_cfg = PersistenceConfig::enabled().with_failure_dir(failure_dir.clone());
+
+        use protest::Arbitrary;
+        let original = PropertyTestBuilder::new()
+            .persistence_config(persistence_cfg)
+            .test_name("reproduce_test")
+            .iterations(50)
+            .seed(4242)
+            .run(u32::arbitrary(), FailsOver1000)
+            .unwrap_err();
+
+        let snapshot = FailureSnapshot::new(&failure_dir).unwrap();
+        let failures = snapshot.load_failures("reproduce_test").unwrap();
+        assert_eq!(failures.len(), 1);
+
+        // Still failing: the same input is regenerated and reported
+        let test = PropertyTest::new(u32::arbitrary(), FailsOver1000, TestConfig::default());
+        let reproduced = test.reproduce(&failures[0]).unwrap_err();
+        assert_eq!(reproduced.original_input, original.original_input);
+        assert_eq!(reproduced.failed_iteration, original.failed_iteration);
+        assert_eq!(reproduced.config.seed, Some(4242));
+
+        // Fixed: the property passes and the run succeeds
+        let test = PropertyTest::new(u32::arbitrary(), AlwaysPass, TestConfig::default());
+        assert_eq!(test.reproduce(&failures[0]).unwrap().iterations, 1);
+    }
+
+    #[test]
+    fn test_reproduce_keeps_custom_ranges() {
+        let temp_dir = TempDir::new().unwrap();
+        let failure_dir = temp_dir.path().join("failures");
+        let persistence_cfg = PersistenceConfig::enabled().with_failure_dir(failure_dir.clone());
+
+        let mut config = TestConfig {
+            iterations: 50,
+            seed: Some(99),
+            ..TestConfig::default()
+        };
+        config.generator_config = config.generator_config.with_custom_range(990u32..=1010);
+
+        let original = PropertyTest::with_full_config(
+            protest::IntGenerator::<u32>::full_range(),
+            FailsOver1000,
+            config.clone(),
+            protest::error::ErrorReporter::default(),
+            None,
+            Some(persistence_cfg),
+            Some("custom_range_test".to_string()),
+        )
+        .run()
+        .unwrap_err();
+        assert!((1001..=1010).contains(&original.original_input));
+
+        let snapshot = FailureSnapshot::new(&failure_dir).unwrap();
+        let failures = snapshot.load_failures("custom_range_test").unwrap();
+        assert_eq!(failures.len(), 1);
+
+        // The replayed config still carries the custom range, so the same input comes back
+        let test = PropertyTest::new(
+            protest::IntGenerator::<u32>::full_range(),
+            FailsOver1000,
+            config,
+        );
+        let reproduced = test.reproduce(&failures[0]).unwrap_err();
+        assert_eq!(reproduced.original_input, original.original_input);
+        assert_eq!(
+            reproduced.config.generator_config.custom_range::<u32>(),
+            Some(&(990..=1010))
+        );
+    }
 }
@@ -49,6 +49,19 @@ fn test_absolute_value_non_negative(x: i32) {
     }
 }
 
+#[property_test(iterations = 30, max_rejections = 1000)]
+fn test_assume_discards_inputs(x: i32) {
+    protest::assume(x % 2 == 0);
+    assert_eq!(x % 2, 0);
+}
+
+#[property_test(iterations = 30, max_rejections = 1000)]
+async fn test_async_assume_discards_inputs(x: i32) {
+    tokio::task::yield_now().await;
+    protest::assume(x % 2 == 0);
+    assert_eq!(x % 2, 0);
+}
+
 #[property_test(shrink_timeout_secs = 5)]
 fn test_char_is_valid(c: char) {
     // All generated chars should be valid Unicode
@@ -62,6 +75,23 @@ fn test_three_params(a: i32, b: u32, c: String) {
     let _result = a.wrapping_add(b as i32).wrapping_add(c.len() as i32);
 }
 
+// Explicit per-parameter generators
+#[property_test(iterations = 50)]
+fn test_strategy_override_single(#[strategy(protest::range(1, 100))] x: i32) {
+    assert!((1..=100).contains(&x));
+}
+
+#[property_test(iterations = 50)]
+fn test_strategy_override_mixed(
+    #[strategy(protest::range(10u32, 20u32))] count: u32,
+    name: String,
+    #[strategy(protest::just(7u8))] flag: u8,
+) {
+    assert!((10..=20).contains(&count));
+    assert_eq!(flag, 7);
+    let _ = name.len();
+}
+
 // Async property test
 #[property_test]
 async fn test_async_property(x: i32) {
@@ -107,3 +137,15 @@ mod macro_expansion_tests {
         // This is verified by the fact that the async tests above compile
     }
 }
+
+#[derive(Debug, Clone, PartialEq, protest::Generator)]
+struct Point {
+    x: i16,
+    y: i16,
+}
+
+#[property_test(iterations = 20)]
+fn test_derived_type_parameter(point: Point, points: Vec<Point>) {
+    assert_eq!(point.clone(), point);
+    assert!(points.len() <= 100);
+}
@@ -532,6 +532,7 @@ fn regression_test_runner_integration() {
         shrink_steps: 5,
         seed: Some(123),
         duration: Duration::from_millis(200),
+        shrink_duration: Duration::from_millis(50),
     };
 
     let failure_output = format!("{}", failure_result);